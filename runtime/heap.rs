@@ -0,0 +1,123 @@
+//! A semispace heap with a copying (Cheney) garbage collector.
+//!
+//! # Value representation
+//!
+//! The collector relies on the tagged representation (`--tagged`): the
+//! lowest bit of every value says what it is.
+//!
+//! - `...0`: an integer or boolean, shifted left by one. Never followed.
+//! - `...1`: a pointer to a heap object, with the lowest bit set.
+//!
+//! A heap object is a header word followed by its fields. The header holds
+//! the number of fields shifted left by one, so it is always even. While a
+//! collection is running, the header of an object that has already been
+//! copied is overwritten by the (odd) tagged pointer to its new location.
+//!
+//! # Roots
+//!
+//! The collector only follows the values registered as roots with
+//! `Heap::push_root`, and updates them in place when it moves the objects
+//! they point to. Roots are kept on a stack, so `Heap::pop_roots(n)`
+//! drops the `n` most recently pushed ones.
+//!
+//! Compiled code has no heap values yet, so the runtime does not export
+//! the heap to it.
+
+/// A pair of semispaces plus the roots registered with `push_root`.
+pub struct Heap {
+    /// the semispace objects are allocated in
+    from: Vec<i64>,
+    /// the semispace live objects are copied to during a collection
+    to: Vec<i64>,
+    /// index of the first free word in `from`
+    top: usize,
+    roots: Vec<*mut i64>,
+}
+
+impl Heap {
+    /// A heap with two semispaces of `words` words each.
+    pub fn new(words: usize) -> Self {
+        Heap { from: vec![0; words], to: vec![0; words], top: 0, roots: Vec::new() }
+    }
+
+    /// Allocate an object with `fields` fields, all `0`, collecting first
+    /// if there is no room. Returns `None` if the live objects leave no
+    /// room even after a collection.
+    pub fn alloc(&mut self, fields: usize) -> Option<i64> {
+        if self.top + fields + 1 > self.from.len() {
+            self.collect();
+        }
+        if self.top + fields + 1 > self.from.len() {
+            return None;
+        }
+        let idx = self.top;
+        self.top += fields + 1;
+        self.from[idx] = (fields as i64) << 1;
+        for field in &mut self.from[idx + 1..self.top] {
+            *field = 0;
+        }
+        Some(&self.from[idx] as *const i64 as i64 | 1)
+    }
+
+    /// Register `slot` as a root.
+    ///
+    /// # Safety
+    ///
+    /// `slot` must stay valid for reads and writes until it is popped
+    /// again with `pop_roots`.
+    pub unsafe fn push_root(&mut self, slot: *mut i64) {
+        self.roots.push(slot);
+    }
+
+    /// Unregister the `n` most recently pushed roots.
+    pub fn pop_roots(&mut self, n: usize) {
+        let len = self.roots.len().saturating_sub(n);
+        self.roots.truncate(len);
+    }
+
+    /// Copy every object reachable from the roots into the other
+    /// semispace, update the roots to point at the copies, and make that
+    /// semispace the one new objects are allocated in.
+    pub fn collect(&mut self) {
+        let mut top = 0;
+        for &slot in &self.roots {
+            // Safety: guaranteed by the contract of `push_root`.
+            unsafe {
+                *slot = forward(&mut self.from, &mut self.to, &mut top, *slot);
+            }
+        }
+        // everything between `scan` and `top` has been copied, but its
+        // fields still point into the old semispace
+        let mut scan = 0;
+        while scan < top {
+            let fields = (self.to[scan] >> 1) as usize;
+            for i in scan + 1..scan + 1 + fields {
+                let val = self.to[i];
+                self.to[i] = forward(&mut self.from, &mut self.to, &mut top, val);
+            }
+            scan += fields + 1;
+        }
+        std::mem::swap(&mut self.from, &mut self.to);
+        self.top = top;
+    }
+}
+
+/// The value `val` should have once live objects are in `to`, copying the
+/// object it points to there if that has not happened yet.
+fn forward(from: &mut [i64], to: &mut [i64], top: &mut usize, val: i64) -> i64 {
+    if val & 1 == 0 {
+        return val;
+    }
+    let idx = ((val & !1) - from.as_ptr() as i64) as usize / std::mem::size_of::<i64>();
+    let header = from[idx];
+    if header & 1 == 1 {
+        // already copied; the header is the forwarding pointer
+        return header;
+    }
+    let len = (header >> 1) as usize + 1;
+    to[*top..*top + len].copy_from_slice(&from[idx..idx + len]);
+    let new = &to[*top] as *const i64 as i64 | 1;
+    from[idx] = new;
+    *top += len;
+    new
+}
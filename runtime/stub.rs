@@ -1,5 +1,6 @@
 #[link(name = "compiled_code", kind = "static")]
 extern "sysv64" {
+    // the compiler's ENTRY_FUN, unless it was given another entry point
     #[link_name = "\x01entry"]
     fn entry(param: i64) -> i64;
 }
@@ -10,6 +11,31 @@ extern "sysv64" fn print(x: i64) -> i64 {
     x
 }
 
+#[export_name = "\x01read_int"]
+extern "sysv64" fn read_int() -> i64 {
+    let mut line = String::new();
+    match std::io::stdin().read_line(&mut line) {
+        Ok(0) => {
+            eprintln!("unexpected end of input");
+            std::process::exit(1)
+        }
+        Ok(_) => line.trim().parse().unwrap_or_else(|_| {
+            eprintln!("invalid input: {}", line.trim());
+            std::process::exit(1)
+        }),
+        Err(e) => {
+            eprintln!("invalid input: {}", e);
+            std::process::exit(1)
+        }
+    }
+}
+
+#[export_name = "\x01snake_error"]
+extern "sysv64" fn snake_error(code: i64) -> i64 {
+    eprintln!("program aborted with error code {}", code);
+    std::process::exit(1)
+}
+
 #[export_name = "\x01big_fun_nine"]
 extern "sysv64" fn big_fun_nine(
     x1: i64, x2: i64, x3: i64, x4: i64, x5: i64, x6: i64, x7: i64, x8: i64,
@@ -21,6 +21,24 @@ pub enum Reg {
     R15,
 }
 
+impl Reg {
+    /// Whether a function may overwrite this register, according to the
+    /// System V ABI, so that a caller relying on its value has to save it.
+    pub fn is_caller_saved(&self) -> bool {
+        use Reg::*;
+        match self {
+            Rax | Rcx | Rdx | Rsi | Rdi | R8 | R9 | R10 | R11 => true,
+            Rbx | Rsp | Rbp | R12 | R13 | R14 | R15 => false,
+        }
+    }
+
+    /// Whether a function has to restore this register before returning,
+    /// according to the System V ABI.
+    pub fn is_callee_saved(&self) -> bool {
+        !self.is_caller_saved()
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Reg8 {
     Ah,
@@ -53,13 +71,47 @@ pub enum ConditionCode {
     LE,
     G,
     GE,
+    B,
+    BE,
+    A,
+    AE,
+    C,
+    NC,
     S,
+    NS,
     Z,
     NZ,
     O,
     NO,
 }
 
+impl ConditionCode {
+    /// The condition code that holds exactly when this one does not.
+    pub fn invert(self) -> Self {
+        use ConditionCode::*;
+        match self {
+            E => NE,
+            NE => E,
+            L => GE,
+            GE => L,
+            LE => G,
+            G => LE,
+            B => AE,
+            AE => B,
+            BE => A,
+            A => BE,
+            C => NC,
+            NC => C,
+            S => NS,
+            NS => S,
+            Z => NZ,
+            NZ => Z,
+            O => NO,
+            NO => O,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct MemRef {
     pub reg: Reg,
@@ -82,6 +134,20 @@ pub enum Arg32 {
     Mem(MemRef),
 }
 
+impl Arg32 {
+    /// The immediate `n`, which must fit in 32 bits. x86 sign-extends
+    /// 32-bit immediates, so larger constants have to be loaded into a
+    /// register with a 64-bit `mov` instead.
+    pub fn signed(n: i64) -> Self {
+        debug_assert!(
+            i32::try_from(n).is_ok(),
+            "{} does not fit in a 32-bit immediate",
+            n
+        );
+        Arg32::Signed(n as i32)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Reg32 {
     Reg(Reg),
@@ -116,10 +182,15 @@ pub enum Instr {
     And(BinArgs),
     Or(BinArgs),
     Xor(BinArgs),
+    Shl(BinArgs),
     Shr(BinArgs),
     Sar(BinArgs),
     Cmp(BinArgs),
     Test(BinArgs),
+    // Sign-extend rax into rdx:rax, the dividend idiv divides
+    Cqo,
+    // Fault immediately, where control should never reach
+    Ud2,
 
     Push(Arg32),
     Pop(Loc),
@@ -129,6 +200,9 @@ pub enum Instr {
     Section(String),
     Global(String),
     Extern(String),
+    // Any other assembler directive, such as a CFI directive or the
+    // GNU-stack note, written out as is but indented
+    Directive(String),
     // TODO: these should not be required to be strings
     Call(String),
     Ret,
@@ -138,6 +212,14 @@ pub enum Instr {
     CMovCC(ConditionCode, BinArgs),
     JCC(ConditionCode, String),
     SetCC(ConditionCode, Reg8),
+
+    // Jump tables: load the address of a label relative to rip, jump to
+    // the address stored at `base + index * 8`, and the table of label
+    // addresses itself, which belongs in a data section. Nothing checks
+    // the index, so code must make sure it is in bounds before jumping.
+    Lea(Reg, String),
+    JmpIndexed { base: Reg, index: Reg },
+    LabelTable(Vec<String>),
 }
 
 impl fmt::Display for ConditionCode {
@@ -150,7 +232,14 @@ impl fmt::Display for ConditionCode {
             LE => write!(f, "le"),
             G => write!(f, "g"),
             GE => write!(f, "ge"),
+            B => write!(f, "b"),
+            BE => write!(f, "be"),
+            A => write!(f, "a"),
+            AE => write!(f, "ae"),
+            C => write!(f, "c"),
+            NC => write!(f, "nc"),
             S => write!(f, "s"),
+            NS => write!(f, "ns"),
             Z => write!(f, "z"),
             NZ => write!(f, "nz"),
             O => write!(f, "o"),
@@ -293,6 +382,9 @@ pub fn instr_to_string(i: &Instr) -> String {
         Instr::Xor(args) => {
             format!("        xor {}", bin_args_to_string(*args))
         }
+        Instr::Shl(args) => {
+            format!("        shl {}", bin_args_to_string(*args))
+        }
         Instr::Shr(args) => {
             format!("        shr {}", bin_args_to_string(*args))
         }
@@ -326,6 +418,9 @@ pub fn instr_to_string(i: &Instr) -> String {
         Instr::Extern(s) => {
             format!("        extern {}", s)
         }
+        Instr::Directive(s) => {
+            format!("        {}", s)
+        }
 
         Instr::Call(s) => {
             format!("        call {}", s)
@@ -333,6 +428,8 @@ pub fn instr_to_string(i: &Instr) -> String {
         Instr::Ret => {
             format!("        ret")
         }
+        Instr::Cqo => "        cqo".to_string(),
+        Instr::Ud2 => "        ud2".to_string(),
 
         Instr::CMovCC(cc, args) => {
             format!("        cmov{} {}", cc, bin_args_to_string(*args))
@@ -347,14 +444,581 @@ pub fn instr_to_string(i: &Instr) -> String {
         Instr::SetCC(cc, a) => {
             format!("        set{} {}", cc, a)
         }
+        Instr::Lea(r, l) => {
+            format!("        lea {}, [rel {}]", reg_to_string(*r), l)
+        }
+        Instr::JmpIndexed { base, index } => {
+            format!(
+                "        jmp QWORD [{} + {} * 8]",
+                reg_to_string(*base),
+                reg_to_string(*index)
+            )
+        }
+        Instr::LabelTable(ls) => {
+            format!("        dq {}", ls.join(", "))
+        }
     }
 }
 
 pub fn instrs_to_string(is: &[Instr]) -> String {
     let mut buf = String::new();
     for i in is {
-        buf.push_str(&instr_to_string(&i));
+        buf.push_str(&instr_to_string(i));
         buf.push_str("\n");
     }
     buf
 }
+
+/// Like `instrs_to_string`, but with the index of each instruction in
+/// front of it, for reading the code in test failures.
+pub fn instrs_pretty(is: &[Instr]) -> String {
+    let width = is.len().saturating_sub(1).to_string().len();
+    let mut buf = String::new();
+    for (ix, i) in is.iter().enumerate() {
+        buf.push_str(&format!("{:>width$} {}\n", ix, instr_to_string(i)));
+    }
+    buf
+}
+
+/// An interpreter for the subset of x86-64 the backend emits, so that
+/// compiled programs can run without an assembler or a linker.
+///
+/// The machine has the sixteen general purpose registers, the flags set
+/// by the arithmetic instructions and a sparse memory, addressed in bytes,
+/// that holds the stack. Only whole, 8-byte aligned words are ever read
+/// or written. A read of a word that was never written is an error, as is
+/// a call made with a stack that is not 16-byte aligned, since both are
+/// backend bugs that would go unnoticed on real hardware most of the time.
+///
+/// Code labels stand for the index of the instruction they label. A
+/// label in front of a `LabelTable`, or of a `dq` directive defining an
+/// integer, stands for the address of the data, which is laid out in
+/// memory apart from the stack.
+///
+/// Calls to labels that are not defined in the program go to the
+/// runtime, which is simulated for the functions in `runtime/stub.rs`
+/// that do not need the heap.
+pub mod interp {
+    use super::*;
+    use crate::interp::{ExternFn, ExternTable};
+    use std::collections::HashMap;
+    use std::io::{BufRead, Write};
+
+    /// Where the stack starts. Any 16-byte aligned address will do.
+    const STACK_TOP: i64 = 1 << 40;
+
+    /// The return address pushed by the call to `entry`: returning to it
+    /// ends the program.
+    const HALT: i64 = -1;
+
+    /// Where label tables and data words are laid out.
+    const DATA_BASE: i64 = 1 << 20;
+
+    /// How many words below `rsp` a runtime call overwrites.
+    const CALLEE_FRAME_WORDS: i64 = 32;
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum MachineErr {
+        UnknownLabel(String),
+        UnknownExtern(String),
+        MisalignedCall(String),
+        UninitializedRead(i64),
+        FellOffEnd,
+        InvalidInput(String),
+        EndOfInput,
+        Output(String),
+        UserError(i64),
+        /// Executed a `ud2`.
+        Trap,
+    }
+
+    impl fmt::Display for MachineErr {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                MachineErr::UnknownLabel(l) => {
+                    write!(f, "unknown label: {}", l)
+                }
+                MachineErr::UnknownExtern(l) => {
+                    write!(f, "unsupported runtime function: {}", l)
+                }
+                MachineErr::MisalignedCall(l) => {
+                    write!(f, "call to {} with a misaligned stack", l)
+                }
+                MachineErr::UninitializedRead(addr) => write!(
+                    f,
+                    "read of uninitialized memory at stack top {:+}",
+                    addr - STACK_TOP
+                ),
+                MachineErr::FellOffEnd => {
+                    write!(f, "ran past the end of the code")
+                }
+                MachineErr::InvalidInput(l) => {
+                    write!(f, "invalid input: {}", l)
+                }
+                MachineErr::EndOfInput => {
+                    write!(f, "unexpected end of input")
+                }
+                MachineErr::Output(e) => write!(f, "I/O error: {}", e),
+                MachineErr::UserError(code) => {
+                    write!(f, "program aborted with error code {}", code)
+                }
+                MachineErr::Trap => write!(f, "executed ud2"),
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Default)]
+    struct Flags {
+        zf: bool,
+        sf: bool,
+        cf: bool,
+        of: bool,
+    }
+
+    impl Flags {
+        fn of_result(res: i64, cf: bool, of: bool) -> Self {
+            Flags { zf: res == 0, sf: res < 0, cf, of }
+        }
+
+        fn holds(self, cc: ConditionCode) -> bool {
+            use ConditionCode::*;
+            let Flags { zf, sf, cf, of } = self;
+            match cc {
+                E | Z => zf,
+                NE | NZ => !zf,
+                L => sf != of,
+                LE => zf || sf != of,
+                G => !zf && sf == of,
+                GE => sf == of,
+                B | C => cf,
+                BE => cf || zf,
+                A => !cf && !zf,
+                AE | NC => !cf,
+                S => sf,
+                NS => !sf,
+                O => of,
+                NO => !of,
+            }
+        }
+    }
+
+    pub struct Machine {
+        regs: [i64; 16],
+        flags: Flags,
+        mem: HashMap<i64, i64>,
+        /// where calls to `read_int` read from
+        input: Box<dyn BufRead>,
+        /// the runtime functions given with `with_extern`
+        extern_fns: ExternTable,
+    }
+
+    impl Machine {
+        pub fn new() -> Self {
+            Machine {
+                regs: [0; 16],
+                flags: Flags::default(),
+                mem: HashMap::new(),
+                input: Box::new(std::io::empty()),
+                extern_fns: ExternTable::new(),
+            }
+        }
+
+        /// Serve calls to `read_int` from `input`, one integer per line.
+        pub fn with_input(mut self, input: impl BufRead + 'static) -> Self {
+            self.input = Box::new(input);
+            self
+        }
+
+        /// Implement the runtime function `name`, of `arity` arguments,
+        /// with `f`, besides `read_int`, `print` and `snake_error`.
+        pub fn with_extern(
+            mut self, name: &str, arity: usize, f: ExternFn,
+        ) -> Self {
+            self.extern_fns.insert(name.to_string(), (arity, f));
+            self
+        }
+
+        /// Call the entry point, the symbol the code declares global, with
+        /// `arg` like the runtime does, writing whatever the program prints
+        /// to `out`, and return its result.
+        pub fn run(
+            &mut self, instrs: &[Instr], arg: i64, out: &mut dyn Write,
+        ) -> Result<i64, MachineErr> {
+            let labels: HashMap<&str, usize> = instrs
+                .iter()
+                .enumerate()
+                .filter_map(|(i, instr)| match instr {
+                    Instr::Label(l) => Some((l.as_str(), i)),
+                    _ => None,
+                })
+                .collect();
+            let jump = |l: &str| {
+                labels
+                    .get(l)
+                    .copied()
+                    .ok_or_else(|| MachineErr::UnknownLabel(l.to_string()))
+            };
+
+            let mut data = HashMap::new();
+            let mut next = DATA_BASE;
+            for pair in instrs.windows(2) {
+                match pair {
+                    [Instr::Label(l), Instr::LabelTable(targets)] => {
+                        data.insert(l.as_str(), next);
+                        for target in targets {
+                            self.mem.insert(next, jump(target)? as i64);
+                            next += 8;
+                        }
+                    }
+                    [Instr::Label(l), Instr::Directive(d)] => {
+                        let word = d.strip_prefix("dq ").map(str::parse);
+                        if let Some(Ok(n)) = word {
+                            data.insert(l.as_str(), next);
+                            self.mem.insert(next, n);
+                            next += 8;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            self.regs[Reg::Rsp as usize] = STACK_TOP;
+            self.regs[Reg::Rdi as usize] = arg;
+            self.push(HALT);
+            let entry = instrs
+                .iter()
+                .find_map(|instr| match instr {
+                    Instr::Global(l) => Some(l.as_str()),
+                    _ => None,
+                })
+                .unwrap_or(crate::ssa::ENTRY_FUN);
+            let mut pc = jump(entry)?;
+            loop {
+                let instr = instrs.get(pc).ok_or(MachineErr::FellOffEnd)?;
+                pc += 1;
+                match instr {
+                    Instr::Mov(MovArgs::ToReg(reg, src)) => {
+                        let val = self.arg64(*src)?;
+                        self.set(*reg, val)
+                    }
+                    Instr::Mov(MovArgs::ToMem(mem, src)) => {
+                        let val = self.reg32(*src);
+                        self.store(*mem, val)
+                    }
+                    Instr::Add(args) => {
+                        self.bin_op(*args, true, |a, b| {
+                            let (res, of) = a.overflowing_add(b);
+                            let cf = (a as u64).overflowing_add(b as u64).1;
+                            (res, Flags::of_result(res, cf, of))
+                        })?
+                    }
+                    Instr::Sub(args) => self.bin_op(*args, true, sub)?,
+                    Instr::Cmp(args) => self.bin_op(*args, false, sub)?,
+                    Instr::IMul(args) => {
+                        self.bin_op(*args, true, |a, b| {
+                            let (res, of) = a.overflowing_mul(b);
+                            (res, Flags::of_result(res, of, of))
+                        })?
+                    }
+                    Instr::Cqo => {
+                        self.set(Reg::Rdx, self.get(Reg::Rax) >> 63)
+                    }
+                    Instr::And(args) => {
+                        self.bin_op(*args, true, |a, b| logical(a & b))?
+                    }
+                    Instr::Test(args) => {
+                        self.bin_op(*args, false, |a, b| logical(a & b))?
+                    }
+                    Instr::Or(args) => {
+                        self.bin_op(*args, true, |a, b| logical(a | b))?
+                    }
+                    Instr::Xor(args) => {
+                        self.bin_op(*args, true, |a, b| logical(a ^ b))?
+                    }
+                    // the carry and overflow flags are not modelled for
+                    // shifts, since the backend never tests them
+                    Instr::Shl(args) => {
+                        self.bin_op(*args, true, |a, b| {
+                            logical(a.wrapping_shl(b as u32 & 63))
+                        })?
+                    }
+                    Instr::Shr(args) => {
+                        self.bin_op(*args, true, |a, b| {
+                            logical(((a as u64) >> (b & 63)) as i64)
+                        })?
+                    }
+                    Instr::Sar(args) => {
+                        self.bin_op(*args, true, |a, b| {
+                            logical(a >> (b & 63))
+                        })?
+                    }
+                    Instr::CMovCC(cc, args) => {
+                        let holds = self.flags.holds(*cc);
+                        let flags = self.flags;
+                        self.bin_op(*args, holds, |_, b| (b, flags))?
+                    }
+                    Instr::SetCC(cc, reg8) => {
+                        let bit = self.flags.holds(*cc) as i64;
+                        self.set_reg8(*reg8, bit)
+                    }
+                    Instr::Push(arg) => {
+                        let val = self.arg32(*arg)?;
+                        self.push(val)
+                    }
+                    Instr::Pop(loc) => {
+                        let val = self.pop()?;
+                        match loc {
+                            Loc::Reg(reg) => self.set(*reg, val),
+                            Loc::Mem(mem) => self.store(*mem, val),
+                        }
+                    }
+                    Instr::Jmp(l) => pc = jump(l)?,
+                    Instr::JCC(cc, l) => {
+                        if self.flags.holds(*cc) {
+                            pc = jump(l)?
+                        }
+                    }
+                    Instr::Call(l) => {
+                        if self.get(Reg::Rsp) % 16 != 0 {
+                            return Err(MachineErr::MisalignedCall(
+                                l.clone(),
+                            ));
+                        }
+                        if labels.contains_key(l.as_str()) {
+                            self.push(pc as i64);
+                            pc = jump(l)?
+                        } else {
+                            let res = self.call_runtime(l, out)?;
+                            self.set(Reg::Rax, res)
+                        }
+                    }
+                    Instr::Lea(reg, l) => {
+                        let addr = match data.get(l.as_str()) {
+                            Some(addr) => *addr,
+                            None => jump(l)? as i64,
+                        };
+                        self.set(*reg, addr)
+                    }
+                    Instr::JmpIndexed { base, index } => {
+                        let addr = self.get(*base) + self.get(*index) * 8;
+                        pc = self.load(addr)? as usize
+                    }
+                    Instr::LabelTable(_) => {
+                        return Err(MachineErr::FellOffEnd)
+                    }
+                    Instr::Ud2 => return Err(MachineErr::Trap),
+                    Instr::Ret => match self.pop()? {
+                        HALT => return Ok(self.get(Reg::Rax)),
+                        ret => pc = ret as usize,
+                    },
+                    Instr::Label(_)
+                    | Instr::Comment(_)
+                    | Instr::Section(_)
+                    | Instr::Global(_)
+                    | Instr::Extern(_)
+                    | Instr::Directive(_) => {}
+                }
+            }
+        }
+
+        /// Run the runtime function `name`, following the calling
+        /// convention: the first six arguments are in registers and the
+        /// rest on the stack, and caller-saved registers are clobbered,
+        /// as is the stack below `rsp`, where the callee's frame would be.
+        fn call_runtime(
+            &mut self, name: &str, out: &mut dyn Write,
+        ) -> Result<i64, MachineErr> {
+            const ARG_REGS: [Reg; 6] =
+                [Reg::Rdi, Reg::Rsi, Reg::Rdx, Reg::Rcx, Reg::R8, Reg::R9];
+            let arity = match name {
+                "read_int" => 0,
+                "print" | "snake_error" => 1,
+                _ => match self.extern_fns.get(name) {
+                    Some(&(arity, _)) => arity,
+                    None => {
+                        return Err(MachineErr::UnknownExtern(
+                            name.to_string(),
+                        ))
+                    }
+                },
+            };
+            let mut args = Vec::with_capacity(arity);
+            for i in 0..arity {
+                args.push(match ARG_REGS.get(i) {
+                    Some(reg) => self.get(*reg),
+                    None => {
+                        let rsp = self.get(Reg::Rsp);
+                        self.load(rsp + 8 * (i - ARG_REGS.len()) as i64)?
+                    }
+                });
+            }
+            let print = |out: &mut dyn Write, s: String| {
+                writeln!(out, "{}", s)
+                    .map_err(|e| MachineErr::Output(e.to_string()))
+            };
+            let res = match name {
+                "read_int" => {
+                    let mut line = String::new();
+                    match self.input.read_line(&mut line) {
+                        Ok(0) => return Err(MachineErr::EndOfInput),
+                        Ok(_) => line.trim().parse().map_err(|_| {
+                            MachineErr::InvalidInput(line.trim().to_string())
+                        })?,
+                        Err(e) => {
+                            return Err(MachineErr::InvalidInput(
+                                e.to_string(),
+                            ))
+                        }
+                    }
+                }
+                "print" => {
+                    print(out, args[0].to_string())?;
+                    args[0]
+                }
+                "snake_error" => return Err(MachineErr::UserError(args[0])),
+                _ => (self.extern_fns[name].1)(&args, out)
+                    .map_err(|e| MachineErr::Output(e.to_string()))?,
+            };
+            for reg in ARG_REGS.iter().chain(&[Reg::R10, Reg::R11]) {
+                self.set(*reg, 0x5EED_DEAD);
+            }
+            let rsp = self.get(Reg::Rsp);
+            for i in 1..=CALLEE_FRAME_WORDS {
+                self.mem.insert(rsp - 8 * i, 0x5EED_DEAD);
+            }
+            Ok(res)
+        }
+
+        /// Apply `op` to the destination and source of `args`, setting
+        /// the flags, and store the result only if `write` is set.
+        fn bin_op(
+            &mut self, args: BinArgs, write: bool,
+            op: impl Fn(i64, i64) -> (i64, Flags),
+        ) -> Result<(), MachineErr> {
+            let (dst, src) = match args {
+                BinArgs::ToReg(reg, src) => {
+                    (Loc::Reg(reg), self.arg32(src)?)
+                }
+                BinArgs::ToMem(mem, src) => (Loc::Mem(mem), self.reg32(src)),
+            };
+            let cur = match dst {
+                Loc::Reg(reg) => self.get(reg),
+                Loc::Mem(mem) => self.load(self.addr(mem))?,
+            };
+            let (res, flags) = op(cur, src);
+            self.flags = flags;
+            if write {
+                match dst {
+                    Loc::Reg(reg) => self.set(reg, res),
+                    Loc::Mem(mem) => self.store(mem, res),
+                }
+            }
+            Ok(())
+        }
+
+        fn get(&self, reg: Reg) -> i64 {
+            self.regs[reg as usize]
+        }
+
+        fn set(&mut self, reg: Reg, val: i64) {
+            self.regs[reg as usize] = val;
+        }
+
+        fn set_reg8(&mut self, reg8: Reg8, val: i64) {
+            use Reg8::*;
+            let (reg, shift) = match reg8 {
+                Al => (Reg::Rax, 0),
+                Ah => (Reg::Rax, 8),
+                Bl => (Reg::Rbx, 0),
+                Bh => (Reg::Rbx, 8),
+                Cl => (Reg::Rcx, 0),
+                Ch => (Reg::Rcx, 8),
+                Dl => (Reg::Rdx, 0),
+                Dh => (Reg::Rdx, 8),
+                Spl => (Reg::Rsp, 0),
+                Bpl => (Reg::Rbp, 0),
+                Sil => (Reg::Rsi, 0),
+                Dil => (Reg::Rdi, 0),
+                R8b => (Reg::R8, 0),
+                R9b => (Reg::R9, 0),
+                R10b => (Reg::R10, 0),
+                R11b => (Reg::R11, 0),
+                R12b => (Reg::R12, 0),
+                R13b => (Reg::R13, 0),
+                R14b => (Reg::R14, 0),
+                R15b => (Reg::R15, 0),
+            };
+            let mask = 0xff << shift;
+            let old = self.get(reg);
+            self.set(reg, (old & !mask) | ((val << shift) & mask));
+        }
+
+        fn addr(&self, MemRef { reg, offset }: MemRef) -> i64 {
+            self.get(reg) + offset as i64
+        }
+
+        fn load(&self, addr: i64) -> Result<i64, MachineErr> {
+            self.mem
+                .get(&addr)
+                .copied()
+                .ok_or(MachineErr::UninitializedRead(addr))
+        }
+
+        fn store(&mut self, mem: MemRef, val: i64) {
+            self.mem.insert(self.addr(mem), val);
+        }
+
+        fn push(&mut self, val: i64) {
+            let rsp = self.get(Reg::Rsp) - 8;
+            self.set(Reg::Rsp, rsp);
+            self.mem.insert(rsp, val);
+        }
+
+        fn pop(&mut self) -> Result<i64, MachineErr> {
+            let rsp = self.get(Reg::Rsp);
+            let val = self.load(rsp)?;
+            self.set(Reg::Rsp, rsp + 8);
+            Ok(val)
+        }
+
+        fn arg64(&self, arg: Arg64) -> Result<i64, MachineErr> {
+            match arg {
+                Arg64::Reg(reg) => Ok(self.get(reg)),
+                Arg64::Signed(n) => Ok(n),
+                Arg64::Unsigned(n) => Ok(n as i64),
+                Arg64::Mem(mem) => self.load(self.addr(mem)),
+            }
+        }
+
+        fn arg32(&self, arg: Arg32) -> Result<i64, MachineErr> {
+            match arg {
+                Arg32::Reg(reg) => Ok(self.get(reg)),
+                Arg32::Signed(n) => Ok(n as i64),
+                Arg32::Unsigned(n) => Ok(n as i64),
+                Arg32::Mem(mem) => self.load(self.addr(mem)),
+            }
+        }
+
+        fn reg32(&self, arg: Reg32) -> i64 {
+            match arg {
+                Reg32::Reg(reg) => self.get(reg),
+                Reg32::Imm(n) => n as i64,
+            }
+        }
+    }
+
+    impl Default for Machine {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    fn sub(a: i64, b: i64) -> (i64, Flags) {
+        let (res, of) = a.overflowing_sub(b);
+        (res, Flags::of_result(res, (a as u64) < (b as u64), of))
+    }
+
+    fn logical(res: i64) -> (i64, Flags) {
+        (res, Flags::of_result(res, false, false))
+    }
+}
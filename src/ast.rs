@@ -7,12 +7,14 @@ pub type SurfProg = Prog<String, String>;
 pub type SurfExtDecl = ExtDecl<String, String>;
 pub type SurfFunDecl = FunDecl<String, String>;
 pub type SurfBinding = Binding<String, String>;
+pub type SurfMatchArm = MatchArm<String, String>;
 pub type SurfExpr = Expr<String, String>;
 
 pub type BoundProg = Prog<VarName, FunName>;
 pub type BoundExtDecl = ExtDecl<VarName, FunName>;
 pub type BoundFunDecl = FunDecl<VarName, FunName>;
 pub type BoundBinding = Binding<VarName, FunName>;
+pub type BoundMatchArm = MatchArm<VarName, FunName>;
 pub type BoundExpr = Expr<VarName, FunName>;
 
 /* ----------------------------------- AST ---------------------------------- */
@@ -20,6 +22,9 @@ pub type BoundExpr = Expr<VarName, FunName>;
 #[derive(Clone, Debug)]
 pub struct Prog<Var, Fun> {
     pub externs: Vec<ExtDecl<Var, Fun>>,
+    /// The functions declared at the top level, before `main`. They may
+    /// all call each other, and `main` may call them.
+    pub funs: Vec<FunDecl<Var, Fun>>,
     /// The name of the main function. Should always be "main".
     pub name: Fun,
     pub param: (Var, SrcLoc),
@@ -40,6 +45,7 @@ pub enum Expr<Var, Fun> {
     Let {
         bindings: Vec<Binding<Var, Fun>>,
         body: Box<Expr<Var, Fun>>,
+        kind: LetKind,
         loc: SrcLoc,
     },
     If {
@@ -53,6 +59,16 @@ pub enum Expr<Var, Fun> {
         body: Box<Expr<Var, Fun>>,
         loc: SrcLoc,
     },
+    /// `match e { n1 => e1, ..., _ => default }`: the body of the first
+    /// arm whose pattern equals the value of `scrutinee`, or `default` if
+    /// none does. The parser accepts a match without `_`, which the
+    /// resolver rejects.
+    Match {
+        scrutinee: Box<Expr<Var, Fun>>,
+        arms: Vec<MatchArm<Var, Fun>>,
+        default: Option<Box<Expr<Var, Fun>>>,
+        loc: SrcLoc,
+    },
     Call {
         fun: Fun,
         args: Vec<Expr<Var, Fun>>,
@@ -60,6 +76,154 @@ pub enum Expr<Var, Fun> {
     },
 }
 
+impl<Var, Fun> Expr<Var, Fun> {
+    /// The source this expression was parsed from.
+    pub fn loc(&self) -> SrcLoc {
+        match self {
+            Expr::Num(_, loc) | Expr::Bool(_, loc) | Expr::Var(_, loc) => {
+                *loc
+            }
+            Expr::Prim { loc, .. }
+            | Expr::Let { loc, .. }
+            | Expr::If { loc, .. }
+            | Expr::FunDefs { loc, .. }
+            | Expr::Match { loc, .. }
+            | Expr::Call { loc, .. } => *loc,
+        }
+    }
+}
+
+/* --------------------------------- Renaming -------------------------------- */
+
+impl<Var, Fun> Prog<Var, Fun> {
+    /// This program with every variable renamed by `var` and every
+    /// function by `fun`. Names are visited in the order they appear in
+    /// the source.
+    pub fn map_names<V, F>(
+        &self, var: &mut impl FnMut(&Var) -> V,
+        fun: &mut impl FnMut(&Fun) -> F,
+    ) -> Prog<V, F> {
+        Prog {
+            externs: self
+                .externs
+                .iter()
+                .map(|ext| ExtDecl {
+                    name: fun(&ext.name),
+                    params: map_params(&ext.params, var),
+                    loc: ext.loc,
+                })
+                .collect(),
+            funs: map_decls(&self.funs, var, fun),
+            name: fun(&self.name),
+            param: (var(&self.param.0), self.param.1),
+            body: self.body.map_names(var, fun),
+            loc: self.loc,
+        }
+    }
+}
+
+impl<Var, Fun> Expr<Var, Fun> {
+    /// This expression with every variable renamed by `var` and every
+    /// function by `fun`, as `Prog::map_names`.
+    pub fn map_names<V, F>(
+        &self, var: &mut impl FnMut(&Var) -> V,
+        fun: &mut impl FnMut(&Fun) -> F,
+    ) -> Expr<V, F> {
+        let mut boxed = |e: &Expr<Var, Fun>| Box::new(e.map_names(var, fun));
+        match self {
+            Expr::Num(n, loc) => Expr::Num(*n, *loc),
+            Expr::Bool(b, loc) => Expr::Bool(*b, *loc),
+            Expr::Var(v, loc) => Expr::Var(var(v), *loc),
+            Expr::Prim { prim, args, loc } => Expr::Prim {
+                prim: prim.clone(),
+                args: args.iter().map(|e| e.map_names(var, fun)).collect(),
+                loc: *loc,
+            },
+            Expr::Let { bindings, body, kind, loc } => Expr::Let {
+                bindings: bindings
+                    .iter()
+                    .map(|Binding { var: (v, v_loc), expr }| Binding {
+                        var: (var(v), *v_loc),
+                        expr: expr.map_names(var, fun),
+                    })
+                    .collect(),
+                body: Box::new(body.map_names(var, fun)),
+                kind: *kind,
+                loc: *loc,
+            },
+            Expr::If { cond, thn, els, loc } => Expr::If {
+                cond: boxed(cond),
+                thn: boxed(thn),
+                els: boxed(els),
+                loc: *loc,
+            },
+            Expr::FunDefs { decls, body, loc } => Expr::FunDefs {
+                decls: map_decls(decls, var, fun),
+                body: Box::new(body.map_names(var, fun)),
+                loc: *loc,
+            },
+            Expr::Match { scrutinee, arms, default, loc } => Expr::Match {
+                scrutinee: Box::new(scrutinee.map_names(var, fun)),
+                arms: arms
+                    .iter()
+                    .map(|MatchArm { pattern, body }| MatchArm {
+                        pattern: *pattern,
+                        body: body.map_names(var, fun),
+                    })
+                    .collect(),
+                default: default
+                    .as_ref()
+                    .map(|e| Box::new(e.map_names(var, fun))),
+                loc: *loc,
+            },
+            Expr::Call { fun: f, args, loc } => Expr::Call {
+                fun: fun(f),
+                args: args.iter().map(|e| e.map_names(var, fun)).collect(),
+                loc: *loc,
+            },
+        }
+    }
+}
+
+/// Every function of a group is named before any of their bodies, since
+/// they may all call each other.
+fn map_decls<Var, Fun, V, F>(
+    decls: &[FunDecl<Var, Fun>], var: &mut impl FnMut(&Var) -> V,
+    fun: &mut impl FnMut(&Fun) -> F,
+) -> Vec<FunDecl<V, F>> {
+    let names: Vec<_> = decls.iter().map(|decl| fun(&decl.name)).collect();
+    decls
+        .iter()
+        .zip(names)
+        .map(|(decl, name)| FunDecl {
+            name,
+            params: map_params(&decl.params, var),
+            body: decl.body.map_names(var, fun),
+            loc: decl.loc,
+        })
+        .collect()
+}
+
+fn map_params<Var, V>(
+    params: &[(Var, SrcLoc)], var: &mut impl FnMut(&Var) -> V,
+) -> Vec<(V, SrcLoc)> {
+    params.iter().map(|(p, loc)| (var(p), *loc)).collect()
+}
+
+/// How the bindings of a `let` see each other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LetKind {
+    /// `let x = e1, y = e2 in ...`: each binding is in scope in the
+    /// bindings after it.
+    Sequential,
+    /// `let x = e1 and y = e2 in ...`: every right-hand side only sees
+    /// the scope outside the `let`.
+    Parallel,
+    /// `letrec x = e1, y = e2 in ...`: every binding is in scope in every
+    /// right-hand side. Only function values may be bound recursively.
+    Recursive,
+}
+
 #[derive(Clone, Debug)]
 pub struct ExtDecl<Var, Fun> {
     pub name: Fun,
@@ -75,6 +239,13 @@ pub struct Binding<Var, Fun> {
     pub expr: Expr<Var, Fun>,
 }
 
+/// An arm of a `match`, with the integer it matches and where that is.
+#[derive(Clone, Debug)]
+pub struct MatchArm<Var, Fun> {
+    pub pattern: (i64, SrcLoc),
+    pub body: Expr<Var, Fun>,
+}
+
 #[derive(Clone, Debug)]
 pub struct FunDecl<Var, Fun> {
     pub name: Fun,
@@ -104,12 +275,92 @@ pub enum Prim {
     Ge,
     Eq,
     Neq,
+    // three-way comparison: -1, 0 or 1 as the first argument is less
+    // than, equal to or greater than the second
+    Cmp,
+    // aborts the program with an error code
+    Error,
+}
+
+/// How a primitive is written in the source.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fixity {
+    /// applied like a function, as in `add1(x)`
+    Call,
+    /// an operator before its operand, as in `!x`
+    Prefix,
+    /// an operator between its operands, as in `x + y`. Operators with a
+    /// higher precedence bind tighter.
+    Infix { precedence: u8, assoc: Assoc },
+}
+
+/// Which way a chain of infix operators of the same precedence groups.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Assoc {
+    Left,
+    Right,
+}
+
+/// What the parser, pretty-printer and interpreters agree on about a
+/// primitive, see [`Prim::info`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrimInfo {
+    pub arity: usize,
+    pub fixity: Fixity,
+    /// the token the primitive is written with
+    pub spelling: &'static str,
 }
 
 impl Prim {
+    /// Every primitive, in the order they are declared.
+    pub const ALL: [Prim; 16] = [
+        Prim::Add1,
+        Prim::Sub1,
+        Prim::Add,
+        Prim::Sub,
+        Prim::Mul,
+        Prim::Not,
+        Prim::And,
+        Prim::Or,
+        Prim::Lt,
+        Prim::Le,
+        Prim::Gt,
+        Prim::Ge,
+        Prim::Eq,
+        Prim::Neq,
+        Prim::Cmp,
+        Prim::Error,
+    ];
+
+    /// The arity, fixity and spelling of this primitive. The grammar
+    /// encodes the same precedences in the nesting of its operator
+    /// levels, so the two must be changed together.
+    pub fn info(&self) -> PrimInfo {
+        use Assoc::*;
+        let (arity, fixity, spelling) = match self {
+            Prim::Add1 => (1, Fixity::Call, "add1"),
+            Prim::Sub1 => (1, Fixity::Call, "sub1"),
+            Prim::Error => (1, Fixity::Call, "error"),
+            Prim::Cmp => (2, Fixity::Call, "cmp"),
+            Prim::Not => (1, Fixity::Prefix, "!"),
+            Prim::And => (2, infix(1, Right), "&&"),
+            Prim::Or => (2, infix(1, Right), "||"),
+            Prim::Lt => (2, infix(2, Left), "<"),
+            Prim::Le => (2, infix(2, Left), "<="),
+            Prim::Gt => (2, infix(2, Left), ">"),
+            Prim::Ge => (2, infix(2, Left), ">="),
+            Prim::Eq => (2, infix(2, Left), "=="),
+            Prim::Neq => (2, infix(2, Left), "!="),
+            Prim::Add => (2, infix(3, Left), "+"),
+            Prim::Sub => (2, infix(3, Left), "-"),
+            Prim::Mul => (2, infix(4, Left), "*"),
+        };
+        PrimInfo { arity, fixity, spelling }
+    }
+
     pub fn arity(&self) -> usize {
         match self {
-            Prim::Add1 | Prim::Sub1 | Prim::Not => 1,
+            Prim::Add1 | Prim::Sub1 | Prim::Not | Prim::Error => 1,
             Prim::Add
             | Prim::Sub
             | Prim::Mul
@@ -120,7 +371,110 @@ impl Prim {
             | Prim::Gt
             | Prim::Ge
             | Prim::Eq
-            | Prim::Neq => 2,
+            | Prim::Neq
+            | Prim::Cmp => 2,
+        }
+    }
+}
+
+fn infix(precedence: u8, assoc: Assoc) -> Fixity {
+    Fixity::Infix { precedence, assoc }
+}
+
+/* ---------------------------------- Spans --------------------------------- */
+
+/// The kind of a node in the AST, as reported by [`spans`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    Prog,
+    Extern,
+    Param,
+    Num,
+    Bool,
+    Var,
+    Prim,
+    Let,
+    Binding,
+    If,
+    FunDefs,
+    FunDecl,
+    Match,
+    Pattern,
+    Call,
+}
+
+/// Every node of a program together with its location in the source,
+/// in pre-order.
+pub fn spans<Var, Fun>(prog: &Prog<Var, Fun>) -> Vec<(NodeKind, SrcLoc)> {
+    let mut out = vec![(NodeKind::Prog, prog.loc)];
+    for ext in &prog.externs {
+        out.push((NodeKind::Extern, ext.loc));
+        out.extend(
+            ext.params.iter().map(|(_, loc)| (NodeKind::Param, *loc)),
+        );
+    }
+    for decl in &prog.funs {
+        decl_spans(decl, &mut out);
+    }
+    out.push((NodeKind::Param, prog.param.1));
+    expr_spans(&prog.body, &mut out);
+    out
+}
+
+fn expr_spans<Var, Fun>(
+    expr: &Expr<Var, Fun>, out: &mut Vec<(NodeKind, SrcLoc)>,
+) {
+    match expr {
+        Expr::Num(_, loc) => out.push((NodeKind::Num, *loc)),
+        Expr::Bool(_, loc) => out.push((NodeKind::Bool, *loc)),
+        Expr::Var(_, loc) => out.push((NodeKind::Var, *loc)),
+        Expr::Prim { args, loc, .. } => {
+            out.push((NodeKind::Prim, *loc));
+            args.iter().for_each(|arg| expr_spans(arg, out));
+        }
+        Expr::Let { bindings, body, loc, .. } => {
+            out.push((NodeKind::Let, *loc));
+            for Binding { var: (_, var_loc), expr } in bindings {
+                out.push((NodeKind::Binding, *var_loc));
+                expr_spans(expr, out);
+            }
+            expr_spans(body, out);
+        }
+        Expr::If { cond, thn, els, loc } => {
+            out.push((NodeKind::If, *loc));
+            expr_spans(cond, out);
+            expr_spans(thn, out);
+            expr_spans(els, out);
+        }
+        Expr::FunDefs { decls, body, loc } => {
+            out.push((NodeKind::FunDefs, *loc));
+            for decl in decls {
+                decl_spans(decl, out);
+            }
+            expr_spans(body, out);
+        }
+        Expr::Match { scrutinee, arms, default, loc } => {
+            out.push((NodeKind::Match, *loc));
+            expr_spans(scrutinee, out);
+            for MatchArm { pattern: (_, pattern_loc), body } in arms {
+                out.push((NodeKind::Pattern, *pattern_loc));
+                expr_spans(body, out);
+            }
+            if let Some(default) = default {
+                expr_spans(default, out);
+            }
+        }
+        Expr::Call { args, loc, .. } => {
+            out.push((NodeKind::Call, *loc));
+            args.iter().for_each(|arg| expr_spans(arg, out));
         }
     }
 }
+
+fn decl_spans<Var, Fun>(
+    decl: &FunDecl<Var, Fun>, out: &mut Vec<(NodeKind, SrcLoc)>,
+) {
+    out.push((NodeKind::FunDecl, decl.loc));
+    out.extend(decl.params.iter().map(|(_, l)| (NodeKind::Param, *l)));
+    expr_spans(&decl.body, out);
+}
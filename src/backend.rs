@@ -5,68 +5,332 @@
 use crate::asm::*;
 use crate::identifiers::*;
 use crate::middle_end::Lowerer;
+use crate::span::SrcLoc;
 use crate::ssa::*;
+use crate::txt::FileInfo;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 static REG_ARG_LOCS: [Reg; 6] =
     [Reg::Rdi, Reg::Rsi, Reg::Rdx, Reg::Rcx, Reg::R8, Reg::R9];
 
+/// The registers variables may be kept in: all but rax and r10, which the
+/// emitter uses as scratch registers, and the stack registers.
+pub static ALLOCATABLE_REGS: [Reg; 12] = [
+    Reg::Rbx,
+    Reg::Rcx,
+    Reg::Rdx,
+    Reg::Rsi,
+    Reg::Rdi,
+    Reg::R8,
+    Reg::R9,
+    Reg::R11,
+    Reg::R12,
+    Reg::R13,
+    Reg::R14,
+    Reg::R15,
+];
+
+/// The slots of variables. The names a program is lowered with come from
+/// one generator, so their indices are distinct and dense, and the slots
+/// are kept in a vector indexed by them, counting from `base`. Names
+/// below it, or whose index another name already has, as after renaming
+/// with `StableNames`, are looked up by hashing instead, as are names so
+/// far past the others that the vector would be mostly empty.
+#[derive(Clone)]
+struct Slots<'a> {
+    base: usize,
+    dense: Vec<Option<(&'a VarName, i32)>>,
+    /// the number of slots in `dense`
+    used: usize,
+    sparse: HashMap<&'a VarName, i32>,
+}
+
+impl<'a> Slots<'a> {
+    fn new(base: usize) -> Self {
+        Slots { base, dense: Vec::new(), used: 0, sparse: HashMap::new() }
+    }
+    fn insert(&mut self, x: &'a VarName, slot: i32) {
+        let ix = match x.index().checked_sub(self.base) {
+            Some(ix) if ix < self.dense.len() || ix <= 2 * (self.used + 1) => {
+                ix
+            }
+            _ => {
+                self.sparse.insert(x, slot);
+                return;
+            }
+        };
+        if ix >= self.dense.len() {
+            self.dense.resize(ix + 1, None);
+        }
+        match &mut self.dense[ix] {
+            Some((y, _)) if *y != x => {
+                self.sparse.insert(x, slot);
+            }
+            entry => {
+                if entry.is_none() {
+                    self.used += 1;
+                }
+                *entry = Some((x, slot))
+            }
+        }
+    }
+    fn get(&self, x: &VarName) -> Option<i32> {
+        let dense = x.index().checked_sub(self.base).and_then(|ix| {
+            match self.dense.get(ix) {
+                Some(Some((y, slot))) if *y == x => Some(*slot),
+                _ => None,
+            }
+        });
+        dense.or_else(|| self.sparse.get(x).copied())
+    }
+}
+
 #[derive(Clone)]
 struct Env<'a> {
     next: i32,
-    arena: HashMap<&'a VarName, i32>,
+    arena: Slots<'a>,
     blocks: HashMap<&'a BlockName, i32>,
-    num_locals: usize,
+    /// the slots already holding a constant on every path to this point
+    pool: HashMap<i64, i32>,
 }
 
 impl<'a> Env<'a> {
-    fn new() -> Self {
+    /// An environment for variables whose indices mostly start at `base`.
+    fn new(base: usize) -> Self {
         Env {
             next: 1,
-            arena: HashMap::new(),
+            arena: Slots::new(base),
             blocks: HashMap::new(),
-            num_locals: 0,
+            pool: HashMap::new(),
         }
     }
     fn allocate(&mut self, x: &'a VarName) -> i32 {
         let loc = self.next;
         self.arena.insert(x, loc);
         self.next += 1;
-        self.num_locals += 1;
         loc
     }
     fn lookup(&self, x: &'a VarName) -> i32 {
-        self.arena.get(x).copied().expect("variable not allocated")
+        self.arena.get(x).expect("variable not allocated")
     }
 }
 
+#[derive(Default)]
 pub struct Emitter {
     // the output buffer for the sequence of instructions we are generating
     instrs: Vec<Instr>,
+    // how integers are represented in the generated code
+    repr: IntRepr,
+    // the variables the program being emitted ever reads
+    used: HashSet<VarName>,
+    // the jump tables emitted so far, with the labels they jump to
+    tables: Vec<(String, Vec<String>)>,
+    // the constants too wide for an immediate loaded so far, with the
+    // labels they are stored under
+    constants: Vec<(String, i64)>,
+    // the highest stack slot any block uses, which every frame reserves
+    frame_slots: i32,
+    // the registers that hold the first slots, in order; the slots after
+    // them spill to the frame
+    registers: Vec<Reg>,
+    // the source to annotate the code with, split into lines
+    source: Option<(FileInfo, Vec<String>)>,
+    // where the operations of the program being emitted came from
+    locs: HashMap<VarName, SrcLoc>,
+    // the source line the code since the last label was annotated with
+    line: Option<usize>,
+    // the function the program being emitted is entered through
+    entry: Option<FunName>,
+    // whether to describe each frame with CFI directives
+    cfi: bool,
 }
 
 impl From<Lowerer> for Emitter {
     fn from(Lowerer { .. }: Lowerer) -> Self {
-        Emitter { instrs: Vec::new() }
+        Emitter::default()
     }
 }
 
+/// The code for `prog`, with each line of `src` that it was lowered from
+/// as a comment above the instructions generated from the expressions on
+/// that line.
+pub fn listing(src: &str, prog: &Program) -> String {
+    let mut emitter = Emitter::default().with_source(src);
+    emitter.emit_prog(prog);
+    instrs_to_string(&emitter.to_asm())
+}
+
 impl Emitter {
+    /// Generate code that represents integers according to `repr`.
+    pub fn with_repr(mut self, repr: IntRepr) -> Self {
+        self.repr = repr;
+        self
+    }
+
+    /// Annotate the code with the lines of `src`, the source of the
+    /// programs emitted, as comments. Each line comes before the code
+    /// generated from the expressions on it.
+    pub fn with_source(mut self, src: &str) -> Self {
+        let lines = src.lines().map(|l| l.to_string()).collect();
+        self.source = Some((FileInfo::new(src), lines));
+        self
+    }
+
+    /// Keep variables in `regs` while there are enough of them, spilling
+    /// the rest to the stack. Registers that are not allocatable are
+    /// skipped, so the teaching set rax, rbx, rcx and rdx leaves three.
+    pub fn with_registers(mut self, regs: &[Reg]) -> Self {
+        self.registers.clear();
+        for reg in regs {
+            if ALLOCATABLE_REGS.contains(reg)
+                && !self.registers.contains(reg)
+            {
+                self.registers.push(*reg);
+            }
+        }
+        self
+    }
+
+    /// Describe how to find each frame's return address and saved rbp
+    /// with CFI directives, so debuggers can unwind the compiled code.
+    /// nasm does not understand these, so the code is for reading or for
+    /// an assembler that does.
+    pub fn with_cfi(mut self) -> Self {
+        self.cfi = true;
+        self
+    }
+
     pub fn to_asm(self) -> Vec<Instr> {
         self.instrs
     }
 
+    /// The label a function is emitted under. In tagged mode the runtime
+    /// still calls the entry point with an untagged argument, and
+    /// variables may be kept in registers the runtime expects the entry
+    /// point to preserve. In either case the compiled entry point is moved
+    /// aside and the entry point becomes a wrapper around it.
+    fn fun_label(&self, fun: &FunName) -> String {
+        if self.wraps_entry() && self.is_entry(fun) {
+            format!("compiled_{}", label(fun))
+        } else {
+            label(fun)
+        }
+    }
+
+    fn wraps_entry(&self) -> bool {
+        self.repr == IntRepr::Tagged || !self.callee_saved().is_empty()
+    }
+
+    /// The registers in use that the runtime expects to be preserved.
+    fn callee_saved(&self) -> Vec<Reg> {
+        self.registers
+            .iter()
+            .copied()
+            .filter(|reg| reg.is_callee_saved())
+            .collect()
+    }
+
+    /// Where the value of `slot` lives: in a register, or in the frame.
+    fn slot_loc(&self, slot: i32) -> Loc {
+        let regs = self.registers.len() as i32;
+        match self.registers.get(slot as usize - 1) {
+            Some(reg) => Loc::Reg(*reg),
+            None => Loc::Mem(MemRef {
+                reg: Reg::Rbp,
+                offset: -8 * (slot - regs),
+            }),
+        }
+    }
+
+    /// The registers holding the slots in use in `env`, which a call
+    /// would clobber.
+    fn live_registers(&self, env: &Env) -> Vec<Reg> {
+        let live = (env.next as usize - 1).min(self.registers.len());
+        self.registers[..live].to_vec()
+    }
+
+    /// Put the value of a slot into a register.
+    fn load(&self, reg: Reg, src: i32) -> Instr {
+        match self.slot_loc(src) {
+            Loc::Reg(src) => {
+                Instr::Mov(MovArgs::ToReg(reg, Arg64::Reg(src)))
+            }
+            Loc::Mem(src) => {
+                Instr::Mov(MovArgs::ToReg(reg, Arg64::Mem(src)))
+            }
+        }
+    }
+
+    /// Flush the value of a register into a slot.
+    fn store(&self, dst: i32, reg: Reg) -> Instr {
+        match self.slot_loc(dst) {
+            Loc::Reg(dst) => {
+                Instr::Mov(MovArgs::ToReg(dst, Arg64::Reg(reg)))
+            }
+            Loc::Mem(dst) => {
+                Instr::Mov(MovArgs::ToMem(dst, Reg32::Reg(reg)))
+            }
+        }
+    }
+
     fn emit(&mut self, instr: Instr) {
+        // code after a label may be reached from anywhere, so it shows
+        // its source line again
+        if let Instr::Label(_) = instr {
+            self.line = None;
+        }
         self.instrs.push(instr);
     }
 
+    /// Emit the CFI `directive`, if the code is described with them.
+    fn emit_cfi(&mut self, directive: &str) {
+        if self.cfi {
+            self.emit(Instr::Directive(directive.to_string()));
+        }
+    }
+
+    /// Show the source line `dest` was lowered from, unless the code since
+    /// the last label already does.
+    fn annotate(&mut self, dest: &VarName) {
+        let (Some((file_info, lines)), Some(loc)) =
+            (&self.source, self.locs.get(dest))
+        else {
+            return;
+        };
+        let line = file_info.span1_to_span2(*loc).start_line;
+        if self.line == Some(line) {
+            return;
+        }
+        let text = format!("{}: {}", line, lines[line - 1].trim());
+        self.line = Some(line);
+        self.emit(Instr::Comment(text));
+    }
+
     pub fn emit_prog(&mut self, prog: &Program) {
+        // On elf64 targets, mark the stack as non-executable. Without this
+        // note the linker assumes an executable stack and warns about it.
+        if cfg!(target_os = "linux") {
+            self.emit(Instr::Directive(
+                "section .note.GNU-stack noalloc noexec nowrite progbits"
+                    .to_string(),
+            ));
+        }
         self.emit(Instr::Section(".data".to_string()));
         self.emit(Instr::Section(".text".to_string()));
-        self.emit(Instr::Global("entry".to_string()));
+        self.entry = prog.entry().map(|f| f.name.clone());
+        let entry = self
+            .entry
+            .as_ref()
+            .map_or(ENTRY_FUN.to_string(), |f| f.to_string());
+        self.emit(Instr::Global(entry));
 
-        let mut env = Env::new();
+        self.used = prog.used_vars();
+        if self.source.is_some() {
+            self.locs = prog.locs.0.clone();
+        }
+        let base = self.used.iter().map(VarName::index).min().unwrap_or(0);
+        let mut env = Env::new(base);
 
         for ext in &prog.externs {
             self.emit(Instr::Extern(ext.name.to_string()));
@@ -81,45 +345,214 @@ impl Emitter {
         }
 
         // Then, emit all the basic block with a cloned environment. (Why
-        // cloned?)
-        for block in &prog.blocks {
+        // cloned?) They all run in a frame a function set up, so they make
+        // up one procedure as far as unwinding is concerned.
+        self.emit_cfi(".cfi_startproc");
+        self.emit_cfi(".cfi_def_cfa rbp, 16");
+        self.emit_cfi(".cfi_offset rbp, -16");
+        for block in layout_blocks(&prog.blocks, None) {
             self.emit_basic_block(block, &mut env.clone());
         }
+        self.emit_cfi(".cfi_endproc");
 
         for fun in &prog.funs {
             self.emit_fun_block(fun, &mut env);
         }
+
+        // The jump tables and wide constants go in the data section,
+        // after all the code.
+        if !self.tables.is_empty() || !self.constants.is_empty() {
+            self.emit(Instr::Section(".data".to_string()));
+            for (name, targets) in std::mem::take(&mut self.tables) {
+                self.emit(Instr::Label(name));
+                self.emit(Instr::LabelTable(targets));
+            }
+            for (name, n) in std::mem::take(&mut self.constants) {
+                self.emit(Instr::Label(name));
+                self.emit(Instr::Directive(format!("dq {}", n)));
+            }
+        }
+
+        self.remove_fallthrough_jumps();
+    }
+
+    /// Drop every unconditional jump whose target label immediately
+    /// follows it, since control would fall through there anyway.
+    fn remove_fallthrough_jumps(&mut self) {
+        let instrs = std::mem::take(&mut self.instrs);
+        let mut iter = instrs.into_iter().peekable();
+        while let Some(instr) = iter.next() {
+            if let (Instr::Jmp(target), Some(Instr::Label(next))) = (&instr, iter.peek()) {
+                if target == next {
+                    continue;
+                }
+            }
+            self.instrs.push(instr);
+        }
     }
 
     fn emit_fun_block<'a>(
         &mut self, fun_block: &'a FunBlock, env: &mut Env<'a>,
     ) {
-        // First, emit the label for the block.
-        self.emit(Instr::Label(fun_block.name.to_string()));
-
-        // Assume that the arguments are passed according to the SYSVAMD64
-        // calling convention. For now, there should only be one argument
-        // since the only FunBlock is main.
-        let offset = 0;
-        let base =
-            env.blocks.get(&fun_block.body.target).unwrap_or_else(|| {
-                panic!(
-                    "no offset found for block '{}'",
-                    &fun_block.body.target
-                )
-            });
-        self.emit(store_mem(base + offset, Reg::Rdi));
+        // The runtime's entry point saves the callee-saved registers that
+        // hold variables, and in tagged mode tags its argument and untags
+        // the result, around a call to the compiled entry point.
+        if self.wraps_entry() && self.is_entry(&fun_block.name) {
+            let saved = self.callee_saved();
+            self.emit(Instr::Label(label(&fun_block.name)));
+            self.emit_cfi(".cfi_startproc");
+            for reg in &saved {
+                self.emit(Instr::Push(Arg32::Reg(*reg)));
+                self.emit_cfi(".cfi_adjust_cfa_offset 8");
+            }
+            if self.repr == IntRepr::Tagged {
+                self.emit(Instr::Shl(BinArgs::ToReg(
+                    Reg::Rdi,
+                    Arg32::Signed(1),
+                )));
+            }
+            // the return address misaligns rsp by a word, as does each
+            // saved register
+            let padding = saved.len().is_multiple_of(2);
+            if padding {
+                self.emit(Instr::Sub(BinArgs::ToReg(
+                    Reg::Rsp,
+                    Arg32::Signed(8),
+                )));
+                self.emit_cfi(".cfi_adjust_cfa_offset 8");
+            }
+            self.emit(Instr::Call(self.fun_label(&fun_block.name)));
+            if padding {
+                self.emit(Instr::Add(BinArgs::ToReg(
+                    Reg::Rsp,
+                    Arg32::Signed(8),
+                )));
+                self.emit_cfi(".cfi_adjust_cfa_offset -8");
+            }
+            if self.repr == IntRepr::Tagged {
+                self.emit(Instr::Sar(BinArgs::ToReg(
+                    Reg::Rax,
+                    Arg32::Signed(1),
+                )));
+            }
+            for reg in saved.iter().rev() {
+                self.emit(Instr::Pop(Loc::Reg(*reg)));
+                self.emit_cfi(".cfi_adjust_cfa_offset -8");
+            }
+            self.emit(Instr::Ret);
+            self.emit_cfi(".cfi_endproc");
+        }
+
+        // First, emit the label for the block, and set up a frame. The
+        // locals live below rbp, and rsp stays below all of them, so a
+        // call cannot overwrite them. Every frame has room for the slots
+        // of every block, rounded up to keep rsp 16-byte aligned.
+        self.emit(Instr::Label(self.fun_label(&fun_block.name)));
+        self.emit_cfi(".cfi_startproc");
+        self.emit(Instr::Push(Arg32::Reg(Reg::Rbp)));
+        self.emit_cfi(".cfi_def_cfa_offset 16");
+        self.emit_cfi(".cfi_offset rbp, -16");
+        self.emit(Instr::Mov(MovArgs::ToReg(
+            Reg::Rbp,
+            Arg64::Reg(Reg::Rsp),
+        )));
+        self.emit_cfi(".cfi_def_cfa_register rbp");
+        let slots = (self.frame_slots + 1) / 2 * 2;
+        if slots > 0 {
+            self.emit(Instr::Sub(BinArgs::ToReg(
+                Reg::Rsp,
+                Arg32::Unsigned(slots as u32 * 8),
+            )));
+        }
+
+        // The arguments are passed according to the SYSVAMD64 calling
+        // convention: the first six in registers, and the rest on the
+        // stack above the return address and the saved rbp. A FunBlock
+        // usually passes its parameters on to its block in order, but each
+        // block parameter is given whatever the branch passes it, so a
+        // parameter the block does not take is never stored, and a block
+        // without parameters gets nothing.
+        let Branch { target, args } = &fun_block.body;
+        let base = *env.blocks.get(target).unwrap_or_else(|| {
+            panic!("no offset found for block '{}'", target)
+        });
+        let sources: Vec<_> = args
+            .iter()
+            .map(|arg| match arg {
+                Immediate::Var(x) => Some(
+                    fun_block.params.iter().position(|p| p == x).unwrap_or_else(
+                        || panic!("{} is not a parameter of {}", x, fun_block.name),
+                    ),
+                ),
+                Immediate::Const(_) => None,
+            })
+            .collect();
+        let direct = self.moves_directly(base, &sources);
+        for (j, (arg, source)) in args.iter().zip(&sources).enumerate() {
+            let reg = match source.map(|i| (i, REG_ARG_LOCS.get(i))) {
+                Some((_, Some(reg))) => *reg,
+                Some((i, None)) => {
+                    let offset = 16 + 8 * (i - REG_ARG_LOCS.len()) as i32;
+                    self.emit(Instr::Mov(MovArgs::ToReg(
+                        Reg::Rax,
+                        Arg64::Mem(MemRef { reg: Reg::Rbp, offset }),
+                    )));
+                    Reg::Rax
+                }
+                None => {
+                    self.emit_imm_reg(arg, Reg::Rax, env);
+                    Reg::Rax
+                }
+            };
+            // A parameter may be kept in an argument register that holds
+            // a later argument, in which case every argument is read onto
+            // the stack before any is stored.
+            if direct {
+                self.emit(self.store(base + j as i32, reg));
+            } else {
+                self.emit(Instr::Push(Arg32::Reg(reg)));
+            }
+        }
+        if !direct {
+            for j in (0..args.len()).rev() {
+                self.emit(Instr::Pop(self.slot_loc(base + j as i32)));
+            }
+        }
 
         // Emit the jmp to the branch
-        self.emit(Instr::Jmp(fun_block.body.target.to_string()));
+        self.emit(Instr::Jmp(label(&fun_block.body.target)));
+        self.emit_cfi(".cfi_endproc");
+    }
+
+    /// Whether the slots from `base` on can be given the arguments of a
+    /// function straight away, in order, without one overwriting an
+    /// argument register before it is read. The slot at `base + j` gets
+    /// argument `sources[j]`, or a constant if there is none.
+    fn moves_directly(&self, base: i32, sources: &[Option<usize>]) -> bool {
+        (0..sources.len()).all(|j| match self.slot_loc(base + j as i32) {
+            Loc::Reg(reg) => !sources[j + 1..]
+                .iter()
+                .flatten()
+                .any(|&i| REG_ARG_LOCS.get(i) == Some(&reg)),
+            Loc::Mem(_) => true,
+        })
+    }
+
+    /// Give `x` the next slot of `env`, making sure every frame is big
+    /// enough to hold it if it spills.
+    fn allocate<'a>(&mut self, x: &'a VarName, env: &mut Env<'a>) -> i32 {
+        let loc = env.allocate(x);
+        let spilled = loc - self.registers.len() as i32;
+        self.frame_slots = self.frame_slots.max(spilled);
+        loc
     }
 
     fn emit_basic_block<'a>(
         &mut self, block: &'a BasicBlock, env: &mut Env<'a>,
     ) {
-        self.emit(Instr::Label(block.label.to_string()));
+        self.emit(Instr::Label(label(&block.label)));
         for param in &block.params {
-            env.allocate(param);
+            self.allocate(param, env);
         }
         self.emit_block_body(&block.body, env);
     }
@@ -140,12 +573,14 @@ impl Emitter {
                 }
                 // then emit the body with a cloned environment
                 self.emit_block_body(next, &mut env.clone());
-                // and finally, emit the sub-blocks, each with a cloned environment
-                for BasicBlock { label, params, body } in blocks {
+                // and finally, emit the sub-blocks, each with a cloned environment,
+                // laid out so that the body falls through into its preferred target
+                let entry = next.terminator().targets().into_iter().next();
+                for BasicBlock { label: name, params, body } in layout_blocks(blocks, entry) {
                     let mut env = env.clone();
-                    self.emit(Instr::Label(label.to_string()));
+                    self.emit(Instr::Label(label(name)));
                     for param in params {
-                        env.allocate(param);
+                        self.allocate(param, &mut env);
                     }
                     self.emit_block_body(body, &mut env);
                 }
@@ -157,20 +592,55 @@ impl Emitter {
         match t {
             Terminator::Return(imm) => {
                 self.emit_imm_reg(imm, Reg::Rax, env);
+                self.emit(Instr::Mov(MovArgs::ToReg(
+                    Reg::Rsp,
+                    Arg64::Reg(Reg::Rbp),
+                )));
+                // the code after the return is still in a frame
+                self.emit_cfi(".cfi_remember_state");
+                self.emit(Instr::Pop(Loc::Reg(Reg::Rbp)));
+                self.emit_cfi(".cfi_def_cfa rsp, 8");
+                self.emit_cfi(".cfi_restore rbp");
                 self.emit(Instr::Ret);
+                self.emit_cfi(".cfi_restore_state");
             }
             Terminator::Branch(branch) => {
                 self.emit_branch(branch, env);
             }
             Terminator::ConditionalBranch { cond, thn, els } => {
                 self.emit_imm_reg(cond, Reg::Rax, env);
+                self.emit_zero_test(Reg::Rax);
+                self.emit(Instr::JCC(ConditionCode::NE, label(thn)));
+                self.emit(Instr::Jmp(label(els)));
+            }
+            Terminator::Switch { scrutinee, targets, default } => {
+                self.emit_imm_reg(scrutinee, Reg::Rax, env);
+                if self.repr == IntRepr::Tagged {
+                    self.emit(Instr::Sar(BinArgs::ToReg(
+                        Reg::Rax,
+                        Arg32::Signed(1),
+                    )));
+                }
+                // The jump through the table does not check its index, so
+                // anything that is not an index into the table has to be
+                // sent to the default first. Compared as unsigned numbers,
+                // negative values are too big as well, so one comparison
+                // covers both bounds.
                 self.emit(Instr::Cmp(BinArgs::ToReg(
                     Reg::Rax,
-                    Arg32::Signed(0),
+                    Arg32::signed(targets.len() as i64),
                 )));
-                self.emit(Instr::JCC(ConditionCode::NE, thn.to_string()));
-                self.emit(Instr::Jmp(els.to_string()));
+                self.emit(Instr::JCC(ConditionCode::AE, label(default)));
+                let table = format!("switch_table_{}", self.tables.len());
+                self.emit(Instr::Lea(Reg::R10, table.clone()));
+                self.emit(Instr::JmpIndexed {
+                    base: Reg::R10,
+                    index: Reg::Rax,
+                });
+                self.tables
+                    .push((table, targets.iter().map(label).collect()));
             }
+            Terminator::Unreachable => self.emit(Instr::Ud2),
         }
     }
 
@@ -182,19 +652,51 @@ impl Emitter {
             panic!("no offset found for block '{}'", target)
         });
 
-        // store arguments in consecutive offsets from the target's base
-        for (i, arg) in args.iter().enumerate() {
-            // using Rax as a temp register
-            self.emit_imm_reg(arg, Reg::Rax, env);
-            self.emit(store_mem(base + i as i32, Reg::Rax));
+        // store arguments in consecutive offsets from the target's base.
+        // If an argument is read from a slot that an earlier one is
+        // stored to, read them all onto the stack before storing any.
+        let dests = *base..*base + args.len() as i32;
+        let overlaps = args.iter().enumerate().any(|(i, arg)| match arg {
+            Immediate::Var(x) => {
+                let slot = env.lookup(x);
+                dests.contains(&slot) && slot != base + i as i32
+            }
+            Immediate::Const(_) => false,
+        });
+        if overlaps {
+            for arg in args {
+                self.emit_imm_reg(arg, Reg::Rax, env);
+                self.emit(Instr::Push(Arg32::Reg(Reg::Rax)));
+            }
+            for i in (0..args.len()).rev() {
+                self.emit(Instr::Pop(Loc::Reg(Reg::Rax)));
+                self.emit(self.store(base + i as i32, Reg::Rax));
+            }
+        } else {
+            for (i, arg) in args.iter().enumerate() {
+                // using Rax as a temp register
+                self.emit_imm_reg(arg, Reg::Rax, env);
+                self.emit(self.store(base + i as i32, Reg::Rax));
+            }
         }
         // finally, jump to the target
-        self.emit(Instr::Jmp(target.to_string()));
+        self.emit(Instr::Jmp(label(target)));
     }
 
     fn emit_operation<'a>(
         &mut self, dest: &'a VarName, op: &Operation, env: &mut Env<'a>,
     ) {
+        // A constant that is already in a slot is not loaded again: the
+        // destination shares that slot instead. Slots are never reused
+        // along a path, and the pool is cloned along with the environment,
+        // so the slot still holds the constant wherever `dest` is visible.
+        if let Operation::Immediate(Immediate::Const(n)) = op {
+            if let Some(&slot) = env.pool.get(n) {
+                env.arena.insert(dest, slot);
+                return;
+            }
+        }
+        self.annotate(dest);
         // First generate code that places the result in rax, using
         // r10 as a scratch register
         match op {
@@ -205,9 +707,11 @@ impl Emitter {
                 self.emit_imm_reg(imm, Reg::Rax, env);
                 match op {
                     Prim1::BitNot => {
+                        // flipping the tag bit of a tagged integer would
+                        // corrupt it, so only flip the payload bits
                         self.emit(Instr::Mov(MovArgs::ToReg(
                             Reg::R10,
-                            Arg64::Signed(-1),
+                            Arg64::Signed(self.repr.tag(-1)),
                         )));
                         self.emit(Instr::Xor(BinArgs::ToReg(
                             Reg::Rax,
@@ -216,15 +720,13 @@ impl Emitter {
                     }
                     Prim1::IntToBool => {
                         // if reg is not zero, make it 1, otherwise make it 0
-                        self.emit(Instr::Cmp(BinArgs::ToReg(
-                            Reg::Rax,
-                            Arg32::Signed(0),
-                        )));
+                        self.emit_zero_test(Reg::Rax);
                         self.emit(Instr::Mov(MovArgs::ToReg(
                             Reg::Rax,
                             Arg64::Signed(0),
                         )));
                         self.emit(Instr::SetCC(ConditionCode::NE, Reg8::Al));
+                        self.emit_retag_bool();
                     }
                 }
             }
@@ -235,7 +737,16 @@ impl Emitter {
                 match op {
                     Prim2::Add => self.emit(Instr::Add(ba)),
                     Prim2::Sub => self.emit(Instr::Sub(ba)),
-                    Prim2::Mul => self.emit(Instr::IMul(ba)),
+                    Prim2::Mul => {
+                        // the product of two tagged integers is shifted twice
+                        if self.repr == IntRepr::Tagged {
+                            self.emit(Instr::Sar(BinArgs::ToReg(
+                                Reg::Rax,
+                                Arg32::Signed(1),
+                            )));
+                        }
+                        self.emit(Instr::IMul(ba))
+                    }
                     Prim2::BitAnd => self.emit(Instr::And(ba)),
                     Prim2::BitOr => self.emit(Instr::Or(ba)),
                     Prim2::BitXor => self.emit(Instr::Xor(ba)),
@@ -245,61 +756,148 @@ impl Emitter {
                     Prim2::Ge => self.emit_cc(ConditionCode::GE, ba),
                     Prim2::Eq => self.emit_cc(ConditionCode::E, ba),
                     Prim2::Neq => self.emit_cc(ConditionCode::NE, ba),
+                    Prim2::ULt => self.emit_cc(ConditionCode::B, ba),
+                    Prim2::ULe => self.emit_cc(ConditionCode::BE, ba),
+                    Prim2::UGt => self.emit_cc(ConditionCode::A, ba),
+                    Prim2::UGe => self.emit_cc(ConditionCode::AE, ba),
                 }
             }
             Operation::Call { fun, args } => {
-                let L = env.num_locals;
-                let A = if args.len() > 6 { args.len() - 6 } else { 0 };
-                let P = if (L + A) % 2 == 0 { 1 } else { 0 };
+                // Our own functions may overwrite any register, so the
+                // ones holding variables are saved on the stack around the
+                // call. External functions preserve the callee-saved ones.
+                let saved: Vec<_> = self
+                    .live_registers(env)
+                    .into_iter()
+                    .filter(|reg| {
+                        self.is_local(fun) || reg.is_caller_saved()
+                    })
+                    .collect();
+                for reg in &saved {
+                    self.emit(Instr::Push(Arg32::Reg(*reg)));
+                }
 
-                let mut args = args.iter();
+                // rsp is 16-byte aligned below the frame, so only the
+                // saved registers and the arguments pushed on the stack
+                // can misalign it
+                let stack_args =
+                    args.len().saturating_sub(REG_ARG_LOCS.len());
+                let padding = (saved.len() + stack_args) % 2;
 
-                // args.zip() will only take as many args as there are in
-                // REG_ARG_LOCS, leaving the remaining for us to stack-allocate.
-                for (arg, dest) in
-                    args.by_ref().take(REG_ARG_LOCS.len()).zip(REG_ARG_LOCS)
-                {
-                    self.emit_imm_reg(arg, dest, env);
-                }
+                // External functions know nothing of our tagging scheme, so
+                // their arguments are untagged and their result is retagged.
+                let untag =
+                    self.repr == IntRepr::Tagged && !self.is_local(fun);
 
-                // Stack-allocate the remaining args
-                for (i, arg) in args.enumerate() {
+                // Push the args that do not fit in registers, the last one
+                // first, so that the seventh ends up on top of the stack.
+                // They are read before any argument register is written.
+                if padding > 0 {
+                    self.emit(Instr::Sub(BinArgs::ToReg(
+                        Reg::Rsp,
+                        Arg32::Unsigned(8),
+                    )));
+                }
+                for arg in args.iter().skip(REG_ARG_LOCS.len()).rev() {
                     self.emit_imm_reg(arg, Reg::Rax, env);
-                    self.emit(store_mem((L + P + A - i) as i32, Reg::Rax));
+                    if untag {
+                        self.emit(Instr::Sar(BinArgs::ToReg(
+                            Reg::Rax,
+                            Arg32::Signed(1),
+                        )));
+                    }
+                    self.emit(Instr::Push(Arg32::Reg(Reg::Rax)));
                 }
 
-                // For debugging purposes
-                if (L + P + A) % 2 == 0 {
-                    panic!("misaligned stack");
+                // zip() will only take as many args as there are in
+                // REG_ARG_LOCS. An argument register may hold a variable a
+                // later argument reads, so when any register is saved every
+                // argument is read onto the stack before any is set. The
+                // argument registers are caller-saved, so a variable in one
+                // is always saved.
+                let reg_args: Vec<_> =
+                    args.iter().zip(REG_ARG_LOCS).collect();
+                for &(arg, dest) in &reg_args {
+                    let dest =
+                        if saved.is_empty() { dest } else { Reg::Rax };
+                    self.emit_imm_reg(arg, dest, env);
+                    if untag {
+                        self.emit(Instr::Sar(BinArgs::ToReg(
+                            dest,
+                            Arg32::Signed(1),
+                        )));
+                    }
+                    if !saved.is_empty() {
+                        self.emit(Instr::Push(Arg32::Reg(Reg::Rax)));
+                    }
+                }
+                if !saved.is_empty() {
+                    for &(_, dest) in reg_args.iter().rev() {
+                        self.emit(Instr::Pop(Loc::Reg(dest)));
+                    }
                 }
-
-                // Decrement stack pointer
-                self.emit(Instr::Sub(BinArgs::ToReg(
-                    Reg::Rsp,
-                    Arg32::Unsigned((L + P + A) as u32 * 8),
-                )));
 
                 // Emit the call
-                self.emit(Instr::Call(fun.to_string()));
+                self.emit(Instr::Call(self.fun_label(fun)));
 
-                // Increment the stack pointer again
-                self.emit(Instr::Add(BinArgs::ToReg(
-                    Reg::Rsp,
-                    Arg32::Unsigned((L + P + A) as u32 * 8),
-                )));
+                // Pop the stack args and restore the saved registers
+                if stack_args + padding > 0 {
+                    self.emit(Instr::Add(BinArgs::ToReg(
+                        Reg::Rsp,
+                        Arg32::Unsigned((stack_args + padding) as u32 * 8),
+                    )));
+                }
+                for reg in saved.iter().rev() {
+                    self.emit(Instr::Pop(Loc::Reg(*reg)));
+                }
+
+                if untag {
+                    self.emit(Instr::Shl(BinArgs::ToReg(
+                        Reg::Rax,
+                        Arg32::Signed(1),
+                    )));
+                }
+
+                // A call made only for its effect, like `print(x)` bound
+                // to a variable that is never read, needs no slot for its
+                // result.
+                if !self.used.contains(dest) {
+                    return;
+                }
             }
         }
-        // allocate the destination to be the next available offset from rsp
-        let dst = env.allocate(dest);
+        // allocate the destination to be the next available slot in the frame
+        let dst = self.allocate(dest, env);
+        if let Operation::Immediate(Immediate::Const(n)) = op {
+            env.pool.insert(*n, dst);
+        }
         // write the return value back to the destination
-        self.emit(store_mem(dst, Reg::Rax))
+        self.emit(self.store(dst, Reg::Rax))
     }
 
     fn emit_cc(&mut self, cc: ConditionCode, ba: BinArgs) {
-        // Here it is important to set rax to be 0, because setcc only sets al, the bottom byte of rax
+        // Here it is important to set rax to be 0, because setcc only sets al, the bottom byte of rax.
+        // The signed codes L/LE/G/GE test SF against OF, so they stay correct
+        // even when the subtraction performed by cmp overflows; the unsigned
+        // codes B/BE/A/AE test CF instead.
         self.emit(Instr::Cmp(ba));
         self.emit(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Signed(0))));
-        self.emit(Instr::SetCC(cc, Reg8::Al))
+        self.emit(Instr::SetCC(cc, Reg8::Al));
+        self.emit_retag_bool();
+    }
+
+    /// Set the flags by whether `reg` is zero. `test reg, reg` sets them
+    /// like `cmp reg, 0` does, with a shorter encoding.
+    fn emit_zero_test(&mut self, reg: Reg) {
+        self.emit(Instr::Test(BinArgs::ToReg(reg, Arg32::Reg(reg))));
+    }
+
+    /// Turn the 0 or 1 left in rax by a setcc into the representation of
+    /// the corresponding integer.
+    fn emit_retag_bool(&mut self) {
+        if self.repr == IntRepr::Tagged {
+            self.emit(Instr::Shl(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1))));
+        }
     }
 
     fn emit_imm_reg<'a>(
@@ -308,32 +906,141 @@ impl Emitter {
         match imm {
             Immediate::Var(v) => {
                 let src = env.lookup(v);
-                self.emit(load_mem(reg, src))
+                self.emit(self.load(reg, src))
             }
+            // constants may not fit in the 32-bit immediate of other
+            // instructions, so they are always loaded into a register.
+            // Those that do not fit in 32 bits either are read from the
+            // data section rather than spelled out in a 10-byte `mov`.
             Immediate::Const(i) => {
-                self.emit(load_signed(reg, *i));
+                let n = self.repr.tag(*i);
+                if i32::try_from(n).is_ok() {
+                    self.emit(load_signed(reg, n));
+                } else {
+                    let name = self.constant(n);
+                    self.emit(Instr::Lea(reg, name));
+                    self.emit(Instr::Mov(MovArgs::ToReg(
+                        reg,
+                        Arg64::Mem(MemRef { reg, offset: 0 }),
+                    )));
+                }
             }
         }
     }
+
+    /// The label of the data section word holding `n`, shared by every
+    /// load of the same constant.
+    fn constant(&mut self, n: i64) -> String {
+        if let Some((name, _)) = self.constants.iter().find(|(_, m)| *m == n)
+        {
+            return name.clone();
+        }
+        let name = format!("constant_{}", self.constants.len());
+        self.constants.push((name.clone(), n));
+        name
+    }
+
+    /// Whether `fun` is the entry point of the program.
+    fn is_entry(&self, fun: &FunName) -> bool {
+        self.entry.as_ref() == Some(fun)
+    }
+
+    /// Whether `fun` is compiled by us, as opposed to an external function.
+    fn is_local(&self, fun: &FunName) -> bool {
+        !fun.is_unmangled() || self.is_entry(fun)
+    }
 }
 
-/// Put the value of a signed constant into a register.
-fn load_signed(reg: Reg, val: i64) -> Instr {
-    Instr::Mov(MovArgs::ToReg(reg, Arg64::Signed(val)))
+/// The label `name` is emitted under. The display forms of our names put
+/// an `@`, `#` or `%` between the hint and the index, which not every
+/// assembler accepts in a label, so that becomes a `.` and a letter for
+/// the kind of name. Source identifiers never contain a `.`, so the
+/// labels of different names cannot collide, even with those of externs.
+fn label(name: &impl std::fmt::Display) -> String {
+    name.to_string().replace('@', ".f").replace('#', ".b").replace('%', ".v")
+}
+
+/// Order a group of sibling blocks so that each block is, where possible,
+/// followed by the successor it would rather fall through to.
+///
+/// This is a greedy trace-building heuristic: starting from `entry` (the
+/// block control falls into from the preceding code, if any), we keep
+/// appending the preferred successor of the last placed block as long as
+/// all of that successor's sibling predecessors have already been placed.
+/// This keeps join points after every arm that branches to them. When a
+/// trace ends we start a new one from the earliest unplaced block.
+pub fn layout_blocks<'a>(
+    blocks: &'a [BasicBlock], entry: Option<&BlockName>,
+) -> Vec<&'a BasicBlock> {
+    let index: HashMap<&BlockName, usize> =
+        blocks.iter().enumerate().map(|(i, b)| (&b.label, i)).collect();
+
+    // the sibling blocks each block may branch to, from anywhere in its body
+    let succs: Vec<HashSet<usize>> = blocks
+        .iter()
+        .map(|b| {
+            let mut targets = Vec::new();
+            branch_targets(&b.body, &mut targets);
+            targets.into_iter().filter_map(|t| index.get(t).copied()).collect()
+        })
+        .collect();
+    let mut unplaced_preds = vec![0; blocks.len()];
+    for ss in &succs {
+        for &j in ss {
+            unplaced_preds[j] += 1;
+        }
+    }
+
+    let mut placed = vec![false; blocks.len()];
+    let mut order = Vec::with_capacity(blocks.len());
+    let mut next = entry.and_then(|l| index.get(l).copied());
+    while order.len() < blocks.len() {
+        let start = next.filter(|&i| !placed[i]).unwrap_or_else(|| {
+            (0..blocks.len())
+                .find(|&i| !placed[i] && unplaced_preds[i] == 0)
+                .or_else(|| (0..blocks.len()).find(|&i| !placed[i]))
+                .expect("some block is still unplaced")
+        });
+        let mut cur = start;
+        loop {
+            placed[cur] = true;
+            order.push(&blocks[cur]);
+            for &j in &succs[cur] {
+                unplaced_preds[j] -= 1;
+            }
+            let fallthrough = blocks[cur]
+                .body
+                .terminator()
+                .targets()
+                .into_iter()
+                .filter_map(|t| index.get(t).copied())
+                .find(|&j| !placed[j] && unplaced_preds[j] == 0);
+            match fallthrough {
+                Some(j) => cur = j,
+                None => break,
+            }
+        }
+        next = None;
+    }
+    order
 }
 
-/// Put the value of a memory reference into a register.
-fn load_mem(reg: Reg, src: i32) -> Instr {
-    Instr::Mov(MovArgs::ToReg(
-        reg,
-        Arg64::Mem(MemRef { reg: Reg::Rsp, offset: -8 * src }),
-    ))
+/// Collect the targets of every terminator in a block body, including
+/// those of its nested sub-blocks.
+fn branch_targets<'a>(body: &'a BlockBody, targets: &mut Vec<&'a BlockName>) {
+    match body {
+        BlockBody::Terminator(terminator) => targets.extend(terminator.targets()),
+        BlockBody::Operation { next, .. } => branch_targets(next, targets),
+        BlockBody::SubBlocks { blocks, next } => {
+            for block in blocks {
+                branch_targets(&block.body, targets);
+            }
+            branch_targets(next, targets);
+        }
+    }
 }
 
-/// Flush the value of a register into a memory reference.
-fn store_mem(dst: i32, reg: Reg) -> Instr {
-    Instr::Mov(MovArgs::ToMem(
-        MemRef { reg: Reg::Rsp, offset: -8 * dst },
-        Reg32::Reg(reg),
-    ))
+/// Put the value of a signed constant into a register.
+fn load_signed(reg: Reg, val: i64) -> Instr {
+    Instr::Mov(MovArgs::ToReg(reg, Arg64::Signed(val)))
 }
@@ -0,0 +1,309 @@
+//! An alternative backend that translates our intermediate representation
+//! into portable C.
+//!
+//! Each function becomes a C function holding the blocks it can reach,
+//! with a label per block and a local variable per SSA variable. Branching
+//! to a block assigns its parameters and then jumps to its label. C
+//! locals may be assigned more than once, so names the lowerer reuses
+//! across blocks need no renaming. Integers are untagged.
+
+use crate::identifiers::*;
+use crate::ssa::*;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// Translate `prog` into a C translation unit.
+pub fn emit_c(prog: &Program) -> String {
+    let mut out = String::from("#include <stdint.h>\n\n");
+    for ext in &prog.externs {
+        writeln!(out, "{};", signature(&ext.name, ext.params.len()))
+            .unwrap();
+    }
+    for fun_block in &prog.funs {
+        writeln!(
+            out,
+            "{};",
+            signature(&fun_block.name, fun_block.params.len())
+        )
+        .unwrap();
+    }
+    for fun_block in &prog.funs {
+        out.push('\n');
+        FunEmitter::new(prog).emit(fun_block, &mut out);
+    }
+    out
+}
+
+struct FunEmitter<'a> {
+    prog: &'a Program,
+    // the C name of each variable and label used in this function
+    vars: HashMap<&'a VarName, String>,
+    labels: HashMap<&'a BlockName, String>,
+    // the next number to make a C name unique within this function
+    next: usize,
+    reachable: HashSet<&'a BlockName>,
+}
+
+impl<'a> FunEmitter<'a> {
+    fn new(prog: &'a Program) -> Self {
+        FunEmitter {
+            prog,
+            vars: HashMap::new(),
+            labels: HashMap::new(),
+            next: 0,
+            reachable: HashSet::new(),
+        }
+    }
+
+    fn emit(mut self, fun_block: &'a FunBlock, out: &mut String) {
+        let params: Vec<_> =
+            fun_block.params.iter().map(|p| self.var(p)).collect();
+        let mut code = String::new();
+        self.emit_branch(&fun_block.body, &mut code);
+
+        self.reachable = self.prog.reachable_from(&fun_block.body.target);
+        for block in &self.prog.blocks {
+            if self.reachable.contains(&block.label) {
+                self.emit_block(block, &mut code);
+            }
+        }
+
+        let params: Vec<_> =
+            params.iter().map(|p| format!("int64_t {}", p)).collect();
+        writeln!(
+            out,
+            "int64_t {}({}) {{",
+            fun(&fun_block.name),
+            params.join(", ")
+        )
+        .unwrap();
+        let mut locals: Vec<_> = self
+            .vars
+            .iter()
+            .filter(|(x, _)| !fun_block.params.contains(x))
+            .map(|(_, name)| name)
+            .collect();
+        locals.sort();
+        for local in locals {
+            writeln!(out, "  int64_t {};", local).unwrap();
+        }
+        out.push_str(&code);
+        writeln!(out, "}}").unwrap();
+    }
+
+    /// A C identifier for `hint` that is unique within this function.
+    /// It starts with `kind` and a number, since our hints may start with
+    /// underscores, which C reserves.
+    fn fresh(&mut self, kind: &str, hint: &str) -> String {
+        let hint: String = hint
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let name = format!("{}{}_{}", kind, self.next, hint);
+        self.next += 1;
+        name
+    }
+
+    /// The C variable holding `x`.
+    fn var(&mut self, x: &'a VarName) -> String {
+        if let Some(name) = self.vars.get(x) {
+            return name.clone();
+        }
+        let name = self.fresh("v", x.hint());
+        self.vars.insert(x, name.clone());
+        name
+    }
+
+    /// The C label of `b`.
+    fn label(&mut self, b: &'a BlockName) -> String {
+        if let Some(name) = self.labels.get(b) {
+            return name.clone();
+        }
+        let name = self.fresh("b", b.hint());
+        self.labels.insert(b, name.clone());
+        name
+    }
+
+    fn imm(&mut self, i: &'a Immediate) -> String {
+        match i {
+            Immediate::Const(n) => int(*n),
+            Immediate::Var(x) => self.var(x),
+        }
+    }
+
+    /// Emit `block` and the reachable blocks nested in it.
+    fn emit_block(&mut self, block: &'a BasicBlock, out: &mut String) {
+        writeln!(out, "{}:;", self.label(&block.label)).unwrap();
+        // nested blocks are only entered by a jump, so they go after the
+        // terminator of the block around them
+        let mut nested = String::new();
+        let mut body = &block.body;
+        loop {
+            match body {
+                BlockBody::Terminator(t) => {
+                    self.emit_terminator(t, out);
+                    break;
+                }
+                BlockBody::Operation { dest, op, next } => {
+                    self.emit_operation(dest, op, out);
+                    body = next;
+                }
+                BlockBody::SubBlocks { blocks, next } => {
+                    for block in blocks {
+                        if self.reachable.contains(&block.label) {
+                            self.emit_block(block, &mut nested);
+                        }
+                    }
+                    body = next;
+                }
+            }
+        }
+        out.push_str(&nested);
+    }
+
+    fn emit_operation(
+        &mut self, dest: &'a VarName, op: &'a Operation, out: &mut String,
+    ) {
+        let rhs = match op {
+            Operation::Immediate(i) => self.imm(i),
+            Operation::Prim1(Prim1::BitNot, i) => {
+                format!("~{}", self.imm(i))
+            }
+            Operation::Prim1(Prim1::IntToBool, i) => {
+                format!("{} != 0", self.imm(i))
+            }
+            Operation::Prim2(prim, i1, i2) => {
+                let (i1, i2) = (self.imm(i1), self.imm(i2));
+                // signed overflow is undefined in C, so arithmetic wraps
+                // through unsigned integers
+                let wrapping = |op| {
+                    format!(
+                        "(int64_t)((uint64_t){} {} (uint64_t){})",
+                        i1, op, i2
+                    )
+                };
+                let unsigned =
+                    |op| format!("(uint64_t){} {} (uint64_t){}", i1, op, i2);
+                let signed = |op| format!("{} {} {}", i1, op, i2);
+                match prim {
+                    Prim2::Add => wrapping("+"),
+                    Prim2::Sub => wrapping("-"),
+                    Prim2::Mul => wrapping("*"),
+                    Prim2::BitAnd => signed("&"),
+                    Prim2::BitOr => signed("|"),
+                    Prim2::BitXor => signed("^"),
+                    Prim2::Lt => signed("<"),
+                    Prim2::Le => signed("<="),
+                    Prim2::Gt => signed(">"),
+                    Prim2::Ge => signed(">="),
+                    Prim2::Eq => signed("=="),
+                    Prim2::Neq => signed("!="),
+                    Prim2::ULt => unsigned("<"),
+                    Prim2::ULe => unsigned("<="),
+                    Prim2::UGt => unsigned(">"),
+                    Prim2::UGe => unsigned(">="),
+                }
+            }
+            Operation::Call { fun: f, args } => {
+                let args: Vec<_> =
+                    args.iter().map(|a| self.imm(a)).collect();
+                format!("{}({})", fun(f), args.join(", "))
+            }
+        };
+        let dest = self.var(dest);
+        writeln!(out, "  {} = {};", dest, rhs).unwrap();
+    }
+
+    /// Assign the parameters of the target of `branch` and jump to it. The
+    /// arguments may read the parameters they replace, so they are all
+    /// read before any is assigned.
+    fn emit_branch(&mut self, branch: &'a Branch, out: &mut String) {
+        let label = self.label(&branch.target);
+        if branch.args.is_empty() {
+            writeln!(out, "  goto {};", label).unwrap();
+            return;
+        }
+        let block = self
+            .prog
+            .blocks_recursive()
+            .find(|b| b.label == branch.target)
+            .unwrap_or_else(|| panic!("no block named '{}'", branch.target));
+        let args: Vec<_> = branch.args.iter().map(|a| self.imm(a)).collect();
+        let params: Vec<_> =
+            block.params.iter().map(|p| self.var(p)).collect();
+        out.push_str("  {\n");
+        for (i, arg) in args.iter().enumerate() {
+            writeln!(out, "    int64_t arg_{} = {};", i, arg).unwrap();
+        }
+        for (i, param) in params.iter().enumerate() {
+            writeln!(out, "    {} = arg_{};", param, i).unwrap();
+        }
+        writeln!(out, "    goto {};", label).unwrap();
+        out.push_str("  }\n");
+    }
+
+    fn emit_terminator(&mut self, t: &'a Terminator, out: &mut String) {
+        match t {
+            Terminator::Return(i) => {
+                writeln!(out, "  return {};", self.imm(i)).unwrap()
+            }
+            Terminator::Branch(branch) => self.emit_branch(branch, out),
+            Terminator::ConditionalBranch { cond, thn, els } => {
+                writeln!(
+                    out,
+                    "  if ({}) goto {}; else goto {};",
+                    self.imm(cond),
+                    self.label(thn),
+                    self.label(els)
+                )
+                .unwrap();
+            }
+            Terminator::Switch { scrutinee, targets, default } => {
+                writeln!(out, "  switch ({}) {{", self.imm(scrutinee))
+                    .unwrap();
+                for (n, target) in targets.iter().enumerate() {
+                    writeln!(
+                        out,
+                        "  case {}: goto {};",
+                        n,
+                        self.label(target)
+                    )
+                    .unwrap();
+                }
+                writeln!(out, "  default: goto {};", self.label(default))
+                    .unwrap();
+                writeln!(out, "  }}").unwrap();
+            }
+            Terminator::Unreachable => {
+                writeln!(out, "  __builtin_trap();").unwrap()
+            }
+        }
+    }
+}
+
+/// The C prototype of a function taking `arity` integers.
+fn signature(f: &FunName, arity: usize) -> String {
+    let params = match arity {
+        0 => "void".to_string(),
+        n => vec!["int64_t"; n].join(", "),
+    };
+    format!("int64_t {}({})", fun(f), params)
+}
+
+/// The C name of `f`. Mangled names carry their index, so that functions
+/// with the same hint stay distinct.
+fn fun(f: &FunName) -> String {
+    match f {
+        FunName::Mangled(idx, hint) => format!("{}_{}", hint, idx),
+        FunName::Unmangled(name) => name.clone(),
+    }
+}
+
+/// A C literal for `n`: the most negative integer has no literal of its own.
+fn int(n: i64) -> String {
+    match n {
+        i64::MIN => format!("({} - 1)", i64::MIN + 1),
+        n => n.to_string(),
+    }
+}
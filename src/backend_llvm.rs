@@ -0,0 +1,331 @@
+//! An alternative backend that translates our intermediate representation
+//! into textual LLVM IR.
+//!
+//! Our SSA is close to LLVM's already: every operation defines a fresh
+//! variable, and a block's parameters become phi nodes fed by the
+//! arguments its predecessors branch with. Blocks are global in our IR
+//! but belong to a function in LLVM, so each function gets its own copy
+//! of the blocks it can reach. Integers are untagged.
+
+use crate::identifiers::*;
+use crate::ssa::*;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// The label of the block that starts every function, which only
+/// branches to the function's first block.
+const START: &str = "start";
+
+/// Translate `prog` into an LLVM module.
+pub fn emit_llvm(prog: &Program) -> String {
+    let mut out = String::new();
+    for ext in &prog.externs {
+        let params = vec!["i64"; ext.params.len()].join(", ");
+        writeln!(out, "declare i64 {}({})", fun(&ext.name), params).unwrap();
+    }
+    for fun_block in &prog.funs {
+        out.push('\n');
+        FunEmitter::new(prog).emit(fun_block, &mut out);
+    }
+    out
+}
+
+/// The LLVM names of the variables in scope.
+type Env<'a> = HashMap<&'a VarName, String>;
+
+/// How a binary primitive is written in LLVM: either an arithmetic
+/// instruction, or an integer comparison whose `i1` result is extended.
+enum Llvm2 {
+    Arith(&'static str),
+    Icmp(&'static str),
+}
+
+/// A block of the function being emitted, before its phi nodes are known.
+struct Emitted<'a> {
+    label: &'a BlockName,
+    params: Vec<String>,
+    code: String,
+}
+
+struct FunEmitter<'a> {
+    prog: &'a Program,
+    // the next number for a temporary that has no name in our IR
+    next_tmp: usize,
+    // how many times each variable has been defined in this function: the
+    // lowerer reuses names across blocks, which LLVM does not allow
+    defined: HashMap<&'a VarName, usize>,
+    reachable: HashSet<&'a BlockName>,
+    emitted: Vec<Emitted<'a>>,
+    // for each block, the arguments of the branches to it, along with
+    // the label of the block each branch is in
+    incoming: HashMap<&'a BlockName, Vec<(Vec<String>, String)>>,
+}
+
+impl<'a> FunEmitter<'a> {
+    fn new(prog: &'a Program) -> Self {
+        FunEmitter {
+            prog,
+            next_tmp: 0,
+            defined: HashMap::new(),
+            reachable: HashSet::new(),
+            emitted: Vec::new(),
+            incoming: HashMap::new(),
+        }
+    }
+
+    fn emit(mut self, fun_block: &'a FunBlock, out: &mut String) {
+        let mut env = Env::new();
+        let params: Vec<_> = fun_block
+            .params
+            .iter()
+            .map(|p| format!("i64 {}", self.define(p, &mut env)))
+            .collect();
+        writeln!(
+            out,
+            "define i64 {}({}) {{",
+            fun(&fun_block.name),
+            params.join(", ")
+        )
+        .unwrap();
+        writeln!(out, "{}:", START).unwrap();
+        writeln!(out, "  br label {}", label(&fun_block.body.target))
+            .unwrap();
+        let args =
+            fun_block.body.args.iter().map(|a| imm(a, &env)).collect();
+        self.incoming
+            .entry(&fun_block.body.target)
+            .or_default()
+            .push((args, format!("%{}", START)));
+
+        self.reachable = self.prog.reachable_from(&fun_block.body.target);
+        for block in &self.prog.blocks {
+            if self.reachable.contains(&block.label) {
+                self.emit_block(block, Env::new());
+            }
+        }
+
+        for Emitted { label: l, params, code } in &self.emitted {
+            writeln!(out, "{}:", label_def(l)).unwrap();
+            for (i, param) in params.iter().enumerate() {
+                let arms: Vec<_> = self
+                    .incoming
+                    .get(l)
+                    .into_iter()
+                    .flatten()
+                    .map(|(args, from)| format!("[ {}, {} ]", args[i], from))
+                    .collect();
+                writeln!(out, "  {} = phi i64 {}", param, arms.join(", "))
+                    .unwrap();
+            }
+            out.push_str(code);
+        }
+        writeln!(out, "}}").unwrap();
+    }
+
+    /// A fresh LLVM name for `x`, which is now in scope in `env`.
+    fn define(&mut self, x: &'a VarName, env: &mut Env<'a>) -> String {
+        let name = self.fresh(x);
+        env.insert(x, name.clone());
+        name
+    }
+
+    /// An LLVM name for `x` that is not yet defined in this function.
+    fn fresh(&mut self, x: &'a VarName) -> String {
+        let count = self.defined.entry(x).or_insert(0);
+        let name = match *count {
+            0 => format!("%\"{}\"", x),
+            n => format!("%\"{}.{}\"", x, n),
+        };
+        *count += 1;
+        name
+    }
+
+    /// Emit `block` and the reachable blocks nested in it, which see the
+    /// variables of `env`.
+    fn emit_block(&mut self, block: &'a BasicBlock, mut env: Env<'a>) {
+        let params =
+            block.params.iter().map(|p| self.define(p, &mut env)).collect();
+        let mut code = String::new();
+        let mut body = &block.body;
+        loop {
+            match body {
+                BlockBody::Terminator(t) => {
+                    self.emit_terminator(t, &block.label, &env, &mut code);
+                    break;
+                }
+                BlockBody::Operation { dest, op, next } => {
+                    self.emit_operation(dest, op, &mut env, &mut code);
+                    body = next;
+                }
+                BlockBody::SubBlocks { blocks, next } => {
+                    for nested in blocks {
+                        if self.reachable.contains(&nested.label) {
+                            self.emit_block(nested, env.clone());
+                        }
+                    }
+                    body = next;
+                }
+            }
+        }
+        self.emitted.push(Emitted { label: &block.label, params, code });
+    }
+
+    fn tmp(&mut self) -> String {
+        let tmp = format!("%tmp.{}", self.next_tmp);
+        self.next_tmp += 1;
+        tmp
+    }
+
+    fn emit_operation(
+        &mut self, dest: &'a VarName, op: &'a Operation, env: &mut Env<'a>,
+        out: &mut String,
+    ) {
+        // the operands are read before `dest` is in scope
+        let dest_var = dest;
+        let dest = self.fresh(dest_var);
+        let val = |i| imm(i, env);
+        match op {
+            Operation::Immediate(i) => {
+                writeln!(out, "  {} = add i64 {}, 0", dest, val(i)).unwrap()
+            }
+            Operation::Prim1(Prim1::BitNot, i) => {
+                writeln!(out, "  {} = xor i64 {}, -1", dest, val(i)).unwrap()
+            }
+            Operation::Prim1(Prim1::IntToBool, i) => {
+                let tmp = self.tmp();
+                writeln!(out, "  {} = icmp ne i64 {}, 0", tmp, val(i))
+                    .unwrap();
+                writeln!(out, "  {} = zext i1 {} to i64", dest, tmp)
+                    .unwrap();
+            }
+            Operation::Prim2(prim, i1, i2) => {
+                let (i1, i2) = (val(i1), val(i2));
+                let instr = match prim {
+                    Prim2::Add => Llvm2::Arith("add"),
+                    Prim2::Sub => Llvm2::Arith("sub"),
+                    Prim2::Mul => Llvm2::Arith("mul"),
+                    Prim2::BitAnd => Llvm2::Arith("and"),
+                    Prim2::BitOr => Llvm2::Arith("or"),
+                    Prim2::BitXor => Llvm2::Arith("xor"),
+                    Prim2::Lt => Llvm2::Icmp("slt"),
+                    Prim2::Le => Llvm2::Icmp("sle"),
+                    Prim2::Gt => Llvm2::Icmp("sgt"),
+                    Prim2::Ge => Llvm2::Icmp("sge"),
+                    Prim2::Eq => Llvm2::Icmp("eq"),
+                    Prim2::Neq => Llvm2::Icmp("ne"),
+                    Prim2::ULt => Llvm2::Icmp("ult"),
+                    Prim2::ULe => Llvm2::Icmp("ule"),
+                    Prim2::UGt => Llvm2::Icmp("ugt"),
+                    Prim2::UGe => Llvm2::Icmp("uge"),
+                };
+                match instr {
+                    Llvm2::Arith(arith) => writeln!(
+                        out,
+                        "  {} = {} i64 {}, {}",
+                        dest, arith, i1, i2
+                    )
+                    .unwrap(),
+                    Llvm2::Icmp(cc) => {
+                        let tmp = self.tmp();
+                        writeln!(
+                            out,
+                            "  {} = icmp {} i64 {}, {}",
+                            tmp, cc, i1, i2
+                        )
+                        .unwrap();
+                        writeln!(out, "  {} = zext i1 {} to i64", dest, tmp)
+                            .unwrap();
+                    }
+                }
+            }
+            Operation::Call { fun: f, args } => {
+                let args: Vec<_> =
+                    args.iter().map(|a| format!("i64 {}", val(a))).collect();
+                writeln!(
+                    out,
+                    "  {} = call i64 {}({})",
+                    dest,
+                    fun(f),
+                    args.join(", ")
+                )
+                .unwrap();
+            }
+        }
+        env.insert(dest_var, dest);
+    }
+
+    fn emit_terminator(
+        &mut self, t: &'a Terminator, from: &'a BlockName, env: &Env<'a>,
+        out: &mut String,
+    ) {
+        let val = |i| imm(i, env);
+        match t {
+            Terminator::Return(i) => {
+                writeln!(out, "  ret i64 {}", val(i)).unwrap()
+            }
+            Terminator::Branch(Branch { target, args }) => {
+                let args = args.iter().map(val).collect();
+                self.incoming
+                    .entry(target)
+                    .or_default()
+                    .push((args, label(from)));
+                writeln!(out, "  br label {}", label(target)).unwrap()
+            }
+            Terminator::ConditionalBranch { cond, thn, els } => {
+                let tmp = self.tmp();
+                writeln!(out, "  {} = icmp ne i64 {}, 0", tmp, val(cond))
+                    .unwrap();
+                writeln!(
+                    out,
+                    "  br i1 {}, label {}, label {}",
+                    tmp,
+                    label(thn),
+                    label(els)
+                )
+                .unwrap();
+            }
+            Terminator::Switch { scrutinee, targets, default } => {
+                let arms: Vec<_> = targets
+                    .iter()
+                    .enumerate()
+                    .map(|(n, t)| format!("i64 {}, label {}", n, label(t)))
+                    .collect();
+                writeln!(
+                    out,
+                    "  switch i64 {}, label {} [ {} ]",
+                    val(scrutinee),
+                    label(default),
+                    arms.join(" ")
+                )
+                .unwrap();
+            }
+            Terminator::Unreachable => {
+                writeln!(out, "  unreachable").unwrap()
+            }
+        }
+    }
+}
+
+fn fun(f: &FunName) -> String {
+    format!("@\"{}\"", f)
+}
+
+fn label(b: &BlockName) -> String {
+    format!("%\"{}\"", b)
+}
+
+fn label_def(b: &BlockName) -> String {
+    format!("\"{}\"", b)
+}
+
+/// The LLVM value of `i`, whose variables are in scope in `env`.
+fn imm(i: &Immediate, env: &Env) -> String {
+    match i {
+        Immediate::Const(n) => n.to_string(),
+        Immediate::Var(v) => env
+            .get(v)
+            .unwrap_or_else(|| panic!("variable '{}' not in scope", v))
+            .clone(),
+    }
+}
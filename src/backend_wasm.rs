@@ -0,0 +1,277 @@
+//! An alternative backend that translates our intermediate representation
+//! into the WebAssembly text format.
+//!
+//! Wasm only has structured control flow, while our blocks may branch to
+//! each other freely. Rather than recover loops and conditionals, each
+//! function runs a dispatch loop: a `$pc` local holds the index of the
+//! block to run next, and a `br_table` jumps to its code. Branching to a
+//! block sets its parameters and `$pc`, then restarts the loop. Every
+//! variable is a local, and integers are untagged.
+
+use crate::identifiers::*;
+use crate::ssa::*;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// The label of the loop that runs the block `$pc` names.
+const DISPATCH: &str = "$dispatch";
+/// The local holding the index of the block to run next.
+const PC: &str = "$pc";
+
+/// Translate `prog` into a wasm module. Externs are imported from the
+/// `env` module, and the entry point is exported.
+pub fn emit_wasm(prog: &Program) -> String {
+    let mut out = String::from("(module\n");
+    for ext in &prog.externs {
+        writeln!(
+            out,
+            "  (import \"env\" \"{}\" (func {}{} (result i64)))",
+            ext.name,
+            fun(&ext.name),
+            " (param i64)".repeat(ext.params.len())
+        )
+        .unwrap();
+    }
+    for fun_block in &prog.funs {
+        FunEmitter::new(prog).emit(fun_block, &mut out);
+    }
+    writeln!(out, ")").unwrap();
+    out
+}
+
+struct FunEmitter<'a> {
+    prog: &'a Program,
+    // the reachable blocks, in the order of their `$pc` index
+    blocks: Vec<&'a BasicBlock>,
+    index: HashMap<&'a BlockName, usize>,
+}
+
+impl<'a> FunEmitter<'a> {
+    fn new(prog: &'a Program) -> Self {
+        FunEmitter { prog, blocks: Vec::new(), index: HashMap::new() }
+    }
+
+    fn emit(mut self, fun_block: &'a FunBlock, out: &mut String) {
+        let reachable = self.prog.reachable_from(&fun_block.body.target);
+        self.blocks = self
+            .prog
+            .blocks_recursive()
+            .filter(|b| reachable.contains(&b.label))
+            .collect();
+        self.index = self
+            .blocks
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (&b.label, i))
+            .collect();
+
+        write!(out, "  (func {}", fun(&fun_block.name)).unwrap();
+        if let FunName::Unmangled(name) = &fun_block.name {
+            write!(out, " (export \"{}\")", name).unwrap();
+        }
+        for param in &fun_block.params {
+            write!(out, " (param {} i64)", var(param)).unwrap();
+        }
+        writeln!(out, " (result i64)").unwrap();
+        writeln!(out, "    (local {} i32)", PC).unwrap();
+        let mut locals = HashSet::new();
+        for block in &self.blocks {
+            for x in block.params.iter().chain(dests(&block.body)) {
+                if !fun_block.params.contains(x) && locals.insert(x) {
+                    writeln!(out, "    (local {} i64)", var(x)).unwrap();
+                }
+            }
+        }
+
+        self.emit_branch(&fun_block.body, out);
+        writeln!(out, "    loop {}", DISPATCH).unwrap();
+        for block in self.blocks.iter().rev() {
+            writeln!(out, "    block {}", label(&block.label)).unwrap();
+        }
+        writeln!(out, "    local.get {}", PC).unwrap();
+        let labels: Vec<_> =
+            self.blocks.iter().map(|b| label(&b.label)).collect();
+        // the last block doubles as the default, which is never taken
+        writeln!(
+            out,
+            "    br_table {} {}",
+            labels.join(" "),
+            labels.last().unwrap()
+        )
+        .unwrap();
+        for block in &self.blocks {
+            writeln!(out, "    end").unwrap();
+            self.emit_block_body(&block.body, out);
+        }
+        writeln!(out, "    end").unwrap();
+        writeln!(out, "    unreachable").unwrap();
+        writeln!(out, "  )").unwrap();
+    }
+
+    /// Emit the code of a block. Nested blocks are run by the dispatch
+    /// loop like any other, so they are skipped here.
+    fn emit_block_body(&self, mut body: &'a BlockBody, out: &mut String) {
+        loop {
+            match body {
+                BlockBody::Terminator(t) => {
+                    self.emit_terminator(t, out);
+                    return;
+                }
+                BlockBody::Operation { dest, op, next } => {
+                    emit_operation(op, out);
+                    writeln!(out, "    local.set {}", var(dest)).unwrap();
+                    body = next;
+                }
+                BlockBody::SubBlocks { next, .. } => body = next,
+            }
+        }
+    }
+
+    /// Set `$pc` to run `target` next.
+    fn goto(&self, target: &BlockName, out: &mut String) {
+        writeln!(out, "    i32.const {}", self.index[target]).unwrap();
+        writeln!(out, "    local.set {}", PC).unwrap();
+    }
+
+    /// Set the parameters of the target of `branch` and `$pc`. All the
+    /// arguments are pushed before any parameter is set, since they may
+    /// read the parameters they replace.
+    fn emit_branch(&self, branch: &Branch, out: &mut String) {
+        let block = self.blocks[self.index[&branch.target]];
+        for arg in &branch.args {
+            push(arg, out);
+        }
+        for param in block.params.iter().rev() {
+            writeln!(out, "    local.set {}", var(param)).unwrap();
+        }
+        self.goto(&branch.target, out);
+    }
+
+    fn emit_terminator(&self, t: &Terminator, out: &mut String) {
+        match t {
+            Terminator::Return(i) => {
+                push(i, out);
+                writeln!(out, "    return").unwrap();
+                return;
+            }
+            Terminator::Branch(branch) => self.emit_branch(branch, out),
+            Terminator::ConditionalBranch { cond, thn, els } => {
+                push(cond, out);
+                writeln!(out, "    i64.const 0\n    i64.ne\n    if")
+                    .unwrap();
+                self.goto(thn, out);
+                writeln!(out, "    else").unwrap();
+                self.goto(els, out);
+                writeln!(out, "    end").unwrap();
+            }
+            Terminator::Switch { scrutinee, targets, default } => {
+                for (n, target) in targets.iter().enumerate() {
+                    push(scrutinee, out);
+                    writeln!(out, "    i64.const {}\n    i64.eq\n    if", n)
+                        .unwrap();
+                    self.goto(target, out);
+                    writeln!(out, "    br {}\n    end", DISPATCH).unwrap();
+                }
+                self.goto(default, out);
+            }
+            Terminator::Unreachable => {
+                writeln!(out, "    unreachable").unwrap();
+                return;
+            }
+        }
+        writeln!(out, "    br {}", DISPATCH).unwrap();
+    }
+}
+
+/// Push the result of `op`.
+fn emit_operation(op: &Operation, out: &mut String) {
+    match op {
+        Operation::Immediate(i) => push(i, out),
+        Operation::Prim1(Prim1::BitNot, i) => {
+            push(i, out);
+            writeln!(out, "    i64.const -1\n    i64.xor").unwrap();
+        }
+        Operation::Prim1(Prim1::IntToBool, i) => {
+            push(i, out);
+            writeln!(
+                out,
+                "    i64.const 0\n    i64.ne\n    i64.extend_i32_u"
+            )
+            .unwrap();
+        }
+        Operation::Prim2(prim, i1, i2) => {
+            push(i1, out);
+            push(i2, out);
+            let (instr, compares) = match prim {
+                Prim2::Add => ("add", false),
+                Prim2::Sub => ("sub", false),
+                Prim2::Mul => ("mul", false),
+                Prim2::BitAnd => ("and", false),
+                Prim2::BitOr => ("or", false),
+                Prim2::BitXor => ("xor", false),
+                Prim2::Lt => ("lt_s", true),
+                Prim2::Le => ("le_s", true),
+                Prim2::Gt => ("gt_s", true),
+                Prim2::Ge => ("ge_s", true),
+                Prim2::Eq => ("eq", true),
+                Prim2::Neq => ("ne", true),
+                Prim2::ULt => ("lt_u", true),
+                Prim2::ULe => ("le_u", true),
+                Prim2::UGt => ("gt_u", true),
+                Prim2::UGe => ("ge_u", true),
+            };
+            writeln!(out, "    i64.{}", instr).unwrap();
+            // comparisons produce an i32
+            if compares {
+                writeln!(out, "    i64.extend_i32_u").unwrap();
+            }
+        }
+        Operation::Call { fun: f, args } => {
+            for arg in args {
+                push(arg, out);
+            }
+            writeln!(out, "    call {}", fun(f)).unwrap();
+        }
+    }
+}
+
+/// The variables defined by the operations of `body`, not counting those
+/// of nested blocks.
+fn dests(mut body: &BlockBody) -> Vec<&VarName> {
+    let mut dests = Vec::new();
+    loop {
+        match body {
+            BlockBody::Terminator(_) => return dests,
+            BlockBody::Operation { dest, next, .. } => {
+                dests.push(dest);
+                body = next;
+            }
+            BlockBody::SubBlocks { next, .. } => body = next,
+        }
+    }
+}
+
+fn push(i: &Immediate, out: &mut String) {
+    match i {
+        Immediate::Const(n) => writeln!(out, "    i64.const {}", n).unwrap(),
+        Immediate::Var(x) => {
+            writeln!(out, "    local.get {}", var(x)).unwrap()
+        }
+    }
+}
+
+// Our names are made of characters wasm allows in identifiers, and their
+// sigils keep variables, functions and labels apart.
+
+fn var(x: &VarName) -> String {
+    format!("${}", x)
+}
+
+fn fun(f: &FunName) -> String {
+    format!("${}", f)
+}
+
+fn label(b: &BlockName) -> String {
+    format!("${}", b)
+}
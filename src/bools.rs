@@ -0,0 +1,117 @@
+//! Removal of redundant boolean coercions on SSA.
+//!
+//! The lowerer coerces the operands of `&&`, `||` and `!` to 0 or 1 with
+//! `IntToBool`, even when they are comparisons that already produce 0 or
+//! 1. Such a coercion is replaced by a copy of its operand.
+
+use crate::identifiers::VarName;
+use crate::ssa::*;
+use std::collections::HashSet;
+
+/// What eliding boolean coercions did to a program.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BoolStats {
+    /// the number of `IntToBool`s replaced by copies
+    pub elided: usize,
+}
+
+pub fn elide_bool_coercions(prog: Program) -> Program {
+    elide_bool_coercions_with_stats(prog).0
+}
+
+/// Elide the coercions in `prog` whose operand is already 0 or 1,
+/// counting how many there were.
+pub fn elide_bool_coercions_with_stats(
+    mut prog: Program,
+) -> (Program, BoolStats) {
+    let bools = known_bools(&prog);
+    let mut stats = BoolStats::default();
+    for block in prog.blocks_recursive_mut() {
+        for (_, op) in block.ops {
+            if let Operation::Prim1(Prim1::IntToBool, imm) = op {
+                if is_bool(imm, &bools) {
+                    *op = Operation::Immediate(imm.clone());
+                    stats.elided += 1;
+                }
+            }
+        }
+    }
+    (prog, stats)
+}
+
+/// The variables that only ever hold 0 or 1. A name the lowerer reuses
+/// counts only if every operation defining it produces 0 or 1, and
+/// parameters may be passed anything. Starting from every other variable,
+/// the ones defined from a variable found not to be boolean are dropped
+/// until none are left to drop.
+fn known_bools(prog: &Program) -> HashSet<VarName> {
+    let mut params = HashSet::new();
+    let mut defs = Vec::new();
+    for fun in &prog.funs {
+        params.extend(fun.params.iter());
+    }
+    for block in prog.blocks_recursive() {
+        params.extend(block.params.iter());
+        let mut body = &block.body;
+        loop {
+            match body {
+                BlockBody::Terminator(_) => break,
+                BlockBody::Operation { dest, op, next } => {
+                    defs.push((dest, op));
+                    body = next;
+                }
+                BlockBody::SubBlocks { next, .. } => body = next,
+            }
+        }
+    }
+
+    let mut bools: HashSet<VarName> = defs
+        .iter()
+        .map(|(dest, _)| *dest)
+        .filter(|dest| !params.contains(dest))
+        .cloned()
+        .collect();
+    loop {
+        let before = bools.len();
+        for (dest, op) in &defs {
+            if !produces_bool(op, &bools) {
+                bools.remove(*dest);
+            }
+        }
+        if bools.len() == before {
+            return bools;
+        }
+    }
+}
+
+fn produces_bool(op: &Operation, bools: &HashSet<VarName>) -> bool {
+    match op {
+        Operation::Immediate(imm) => is_bool(imm, bools),
+        Operation::Prim1(Prim1::IntToBool, _) => true,
+        Operation::Prim1(Prim1::BitNot, _) => false,
+        Operation::Prim2(prim, imm1, imm2) => match prim {
+            Prim2::Add | Prim2::Sub | Prim2::Mul => false,
+            Prim2::BitAnd | Prim2::BitOr | Prim2::BitXor => {
+                is_bool(imm1, bools) && is_bool(imm2, bools)
+            }
+            Prim2::Lt
+            | Prim2::Le
+            | Prim2::Gt
+            | Prim2::Ge
+            | Prim2::Eq
+            | Prim2::Neq
+            | Prim2::ULt
+            | Prim2::ULe
+            | Prim2::UGt
+            | Prim2::UGe => true,
+        },
+        Operation::Call { .. } => false,
+    }
+}
+
+fn is_bool(imm: &Immediate, bools: &HashSet<VarName>) -> bool {
+    match imm {
+        Immediate::Const(n) => *n == 0 || *n == 1,
+        Immediate::Var(x) => bools.contains(x),
+    }
+}
@@ -1,46 +1,431 @@
-use crate::asm::instrs_to_string;
+use crate::asm::{instrs_to_string, Reg};
 use crate::ast::BoundProg;
 use crate::backend::Emitter;
-use crate::frontend::Resolver;
+use crate::bools::{elide_bool_coercions_with_stats, BoolStats};
+use crate::dce::{eliminate_dead_code_with_stats, DceStats};
+use crate::fold::{fold_prog_with_stats, FoldStats};
+use crate::frontend::{CompileErr, Resolver, MAX_ERRORS};
+use crate::identifiers::IdGen;
+use crate::licm::{hoist_invariants_with_stats, LicmStats};
 use crate::middle_end::Lowerer;
 use crate::parser::ProgParser;
-use crate::ssa::Program;
+use crate::ssa::{Immediate, IntRepr, Program};
 use crate::txt::FileInfo;
+use crate::types::bool_vars;
+use std::fmt;
+
+/// The ways compiling and running a program can fail, so that library
+/// users can tell them apart. Displaying one gives the message the CLI
+/// prints.
+#[derive(Debug)]
+pub enum Error {
+    /// The program does not parse, with the parser's message.
+    Parse(String),
+    /// The program does not resolve. The errors are reported against the
+    /// source they were found in.
+    Resolve(Vec<CompileErr>, FileInfo),
+    /// The program has a constant that the integer representation has no
+    /// room for.
+    OutOfRange(i64),
+    /// Assembling or linking the compiled code failed.
+    Link(String),
+    /// The compiled program exited unsuccessfully, with its exit code if
+    /// it was not killed by a signal.
+    Run { code: Option<i32>, stderr: String },
+    /// The compiled program was still running after the given time, and
+    /// was killed.
+    Timeout(std::time::Duration),
+    /// Reading the program or talking to a process failed.
+    Io(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(msg) => write!(f, "Error parsing program: {}", msg),
+            Error::Resolve(errs, file_info) => {
+                let reports: Vec<_> = errs
+                    .iter()
+                    .map(|e| format!("Error resolving ast: {}", file_info.report_error(e.clone())))
+                    .collect();
+                write!(f, "{}", reports.join("\n"))
+            }
+            Error::OutOfRange(n) => write!(
+                f,
+                "Error: constant {} does not fit in a tagged integer",
+                n
+            ),
+            Error::Link(msg) | Error::Io(msg) => write!(f, "{}", msg),
+            Error::Run { code, stderr } => {
+                let status = match code {
+                    Some(code) => format!("exit status: {}", code),
+                    None => "killed by a signal".to_string(),
+                };
+                write!(f, "Error code {} when running compiled code Stderr:\n{}", status, stderr)
+            }
+            Error::Timeout(limit) => {
+                write!(f, "compiled code killed after running for {:?}", limit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
 
 /// compiler pipeline
-pub fn compile(s: &str) -> Result<String, String> {
-    let (resolver, resolved_ast) = frontend(s)?;
-    let (lowerer, ssa) = middle_end(resolver, resolved_ast)?;
-    let asm = backend(lowerer, ssa);
-    Ok(asm)
+pub fn compile(s: &str) -> Result<String, Error> {
+    Pipeline::default().compile(s)
+}
+
+/// Like `compile`, but reports errors against `file_info`, which the
+/// caller may already have for `s`.
+pub fn compile_with(s: &str, file_info: &FileInfo) -> Result<String, Error> {
+    Pipeline::default().compile_with(s, file_info)
 }
 
 /// Frontend, parsing and validation
-pub fn frontend(s: &str) -> Result<(Resolver, BoundProg), String> {
-    let file_info = FileInfo::new(s);
-    let raw_ast =
-        ProgParser::new().parse(s).map_err(|e| format!("Error parsing program: {}", e))?;
-    let mut resolver = Resolver::new();
-    let resolved_ast = resolver
-        .resolve_prog(raw_ast)
-        .map_err(|e| format!("Error resolving ast: {}", file_info.report_error(e)))?;
-    Ok((resolver, resolved_ast))
+pub fn frontend(s: &str) -> Result<(Resolver, BoundProg), Error> {
+    Pipeline::default().frontend(s)
+}
+
+/// Like `frontend`, but reports errors against `file_info`.
+pub fn frontend_with(
+    s: &str, file_info: &FileInfo,
+) -> Result<(Resolver, BoundProg), Error> {
+    Pipeline::default().frontend_with(s, file_info)
 }
 
 /// Middle-end, lambda lifting and SSA construction
 pub fn middle_end(
     resolver: Resolver, resolved_ast: BoundProg,
-) -> Result<(Lowerer, Program), String> {
-    let mut lowerer = Lowerer::from(resolver);
-    let ssa = lowerer.lower_prog(resolved_ast);
-    Ok((lowerer, ssa))
+) -> Result<(Lowerer, Program), Error> {
+    Pipeline::default().middle_end(resolver, resolved_ast)
 }
 
 /// Backend, code generation
 pub fn backend(lowerer: Lowerer, ssa: Program) -> String {
-    let mut emitter = Emitter::from(lowerer);
-    emitter.emit_prog(&ssa);
-    let asm = emitter.to_asm();
-    let txt = instrs_to_string(&asm);
-    txt
+    Pipeline::default()
+        .backend(lowerer, ssa)
+        .expect("untagged integers have room for every constant")
+}
+
+/// A short description of what each stage of the pipeline did to `s`.
+pub fn explain(s: &str) -> Result<String, Error> {
+    Pipeline::default().explain(s)
+}
+
+/// What the optimization passes a pipeline ran did. Displaying it gives
+/// a line for each pass that ran.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OptReport {
+    pub fold: Option<FoldStats>,
+    pub bools: Option<BoolStats>,
+    pub licm: Option<LicmStats>,
+    pub dce: Option<DceStats>,
+}
+
+impl fmt::Display for OptReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(stats) = self.fold {
+            writeln!(f, "fold: folded {} primitives", stats.folded)?;
+        }
+        if let Some(stats) = self.bools {
+            writeln!(f, "bools: elided {} coercions", stats.elided)?;
+        }
+        if let Some(stats) = self.licm {
+            writeln!(f, "licm: hoisted {} operations", stats.hoisted)?;
+        }
+        if let Some(stats) = self.dce {
+            writeln!(f, "dce: removed {} operations", stats.removed)?;
+        }
+        Ok(())
+    }
+}
+
+/// The stages of the compiler with the options they share, so that they
+/// can be configured once and run together or one at a time. The default
+/// pipeline compiles as `compile` does.
+#[derive(Clone, Debug)]
+pub struct Pipeline {
+    entry: Option<String>,
+    max_errors: usize,
+    forward_refs: bool,
+    fold_constants: bool,
+    elide_bool_coercions: bool,
+    bool_types: bool,
+    hoist_invariants: bool,
+    eliminate_dead_code: bool,
+    repr: IntRepr,
+    registers: Option<Vec<Reg>>,
+    id_offset: usize,
+}
+
+impl Default for Pipeline {
+    fn default() -> Self {
+        Pipeline {
+            entry: None,
+            max_errors: MAX_ERRORS,
+            forward_refs: false,
+            fold_constants: false,
+            elide_bool_coercions: false,
+            bool_types: false,
+            hoist_invariants: false,
+            eliminate_dead_code: false,
+            repr: IntRepr::default(),
+            registers: None,
+            id_offset: 0,
+        }
+    }
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Pipeline::default()
+    }
+
+    /// Compile `main` to the symbol `entry`, as `Resolver::with_entry`.
+    pub fn with_entry(mut self, entry: impl Into<String>) -> Self {
+        self.entry = Some(entry.into());
+        self
+    }
+
+    /// Report at most `max` resolution errors.
+    pub fn with_max_errors(mut self, max: usize) -> Self {
+        self.max_errors = max;
+        self
+    }
+
+    /// Resolve as `Resolver::with_forward_refs`.
+    pub fn with_forward_refs(mut self) -> Self {
+        self.forward_refs = true;
+        self
+    }
+
+    /// Fold constant primitives after resolving.
+    pub fn with_fold_constants(mut self) -> Self {
+        self.fold_constants = true;
+        self
+    }
+
+    /// Elide boolean coercions after lowering.
+    pub fn with_elide_bool_coercions(mut self) -> Self {
+        self.elide_bool_coercions = true;
+        self
+    }
+
+    /// Type the resolved program and lower it without coercing operands
+    /// already known to be 0 or 1, as `Lowerer::with_bool_types`.
+    pub fn with_bool_types(mut self) -> Self {
+        self.bool_types = true;
+        self
+    }
+
+    /// Hoist loop-invariant operations after lowering, and after eliding
+    /// coercions.
+    pub fn with_hoist_invariants(mut self) -> Self {
+        self.hoist_invariants = true;
+        self
+    }
+
+    /// Eliminate dead code after lowering, and after the other passes.
+    pub fn with_eliminate_dead_code(mut self) -> Self {
+        self.eliminate_dead_code = true;
+        self
+    }
+
+    /// Generate code that represents integers according to `repr`.
+    pub fn with_repr(mut self, repr: IntRepr) -> Self {
+        self.repr = repr;
+        self
+    }
+
+    /// Keep variables in `regs`, as `Emitter::with_registers`.
+    pub fn with_registers(mut self, regs: &[Reg]) -> Self {
+        self.registers = Some(regs.to_vec());
+        self
+    }
+
+    /// Start the indices of the names the compiler makes up at `offset`
+    /// rather than 0. The output is the same but for the names, which
+    /// shakes out any code that depends on what the indices are.
+    pub fn with_id_offset(mut self, offset: usize) -> Self {
+        self.id_offset = offset;
+        self
+    }
+
+    /// The integer representation the generated code uses.
+    pub fn repr(&self) -> IntRepr {
+        self.repr
+    }
+
+    /// A resolver configured as this pipeline's frontend uses it.
+    pub fn resolver(&self) -> Resolver {
+        let mut resolver = Resolver::new()
+            .with_max_errors(self.max_errors)
+            .with_ids(IdGen::starting_at(self.id_offset), IdGen::starting_at(self.id_offset));
+        if let Some(entry) = &self.entry {
+            resolver = resolver.with_entry(entry.clone());
+        }
+        if self.forward_refs {
+            resolver = resolver.with_forward_refs();
+        }
+        resolver
+    }
+
+    /// A lowerer for the program `resolver` resolved, configured as this
+    /// pipeline's middle-end uses it.
+    pub fn lowerer(&self, resolver: Resolver) -> Lowerer {
+        Lowerer::from(resolver).with_block_ids(IdGen::starting_at(self.id_offset))
+    }
+
+    /// An emitter for the functions `lowerer` lowered into `ssa`,
+    /// configured as this pipeline's backend uses it. Fails if `ssa` has a
+    /// constant that the pipeline's integer representation has no room
+    /// for.
+    pub fn emitter(
+        &self, lowerer: Lowerer, ssa: &Program,
+    ) -> Result<Emitter, Error> {
+        check_constants(ssa, self.repr)?;
+        let mut emitter = Emitter::from(lowerer).with_repr(self.repr);
+        if let Some(regs) = &self.registers {
+            emitter = emitter.with_registers(regs);
+        }
+        Ok(emitter)
+    }
+
+    /// Frontend, parsing, validation and folding if enabled
+    pub fn frontend(&self, s: &str) -> Result<(Resolver, BoundProg), Error> {
+        self.frontend_with(s, &FileInfo::new(s))
+    }
+
+    /// Like `frontend`, but reports errors against `file_info`.
+    pub fn frontend_with(
+        &self, s: &str, file_info: &FileInfo,
+    ) -> Result<(Resolver, BoundProg), Error> {
+        let raw_ast = ProgParser::new()
+            .parse(s)
+            .map_err(|e| Error::Parse(file_info.report_parse_error(s, e)))?;
+        let mut resolver = self.resolver();
+        let resolved_ast = resolver
+            .resolve_prog_all(raw_ast)
+            .map_err(|errs| Error::Resolve(errs, file_info.clone()))?;
+        Ok((resolver, self.fold(resolved_ast).0))
+    }
+
+    /// Fold the constants of `resolved_ast` if enabled.
+    pub fn fold(&self, resolved_ast: BoundProg) -> (BoundProg, OptReport) {
+        let mut report = OptReport::default();
+        if !self.fold_constants {
+            return (resolved_ast, report);
+        }
+        let (folded, stats) = fold_prog_with_stats(resolved_ast);
+        report.fold = Some(stats);
+        (folded, report)
+    }
+
+    /// Middle-end, lambda lifting, SSA construction and the enabled passes
+    pub fn middle_end(
+        &self, resolver: Resolver, resolved_ast: BoundProg,
+    ) -> Result<(Lowerer, Program), Error> {
+        let (lowerer, ssa) = self.lower(resolver, resolved_ast);
+        let (ssa, _) = self.optimize(ssa);
+        Ok((lowerer, ssa))
+    }
+
+    /// Lambda lifting and SSA construction, without the passes
+    pub fn lower(
+        &self, resolver: Resolver, resolved_ast: BoundProg,
+    ) -> (Lowerer, Program) {
+        let mut lowerer = self.lowerer(resolver);
+        if self.bool_types {
+            lowerer = lowerer.with_bool_types(bool_vars(&resolved_ast));
+        }
+        let ssa = lowerer.lower_prog(resolved_ast);
+        (lowerer, ssa)
+    }
+
+    /// Run the enabled passes on `ssa`, in the order they depend on each
+    /// other.
+    pub fn optimize(&self, mut ssa: Program) -> (Program, OptReport) {
+        let mut report = OptReport::default();
+        if self.elide_bool_coercions {
+            let (elided, stats) = elide_bool_coercions_with_stats(ssa);
+            ssa = elided;
+            report.bools = Some(stats);
+        }
+        if self.hoist_invariants {
+            let (hoisted, stats) = hoist_invariants_with_stats(ssa);
+            ssa = hoisted;
+            report.licm = Some(stats);
+        }
+        if self.eliminate_dead_code {
+            let (live, stats) = eliminate_dead_code_with_stats(ssa);
+            ssa = live;
+            report.dce = Some(stats);
+        }
+        (ssa, report)
+    }
+
+    /// Backend, code generation
+    pub fn backend(
+        &self, lowerer: Lowerer, ssa: Program,
+    ) -> Result<String, Error> {
+        let mut emitter = self.emitter(lowerer, &ssa)?;
+        emitter.emit_prog(&ssa);
+        Ok(instrs_to_string(&emitter.to_asm()))
+    }
+
+    /// The whole pipeline, from source to assembly
+    pub fn compile(&self, s: &str) -> Result<String, Error> {
+        self.compile_with(s, &FileInfo::new(s))
+    }
+
+    /// Like `compile`, but reports errors against `file_info`.
+    pub fn compile_with(
+        &self, s: &str, file_info: &FileInfo,
+    ) -> Result<String, Error> {
+        let (resolver, resolved_ast) = self.frontend_with(s, file_info)?;
+        let (lowerer, ssa) = self.middle_end(resolver, resolved_ast)?;
+        self.backend(lowerer, ssa)
+    }
+
+    /// A short description of what each stage of this pipeline did to
+    /// `s`.
+    pub fn explain(&self, s: &str) -> Result<String, Error> {
+        let (resolver, resolved_ast) = self.frontend(s)?;
+        let renamed = resolver.renamed_vars();
+        let (lowerer, ssa) = self.middle_end(resolver, resolved_ast)?;
+        let lifted = lowerer.lifted_funs();
+        let blocks = ssa.blocks_recursive().count();
+        let mut emitter = self.emitter(lowerer, &ssa)?;
+        emitter.emit_prog(&ssa);
+        let instrs = emitter.to_asm().len();
+        Ok(format!(
+            "resolver: gave {} variables unique names\n\
+             lowerer: lifted {} local functions to the top level\n\
+             lowerer: generated {} basic blocks\n\
+             emitter: emitted {} instructions",
+            renamed, lifted, blocks, instrs
+        ))
+    }
+}
+
+/// Check that `repr` has room for every constant in `prog`, which the
+/// backend needs to spell them out.
+pub fn check_constants(prog: &Program, repr: IntRepr) -> Result<(), Error> {
+    for imm in prog.immediates() {
+        if let Immediate::Const(n) = imm {
+            if !repr.in_range(*n) {
+                return Err(Error::OutOfRange(*n));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The symbols a runtime must provide for `prog`, with their arities:
+/// the externs it declares, and those the lowerer added, like the error
+/// function.
+pub fn externs_of(prog: &Program) -> Vec<(String, usize)> {
+    prog.externs.iter().map(|ext| (ext.name.to_string(), ext.params.len())).collect()
 }
@@ -0,0 +1,73 @@
+//! Dead code elimination on SSA.
+//!
+//! An operation whose result is never read is removed, unless it is a
+//! call, which may have an effect. Removing an operation can leave the
+//! operations it read from unread in turn, so this repeats until nothing
+//! more can be removed.
+
+use crate::identifiers::VarName;
+use crate::ssa::*;
+use std::collections::HashSet;
+
+/// What dead code elimination did to a program.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DceStats {
+    /// the number of operations removed
+    pub removed: usize,
+}
+
+pub fn eliminate_dead_code(prog: Program) -> Program {
+    eliminate_dead_code_with_stats(prog).0
+}
+
+/// Eliminate dead code from `prog`, counting what was removed.
+pub fn eliminate_dead_code_with_stats(
+    mut prog: Program,
+) -> (Program, DceStats) {
+    let mut stats = DceStats::default();
+    loop {
+        let used = prog.used_vars();
+        let before = stats.removed;
+        prog.blocks = prog
+            .blocks
+            .into_iter()
+            .map(|block| sweep_block(block, &used, &mut stats))
+            .collect();
+        if stats.removed == before {
+            return (prog, stats);
+        }
+    }
+}
+
+fn sweep_block(
+    block: BasicBlock, used: &HashSet<VarName>, stats: &mut DceStats,
+) -> BasicBlock {
+    BasicBlock { body: sweep(block.body, used, stats), ..block }
+}
+
+fn sweep(
+    body: BlockBody, used: &HashSet<VarName>, stats: &mut DceStats,
+) -> BlockBody {
+    match body {
+        BlockBody::Operation { dest, op, next }
+            if !used.contains(&dest)
+                && !matches!(op, Operation::Call { .. }) =>
+        {
+            stats.removed += 1;
+            sweep(*next, used, stats)
+        }
+        BlockBody::Operation { dest, op, next } => BlockBody::Operation {
+            dest,
+            op,
+            next: Box::new(sweep(*next, used, stats)),
+        },
+        BlockBody::SubBlocks { blocks, next } => BlockBody::SubBlocks {
+            blocks: blocks
+                .into_iter()
+                .map(|block| sweep_block(block, used, stats))
+                .collect(),
+            next: Box::new(sweep(*next, used, stats)),
+        },
+        BlockBody::Terminator(_) => body,
+    }
+}
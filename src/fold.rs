@@ -0,0 +1,130 @@
+//! Constant folding on the AST.
+//!
+//! A primitive whose arguments fold to constants is replaced by its value.
+//! The new constant takes the location of the primitive it replaces, so
+//! that it covers the whole original subexpression: every node of the
+//! folded program still points at the source it came from.
+
+use crate::ast::*;
+
+/// What constant folding did to a program.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FoldStats {
+    /// the number of primitives replaced by their value
+    pub folded: usize,
+}
+
+pub fn fold_prog<Var, Fun>(prog: Prog<Var, Fun>) -> Prog<Var, Fun> {
+    fold_prog_with_stats(prog).0
+}
+
+/// Fold `prog`, counting what was folded.
+pub fn fold_prog_with_stats<Var, Fun>(
+    prog: Prog<Var, Fun>,
+) -> (Prog<Var, Fun>, FoldStats) {
+    let mut stats = FoldStats::default();
+    let funs = prog
+        .funs
+        .into_iter()
+        .map(|decl| FunDecl {
+            body: fold_expr(decl.body, &mut stats),
+            ..decl
+        })
+        .collect();
+    let body = fold_expr(prog.body, &mut stats);
+    (Prog { funs, body, ..prog }, stats)
+}
+
+fn fold_expr<Var, Fun>(
+    expr: Expr<Var, Fun>, stats: &mut FoldStats,
+) -> Expr<Var, Fun> {
+    let mut fold = |e| fold_expr(e, stats);
+    match expr {
+        Expr::Num(..) | Expr::Bool(..) | Expr::Var(..) => expr,
+        Expr::Prim { prim, args, loc } => {
+            let args: Vec<_> = args.into_iter().map(&mut fold).collect();
+            match eval_prim(&prim, &args, loc) {
+                Some(folded) => {
+                    stats.folded += 1;
+                    folded
+                }
+                None => Expr::Prim { prim, args, loc },
+            }
+        }
+        Expr::Let { bindings, body, kind, loc } => Expr::Let {
+            bindings: bindings
+                .into_iter()
+                .map(|Binding { var, expr }| Binding {
+                    var,
+                    expr: fold(expr),
+                })
+                .collect(),
+            body: Box::new(fold(*body)),
+            kind,
+            loc,
+        },
+        Expr::If { cond, thn, els, loc } => Expr::If {
+            cond: Box::new(fold(*cond)),
+            thn: Box::new(fold(*thn)),
+            els: Box::new(fold(*els)),
+            loc,
+        },
+        Expr::FunDefs { decls, body, loc } => Expr::FunDefs {
+            decls: decls
+                .into_iter()
+                .map(|decl| FunDecl { body: fold(decl.body), ..decl })
+                .collect(),
+            body: Box::new(fold(*body)),
+            loc,
+        },
+        Expr::Match { scrutinee, arms, default, loc } => Expr::Match {
+            scrutinee: Box::new(fold(*scrutinee)),
+            arms: arms
+                .into_iter()
+                .map(|MatchArm { pattern, body }| MatchArm {
+                    pattern,
+                    body: fold(body),
+                })
+                .collect(),
+            default: default.map(|default| Box::new(fold(*default))),
+            loc,
+        },
+        Expr::Call { fun, args, loc } => Expr::Call {
+            fun,
+            args: args.into_iter().map(&mut fold).collect(),
+            loc,
+        },
+    }
+}
+
+/// The value of `prim` applied to `args`, located at `loc`, if the
+/// arguments are constants of the right type. Arithmetic that would
+/// overflow is left for the program to do at runtime.
+fn eval_prim<Var, Fun>(
+    prim: &Prim, args: &[Expr<Var, Fun>], loc: SrcLoc,
+) -> Option<Expr<Var, Fun>> {
+    use Expr::{Bool, Num};
+    let num = |n: Option<i64>| n.map(|n| Num(n, loc));
+    match (prim, args) {
+        (Prim::Add1, [Num(n, _)]) => num(n.checked_add(1)),
+        (Prim::Sub1, [Num(n, _)]) => num(n.checked_sub(1)),
+        (Prim::Add, [Num(n, _), Num(m, _)]) => num(n.checked_add(*m)),
+        (Prim::Sub, [Num(n, _), Num(m, _)]) => num(n.checked_sub(*m)),
+        (Prim::Mul, [Num(n, _), Num(m, _)]) => num(n.checked_mul(*m)),
+        (Prim::Not, [Bool(b, _)]) => Some(Bool(!b, loc)),
+        (Prim::And, [Bool(b, _), Bool(c, _)]) => Some(Bool(*b && *c, loc)),
+        (Prim::Or, [Bool(b, _), Bool(c, _)]) => Some(Bool(*b || *c, loc)),
+        (Prim::Lt, [Num(n, _), Num(m, _)]) => Some(Bool(n < m, loc)),
+        (Prim::Le, [Num(n, _), Num(m, _)]) => Some(Bool(n <= m, loc)),
+        (Prim::Gt, [Num(n, _), Num(m, _)]) => Some(Bool(n > m, loc)),
+        (Prim::Ge, [Num(n, _), Num(m, _)]) => Some(Bool(n >= m, loc)),
+        (Prim::Eq, [Num(n, _), Num(m, _)]) => Some(Bool(n == m, loc)),
+        (Prim::Eq, [Bool(b, _), Bool(c, _)]) => Some(Bool(b == c, loc)),
+        (Prim::Neq, [Num(n, _), Num(m, _)]) => Some(Bool(n != m, loc)),
+        (Prim::Neq, [Bool(b, _), Bool(c, _)]) => Some(Bool(b != c, loc)),
+        (Prim::Cmp, [Num(n, _), Num(m, _)]) => {
+            Some(Num(n.cmp(m) as i64, loc))
+        }
+        _ => None,
+    }
+}
@@ -6,29 +6,61 @@
 use crate::ast::*;
 use crate::identifiers::*;
 use crate::span::SrcLoc;
+use crate::ssa::ENTRY_FUN;
 use im::HashMap;
 use std::collections::HashSet;
 
 pub struct Resolver {
     pub vars: IdGen<VarName>,
     pub funs: IdGen<FunName>,
+    /// If present, maps the location of each variable use and function
+    /// call to the location of the binding, parameter, or declaration it
+    /// resolved to.
+    pub definitions: Option<std::collections::HashMap<SrcLoc, SrcLoc>>,
+    /// the number of variables given fresh names so far
+    renamed: usize,
+    /// whether a function may call those of the sibling `FunDefs` after
+    /// its own
+    forward_refs: bool,
+    /// the symbol `main` is compiled to
+    entry: String,
+    /// If present, every name bound so far, see `Resolver::bindings`
+    bindings: Option<Vec<(String, Bound, SrcLoc)>>,
+    /// If present, the errors found so far by `Resolver::resolve_prog_all`
+    errors: Option<Vec<CompileErr>>,
+    /// the number of errors `Resolver::resolve_prog_all` reports at most
+    max_errors: usize,
+}
+
+/// The number of errors `Resolver::resolve_prog_all` reports at most,
+/// unless told otherwise.
+pub const MAX_ERRORS: usize = 20;
+
+/// The unique name a source name was resolved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Bound {
+    Var(VarName),
+    Fun(FunName),
 }
 
 #[derive(Debug, Clone)]
 struct EnvFun {
     name: FunName,
     arity: usize,
+    /// where the function was declared
+    loc: SrcLoc,
 }
 
 impl EnvFun {
-    fn new(name: FunName, arity: usize) -> Self {
-        Self { name, arity }
+    fn new(name: FunName, arity: usize, loc: SrcLoc) -> Self {
+        Self { name, arity, loc }
     }
 }
 
 #[derive(Debug, Clone)]
 struct Env {
-    vars: HashMap<String, VarName>,
+    /// each variable's unique name and where it was bound
+    vars: HashMap<String, (VarName, SrcLoc)>,
     labels: HashMap<String, EnvFun>,
 }
 
@@ -37,18 +69,19 @@ impl Env {
         Self { vars: HashMap::new(), labels: HashMap::new() }
     }
 
-    fn insert_var(&mut self, var: String, var_name: VarName) {
-        self.vars.insert(var, var_name);
+    fn insert_var(&mut self, var: String, var_name: VarName, loc: SrcLoc) {
+        self.vars.insert(var, (var_name, loc));
     }
 
-    fn get_var_name(&self, var: &String) -> Option<&VarName> {
+    fn get_var(&self, var: &String) -> Option<&(VarName, SrcLoc)> {
         self.vars.get(var)
     }
 
     fn insert_label(
         &mut self, label: String, fun_name: FunName, arity: usize,
+        loc: SrcLoc,
     ) {
-        self.labels.insert(label, EnvFun::new(fun_name, arity));
+        self.labels.insert(label, EnvFun::new(fun_name, arity, loc));
     }
 
     fn get_env_fun(&self, label: &String) -> Option<&EnvFun> {
@@ -69,17 +102,171 @@ pub enum CompileErr {
     UnboundFunction(String, SrcLoc),
     DuplicateFunction(String, SrcLoc),
     DuplicateParameter(String, SrcLoc),
+    /// A `letrec` binding whose right-hand side is not a function value.
+    NonFunctionRecursiveBinding(String, SrcLoc),
     ArityMismatch {
         name: String,
         expected: usize,
         found: usize,
         loc: SrcLoc,
     },
+    /// A `match` without a catch-all `_` arm.
+    MatchWithoutDefault(SrcLoc),
+    /// Stands in for the errors found after the maximum number reported,
+    /// the first of which is at the given location.
+    ErrorsSuppressed(SrcLoc),
+}
+
+impl CompileErr {
+    /// Where in the source program the error occurred.
+    pub fn loc(&self) -> SrcLoc {
+        match self {
+            CompileErr::UnboundVariable(_, loc)
+            | CompileErr::DuplicateVariable(_, loc)
+            | CompileErr::UnboundFunction(_, loc)
+            | CompileErr::DuplicateFunction(_, loc)
+            | CompileErr::DuplicateParameter(_, loc)
+            | CompileErr::NonFunctionRecursiveBinding(_, loc)
+            | CompileErr::ArityMismatch { loc, .. }
+            | CompileErr::MatchWithoutDefault(loc)
+            | CompileErr::ErrorsSuppressed(loc) => *loc,
+        }
+    }
 }
 
 impl Resolver {
     pub fn new() -> Self {
-        Resolver { vars: IdGen::new(), funs: IdGen::new() }
+        Resolver {
+            vars: IdGen::new(),
+            funs: IdGen::new(),
+            definitions: None,
+            renamed: 0,
+            forward_refs: false,
+            entry: ENTRY_FUN.to_string(),
+            bindings: None,
+            errors: None,
+            max_errors: MAX_ERRORS,
+        }
+    }
+
+    /// The number of variables given fresh names so far.
+    pub fn renamed_vars(&self) -> usize {
+        self.renamed
+    }
+
+    fn fresh_var(&mut self, hint: &str, loc: SrcLoc) -> VarName {
+        self.renamed += 1;
+        let name = self.vars.fresh(hint);
+        self.record_binding(hint, Bound::Var(name.clone()), loc);
+        name
+    }
+
+    /// Record where each use resolves to while resolving, see
+    /// `Resolver::definitions`.
+    pub fn with_definitions(mut self) -> Self {
+        self.definitions = Some(std::collections::HashMap::new());
+        self
+    }
+
+    /// Record every name bound while resolving, see `Resolver::bindings`.
+    pub fn with_bindings(mut self) -> Self {
+        self.bindings = Some(Vec::new());
+        self
+    }
+
+    /// Each source name bound so far, in the order they were bound, with
+    /// the unique name it was given and where it was bound. Empty unless
+    /// the resolver was made `with_bindings`. A shadowed name appears once
+    /// per binding.
+    pub fn bindings(&self) -> &[(String, Bound, SrcLoc)] {
+        self.bindings.as_deref().unwrap_or_default()
+    }
+
+    /// Report at most `max` errors from `Resolver::resolve_prog_all`.
+    pub fn with_max_errors(mut self, max: usize) -> Self {
+        self.max_errors = max;
+        self
+    }
+
+    /// Mint names with `vars` and `funs`, so that a resolver can carry on
+    /// where another left off, as a REPL resolving successive edits would.
+    /// Resolving more programs with the same resolver does the same.
+    pub fn with_ids(
+        mut self, vars: IdGen<VarName>, funs: IdGen<FunName>,
+    ) -> Self {
+        self.vars = vars;
+        self.funs = funs;
+        self
+    }
+
+    /// Let functions call those declared in the sibling `FunDefs` that
+    /// follow their own, as in
+    ///
+    /// ```text
+    /// def f(x): g(x) in
+    /// def g(x): x + 1 in
+    /// f(1)
+    /// ```
+    ///
+    /// Names already in scope keep their meaning, except where a later
+    /// sibling declares them again.
+    pub fn with_forward_refs(mut self) -> Self {
+        self.forward_refs = true;
+        self
+    }
+
+    /// Compile `main` to the symbol `entry` rather than `ENTRY_FUN`, for a
+    /// runtime that calls it by that name.
+    pub fn with_entry(mut self, entry: impl Into<String>) -> Self {
+        self.entry = entry.into();
+        self
+    }
+
+    /// Carry on past `err` if collecting errors and there is room for it,
+    /// and fail with it otherwise.
+    fn report(&mut self, err: CompileErr) -> Result<(), CompileErr> {
+        match &mut self.errors {
+            Some(errors) if errors.len() < self.max_errors => {
+                errors.push(err);
+                Ok(())
+            }
+            _ => Err(err),
+        }
+    }
+
+    fn record_binding(&mut self, name: &str, bound: Bound, loc: SrcLoc) {
+        if let Some(bindings) = &mut self.bindings {
+            bindings.push((name.to_string(), bound, loc));
+        }
+    }
+
+    fn record_definition(&mut self, use_loc: SrcLoc, def_loc: SrcLoc) {
+        if let Some(definitions) = &mut self.definitions {
+            definitions.insert(use_loc, def_loc);
+        }
+    }
+
+    /// Like `resolve_prog`, but carries on past each error to report all
+    /// of them, in the order they were found. Past the maximum number of
+    /// errors, see `Resolver::with_max_errors`, it stops, with the errors
+    /// left unreported standing for the rest.
+    pub fn resolve_prog_all(
+        &mut self, prog: SurfProg,
+    ) -> Result<BoundProg, Vec<CompileErr>> {
+        self.errors = Some(Vec::new());
+        let res = self.resolve_prog(prog);
+        let mut errors = self.errors.take().unwrap_or_default();
+        match res {
+            Ok(prog) if errors.is_empty() => Ok(prog),
+            Ok(_) => Err(errors),
+            Err(err) => {
+                errors.push(match errors.len() < self.max_errors {
+                    true => err,
+                    false => CompileErr::ErrorsSuppressed(err.loc()),
+                });
+                Err(errors)
+            }
+        }
     }
 
     pub fn resolve_prog(
@@ -88,19 +275,22 @@ impl Resolver {
         let mut env = Env::new();
 
         // Add main function to environment
-        let name = FunName::Unmangled("entry".to_string());
-        env.insert_label(prog.name.clone(), name.clone(), 1);
+        let name = FunName::Unmangled(self.entry.clone());
+        env.insert_label(prog.name.clone(), name.clone(), 1, prog.loc);
+        self.record_binding(&prog.name, Bound::Fun(name.clone()), prog.loc);
 
         // Add extern functions to environment
         let externs = prog
             .externs
             .iter()
             .map(|decl| {
-                if let Some(_) = env.get_env_fun(&decl.name) {
-                    return Err(CompileErr::DuplicateFunction(
+                if env.get_env_fun(&decl.name).is_some()
+                    || decl.name == self.entry
+                {
+                    self.report(CompileErr::DuplicateFunction(
                         decl.name.clone(),
                         decl.loc,
-                    ));
+                    ))?;
                 }
 
                 let name = FunName::Unmangled(decl.name.clone());
@@ -108,22 +298,61 @@ impl Resolver {
                     self.resolve_params(&decl.params, &mut env.clone())?;
                 let loc = decl.loc;
 
+                self.record_binding(
+                    &decl.name,
+                    Bound::Fun(name.clone()),
+                    loc,
+                );
                 env.insert_label(
                     decl.name.clone(),
                     name.clone(),
                     params.len(),
+                    decl.loc,
                 );
 
                 Ok(BoundExtDecl { name, params, loc })
             })
             .collect::<Result<Vec<BoundExtDecl>, _>>()?;
 
+        // Add the top-level functions to the environment, all of them
+        // before any is resolved so that they may call each other
+        let mut dup: HashSet<String> = HashSet::new();
+        for decl in &prog.funs {
+            if !dup.insert(decl.name.clone())
+                || env.get_env_fun(&decl.name).is_some()
+                || decl.name == self.entry
+            {
+                self.report(CompileErr::DuplicateFunction(
+                    decl.name.clone(),
+                    decl.loc,
+                ))?;
+            }
+            let name = self.funs.fresh(&decl.name);
+            self.record_binding(
+                &decl.name,
+                Bound::Fun(name.clone()),
+                decl.loc,
+            );
+            env.insert_label(
+                decl.name.clone(),
+                name,
+                decl.params.len(),
+                decl.loc,
+            );
+        }
+        let funs = prog
+            .funs
+            .into_iter()
+            .map(|decl| self.resolve_fun_decl(decl, env.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+
         // Add parameter to environment
-        let param = self.vars.fresh(&prog.param.0);
-        env.insert_var(prog.param.0, param.clone());
+        let param = self.fresh_var(&prog.param.0, prog.param.1);
+        env.insert_var(prog.param.0, param.clone(), prog.param.1);
 
         Ok(BoundProg {
             externs,
+            funs,
             name,
             param: (param, prog.param.1),
             body: self.resolve_expr(prog.body, env)?,
@@ -138,18 +367,18 @@ impl Resolver {
         let mut param_set: HashSet<String> = HashSet::new();
         for (param, loc) in params {
             if !param_set.insert(param.clone()) {
-                return Err(CompileErr::DuplicateParameter(
+                self.report(CompileErr::DuplicateParameter(
                     param.clone(),
                     *loc,
-                ));
+                ))?;
             }
         }
 
         Ok(params
             .iter()
             .map(|(param, loc)| {
-                let param_var_name = self.vars.fresh(param);
-                env.insert_var(param.clone(), param_var_name.clone());
+                let param_var_name = self.fresh_var(param, *loc);
+                env.insert_var(param.clone(), param_var_name.clone(), *loc);
                 (param_var_name, *loc)
             })
             .collect())
@@ -161,12 +390,18 @@ impl Resolver {
         let bound_expr = match expr {
             Expr::Num(n, loc) => Expr::Num(n, loc),
             Expr::Bool(b, loc) => Expr::Bool(b, loc),
-            Expr::Var(var, loc) => Expr::Var(
-                env.get_var_name(&var)
-                    .ok_or(CompileErr::UnboundVariable(var.clone(), loc))?
-                    .clone(),
-                loc,
-            ),
+            Expr::Var(var, loc) => {
+                let Some((var_name, def_loc)) = env.get_var(&var).cloned()
+                else {
+                    self.report(CompileErr::UnboundVariable(
+                        var.clone(),
+                        loc,
+                    ))?;
+                    return Ok(Expr::Var(self.vars.fresh(&var), loc));
+                };
+                self.record_definition(loc, def_loc);
+                Expr::Var(var_name, loc)
+            }
             Expr::Prim { prim, args, loc } => Expr::Prim {
                 prim,
                 args: args
@@ -175,25 +410,54 @@ impl Resolver {
                     .collect::<Result<_, _>>()?,
                 loc,
             },
-            Expr::Let { bindings, body, loc } => {
+            Expr::Let { bindings, body, kind, loc } => {
                 let mut dup: HashSet<String> = HashSet::new();
                 for binding in &bindings {
                     if !dup.insert(binding.var.0.clone()) {
-                        return Err(CompileErr::DuplicateVariable(
+                        self.report(CompileErr::DuplicateVariable(
                             binding.var.0.clone(),
                             binding.var.1,
-                        ));
+                        ))?;
                     }
                 }
 
+                if kind == LetKind::Recursive {
+                    // Every name is in scope in every right-hand side, so
+                    // the lowerer would have to allocate each value before
+                    // filling it in. Only function values can be built
+                    // that way, and the language has none yet.
+                    let binding = &bindings[0];
+                    self.report(CompileErr::NonFunctionRecursiveBinding(
+                        binding.var.0.clone(),
+                        binding.var.1,
+                    ))?;
+                }
+                // carrying on past that, as if the bindings were in order
+                let kind = match kind {
+                    LetKind::Recursive => LetKind::Sequential,
+                    kind => kind,
+                };
+
+                // the scope the right-hand sides of a parallel let see
+                let outer = env.clone();
                 let bindings = bindings
                     .into_iter()
                     .map(|binding| {
-                        let var_name = self.vars.fresh(&binding.var.0);
+                        let var_name =
+                            self.fresh_var(&binding.var.0, binding.var.1);
+                        let rhs_env = match kind {
+                            LetKind::Sequential => env.clone(),
+                            LetKind::Parallel => outer.clone(),
+                            LetKind::Recursive => unreachable!(),
+                        };
                         let expr =
-                            self.resolve_expr(binding.expr, env.clone())?;
+                            self.resolve_expr(binding.expr, rhs_env)?;
 
-                        env.insert_var(binding.var.0, var_name.clone());
+                        env.insert_var(
+                            binding.var.0,
+                            var_name.clone(),
+                            binding.var.1,
+                        );
                         Ok(Binding { var: (var_name, binding.var.1), expr })
                     })
                     .collect::<Result<_, _>>()?;
@@ -201,6 +465,7 @@ impl Resolver {
                 Expr::Let {
                     bindings,
                     body: Box::new(self.resolve_expr(*body, env)?),
+                    kind,
                     loc,
                 }
             }
@@ -211,47 +476,138 @@ impl Resolver {
                 loc,
             },
             Expr::FunDefs { decls, body, loc } => {
-                // Check for duplication. If there are no duplicates, add
-                // function names to env before resolving them.
-                let mut dup: HashSet<String> = HashSet::new();
-                for decl in &decls {
-                    if !dup.insert(decl.name.clone()) {
-                        return Err(CompileErr::DuplicateFunction(
-                            decl.name.clone(),
+                // Sibling groups are each the body of the one before. With
+                // forward references they may call the functions of the
+                // groups after them, so every group is named before any
+                // function is resolved.
+                let mut groups = vec![(decls, loc)];
+                let mut body = *body;
+                while let Expr::FunDefs { decls, body: next, loc } = body {
+                    groups.push((decls, loc));
+                    body = *next;
+                }
+
+                // Check each group for duplication, and give its functions
+                // their names.
+                let mut names = Vec::new();
+                for (decls, _) in &groups {
+                    let mut dup: HashSet<String> = HashSet::new();
+                    let mut group = Vec::new();
+                    for decl in decls {
+                        if !dup.insert(decl.name.clone()) {
+                            self.report(CompileErr::DuplicateFunction(
+                                decl.name.clone(),
+                                decl.loc,
+                            ))?;
+                        }
+                        let name = self.funs.fresh(&decl.name);
+                        self.record_binding(
+                            &decl.name,
+                            Bound::Fun(name.clone()),
                             decl.loc,
-                        ));
+                        );
+                        group.push((decl, name));
                     }
-                    env.insert_label(
-                        decl.name.clone(),
-                        self.funs.fresh(&decl.name),
-                        decl.params.len(),
-                    );
+                    names.push(group);
                 }
 
-                let decls = decls
-                    .into_iter()
-                    .map(|decl| self.resolve_fun_decl(decl, env.clone()))
-                    .collect::<Result<_, _>>()?;
+                // A group sees the groups after it, the nearest first, then
+                // the groups before it and itself as usual.
+                let forward_refs = self.forward_refs;
+                let group_env = |i: usize| {
+                    let mut env = env.clone();
+                    let later = match forward_refs {
+                        true => &names[i + 1..],
+                        false => &names[..0],
+                    };
+                    let later = later.iter().rev();
+                    for group in later.chain(&names[..=i]) {
+                        for (decl, name) in group {
+                            env.insert_label(
+                                decl.name.clone(),
+                                name.clone(),
+                                decl.params.len(),
+                                decl.loc,
+                            );
+                        }
+                    }
+                    env
+                };
+                let envs: Vec<_> =
+                    (0..groups.len()).map(group_env).collect();
+
+                let mut resolved = Vec::new();
+                for ((decls, loc), env) in groups.into_iter().zip(&envs) {
+                    let decls = decls
+                        .into_iter()
+                        .map(|decl| self.resolve_fun_decl(decl, env.clone()))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    resolved.push((decls, loc));
+                }
 
-                let body = self.resolve_expr(*body, env)?;
+                let body =
+                    self.resolve_expr(body, envs.last().unwrap().clone())?;
 
-                Expr::FunDefs { decls, body: Box::new(body), loc }
-            }
-            Expr::Call { fun, args, loc } => {
-                let env_fun = env.get_env_fun(&fun).ok_or_else(|| {
-                    CompileErr::UnboundFunction(fun.clone(), loc)
-                })?;
-
-                if env_fun.arity != args.len() {
-                    return Err(CompileErr::ArityMismatch {
-                        name: fun.clone(),
-                        expected: env_fun.arity,
-                        found: args.len(),
+                resolved.into_iter().rev().fold(
+                    body,
+                    |body, (decls, loc)| Expr::FunDefs {
+                        decls,
+                        body: Box::new(body),
                         loc,
-                    });
+                    },
+                )
+            }
+            Expr::Match { scrutinee, arms, default, loc } => {
+                // the patterns are all integers, so without `_` there is a
+                // value no arm matches
+                if default.is_none() {
+                    self.report(CompileErr::MatchWithoutDefault(loc))?;
                 }
-
-                let fun = env_fun.name.clone();
+                Expr::Match {
+                    scrutinee: Box::new(
+                        self.resolve_expr(*scrutinee, env.clone())?,
+                    ),
+                    arms: arms
+                        .into_iter()
+                        .map(|MatchArm { pattern, body }| {
+                            Ok(MatchArm {
+                                pattern,
+                                body: self
+                                    .resolve_expr(body, env.clone())?,
+                            })
+                        })
+                        .collect::<Result<_, _>>()?,
+                    default: match default {
+                        Some(default) => {
+                            Some(Box::new(self.resolve_expr(*default, env)?))
+                        }
+                        None => None,
+                    },
+                    loc,
+                }
+            }
+            Expr::Call { fun, args, loc } => {
+                let fun = match env.get_env_fun(&fun).cloned() {
+                    Some(env_fun) => {
+                        if env_fun.arity != args.len() {
+                            self.report(CompileErr::ArityMismatch {
+                                name: fun.clone(),
+                                expected: env_fun.arity,
+                                found: args.len(),
+                                loc,
+                            })?;
+                        }
+                        self.record_definition(loc, env_fun.loc);
+                        env_fun.name
+                    }
+                    None => {
+                        self.report(CompileErr::UnboundFunction(
+                            fun.clone(),
+                            loc,
+                        ))?;
+                        self.funs.fresh(&fun)
+                    }
+                };
                 let args = args
                     .into_iter()
                     .map(|arg| self.resolve_expr(arg, env.clone()))
@@ -6,9 +6,18 @@
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct VarName(usize, String);
 impl VarName {
+    /// The name a generator would have made with index `idx` and `hint`,
+    /// for rebuilding names that were written out.
+    pub fn from_parts(idx: usize, hint: impl Into<String>) -> Self {
+        Self(idx, hint.into())
+    }
     pub fn hint(&self) -> &str {
         &self.1
     }
+    /// The index that makes this name unique.
+    pub fn index(&self) -> usize {
+        self.0
+    }
 }
 
 /// A `FunName` is a unique identifier for a function name.
@@ -22,6 +31,11 @@ pub enum FunName {
     Unmangled(String),
 }
 impl FunName {
+    /// The mangled name a generator would have made with index `idx` and
+    /// `hint`, for rebuilding names that were written out.
+    pub fn mangled(idx: usize, hint: impl Into<String>) -> Self {
+        Self::Mangled(idx, hint.into())
+    }
     pub fn unmangled(hint: impl Into<String>) -> Self {
         Self::Unmangled(hint.into())
     }
@@ -30,6 +44,14 @@ impl FunName {
             FunName::Mangled(_, hint) | FunName::Unmangled(hint) => hint,
         }
     }
+    /// The index that makes this name unique, which unmangled names do
+    /// not have.
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            FunName::Mangled(idx, _) => Some(*idx),
+            FunName::Unmangled(..) => None,
+        }
+    }
     pub fn is_unmangled(&self) -> bool {
         match self {
             FunName::Unmangled(..) => true,
@@ -42,9 +64,18 @@ impl FunName {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct BlockName(usize, String);
 impl BlockName {
+    /// The name a generator would have made with index `idx` and `hint`,
+    /// for rebuilding names that were written out.
+    pub fn from_parts(idx: usize, hint: impl Into<String>) -> Self {
+        Self(idx, hint.into())
+    }
     pub fn hint(&self) -> &str {
         &self.1
     }
+    /// The index that makes this name unique.
+    pub fn index(&self) -> usize {
+        self.0
+    }
 }
 
 /* --------------------------------- Display -------------------------------- */
@@ -73,6 +104,60 @@ mod impl_display {
     }
 }
 
+/* --------------------------------- Parsing -------------------------------- */
+
+/// Text that is not the display form of an identifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseIdErr(pub String);
+
+mod impl_from_str {
+    use super::*;
+    use std::fmt;
+    use std::str::FromStr;
+
+    impl fmt::Display for ParseIdErr {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "invalid identifier: {}", self.0)
+        }
+    }
+
+    impl std::error::Error for ParseIdErr {}
+
+    /// The hint and index of `s`, which separates them with `sep`. Hints
+    /// are split off at the last `sep`, as the index never contains it.
+    fn parts(s: &str, sep: char) -> Result<(usize, &str), ParseIdErr> {
+        s.rsplit_once(sep)
+            .and_then(|(hint, idx)| Some((idx.parse().ok()?, hint)))
+            .ok_or_else(|| ParseIdErr(s.to_string()))
+    }
+
+    impl FromStr for VarName {
+        type Err = ParseIdErr;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (idx, hint) = parts(s, '%')?;
+            Ok(VarName::from_parts(idx, hint))
+        }
+    }
+    /// Names without an `@` are unmangled.
+    impl FromStr for FunName {
+        type Err = ParseIdErr;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            if !s.contains('@') {
+                return Ok(FunName::unmangled(s));
+            }
+            let (idx, hint) = parts(s, '@')?;
+            Ok(FunName::mangled(idx, hint))
+        }
+    }
+    impl FromStr for BlockName {
+        type Err = ParseIdErr;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (idx, hint) = parts(s, '#')?;
+            Ok(BlockName::from_parts(idx, hint))
+        }
+    }
+}
+
 /* -------------------------- Identifier Generator -------------------------- */
 
 /// A `IdGen` is a generator of unique `VarName`s.
@@ -81,25 +166,28 @@ pub struct IdGen<Id> {
     _marker: std::marker::PhantomData<Id>,
 }
 
+pub use impl_idgen::Identifier;
+
 mod impl_idgen {
     use super::*;
 
+    /// The identifiers an `IdGen` can generate.
     pub trait Identifier: Clone {
         fn new(idx: usize, hint: impl Into<String>) -> Self;
     }
     impl Identifier for VarName {
         fn new(idx: usize, hint: impl Into<String>) -> Self {
-            Self(idx, hint.into())
+            Self::from_parts(idx, hint)
         }
     }
     impl Identifier for FunName {
         fn new(idx: usize, hint: impl Into<String>) -> Self {
-            Self::Mangled(idx, hint.into())
+            Self::mangled(idx, hint)
         }
     }
     impl Identifier for BlockName {
         fn new(idx: usize, hint: impl Into<String>) -> Self {
-            Self(idx, hint.into())
+            Self::from_parts(idx, hint)
         }
     }
 
@@ -107,6 +195,15 @@ mod impl_idgen {
         pub fn new() -> Self {
             Self { count: 0, _marker: std::marker::PhantomData }
         }
+        /// A generator that carries on where one that has handed out
+        /// `count` identifiers left off.
+        pub fn starting_at(count: usize) -> Self {
+            Self { count, _marker: std::marker::PhantomData }
+        }
+        /// The index the next identifier will get.
+        pub fn next_index(&self) -> usize {
+            self.count
+        }
         pub fn fresh(&mut self, hint: impl Into<String>) -> Id {
             let id = Id::new(self.count, hint);
             self.count += 1;
@@ -1,13 +1,20 @@
 //! Interpreter for the snake language and its SSA form.
+//!
+//! Arithmetic wraps around on overflow, as it does in compiled code, so
+//! that the interpreters agree with it in debug builds too.
 
 use crate::identifiers::*;
 use std::{
     fmt::{self, Display},
     hash::Hash,
+    io::{BufRead, Write},
     rc::Rc,
 };
 
-#[derive(Clone, Debug)]
+/// A value a program computes. Values of different variants are never
+/// equal, and order by variant, in the order they are declared here;
+/// values of the same variant order by their contents.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Value {
     Int(i64),
 }
@@ -25,12 +32,31 @@ pub enum InterpErr<Var, Fun> {
     Unimplemented,
     InvalidArg(String),
     UnboundVar(Var),
+    /// A variable read after a branch dropped it, with the block that
+    /// defined it, or `None` for a parameter of the function.
+    OutOfScope { var: Var, block: Option<BlockName> },
+    /// A variable defined while an earlier definition of it is still in
+    /// scope, with the block of that definition, or `None` for a parameter
+    /// of the function.
+    Redefined { var: Var, block: Option<BlockName> },
     UnboundFun(Fun),
     UnExpectedFun(Fun),
     CallToConst(i64),
     CallWrongArity { name: Fun, expected: usize, got: usize },
     UnboundBlock(BlockName),
     BrWrongArity { name: BlockName, expected: usize, got: usize },
+    InvalidInput(String),
+    EndOfInput,
+    UserError(i64),
+    /// Control reached a `Terminator::Unreachable`.
+    ReachedUnreachable,
+    /// A constant that the integer representation has no room for.
+    OutOfRange(i64),
+    /// A `letrec`, with the first variable it binds: the AST interpreter
+    /// does not run them.
+    RecursiveBinding(Var),
+    /// Writing what the program prints failed.
+    Output(String),
 }
 
 impl<Var: Display, Fun: Display> Display for InterpErr<Var, Fun> {
@@ -39,6 +65,22 @@ impl<Var: Display, Fun: Display> Display for InterpErr<Var, Fun> {
             InterpErr::Unimplemented => write!(f, "unimplemented"),
             InterpErr::InvalidArg(arg) => write!(f, "invalid argument: {}", arg),
             InterpErr::UnboundVar(var) => write!(f, "unbound variable: {}", var),
+            InterpErr::OutOfScope { var, block: Some(block) } => {
+                write!(f, "variable {} is out of scope here (defined in block {})", var, block)
+            }
+            InterpErr::OutOfScope { var, block: None } => {
+                write!(f, "variable {} is out of scope here (a parameter of the function)", var)
+            }
+            InterpErr::Redefined { var, block: Some(block) } => write!(
+                f,
+                "variable {} is defined again while in scope (defined in block {})",
+                var, block
+            ),
+            InterpErr::Redefined { var, block: None } => write!(
+                f,
+                "variable {} is defined again while in scope (a parameter of the function)",
+                var
+            ),
             InterpErr::UnboundFun(fun) => write!(f, "unbound function: {}", fun),
             InterpErr::UnExpectedFun(fun) => write!(f, "unexpected function: {}", fun),
             InterpErr::CallToConst(n) => write!(f, "call to constant: {}", n),
@@ -57,17 +99,173 @@ impl<Var: Display, Fun: Display> Display for InterpErr<Var, Fun> {
                     name, expected, got
                 )
             }
+            InterpErr::InvalidInput(line) => write!(f, "invalid input: {}", line),
+            InterpErr::EndOfInput => write!(f, "unexpected end of input"),
+            InterpErr::UserError(code) => write!(f, "program aborted with error code {}", code),
+            InterpErr::ReachedUnreachable => write!(f, "reached unreachable code"),
+            InterpErr::OutOfRange(n) => {
+                write!(f, "constant out of range: {}", n)
+            }
+            InterpErr::RecursiveBinding(var) => {
+                write!(f, "unimplemented: recursive binding of {}", var)
+            }
+            InterpErr::Output(e) => write!(f, "I/O error: {}", e),
         }
     }
 }
 
+/// The name of the extern function that reads an integer from the input.
+pub const READ_INT: &str = "read_int";
+
+/// The extern functions the interpreters implement, with their arities:
+/// those of the runtime.
+const EXTERNS: [(&str, usize); 3] = [(READ_INT, 0), (crate::ssa::ERROR_FUN, 1), ("print", 1)];
+
+/// An extern function that an interpreter is given with `with_extern`. It
+/// is called with the arguments as the program represents them untagged,
+/// and writes whatever it prints to the output it is passed.
+pub type ExternFn = fn(&[i64], &mut dyn Write) -> std::io::Result<i64>;
+
+/// The extern functions given with `with_extern`, by name, with their
+/// arities.
+pub(crate) type ExternTable = std::collections::HashMap<String, (usize, ExternFn)>;
+
+/// Whether the interpreters implement the extern function `name`, either
+/// themselves or as one of `extern_fns`.
+fn implements_extern(name: &str, extern_fns: &ExternTable) -> bool {
+    EXTERNS.iter().any(|(ext, _)| *ext == name) || extern_fns.contains_key(name)
+}
+
+/// Call the extern function `fun`, declared with the name `name`, as the
+/// runtime would, writing whatever it prints to `out`.
+fn call_extern<Var, Fun: Clone>(
+    fun: &Fun, name: &str, args: &[i64], input: &mut dyn BufRead, out: &mut dyn Write,
+    extern_fns: &ExternTable,
+) -> Result<i64, InterpErr<Var, Fun>> {
+    let builtin = EXTERNS.iter().find(|(ext, _)| *ext == name).map(|&(_, arity)| arity);
+    let Some(expected) = builtin.or_else(|| extern_fns.get(name).map(|&(arity, _)| arity)) else {
+        return Err(InterpErr::UnboundFun(fun.clone()));
+    };
+    if args.len() != expected {
+        Err(InterpErr::CallWrongArity { name: fun.clone(), expected, got: args.len() })?
+    }
+    let output = |e: std::io::Error| InterpErr::Output(e.to_string());
+    match name {
+        READ_INT => read_int(input),
+        crate::ssa::ERROR_FUN => Err(InterpErr::UserError(args[0])),
+        "print" => {
+            writeln!(out, "{}", args[0]).map_err(output)?;
+            Ok(args[0])
+        }
+        _ => (extern_fns[name].1)(args, out).map_err(output),
+    }
+}
+
+/// Parse the argument to `main` as the runtime does.
+pub fn parse_arg<Var, Fun>(arg: &str) -> Result<Value, InterpErr<Var, Fun>> {
+    arg.parse().map(Value::Int).map_err(|_| InterpErr::InvalidArg(arg.to_string()))
+}
+
+/// Read the next integer from `input`, which holds one integer per line.
+fn read_int<Var, Fun>(input: &mut dyn BufRead) -> Result<i64, InterpErr<Var, Fun>> {
+    let mut line = String::new();
+    match input.read_line(&mut line) {
+        Ok(0) => Err(InterpErr::EndOfInput),
+        Ok(_) => line.trim().parse().map_err(|_| InterpErr::InvalidInput(line.trim().to_string())),
+        Err(e) => Err(InterpErr::InvalidInput(e.to_string())),
+    }
+}
+
 /* ---------------------------------- Snake --------------------------------- */
 
 pub mod ast {
     use super::*;
     use crate::ast::*;
+    use crate::ssa::IntRepr;
     use im::HashMap;
 
+    /// Runs programs on the `Machine`, configured the way `ssa::Interp`
+    /// is.
+    pub struct Interp<'a> {
+        /// where calls to `read_int` read from
+        input: Box<dyn BufRead + 'a>,
+        /// where the extern functions write what they print
+        output: Box<dyn Write + 'a>,
+        /// the extern functions given with `with_extern`
+        extern_fns: ExternTable,
+        /// how integers are represented while the program runs
+        repr: IntRepr,
+        /// whether a group of local functions may call the groups
+        /// directly in its body
+        forward_refs: bool,
+    }
+
+    impl<'a> Default for Interp<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<'a> Interp<'a> {
+        pub fn new() -> Self {
+            Interp {
+                input: Box::new(std::io::empty()),
+                output: Box::new(std::io::stdout()),
+                extern_fns: ExternTable::new(),
+                repr: IntRepr::default(),
+                forward_refs: false,
+            }
+        }
+        /// Serve calls to `read_int` from `input`, one integer per line.
+        pub fn with_input(mut self, input: impl BufRead + 'a) -> Self {
+            self.input = Box::new(input);
+            self
+        }
+        /// Write what the program prints to `output` instead of stdout.
+        pub fn with_output(mut self, output: impl Write + 'a) -> Self {
+            self.output = Box::new(output);
+            self
+        }
+        /// Implement the extern function `name`, of `arity` arguments,
+        /// with `f`, besides those the runtime always provides.
+        pub fn with_extern(mut self, name: &str, arity: usize, f: ExternFn) -> Self {
+            self.extern_fns.insert(name.to_string(), (arity, f));
+            self
+        }
+        /// Run programs with integers in the range `repr` has room for,
+        /// wrapping arithmetic around as the compiled code does.
+        pub fn with_repr(mut self, repr: IntRepr) -> Self {
+            self.repr = repr;
+            self
+        }
+        /// Let a group of local functions call the functions of the
+        /// groups directly in its body, as `Resolver::with_forward_refs`
+        /// allows.
+        pub fn with_forward_refs(mut self) -> Self {
+            self.forward_refs = true;
+            self
+        }
+        pub fn run<Var, Fun>(
+            &mut self, prog: &Prog<Var, Fun>, arg: String,
+        ) -> Result<Value, InterpErr<Var, Fun>>
+        where
+            Var: Hash + Eq + Clone,
+            Fun: Hash + Eq + Clone + Display,
+        {
+            self.run_value(prog, parse_arg(&arg)?)
+        }
+        /// Like `run`, but takes the argument as an already-parsed `Value`.
+        pub fn run_value<Var, Fun>(
+            &mut self, prog: &Prog<Var, Fun>, arg: Value,
+        ) -> Result<Value, InterpErr<Var, Fun>>
+        where
+            Var: Hash + Eq + Clone,
+            Fun: Hash + Eq + Clone + Display,
+        {
+            Machine::run_prog_in(prog, arg, self)
+        }
+    }
+
     #[derive(Clone)]
     pub struct Machine<Var, Fun> {
         redex: Redex<Var, Fun>,
@@ -97,6 +295,8 @@ pub mod ast {
     enum DynValue<Var, Fun> {
         Int(i64),
         Closure(Closure<Var, Fun>),
+        /// an extern function implemented by the interpreter
+        Extern(Fun),
     }
 
     #[derive(Clone, Hash, PartialEq, Eq)]
@@ -127,6 +327,8 @@ pub mod ast {
         },
         Let {
             env: Env<Var, Fun>,
+            /// for a parallel let, the env every right-hand side is run in
+            outer: Option<Env<Var, Fun>>,
             var: Var,
             remaining: Vec<(Var, Rc<Expr<Var, Fun>>)>,
             body: Rc<Expr<Var, Fun>>,
@@ -138,59 +340,118 @@ pub mod ast {
             els: Rc<Expr<Var, Fun>>,
             stack: Box<Stack<Var, Fun>>,
         },
+        Match {
+            env: Env<Var, Fun>,
+            arms: Vec<(i64, Rc<Expr<Var, Fun>>)>,
+            default: Option<Rc<Expr<Var, Fun>>>,
+            stack: Box<Stack<Var, Fun>>,
+        },
     }
 
     impl<Var, Fun> Machine<Var, Fun>
     where
         Var: Hash + Eq + Clone,
-        Fun: Hash + Eq + Clone,
+        Fun: Hash + Eq + Clone + Display,
     {
-        pub fn run_prog(
-            Prog { externs, name, param: (param, _), body, loc: _ }: &Prog<Var, Fun>, arg: String,
+        pub fn run_prog(prog: &Prog<Var, Fun>, arg: String) -> Result<Value, InterpErr<Var, Fun>> {
+            Self::run_prog_value(prog, parse_arg(&arg)?)
+        }
+        /// Like `run_prog`, but takes the argument as an already-parsed `Value`.
+        pub fn run_prog_value(
+            prog: &Prog<Var, Fun>, arg: Value,
         ) -> Result<Value, InterpErr<Var, Fun>> {
-            // Note: extern functions are not supported
-            assert!(externs.is_empty(), "extern functions are not supported");
+            Self::run_prog_with_input(prog, arg, &mut std::io::empty())
+        }
+        /// Like `run_prog_value`, but calls to `read_int` read from `input`.
+        pub fn run_prog_with_input(
+            prog: &Prog<Var, Fun>, arg: Value, input: &mut dyn BufRead,
+        ) -> Result<Value, InterpErr<Var, Fun>> {
+            Interp::new().with_input(input).run_value(prog, arg)
+        }
+        /// Like `run_prog_with_input`, but a group of local functions may
+        /// call the functions of the groups directly in its body, as
+        /// `Resolver::with_forward_refs` allows.
+        pub fn run_prog_with_forward_refs(
+            prog: &Prog<Var, Fun>, arg: Value, input: &mut dyn BufRead,
+        ) -> Result<Value, InterpErr<Var, Fun>> {
+            Interp::new().with_input(input).with_forward_refs().run_value(prog, arg)
+        }
+        fn run_prog_in(
+            Prog { externs, funs, name, param: (param, _), body, loc: _ }: &Prog<Var, Fun>,
+            arg: Value,
+            interp: &mut Interp,
+        ) -> Result<Value, InterpErr<Var, Fun>> {
+            // Note: only the extern functions in `EXTERNS` or given with
+            // `with_extern` are supported
+            assert!(
+                externs
+                    .iter()
+                    .all(|ext| implements_extern(&ext.name.to_string(), &interp.extern_fns)),
+                "extern functions other than {:?} and those given with `with_extern` are not \
+                 supported",
+                EXTERNS.map(|(ext, _)| ext)
+            );
 
-            let arg = DynValue::Int(arg.parse().map_err(|_| InterpErr::InvalidArg(arg))?);
-            let mut env = HashMap::new();
-            let decls = HashMap::from_iter([(
+            let arg = match arg {
+                Value::Int(n) if interp.repr.in_range(n) => DynValue::Int(n),
+                Value::Int(n) => Err(InterpErr::InvalidArg(n.to_string()))?,
+            };
+            let globals = HashMap::from_iter(externs.iter().map(|ext| {
+                (VarOrFun::Fun(ext.name.clone()), DynValue::Extern(ext.name.clone()))
+            }));
+            let mut env = globals.clone();
+            // main and the top-level functions may all call each other
+            let mut decls = HashMap::from_iter([(
                 name.clone(),
                 RcFunDef { params: vec![param.clone()], body: Rc::new(body.clone()) },
             )]);
-            env.insert(
-                VarOrFun::Fun(name.clone()),
-                DynValue::Closure(Closure { env: HashMap::new(), decls, name: name.clone() }),
-            );
+            for FunDecl { name, params, body, loc: _ } in funs {
+                let params = params.iter().map(|(var, _)| var.clone()).collect();
+                decls.insert(name.clone(), RcFunDef { params, body: Rc::new(body.clone()) });
+            }
+            for name in decls.keys() {
+                let decls = decls.clone();
+                let closure = Closure { env: globals.clone(), decls, name: name.clone() };
+                env.insert(VarOrFun::Fun(name.clone()), DynValue::Closure(closure));
+            }
             env.insert(VarOrFun::Var(param.clone()), arg);
             let redex = Redex::Decending { expr: Rc::new(body.clone()), env };
             let machine = Machine { redex, stack: Stack::Return };
-            match machine.run_expr()? {
+            match machine.run_expr(interp)? {
                 DynValue::Int(n) => Ok(Value::Int(n)),
-                DynValue::Closure(Closure { name, .. }) => Err(InterpErr::UnExpectedFun(name)),
+                DynValue::Closure(Closure { name, .. }) | DynValue::Extern(name) => {
+                    Err(InterpErr::UnExpectedFun(name))
+                }
             }
         }
-        fn run_expr(mut self) -> Result<DynValue<Var, Fun>, InterpErr<Var, Fun>> {
+        fn run_expr(
+            mut self, interp: &mut Interp,
+        ) -> Result<DynValue<Var, Fun>, InterpErr<Var, Fun>> {
             loop {
                 self = match self {
                     Machine { redex: Redex::Decending { expr, env }, stack } => {
-                        Self::dive_expr(expr, env, stack)?
+                        Self::dive_expr(expr, env, stack, interp)?
                     }
                     Machine { redex: Redex::Ascending(dv), stack: Stack::Return } => {
                         // the termination of the interpreter
                         break Ok(dv);
                     }
-                    Machine { redex: Redex::Ascending(dv), stack } => Self::run_kont(dv, stack)?,
+                    Machine { redex: Redex::Ascending(dv), stack } => {
+                        Self::run_kont(dv, stack, interp)?
+                    }
                 };
             }
         }
         fn dive_expr(
             expr: Rc<Expr<Var, Fun>>, env: Env<Var, Fun>, stack: Stack<Var, Fun>,
+            interp: &mut Interp,
         ) -> Result<Self, InterpErr<Var, Fun>> {
             let ret_machine =
                 |dv: DynValue<Var, Fun>, stack| Machine { redex: Redex::Ascending(dv), stack };
             let dive_machine =
                 |expr, env, stack| Machine { redex: Redex::Decending { expr, env }, stack };
             match expr.as_ref() {
+                Expr::Num(n, _) if !interp.repr.in_range(*n) => Err(InterpErr::OutOfRange(*n)),
                 Expr::Num(n, _) => Ok(ret_machine(DynValue::Int(*n), stack)),
                 Expr::Bool(b, _) => Ok(ret_machine(DynValue::Int(if *b { 1 } else { 0 }), stack)),
                 Expr::Var(v, _) => {
@@ -199,10 +460,24 @@ pub mod ast {
                         .ok_or_else(|| InterpErr::UnboundVar(v.clone()))?;
                     Ok(ret_machine(val.clone(), stack))
                 }
-                Expr::Prim { prim, args, loc: _ } => {
-                    Self::dive_operator(Operator::Prim(prim.clone()), args, env.clone(), stack)
-                }
-                Expr::Let { bindings, body, loc: _ } => {
+                Expr::Prim { prim, args, loc: _ } => Self::dive_operator(
+                    Operator::Prim(prim.clone()),
+                    args,
+                    env.clone(),
+                    stack,
+                    interp,
+                ),
+                Expr::Let { bindings, body, kind, loc: _ } => {
+                    let outer = match kind {
+                        LetKind::Sequential => None,
+                        LetKind::Parallel => Some(env.clone()),
+                        LetKind::Recursive => match bindings.first() {
+                            Some(Binding { var: (var, _), .. }) => {
+                                Err(InterpErr::RecursiveBinding(var.clone()))?
+                            }
+                            None => None,
+                        },
+                    };
                     let mut remaining: Vec<_> = bindings
                         .iter()
                         .cloned()
@@ -215,7 +490,7 @@ pub mod ast {
                         Ok(dive_machine(
                             expr,
                             env.clone(),
-                            Stack::Let { env, var, remaining, body, stack },
+                            Stack::Let { env, outer, var, remaining, body, stack },
                         ))
                     } else {
                         Ok(dive_machine(body, env.clone(), stack))
@@ -231,21 +506,58 @@ pub mod ast {
                         Stack::If { env, thn, els, stack },
                     ))
                 }
+                Expr::Match { scrutinee, arms, default, loc: _ } => {
+                    let arms = arms
+                        .iter()
+                        .map(|MatchArm { pattern: (n, _), body }| {
+                            (*n, Rc::new(body.clone()))
+                        })
+                        .collect();
+                    let default = default
+                        .as_ref()
+                        .map(|default| Rc::new(default.as_ref().clone()));
+                    let stack = Box::new(stack);
+                    Ok(dive_machine(
+                        Rc::new(scrutinee.as_ref().clone()),
+                        env.clone(),
+                        Stack::Match { env, arms, default, stack },
+                    ))
+                }
                 Expr::FunDefs { decls, body, loc: _ } => {
                     let curr = env;
                     let mut next = curr.clone();
-                    let decls = HashMap::from_iter(decls.iter().cloned().map(
-                        |FunDecl { name, params, body, loc: _ }| {
-                            (
-                                name.clone(),
-                                RcFunDef {
-                                    params: params.into_iter().map(|(var, _)| var).collect(),
-                                    body: Rc::new(body),
-                                },
-                            )
-                        },
-                    ));
-                    for name in decls.keys() {
+                    let to_def = |FunDecl { name, params, body, loc: _ }: FunDecl<Var, Fun>| {
+                        (
+                            name,
+                            RcFunDef {
+                                params: params.into_iter().map(|(var, _)| var).collect(),
+                                body: Rc::new(body),
+                            },
+                        )
+                    };
+                    let own: HashMap<_, _> = decls.iter().cloned().map(to_def).collect();
+                    // With forward references, the groups directly in the
+                    // body may be called from this one, the nearest first,
+                    // unless an earlier function has the same name. Only its
+                    // own functions are bound in the body.
+                    let mut later = Vec::new();
+                    let mut rest = body.as_ref();
+                    while let Expr::FunDefs { decls, body, .. } = rest {
+                        if !interp.forward_refs {
+                            break;
+                        }
+                        later.push(decls);
+                        rest = body;
+                    }
+                    let mut decls = HashMap::new();
+                    for group in later.into_iter().rev() {
+                        let unshadowed = group
+                            .iter()
+                            .filter(|decl| !curr.contains_key(&VarOrFun::Fun(decl.name.clone())));
+                        decls.extend(unshadowed.cloned().map(to_def));
+                    }
+                    decls.extend(own.clone());
+                    for name in own.keys() {
                         next.insert(
                             VarOrFun::Fun(name.clone()),
                             DynValue::Closure(Closure {
@@ -257,14 +569,18 @@ pub mod ast {
                     }
                     Ok(dive_machine(Rc::new(body.as_ref().clone()), next, stack))
                 }
-                Expr::Call { fun, args, loc: _ } => {
-                    Self::dive_operator(Operator::Call(fun.clone()), args, env.clone(), stack)
-                }
+                Expr::Call { fun, args, loc: _ } => Self::dive_operator(
+                    Operator::Call(fun.clone()),
+                    args,
+                    env.clone(),
+                    stack,
+                    interp,
+                ),
             }
         }
         fn dive_operator(
             operator: Operator<Fun>, args: &[Expr<Var, Fun>], env: Env<Var, Fun>,
-            stack: Stack<Var, Fun>,
+            stack: Stack<Var, Fun>, interp: &mut Interp,
         ) -> Result<Self, InterpErr<Var, Fun>> {
             let dive_machine =
                 |expr, env, stack| Machine { redex: Redex::Decending { expr, env }, stack };
@@ -283,12 +599,13 @@ pub mod ast {
                         "no arguments to evaluate in primitive operator, error in our interpreter?!"
                     )
                 };
-                Self::run_call(fun, Vec::new(), env, stack)
+                Self::run_call(fun, Vec::new(), env, stack, interp)
             }
         }
         fn run_kont(
-            dv: DynValue<Var, Fun>, stack: Stack<Var, Fun>,
+            dv: DynValue<Var, Fun>, stack: Stack<Var, Fun>, interp: &mut Interp,
         ) -> Result<Self, InterpErr<Var, Fun>> {
+            let repr = interp.repr;
             match stack {
                 Stack::Return => {
                     unreachable!("return kont should not be run, error in our interpreter?!")
@@ -301,19 +618,45 @@ pub mod ast {
                             stack: Stack::Operation { operator, env, evaluated, remaining, stack },
                         })
                     } else {
-                        use std::ops::*;
                         match operator {
+                            Operator::Prim(prim) if evaluated.len() != prim.info().arity => {
+                                unreachable!(
+                                    "wrong arity to primitive operator {}, \
+                                     error in our interpreter?!",
+                                    prim
+                                )
+                            }
                             Operator::Prim(prim) => match prim {
-                                Prim::Add1 => Self::run_prim1(|n| n + 1, evaluated, *stack),
-                                Prim::Sub1 => Self::run_prim1(|n| n - 1, evaluated, *stack),
+                                Prim::Add1 => Self::run_prim1(
+                                    |n| repr.wrap(n.wrapping_add(1)),
+                                    evaluated,
+                                    *stack,
+                                ),
+                                Prim::Sub1 => Self::run_prim1(
+                                    |n| repr.wrap(n.wrapping_sub(1)),
+                                    evaluated,
+                                    *stack,
+                                ),
                                 Prim::Not => Self::run_prim1(
                                     |n| if n == 0 { 1 } else { 0 },
                                     evaluated,
                                     *stack,
                                 ),
-                                Prim::Add => Self::run_prim2(Add::add, evaluated, *stack),
-                                Prim::Sub => Self::run_prim2(Sub::sub, evaluated, *stack),
-                                Prim::Mul => Self::run_prim2(Mul::mul, evaluated, *stack),
+                                Prim::Add => Self::run_prim2(
+                                    |n, m| repr.wrap(n.wrapping_add(m)),
+                                    evaluated,
+                                    *stack,
+                                ),
+                                Prim::Sub => Self::run_prim2(
+                                    |n, m| repr.wrap(n.wrapping_sub(m)),
+                                    evaluated,
+                                    *stack,
+                                ),
+                                Prim::Mul => Self::run_prim2(
+                                    |n, m| repr.wrap(n.wrapping_mul(m)),
+                                    evaluated,
+                                    *stack,
+                                ),
                                 Prim::And => Self::run_prim2(
                                     |n, m| if n != 0 && m != 0 { 1 } else { 0 },
                                     evaluated,
@@ -354,17 +697,30 @@ pub mod ast {
                                     evaluated,
                                     *stack,
                                 ),
+                                Prim::Cmp => Self::run_prim2(
+                                    |n, m| n.cmp(&m) as i64,
+                                    evaluated,
+                                    *stack,
+                                ),
+                                Prim::Error => match evaluated.into_iter().next().unwrap() {
+                                    DynValue::Int(code) => Err(InterpErr::UserError(code)),
+                                    DynValue::Closure(Closure { name, .. })
+                                    | DynValue::Extern(name) => Err(InterpErr::UnExpectedFun(name)),
+                                },
                             },
-                            Operator::Call(fun) => Self::run_call(fun, evaluated, env, *stack),
+                            Operator::Call(fun) => {
+                                Self::run_call(fun, evaluated, env, *stack, interp)
+                            }
                         }
                     }
                 }
-                Stack::Let { mut env, var, mut remaining, body, stack } => {
+                Stack::Let { mut env, outer, var, mut remaining, body, stack } => {
                     env.insert(VarOrFun::Var(var.clone()), dv);
                     if let Some((var, expr)) = remaining.pop() {
+                        let rhs_env = outer.clone().unwrap_or_else(|| env.clone());
                         Ok(Machine {
-                            redex: Redex::Decending { expr: expr.clone(), env: env.clone() },
-                            stack: Stack::Let { env, var, remaining, body, stack },
+                            redex: Redex::Decending { expr: expr.clone(), env: rhs_env },
+                            stack: Stack::Let { env, outer, var, remaining, body, stack },
                         })
                     } else {
                         let stack = *stack;
@@ -374,7 +730,7 @@ pub mod ast {
                 Stack::If { env, thn, els, stack } => {
                     let n = match dv {
                         DynValue::Int(n) => n,
-                        DynValue::Closure(Closure { name, .. }) => {
+                        DynValue::Closure(Closure { name, .. }) | DynValue::Extern(name) => {
                             Err(InterpErr::UnExpectedFun(name))?
                         }
                     };
@@ -387,17 +743,35 @@ pub mod ast {
                         Ok(Machine { redex: Redex::Decending { expr, env }, stack })
                     }
                 }
+                Stack::Match { env, arms, default, stack } => {
+                    let n = match dv {
+                        DynValue::Int(n) => n,
+                        DynValue::Closure(Closure { name, .. }) | DynValue::Extern(name) => {
+                            Err(InterpErr::UnExpectedFun(name))?
+                        }
+                    };
+                    let expr = match arms
+                        .into_iter()
+                        .find(|(pattern, _)| *pattern == n)
+                    {
+                        Some((_, body)) => body,
+                        None => default.expect(
+                            "the resolver rejects a match without a default, \
+                             error in our interpreter?!",
+                        ),
+                    };
+                    Ok(Machine { redex: Redex::Decending { expr, env }, stack: *stack })
+                }
             }
         }
         fn run_prim1(
             prim_f: impl Fn(i64) -> i64, args: Vec<DynValue<Var, Fun>>, stack: Stack<Var, Fun>,
         ) -> Result<Self, InterpErr<Var, Fun>> {
-            if args.len() != 1 {
-                unreachable!("wrong arity to unary primitive operator, error in our interpreter?!");
-            }
             let n = match args.into_iter().next().unwrap() {
                 DynValue::Int(n) => n,
-                DynValue::Closure(Closure { name, .. }) => Err(InterpErr::UnExpectedFun(name))?,
+                DynValue::Closure(Closure { name, .. }) | DynValue::Extern(name) => {
+                    Err(InterpErr::UnExpectedFun(name))?
+                }
             };
             let o = prim_f(n);
             Ok(Machine { redex: Redex::Ascending(DynValue::Int(o)), stack })
@@ -405,16 +779,13 @@ pub mod ast {
         fn run_prim2(
             prim_f: impl Fn(i64, i64) -> i64, args: Vec<DynValue<Var, Fun>>, stack: Stack<Var, Fun>,
         ) -> Result<Self, InterpErr<Var, Fun>> {
-            if args.len() != 2 {
-                unreachable!(
-                    "wrong arity to binary primitive operator, error in our interpreter?!"
-                );
-            }
             let args = args
                 .into_iter()
                 .map(|dv| match dv {
                     DynValue::Int(n) => Ok(n),
-                    DynValue::Closure(Closure { name, .. }) => Err(InterpErr::UnExpectedFun(name)),
+                    DynValue::Closure(Closure { name, .. }) | DynValue::Extern(name) => {
+                        Err(InterpErr::UnExpectedFun(name))
+                    }
                 })
                 .collect::<Result<Vec<_>, InterpErr<Var, Fun>>>()?;
             let n1 = args[0];
@@ -424,6 +795,7 @@ pub mod ast {
         }
         fn run_call(
             fun: Fun, args: Vec<DynValue<Var, Fun>>, env: Env<Var, Fun>, stack: Stack<Var, Fun>,
+            interp: &mut Interp,
         ) -> Result<Self, InterpErr<Var, Fun>> {
             {
                 let dv = env
@@ -432,6 +804,28 @@ pub mod ast {
                 let Closure { env: clo_env, decls, name } = match dv {
                     DynValue::Closure(closure) => closure,
                     DynValue::Int(n) => Err(InterpErr::CallToConst(*n))?,
+                    DynValue::Extern(name) => {
+                        let args = args
+                            .into_iter()
+                            .map(|dv| match dv {
+                                DynValue::Int(n) => Ok(n),
+                                DynValue::Closure(Closure { name, .. })
+                                | DynValue::Extern(name) => Err(InterpErr::UnExpectedFun(name)),
+                            })
+                            .collect::<Result<Vec<_>, _>>()?;
+                        let n = call_extern(
+                            name,
+                            &name.to_string(),
+                            &args,
+                            &mut interp.input,
+                            &mut interp.output,
+                            &interp.extern_fns,
+                        )?;
+                        if !interp.repr.in_range(n) {
+                            Err(InterpErr::InvalidInput(n.to_string()))?
+                        }
+                        return Ok(Machine { redex: Redex::Ascending(DynValue::Int(n)), stack });
+                    }
                 };
                 let mut env = clo_env.clone();
                 for (name, _) in decls {
@@ -444,7 +838,7 @@ pub mod ast {
                         }),
                     );
                 }
-                let Some(RcFunDef { params, body }) = decls.get(&name) else {
+                let Some(RcFunDef { params, body }) = decls.get(name) else {
                     unreachable!("no corresponding function in closure, error in our interpreter?!")
                 };
                 if args.len() != params.len() {
@@ -476,10 +870,14 @@ pub mod ssa {
     struct StackEnv(Frame, Vec<Frame>);
     impl StackEnv {
         fn new() -> Self {
-            Self(Frame::new([]), Vec::new())
+            Self(Frame::new(im::HashMap::new()), Vec::new())
         }
+        /// Push a fresh frame for a call. The callee sees the blocks that
+        /// were visible to the caller, but none of its variables, and any
+        /// blocks it registers are dropped again on `exit`.
         fn enter(&mut self) {
-            let frame = std::mem::replace(&mut self.0, Frame::new([]));
+            let callee = Frame::new(self.0.blocks.clone());
+            let frame = std::mem::replace(&mut self.0, callee);
             self.1.push(frame);
         }
         fn exit(&mut self) {
@@ -489,32 +887,121 @@ pub mod ssa {
             &mut self.0
         }
     }
-    struct Frame(HashMap<VarName, (usize, Value)>);
+
+    /// A point in the history of a frame. Anchors come from a counter that
+    /// only ever increases, so an anchor taken before some variable was
+    /// defined stays before it no matter how often the frame is chopped.
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    struct Anchor(usize);
+
+    /// The variables of a frame, each tagged with the anchor at which it
+    /// was defined, together with the blocks it can branch to.
+    struct Frame {
+        vars: HashMap<VarName, (Anchor, Value)>,
+        next: Anchor,
+        blocks: im::HashMap<BlockName, AnchorBlock>,
+        /// the blocks entered and not yet chopped, each with the anchor its
+        /// variables start at
+        scopes: Vec<(Anchor, BlockName)>,
+        /// the variables chopped out of the frame, with the block that
+        /// defined them, so reading one can be told apart from reading a
+        /// variable that was never defined
+        chopped: HashMap<VarName, Option<BlockName>>,
+    }
     impl Frame {
-        fn new(param_assign: impl IntoIterator<Item = (VarName, Value)>) -> Self {
-            Self(HashMap::from_iter(
-                param_assign.into_iter().enumerate().map(|(pos, (var, val))| (var, (pos, val))),
-            ))
+        fn new(blocks: im::HashMap<BlockName, AnchorBlock>) -> Self {
+            Self {
+                vars: HashMap::new(),
+                next: Anchor(0),
+                blocks,
+                scopes: Vec::new(),
+                chopped: HashMap::new(),
+            }
         }
-        fn len(&self) -> usize {
-            self.0.len()
+        /// The anchor the next defined variable will get.
+        fn anchor(&self) -> Anchor {
+            self.next
         }
         fn insert(&mut self, var: VarName, val: Value) {
-            let pos = self.0.len();
-            self.0.insert(var, (pos, val));
+            let anchor = self.next;
+            self.next = Anchor(anchor.0 + 1);
+            self.chopped.remove(&var);
+            self.vars.insert(var, (anchor, val));
+        }
+        fn get(&self, var: &VarName) -> Result<&Value, InterpErr<VarName, FunName>> {
+            match self.vars.get(var) {
+                Some((_, val)) => Ok(val),
+                None => match self.chopped.get(var) {
+                    Some(block) => {
+                        Err(InterpErr::OutOfScope { var: var.clone(), block: block.clone() })
+                    }
+                    None => Err(InterpErr::UnboundVar(var.clone())),
+                },
+            }
+        }
+        /// The block that defined `var` if it is in scope, with `None` for a
+        /// parameter of the function.
+        fn defined_in(&self, var: &VarName) -> Option<Option<BlockName>> {
+            let (a, _) = self.vars.get(var)?;
+            let block = self.scopes.iter().rev().find(|(start, _)| start <= a);
+            Some(block.map(|(_, label)| label.clone()))
         }
-        fn get(&self, var: &VarName) -> Option<(usize, &Value)> {
-            self.0.get(var).map(|(pos, val)| (*pos, val))
+        /// Forget every variable defined at or after `anchor`.
+        fn chop(&mut self, anchor: Anchor) {
+            let dropped: Vec<_> = self
+                .vars
+                .iter()
+                .filter(|(_, (a, _))| *a >= anchor)
+                .map(|(var, _)| var.clone())
+                .collect();
+            for var in dropped {
+                let block = self.defined_in(&var).flatten();
+                self.vars.remove(&var);
+                self.chopped.insert(var, block);
+            }
+            self.scopes.retain(|(start, _)| *start < anchor);
+        }
+        /// Start running `label`: the variables defined from now on belong
+        /// to it.
+        fn enter(&mut self, label: &BlockName) {
+            self.scopes.push((self.next, label.clone()));
         }
-        fn chop(&mut self, anchor: usize) {
-            self.0.retain(|_, (p, _)| *p < anchor);
+        fn register(&mut self, anchor: Anchor, blocks: &[BasicBlock]) {
+            self.blocks.extend(blocks.iter().cloned().map(|BasicBlock { label, params, body }| {
+                (label, AnchorBlock { anchor, params, body })
+            }));
+        }
+    }
+
+    /// Each variable of the frame with its value, a line each, in the
+    /// order they were defined.
+    impl Display for Frame {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let mut vars: Vec<_> = self.vars.iter().collect();
+            vars.sort_by_key(|(_, (anchor, _))| *anchor);
+            for (var, (_, val)) in vars {
+                writeln!(f, "  {} = {}", var, val)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// The frames of the stack, innermost first.
+    impl Display for StackEnv {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let frames = std::iter::once(&self.0).chain(self.1.iter().rev());
+            for (depth, frame) in frames.enumerate() {
+                writeln!(f, "frame {}:", depth)?;
+                write!(f, "{}", frame)?;
+            }
+            Ok(())
         }
     }
 
     #[derive(Clone)]
     struct AnchorBlock {
-        /// the position on the stack indicating the start of the block
-        anchor: usize,
+        /// the anchor of the frame at the start of the block
+        anchor: Anchor,
         params: Vec<VarName>,
         body: BlockBody,
     }
@@ -523,7 +1010,19 @@ pub mod ssa {
         stack: StackEnv,
         kont: Vec<(VarName, BlockBody)>,
         funs: im::HashMap<FunName, FunBlock>,
-        blocks: im::HashMap<BlockName, AnchorBlock>,
+        externs: im::HashSet<FunName>,
+        /// where calls to `read_int` read from
+        input: Box<dyn BufRead>,
+        /// where the extern functions write what they print
+        output: Box<dyn Write>,
+        /// the extern functions given with `with_extern`
+        extern_fns: ExternTable,
+        /// how integers are represented while the program runs
+        repr: IntRepr,
+        /// how many trampoline steps have been taken so far
+        steps: usize,
+        /// whether to check that the program is well-formed as it runs
+        strict: bool,
     }
 
     /// Trampoline for the interpreter.
@@ -543,40 +1042,115 @@ pub mod ssa {
                 stack: StackEnv::new(),
                 kont: Vec::new(),
                 funs: im::HashMap::new(),
-                blocks: im::HashMap::new(),
+                externs: im::HashSet::new(),
+                input: Box::new(std::io::empty()),
+                output: Box::new(std::io::stdout()),
+                extern_fns: ExternTable::new(),
+                repr: IntRepr::default(),
+                steps: 0,
+                strict: false,
             }
         }
-        fn alloc(&mut self, var: VarName, val: Value) {
+        /// Serve calls to `read_int` from `input`, one integer per line.
+        pub fn with_input(mut self, input: impl BufRead + 'static) -> Self {
+            self.input = Box::new(input);
+            self
+        }
+        /// Write what the program prints to `output` instead of stdout.
+        pub fn with_output(mut self, output: impl Write + 'static) -> Self {
+            self.output = Box::new(output);
+            self
+        }
+        /// Implement the extern function `name`, of `arity` arguments,
+        /// with `f`, besides those the runtime always provides.
+        pub fn with_extern(mut self, name: &str, arity: usize, f: ExternFn) -> Self {
+            self.extern_fns.insert(name.to_string(), (arity, f));
+            self
+        }
+        /// Run programs with integers represented according to `repr`.
+        pub fn with_repr(mut self, repr: IntRepr) -> Self {
+            self.repr = repr;
+            self
+        }
+        /// Check as the program runs that it is well-formed SSA, failing
+        /// when a branch or call passes the wrong number of arguments, a
+        /// branch targets a block that is not in scope, or a variable is
+        /// defined again while its earlier definition is still in scope.
+        /// Reading a variable that is out of scope fails either way.
+        pub fn with_strict(mut self) -> Self {
+            self.strict = true;
+            self
+        }
+        /// The number of trampoline steps taken by the programs run so far.
+        pub fn steps(&self) -> usize {
+            self.steps
+        }
+        /// The variables in scope in each frame of the stack, innermost
+        /// first, as the last program run left them. After an error, that
+        /// is where it went wrong. Integers are shown as the program
+        /// represents them.
+        pub fn dump_stack(&self) -> String {
+            self.stack.to_string()
+        }
+        fn alloc(&mut self, var: VarName, val: Value) -> Result<(), InterpErr<VarName, FunName>> {
+            let strict = self.strict;
             let frame = self.stack.current();
+            if let Some(block) = frame.defined_in(&var).filter(|_| strict) {
+                return Err(InterpErr::Redefined { var, block });
+            }
             frame.insert(var, val);
+            Ok(())
         }
 
         pub fn run(
-            &mut self, Program { externs, funs, blocks }: &Program, arg: String,
+            &mut self, prog: &Program, arg: String,
         ) -> Result<Value, InterpErr<VarName, FunName>> {
-            let val = Value::Int(arg.parse().map_err(|_| InterpErr::InvalidArg(arg))?);
-            // Note: extern functions are not supported
-            assert!(externs.is_empty(), "extern functions are not supported");
+            self.run_value(prog, parse_arg(&arg)?)
+        }
 
-            self.funs.extend(funs.iter().cloned().map(|f| (f.name.clone(), f.clone())));
-            self.blocks.extend(blocks.iter().cloned().map(|BasicBlock { label, params, body }| {
-                (label.clone(), AnchorBlock { anchor: 0, params, body })
-            }));
+        /// Like `run`, but takes the argument as an already-parsed `Value`.
+        pub fn run_value(
+            &mut self, prog: &Program, arg: Value,
+        ) -> Result<Value, InterpErr<VarName, FunName>> {
+            let Program { externs, funs, blocks, .. } = prog;
+            let Value::Int(n) = arg;
+            if !self.repr.in_range(n) {
+                Err(InterpErr::InvalidArg(n.to_string()))?
+            }
+            let val = Value::Int(self.repr.tag(n));
+            // Note: only the extern functions in `EXTERNS` or given with
+            // `with_extern` are supported
+            assert!(
+                externs.iter().all(|ext| implements_extern(ext.name.hint(), &self.extern_fns)),
+                "extern functions other than {:?} and those given with `with_extern` are not \
+                 supported",
+                EXTERNS.map(|(ext, _)| ext)
+            );
+
+            self.externs.extend(externs.iter().map(|ext| ext.name.clone()));
+
+            self.funs.extend(funs.iter().map(|f| (f.name.clone(), f.clone())));
+            self.stack.current().register(Anchor(0), blocks);
 
-            let mut state = self.run_call(&FunName::unmangled("entry"), vec![val])?;
+            let entry = prog.entry().ok_or(InterpErr::UnboundFun(FunName::unmangled(ENTRY_FUN)))?;
+            let mut state = self.run_call(&entry.name, vec![val])?;
             loop {
+                self.steps += 1;
                 match state {
                     State::Return(val) => match self.kont.pop() {
                         Some((dest, next)) => {
                             self.stack.exit();
-                            self.alloc(dest.clone(), val);
+                            self.alloc(dest.clone(), val)?;
                             state = State::BlockBody(next.clone())
                         }
-                        None => return Ok(val),
+                        None => {
+                            let Value::Int(n) = val;
+                            return Ok(Value::Int(self.repr.untag(n)));
+                        }
                     },
                     State::OpReturn(val) => match self.kont.pop() {
                         Some((dest, next)) => {
-                            self.alloc(dest.clone(), val);
+                            self.alloc(dest.clone(), val)?;
                             state = State::BlockBody(next.clone())
                         }
                         None => {
@@ -603,8 +1177,15 @@ pub mod ssa {
             &mut self, fun: &FunName, args: Vec<Value>,
         ) -> Result<State, InterpErr<VarName, FunName>> {
             let FunBlock { name: _, params, body: branch } = self.funs[fun].clone();
+            if self.strict && params.len() != args.len() {
+                Err(InterpErr::CallWrongArity {
+                    name: fun.clone(),
+                    expected: params.len(),
+                    got: args.len(),
+                })?
+            }
             for (param, arg) in params.iter().zip(args) {
-                self.alloc(param.clone(), arg.clone());
+                self.alloc(param.clone(), arg.clone())?;
             }
             Ok(State::Branch(branch.clone()))
         }
@@ -614,28 +1195,45 @@ pub mod ssa {
         ) -> Result<State, InterpErr<VarName, FunName>> {
             let args =
                 args.iter().map(|imm| self.run_immediate(imm)).collect::<Result<Vec<_>, _>>()?;
-            let AnchorBlock { anchor, params, body } = self.blocks[target].clone();
-            self.stack.current().chop(anchor);
+            let strict = self.strict;
+            let frame = self.stack.current();
+            let AnchorBlock { anchor, params, body } = match frame.blocks.get(target) {
+                Some(block) => block.clone(),
+                None if strict => Err(InterpErr::UnboundBlock(target.clone()))?,
+                None => panic!("branch to unbound block {}", target),
+            };
+            if strict && params.len() != args.len() {
+                Err(InterpErr::BrWrongArity {
+                    name: target.clone(),
+                    expected: params.len(),
+                    got: args.len(),
+                })?
+            }
+            frame.chop(anchor);
+            frame.enter(target);
             for (param, arg) in params.iter().zip(args) {
-                self.alloc(param.clone(), arg.clone());
+                self.alloc(param.clone(), arg.clone())?;
             }
             Ok(State::BlockBody(body.clone()))
         }
         fn run_block_body(
-            &mut self, block: &BlockBody,
+            &mut self, mut block: &BlockBody,
         ) -> Result<State, InterpErr<VarName, FunName>> {
+            // copies are common enough to run them in place, rather than
+            // taking a trip through the trampoline for each one
+            while let BlockBody::Operation { dest, op: Operation::Immediate(imm), next } = block {
+                let val = self.run_immediate(imm)?;
+                self.alloc(dest.clone(), val)?;
+                block = next;
+            }
             match block {
                 BlockBody::Terminator(terminator) => Ok(State::Terminator(terminator.clone())),
                 BlockBody::Operation { dest, op, next } => {
                     Ok(State::Operation(op.clone(), dest.clone(), next.as_ref().clone()))
                 }
                 BlockBody::SubBlocks { blocks, next } => {
-                    let anchor = self.stack.current().len();
-                    self.blocks.extend(blocks.iter().cloned().map(
-                        |BasicBlock { label, params, body }| {
-                            (label.clone(), AnchorBlock { anchor, params, body })
-                        },
-                    ));
+                    let frame = self.stack.current();
+                    frame.register(frame.anchor(), blocks);
                     Ok(State::BlockBody(next.as_ref().clone()))
                 }
             }
@@ -655,6 +1253,14 @@ pub mod ssa {
                         Ok(State::Branch(Branch { target: els.clone(), args: Vec::new() }))
                     }
                 }
+                Terminator::Switch { scrutinee, targets, default } => {
+                    let Value::Int(n) = self.run_immediate(scrutinee)?;
+                    let n = self.repr.untag(n);
+                    let target = usize::try_from(n).ok().and_then(|n| targets.get(n));
+                    let target = target.unwrap_or(default).clone();
+                    Ok(State::Branch(Branch { target, args: Vec::new() }))
+                }
+                Terminator::Unreachable => Err(InterpErr::ReachedUnreachable),
             }
         }
 
@@ -664,10 +1270,10 @@ pub mod ssa {
                 Operation::Prim1(prim, imm) => {
                     let Value::Int(n) = self.run_immediate(imm)?;
                     let o = match prim {
-                        Prim1::BitNot => !n,
+                        Prim1::BitNot => n ^ self.repr.tag(-1),
                         Prim1::IntToBool => {
                             if n != 0 {
-                                1
+                                self.repr.tag(1)
                             } else {
                                 0
                             }
@@ -678,19 +1284,24 @@ pub mod ssa {
                 Operation::Prim2(prim, imm1, imm2) => {
                     let Value::Int(n) = self.run_immediate(imm1)?;
                     let Value::Int(m) = self.run_immediate(imm2)?;
+                    let t = self.repr.tag(1);
                     let o = match prim {
-                        Prim2::Add => n + m,
-                        Prim2::Sub => n - m,
-                        Prim2::Mul => n * m,
+                        Prim2::Add => n.wrapping_add(m),
+                        Prim2::Sub => n.wrapping_sub(m),
+                        Prim2::Mul => self.repr.untag(n).wrapping_mul(m),
                         Prim2::BitAnd => n & m,
                         Prim2::BitOr => n | m,
                         Prim2::BitXor => n ^ m,
-                        Prim2::Lt => (if n < m { 1 } else { 0 }).clone(),
-                        Prim2::Le => (if n <= m { 1 } else { 0 }).clone(),
-                        Prim2::Gt => (if n > m { 1 } else { 0 }).clone(),
-                        Prim2::Ge => (if n >= m { 1 } else { 0 }).clone(),
-                        Prim2::Eq => (if n == m { 1 } else { 0 }).clone(),
-                        Prim2::Neq => (if n != m { 1 } else { 0 }).clone(),
+                        Prim2::Lt => if n < m { t } else { 0 },
+                        Prim2::Le => if n <= m { t } else { 0 },
+                        Prim2::Gt => if n > m { t } else { 0 },
+                        Prim2::Ge => if n >= m { t } else { 0 },
+                        Prim2::Eq => if n == m { t } else { 0 },
+                        Prim2::Neq => if n != m { t } else { 0 },
+                        Prim2::ULt => if (n as u64) < (m as u64) { t } else { 0 },
+                        Prim2::ULe => if (n as u64) <= (m as u64) { t } else { 0 },
+                        Prim2::UGt => if (n as u64) > (m as u64) { t } else { 0 },
+                        Prim2::UGe => if (n as u64) >= (m as u64) { t } else { 0 },
                     };
                     Ok(State::OpReturn(Value::Int(o)))
                 }
@@ -699,6 +1310,22 @@ pub mod ssa {
                         .iter()
                         .map(|imm| self.run_immediate(imm))
                         .collect::<Result<Vec<_>, _>>()?;
+                    if self.externs.contains(fun) {
+                        let args: Vec<_> =
+                            args.iter().map(|Value::Int(n)| self.repr.untag(*n)).collect();
+                        let n = call_extern(
+                            fun,
+                            fun.hint(),
+                            &args,
+                            &mut self.input,
+                            &mut self.output,
+                            &self.extern_fns,
+                        )?;
+                        if !self.repr.in_range(n) {
+                            Err(InterpErr::InvalidInput(n.to_string()))?
+                        }
+                        return Ok(State::OpReturn(Value::Int(self.repr.tag(n))));
+                    }
                     Ok(State::Call(fun.clone(), args))
                 }
             }
@@ -707,11 +1334,13 @@ pub mod ssa {
         fn run_immediate(&mut self, imm: &Immediate) -> Result<Value, InterpErr<VarName, FunName>> {
             match imm {
                 Immediate::Var(v) => {
-                    let (_, val) =
-                        self.stack.current().get(v).ok_or(InterpErr::UnboundVar(v.clone()))?;
+                    let val = self.stack.current().get(v)?;
                     Ok(val.clone())
                 }
-                Immediate::Const(n) => Ok(Value::Int(*n)),
+                Immediate::Const(n) => {
+                    let bits = self.repr.checked_tag(*n);
+                    Ok(Value::Int(bits.ok_or(InterpErr::OutOfRange(*n))?))
+                }
             }
         }
     }
@@ -0,0 +1,72 @@
+//! A thin tokenizer mirroring the lexer lalrpop generates for our
+//! grammar, so that we can look at the token stream the parser sees.
+
+use crate::span::SrcLoc;
+use lalrpop_util::lexer::{MatcherBuilder, Token};
+use lalrpop_util::ParseError;
+
+/// The regular-expression terminals of `parser.lalrpop`, with what to
+/// call them in messages.
+///
+/// These must be kept in sync with the grammar, which `terminal_names`
+/// lets a test check. They come before the literal terminals because,
+/// like in lalrpop, ties between equally long matches go to the later
+/// entry, so that keywords beat identifiers.
+static REGEX_TERMINALS: [(&str, &str); 2] = [
+    (r"[+-]?[0-9]+", "a number"),
+    (r"[a-zA-Z_][a-zA-Z0-9_]*", "an identifier"),
+];
+
+/// The literal terminals of `parser.lalrpop`.
+static LITERAL_TERMINALS: [&str; 35] = [
+    "!", "!=", "&&", "(", ")", "*", "+", ",", "-", ":", "<", "<=", "=",
+    "==", "=>", ">", ">=", "add1", "and", "cmp", "def", "else", "error",
+    "extern", "false", "if", "in", "let", "letrec", "match", "sub1", "true",
+    "{", "||", "}",
+];
+
+/// The terminals of `parser.lalrpop`, named as the generated parser
+/// names them in the tokens it expects: literals in double quotes, and
+/// regular expressions as raw strings.
+pub fn terminal_names() -> Vec<String> {
+    REGEX_TERMINALS
+        .iter()
+        .map(|(r, _)| regex_name(r))
+        .chain(LITERAL_TERMINALS.iter().map(|l| format!("\"{}\"", l)))
+        .collect()
+}
+
+/// The terminal the generated parser names `name`, in words a user can
+/// read: quoted literals stay as they are, and regular expressions are
+/// described.
+pub fn describe_terminal(name: &str) -> String {
+    REGEX_TERMINALS
+        .iter()
+        .find(|(r, _)| regex_name(r) == name)
+        .map_or_else(|| name.to_string(), |(_, what)| what.to_string())
+}
+
+fn regex_name(regex: &str) -> String {
+    format!("r#\"{}\"#", regex)
+}
+
+/// An error encountered while tokenizing.
+pub type LexErr<'input> = ParseError<usize, Token<'input>, &'static str>;
+
+/// Split the source into the tokens the parser would see, along with
+/// their locations.
+pub fn tokenize(s: &str) -> Result<Vec<(SrcLoc, &str)>, LexErr<'_>> {
+    let terminals = REGEX_TERMINALS
+        .iter()
+        .map(|(r, _)| (r.to_string(), false))
+        .chain(LITERAL_TERMINALS.iter().map(|l| (regex::escape(l), false)))
+        .chain([(r"\s+".to_string(), true)]);
+    let builder = MatcherBuilder::new(terminals)
+        .expect("the terminals of our grammar are valid regexes");
+    builder
+        .matcher(s)
+        .map(|tok| {
+            tok.map(|(l, Token(_, text), r)| (SrcLoc::new(l, r), text))
+        })
+        .collect()
+}
@@ -2,11 +2,20 @@
 pub mod txt;
 pub mod frontend;
 pub mod ast;
+pub mod fold;
+pub mod types;
 pub mod middle_end;
 pub mod ssa;
+pub mod dce;
+pub mod licm;
+pub mod bools;
 pub mod backend;
+pub mod backend_llvm;
+pub mod backend_c;
+pub mod backend_wasm;
 pub mod asm;
 pub mod compile;
+#[allow(clippy::type_complexity)]
 pub mod parser;
 
 /* -------------------------------- Utilities ------------------------------- */
@@ -15,3 +24,4 @@ pub mod span;
 pub mod pretty;
 pub mod interp;
 pub mod runner;
+pub mod lexer;
@@ -0,0 +1,387 @@
+//! Loop-invariant code motion on SSA.
+//!
+//! A loop is found from its back edges: branches to a block, its header,
+//! from blocks the header dominates. An operation in the loop whose
+//! operands hold the same value on every iteration computes the same
+//! value every time, so it is hoisted into a preheader that runs once
+//! before the loop is entered. Calls may have an effect and stay put.
+//!
+//! A block only sees the variables of the blocks it is nested in, so the
+//! preheader takes the place of the header, label and all, and the header
+//! is nested in it under a fresh label that the back edges branch to. A
+//! parameter of the header that every back edge passes back unchanged is
+//! invariant too. The preheader gets a copy of each parameter, which the
+//! hoisted operations read instead.
+
+use crate::identifiers::*;
+use crate::ssa::*;
+use std::collections::{HashMap, HashSet};
+
+/// What loop-invariant code motion did to a program.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LicmStats {
+    /// the number of operations hoisted out of loops
+    pub hoisted: usize,
+}
+
+pub fn hoist_invariants(prog: Program) -> Program {
+    hoist_invariants_with_stats(prog).0
+}
+
+/// Hoist the invariant operations of the loops in `prog` into
+/// preheaders, counting how many there were. Each loop is done in turn,
+/// since hoisting out of one changes the blocks of the others.
+pub fn hoist_invariants_with_stats(
+    mut prog: Program,
+) -> (Program, LicmStats) {
+    let mut stats = LicmStats::default();
+    let mut names = FreshNames::for_prog(&prog);
+    while let Some(hoist) = find_hoist(&prog) {
+        stats.hoisted += hoist.ops.len();
+        prog.blocks = prog
+            .blocks
+            .into_iter()
+            .map(|block| rewrite_block(block, &hoist, &mut names))
+            .collect();
+    }
+    (prog, stats)
+}
+
+/// The operations to hoist out of the loop headed by `header`, in an
+/// order in which each comes after those it reads.
+struct Hoist {
+    header: BlockName,
+    /// the parameters of the header that every back edge passes back
+    /// unchanged
+    invariant_params: Vec<VarName>,
+    ops: Vec<(VarName, Operation)>,
+}
+
+/// The first loop with an operation to hoist, if any. The loops of the
+/// back edges to a header are hoisted out of together.
+fn find_hoist(prog: &Program) -> Option<Hoist> {
+    let loops = prog.loops();
+    let defs = definitions(prog);
+    prog.blocks_recursive().find_map(|header| {
+        let loops: Vec<&Loop> =
+            loops.iter().filter(|l| l.header == header.label).collect();
+        if loops.is_empty() {
+            return None;
+        }
+        let latches: Vec<&BlockName> =
+            loops.iter().map(|l| &l.latch).collect();
+        let body: HashSet<&BlockName> =
+            loops.iter().flat_map(|l| &l.body).collect();
+        let nested: HashMap<&BlockName, &BasicBlock> = header
+            .blocks_recursive()
+            .map(|block| (&block.label, block))
+            .collect();
+        // the back edges must be able to see the label of the header
+        // once it is nested in the preheader
+        if body.iter().any(|label| !nested.contains_key(label)) {
+            return None;
+        }
+
+        let invariant_params: Vec<VarName> = header
+            .params
+            .iter()
+            .enumerate()
+            .filter(|(i, param)| {
+                latches.iter().all(|latch| {
+                    match nested[latch].body.terminator() {
+                        Terminator::Branch(Branch { target, args })
+                            if *target == header.label =>
+                        {
+                            args.get(*i)
+                                == Some(&Immediate::Var((*param).clone()))
+                        }
+                        _ => true,
+                    }
+                })
+            })
+            .map(|(_, param)| param.clone())
+            .collect();
+        let defined_inside: HashSet<&VarName> = nested
+            .values()
+            .flat_map(|block| {
+                block
+                    .params
+                    .iter()
+                    .chain(own_ops(block).map(|(dest, _)| dest))
+            })
+            .collect();
+
+        let mut hoisted: Vec<(VarName, Operation)> = Vec::new();
+        let mut hoisted_dests: HashSet<VarName> = HashSet::new();
+        loop {
+            let before = hoisted.len();
+            for block in header
+                .blocks_recursive()
+                .filter(|block| body.contains(&block.label))
+            {
+                for (dest, op) in own_ops(block) {
+                    let invariant = |imm: &Immediate| match imm {
+                        Immediate::Const(_) => true,
+                        Immediate::Var(v) => {
+                            !defined_inside.contains(v)
+                                || invariant_params.contains(v)
+                                || hoisted_dests.contains(v)
+                        }
+                    };
+                    let pure_and_invariant = match op {
+                        Operation::Immediate(imm)
+                        | Operation::Prim1(_, imm) => invariant(imm),
+                        Operation::Prim2(_, imm1, imm2) => {
+                            invariant(imm1) && invariant(imm2)
+                        }
+                        Operation::Call { .. } => false,
+                    };
+                    // a name the lowerer reuses may hold a different value
+                    // on each path that defines it
+                    if pure_and_invariant
+                        && defs[dest] == 1
+                        && !hoisted_dests.contains(dest)
+                    {
+                        hoisted_dests.insert(dest.clone());
+                        hoisted.push((dest.clone(), op.clone()));
+                    }
+                }
+            }
+            if hoisted.len() == before {
+                break;
+            }
+        }
+        (!hoisted.is_empty()).then(|| Hoist {
+            header: header.label.clone(),
+            invariant_params,
+            ops: hoisted,
+        })
+    })
+}
+
+/// How many times each variable is bound, as a parameter or by an
+/// operation.
+fn definitions(prog: &Program) -> HashMap<&VarName, usize> {
+    let mut defs = HashMap::new();
+    for fun in &prog.funs {
+        for param in &fun.params {
+            *defs.entry(param).or_default() += 1;
+        }
+    }
+    for block in prog.blocks_recursive() {
+        for var in
+            block.params.iter().chain(own_ops(block).map(|(dest, _)| dest))
+        {
+            *defs.entry(var).or_default() += 1;
+        }
+    }
+    defs
+}
+
+/// The operations of `block` itself, leaving out those of the blocks
+/// nested in it.
+fn own_ops(
+    block: &BasicBlock,
+) -> impl Iterator<Item = (&VarName, &Operation)> {
+    let mut ops = Vec::new();
+    let mut body = &block.body;
+    loop {
+        match body {
+            BlockBody::Terminator(_) => break,
+            BlockBody::Operation { dest, op, next } => {
+                ops.push((dest, op));
+                body = next;
+            }
+            BlockBody::SubBlocks { next, .. } => body = next,
+        }
+    }
+    ops.into_iter()
+}
+
+fn rewrite_block(
+    block: BasicBlock, hoist: &Hoist, names: &mut FreshNames,
+) -> BasicBlock {
+    if block.label == hoist.header {
+        return preheader(block, hoist, names);
+    }
+    let BasicBlock { label, params, body } = block;
+    BasicBlock { label, params, body: rewrite_body(body, hoist, names) }
+}
+
+fn rewrite_body(
+    body: BlockBody, hoist: &Hoist, names: &mut FreshNames,
+) -> BlockBody {
+    match body {
+        BlockBody::Operation { dest, op, next } => BlockBody::Operation {
+            dest,
+            op,
+            next: Box::new(rewrite_body(*next, hoist, names)),
+        },
+        BlockBody::SubBlocks { blocks, next } => BlockBody::SubBlocks {
+            blocks: blocks
+                .into_iter()
+                .map(|block| rewrite_block(block, hoist, names))
+                .collect(),
+            next: Box::new(rewrite_body(*next, hoist, names)),
+        },
+        BlockBody::Terminator(_) => body,
+    }
+}
+
+/// The preheader that replaces `header`: it computes the hoisted
+/// operations and branches to the loop, which is `header` without them.
+fn preheader(
+    header: BasicBlock, hoist: &Hoist, names: &mut FreshNames,
+) -> BasicBlock {
+    let loop_label = names.block(header.label.hint());
+    let params: Vec<VarName> =
+        header.params.iter().map(|p| names.var(p.hint())).collect();
+    let copies: HashMap<VarName, VarName> =
+        header.params.iter().cloned().zip(params.iter().cloned()).collect();
+    let subst = |imm: &Immediate| match imm {
+        Immediate::Var(v) if hoist.invariant_params.contains(v) => {
+            Immediate::Var(copies[v].clone())
+        }
+        _ => imm.clone(),
+    };
+    let hoisted: HashSet<&VarName> =
+        hoist.ops.iter().map(|(dest, _)| dest).collect();
+    let the_loop = BasicBlock {
+        label: loop_label.clone(),
+        body: strip(header.body, &hoisted, &header.label, &loop_label),
+        params: header.params,
+    };
+    let entry = Branch {
+        target: loop_label,
+        args: params.iter().map(|p| Immediate::Var(p.clone())).collect(),
+    };
+    let body = hoist.ops.iter().rev().fold(
+        BlockBody::SubBlocks {
+            blocks: vec![the_loop],
+            next: Box::new(BlockBody::Terminator(Terminator::Branch(entry))),
+        },
+        |next, (dest, op)| BlockBody::Operation {
+            dest: dest.clone(),
+            op: match op {
+                Operation::Immediate(imm) => {
+                    Operation::Immediate(subst(imm))
+                }
+                Operation::Prim1(prim, imm) => {
+                    Operation::Prim1(prim.clone(), subst(imm))
+                }
+                Operation::Prim2(prim, imm1, imm2) => {
+                    Operation::Prim2(prim.clone(), subst(imm1), subst(imm2))
+                }
+                Operation::Call { .. } => {
+                    unreachable!("calls are never hoisted")
+                }
+            },
+            next: Box::new(next),
+        },
+    );
+    BasicBlock { label: header.label, params, body }
+}
+
+/// `body` without the operations defining `hoisted`, branching to `to`
+/// wherever it branched to `from`.
+fn strip(
+    body: BlockBody, hoisted: &HashSet<&VarName>, from: &BlockName,
+    to: &BlockName,
+) -> BlockBody {
+    let retarget =
+        |label: BlockName| if label == *from { to.clone() } else { label };
+    match body {
+        BlockBody::Operation { dest, next, .. }
+            if hoisted.contains(&dest) =>
+        {
+            strip(*next, hoisted, from, to)
+        }
+        BlockBody::Operation { dest, op, next } => BlockBody::Operation {
+            dest,
+            op,
+            next: Box::new(strip(*next, hoisted, from, to)),
+        },
+        BlockBody::SubBlocks { blocks, next } => BlockBody::SubBlocks {
+            blocks: blocks
+                .into_iter()
+                .map(|BasicBlock { label, params, body }| BasicBlock {
+                    label,
+                    params,
+                    body: strip(body, hoisted, from, to),
+                })
+                .collect(),
+            next: Box::new(strip(*next, hoisted, from, to)),
+        },
+        BlockBody::Terminator(Terminator::Branch(Branch {
+            target,
+            args,
+        })) => BlockBody::Terminator(Terminator::Branch(Branch {
+            target: retarget(target),
+            args,
+        })),
+        BlockBody::Terminator(Terminator::ConditionalBranch {
+            cond,
+            thn,
+            els,
+        }) => BlockBody::Terminator(Terminator::ConditionalBranch {
+            cond,
+            thn: retarget(thn),
+            els: retarget(els),
+        }),
+        BlockBody::Terminator(Terminator::Switch {
+            scrutinee,
+            targets,
+            default,
+        }) => BlockBody::Terminator(Terminator::Switch {
+            scrutinee,
+            targets: targets.into_iter().map(retarget).collect(),
+            default: retarget(default),
+        }),
+        BlockBody::Terminator(
+            Terminator::Return(_) | Terminator::Unreachable,
+        ) => body,
+    }
+}
+
+/// Mints names that clash with none already in the program.
+struct FreshNames {
+    vars: IdGen<VarName>,
+    blocks: IdGen<BlockName>,
+}
+
+impl FreshNames {
+    fn for_prog(prog: &Program) -> Self {
+        #[derive(Default)]
+        struct Highest {
+            var: usize,
+            block: usize,
+        }
+        impl NameMap for Highest {
+            fn var(&mut self, var: &VarName) -> VarName {
+                self.var = self.var.max(var.index() + 1);
+                var.clone()
+            }
+            fn fun(&mut self, fun: &FunName) -> FunName {
+                fun.clone()
+            }
+            fn block(&mut self, block: &BlockName) -> BlockName {
+                self.block = self.block.max(block.index() + 1);
+                block.clone()
+            }
+        }
+        let mut highest = Highest::default();
+        prog.map_names(&mut highest);
+        FreshNames {
+            vars: IdGen::starting_at(highest.var),
+            blocks: IdGen::starting_at(highest.block),
+        }
+    }
+
+    fn var(&mut self, hint: &str) -> VarName {
+        self.vars.fresh(hint)
+    }
+
+    fn block(&mut self, hint: &str) -> BlockName {
+        self.blocks.fresh(hint)
+    }
+}
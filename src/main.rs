@@ -1,10 +1,15 @@
-use snake::asm::instrs_to_string;
-use snake::backend::Emitter;
-use snake::frontend::Resolver;
+use snake::asm::{instrs_to_string, interp::Machine, reg_to_string, Reg};
+use snake::backend::ALLOCATABLE_REGS;
+use snake::backend_c::emit_c;
+use snake::backend_llvm::emit_llvm;
+use snake::backend_wasm::emit_wasm;
+use snake::compile;
+use snake::frontend::Bound;
 use snake::interp;
-use snake::middle_end::Lowerer;
+use snake::lexer;
 use snake::parser::ProgParser;
 use snake::runner::*;
+use snake::ssa::{IntRepr, StableNames};
 use snake::txt::FileInfo;
 use std::path::Path;
 
@@ -16,13 +21,15 @@ use clap::{Parser, ValueEnum};
 #[command(about, long_about = None)]
 struct Cli {
     /// File containing the input program
-    input_file: String,
+    #[arg(required_unless_present = "repl")]
+    input_file: Option<String>,
 
     /// Optional target type. Defaults to asm
     #[arg(value_enum, short, long, value_name = "target")]
     target: Option<Target>,
 
-    /// Optional output file. For target exe, defaults to runtime/stub.exe, otherwise if not present prints to stdout
+    /// Optional output file. For target exe, defaults to runtime/stub.exe, for target lib to
+    /// runtime/libcompiled_code.a, otherwise if not present prints to stdout
     #[arg(short, long, value_name = "output")]
     output: Option<PathBuf>,
 
@@ -33,6 +40,155 @@ struct Cli {
     /// Optional runtime file. Defaults to runtime/stub.rs
     #[arg(short, long, value_name = "runtime")]
     runtime: Option<PathBuf>,
+
+    /// If set, represents integers as 63-bit values shifted left by one. Affects every target
+    /// but llvm, c and wasm
+    #[arg(long)]
+    tagged: bool,
+
+    /// If set, folds constant subexpressions of the resolved AST. Affects every target after it
+    #[arg(long)]
+    fold_constants: bool,
+
+    /// If set, removes operations whose results are never read from the ssa. Affects every
+    /// target after it
+    #[arg(long)]
+    eliminate_dead_code: bool,
+
+    /// If set, replaces coercions to 0 or 1 of values that are already 0 or 1 with copies in
+    /// the ssa. Affects every target after it
+    #[arg(long)]
+    elide_bool_coercions: bool,
+
+    /// If set, types the resolved ast and lowers it without coercing to 0 or 1 the operands of
+    /// logical operations that already are. Affects every target after it
+    #[arg(long)]
+    bool_types: bool,
+
+    /// If set, moves operations that compute the same value on every iteration of a loop out
+    /// of it in the ssa, to run once before the loop. Affects every target after it
+    #[arg(long)]
+    hoist_invariants: bool,
+
+    /// Starts the indices of the names the compiler makes up at this offset rather than 0, to
+    /// check that nothing depends on them
+    #[arg(long, value_name = "offset", default_value_t = 0)]
+    id_offset: usize,
+
+    /// If set, reports on stderr what each optimization pass that ran removed or rewrote
+    #[arg(long)]
+    opt_report: bool,
+
+    /// The symbol to compile main to, which the runtime calls. Defaults to entry
+    #[arg(long, value_name = "symbol")]
+    entry: Option<String>,
+
+    /// If set, keeps variables in these comma-separated registers, spilling the rest to the
+    /// stack. Without a list, uses every register the backend does not need for itself
+    #[arg(long, value_name = "regs", value_delimiter = ',', num_args = 0.., require_equals = true,
+          value_parser = parse_reg)]
+    registers: Option<Vec<Reg>>,
+
+    /// If set, runs the program on this argument with the ssa interpreter and prints the
+    /// result, whatever the target. Needs no assembler or linker
+    #[arg(long, value_name = "arg", allow_hyphen_values = true)]
+    run_interp: Option<String>,
+
+    /// If set, executes asm and exe targets with the built-in x86 interpreter instead of
+    /// assembling and linking them
+    #[arg(long)]
+    emulate: bool,
+
+    /// If set, comments the assembly with each source line, above the code generated from the
+    /// expressions on it
+    #[arg(long)]
+    listing: bool,
+
+    /// If set, describes each frame in the assembly listing with CFI directives, so debuggers can
+    /// unwind it. nasm does not accept them, so this is only for the asm target, and is rejected
+    /// when the code would be assembled
+    #[arg(long)]
+    cfi: bool,
+
+    /// If set, prints the tokens the parser sees instead of compiling
+    #[arg(long)]
+    dump_tokens: bool,
+
+    /// If set, prints the resolved ast with the names from the source, priming those bound while
+    /// an earlier binding of the same name is in scope, instead of their unique names. Only for
+    /// the resolved-ast target
+    #[arg(long)]
+    source_names: bool,
+
+    /// If set, prints each name the resolver bound with the unique name it was given, instead of
+    /// compiling
+    #[arg(long)]
+    dump_env: bool,
+
+    /// If set, lists the externs the runtime must provide, one per line with its arity,
+    /// instead of compiling
+    #[arg(long)]
+    externs: bool,
+
+    /// If set, describes what each stage of the compiler did instead of compiling
+    #[arg(long)]
+    explain: bool,
+
+    /// If set, only parses and resolves the program, reporting any errors, instead of compiling
+    #[arg(long)]
+    check: bool,
+
+    /// The assembler to run when linking. Defaults to nasm
+    #[arg(long, value_name = "program")]
+    assembler: Option<String>,
+
+    /// The archiver to run when linking. Defaults to ar
+    #[arg(long, value_name = "program")]
+    archiver: Option<String>,
+
+    /// The Rust compiler to build the runtime with when linking. Defaults to rustc
+    #[arg(long, value_name = "program")]
+    rustc: Option<String>,
+
+    /// If set, writes the intermediate .s/.o/.a files to this directory and keeps them
+    #[arg(long, value_name = "dir")]
+    keep_temps: Option<PathBuf>,
+
+    /// If set, also writes the ssa that the asm and exe targets are generated from to this file
+    #[arg(long, value_name = "path")]
+    save_ssa: Option<PathBuf>,
+
+    /// If set, numbers identifiers per hint in order of appearance, so the printed ssa does not
+    /// depend on how many fresh names were generated. Affects the ssa target
+    #[arg(long)]
+    stable_names: bool,
+
+    /// If set, reads expressions from stdin a line at a time and prints their values, instead of
+    /// compiling a file. Lines starting with def declare functions for the lines after them
+    #[arg(long)]
+    repl: bool,
+
+    /// The number of resolution errors to report at most, after which the rest are suppressed.
+    /// Defaults to 20
+    #[arg(long, value_name = "n")]
+    max_errors: Option<usize>,
+
+    /// If set, counts a tab in the source as reaching the next multiple of this many columns
+    /// when reporting positions, rather than as one column
+    #[arg(long, value_name = "width")]
+    tab_width: Option<usize>,
+
+    /// How to format error messages. Defaults to human
+    #[arg(value_enum, long, value_name = "format")]
+    message_format: Option<MessageFormat>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum MessageFormat {
+    /// Human-readable messages
+    Human,
+    /// One JSON object per diagnostic, for editor integration
+    Json,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
@@ -45,21 +201,119 @@ enum Target {
     SSA,
     /// x86_64 Assembly Code
     Asm,
+    /// Textual LLVM IR, with untagged integers
+    Llvm,
+    /// Portable C, with untagged integers
+    C,
+    /// WebAssembly text format, with untagged integers
+    Wasm,
     /// Binary executable
     Exe,
+    /// Static library exporting `entry`, for linking into a C or Rust program
+    Lib,
 }
 use Target::*;
 
+/// Parse the name of a register, like `rbx`.
+fn parse_reg(s: &str) -> Result<Reg, String> {
+    [Reg::Rax, Reg::R10, Reg::Rsp, Reg::Rbp]
+        .iter()
+        .chain(&ALLOCATABLE_REGS)
+        .find(|reg| reg_to_string(**reg) == s)
+        .copied()
+        .ok_or_else(|| format!("unknown register: {}", s))
+}
+
+/// The pipeline `cli` configures.
+fn pipeline_of(cli: &Cli) -> compile::Pipeline {
+    let mut pipeline = compile::Pipeline::new()
+        .with_repr(if cli.tagged { IntRepr::Tagged } else { IntRepr::Untagged })
+        .with_id_offset(cli.id_offset);
+    if let Some(entry) = &cli.entry {
+        pipeline = pipeline.with_entry(entry);
+    }
+    if let Some(max) = cli.max_errors {
+        pipeline = pipeline.with_max_errors(max);
+    }
+    if let Some(regs) = &cli.registers {
+        pipeline = pipeline.with_registers(if regs.is_empty() { &ALLOCATABLE_REGS } else { regs });
+    }
+    if cli.fold_constants {
+        pipeline = pipeline.with_fold_constants();
+    }
+    if cli.bool_types {
+        pipeline = pipeline.with_bool_types();
+    }
+    if cli.elide_bool_coercions {
+        pipeline = pipeline.with_elide_bool_coercions();
+    }
+    if cli.hoist_invariants {
+        pipeline = pipeline.with_hoist_invariants();
+    }
+    if cli.eliminate_dead_code {
+        pipeline = pipeline.with_eliminate_dead_code();
+    }
+    pipeline
+}
+
 fn run_cli(cli: &Cli) -> Result<(), String> {
+    if cli.repl {
+        return repl(std::io::stdin().lock(), &mut std::io::stdout())
+            .map_err(|e| format!("Error reading input: {}", e));
+    }
+    if cli.source_names && cli.target != Some(ResolvedAST) {
+        return Err("Error: --source-names only applies to the resolved-ast target".to_string());
+    }
+    let input_file = cli.input_file.as_ref().expect("clap requires an input file without --repl");
     let inp =
-        read_file(Path::new(&cli.input_file)).map_err(|e| format!("Error reading file: {}", e))?;
-    let file_info = FileInfo::new(&inp);
-    let raw_ast =
-        ProgParser::new().parse(&inp).map_err(|e| format!("Error parsing program: {}", e))?;
+        read_file(Path::new(input_file)).map_err(|e| format!("Error reading file: {}", e))?;
+    let mut file_info = FileInfo::new(&inp);
+    if let Some(width) = cli.tab_width {
+        file_info = file_info.with_tab_width(width);
+    }
+    if cli.dump_tokens {
+        let tokens =
+            lexer::tokenize(&inp).map_err(|e| format!("Error tokenizing program: {}", e))?;
+        for (loc, text) in tokens {
+            println!("{}\t{}", file_info.span1_to_span2(loc), text);
+        }
+        return Ok(());
+    }
+    if cli.explain {
+        println!("{}", pipeline_of(cli).explain(&inp).map_err(|e| e.to_string())?);
+        return Ok(());
+    }
+    let raw_ast = ProgParser::new().parse(&inp).map_err(|e| match cli.message_format {
+        Some(MessageFormat::Json) => file_info.parse_diagnostic(&inp, e).to_json(),
+        Some(MessageFormat::Human) | None => {
+            format!("Error parsing program: {}", file_info.report_parse_error(&inp, e))
+        }
+    })?;
+    let resolve_errors = |errs: Vec<_>| {
+        let reports: Vec<_> = errs
+            .into_iter()
+            .map(|e| match cli.message_format {
+                Some(MessageFormat::Json) => file_info.diagnostic(e).to_json(),
+                Some(MessageFormat::Human) | None => {
+                    format!("Error resolving ast: {}", file_info.report_error(e))
+                }
+            })
+            .collect();
+        reports.join("\n")
+    };
+    let pipeline = pipeline_of(cli);
+    if cli.check {
+        pipeline.resolver().resolve_prog_all(raw_ast).map_err(resolve_errors)?;
+        return Ok(());
+    }
+    let repr = pipeline.repr();
     match cli.target {
-        Some(AST) => {
+        Some(AST) if cli.run_interp.is_none() => {
             if let Some(ref arg) = cli.execute {
-                let value = interp::ast::Machine::run_prog(&raw_ast, arg.clone())
+                let value = interp::ast::Interp::new()
+                    .with_repr(repr)
+                    .with_input(std::io::stdin().lock())
+                    .run_value(&raw_ast, parse_arg(arg)?)
                     .map_err(|e| format!("Error interpreting program: {}", e))?;
                 println!("{}", value);
             } else {
@@ -69,16 +323,36 @@ fn run_cli(cli: &Cli) -> Result<(), String> {
         }
         _ => {}
     }
-    let mut resolver = Resolver::new();
-    let resolved_ast = resolver
-        .resolve_prog(raw_ast)
-        .map_err(|e| format!("Error resolving ast: {}", file_info.report_error(e)))?;
+    let mut resolver = pipeline.resolver();
+    if cli.dump_env {
+        resolver = resolver.with_bindings();
+    }
+    let resolved_ast = resolver.resolve_prog_all(raw_ast).map_err(resolve_errors)?;
+    if cli.dump_env {
+        for (name, bound, loc) in resolver.bindings() {
+            let unique = match bound {
+                Bound::Var(var) => var.to_string(),
+                Bound::Fun(fun) => fun.to_string(),
+            };
+            println!("{}\t{}\t{}", file_info.span1_to_span2(*loc), name, unique);
+        }
+        return Ok(());
+    }
+    let (resolved_ast, report) = pipeline.fold(resolved_ast);
+    if cli.opt_report {
+        eprint!("{}", report);
+    }
     match cli.target {
-        Some(ResolvedAST) => {
+        Some(ResolvedAST) if cli.run_interp.is_none() => {
             if let Some(ref arg) = cli.execute {
-                let value = interp::ast::Machine::run_prog(&resolved_ast, arg.clone())
+                let value = interp::ast::Interp::new()
+                    .with_repr(repr)
+                    .with_input(std::io::stdin().lock())
+                    .run_value(&resolved_ast, parse_arg(arg)?)
                     .map_err(|e| format!("Error interpreting program: {}", e))?;
                 println!("{}", value);
+            } else if cli.source_names {
+                println!("{}", snake::pretty::readable(&resolved_ast));
             } else {
                 println!("{}", resolved_ast);
             }
@@ -86,16 +360,38 @@ fn run_cli(cli: &Cli) -> Result<(), String> {
         }
         _ => {}
     }
-    let mut lowerer = Lowerer::from(resolver);
-    let ssa = lowerer.lower_prog(resolved_ast);
+    let (lowerer, ssa) = pipeline.lower(resolver, resolved_ast);
+    let (ssa, report) = pipeline.optimize(ssa);
+    if cli.opt_report {
+        eprint!("{}", report);
+    }
+    if let Some(ref arg) = cli.run_interp {
+        let mut interp =
+            interp::ssa::Interp::new().with_repr(repr).with_input(std::io::stdin().lock());
+        let value = interp
+            .run(&ssa, arg.clone())
+            .map_err(|e| format!("Error interpreting program: {}", e))?;
+        println!("{}", value);
+        return Ok(());
+    }
+    if cli.externs {
+        for (name, arity) in compile::externs_of(&ssa) {
+            println!("{}\t{}", name, arity);
+        }
+        return Ok(());
+    }
     match cli.target {
         Some(SSA) => {
             if let Some(ref arg) = cli.execute {
-                let mut interp = interp::ssa::Interp::new();
+                let mut interp = interp::ssa::Interp::new()
+                    .with_repr(repr)
+                    .with_input(std::io::stdin().lock());
                 let value = interp
                     .run(&ssa, arg.clone())
                     .map_err(|e| format!("Error interpreting program: {}", e))?;
                 println!("{}", value);
+            } else if cli.stable_names {
+                println!("{}", ssa.map_names(&mut StableNames::default()));
             } else {
                 println!("{}", ssa);
             }
@@ -103,7 +399,41 @@ fn run_cli(cli: &Cli) -> Result<(), String> {
         }
         _ => {}
     }
-    let mut emitter = Emitter::from(lowerer);
+    if let Some(Llvm) = cli.target {
+        if cli.execute.is_some() {
+            return Err("Error: the llvm target cannot be executed".to_string());
+        }
+        print!("{}", emit_llvm(&ssa));
+        return Ok(());
+    }
+    if let Some(C) = cli.target {
+        if cli.execute.is_some() {
+            return Err("Error: the c target cannot be executed".to_string());
+        }
+        print!("{}", emit_c(&ssa));
+        return Ok(());
+    }
+    if let Some(Wasm) = cli.target {
+        if cli.execute.is_some() {
+            return Err("Error: the wasm target cannot be executed".to_string());
+        }
+        print!("{}", emit_wasm(&ssa));
+        return Ok(());
+    }
+    if let (Some(Lib), Some(_)) = (cli.target, &cli.execute) {
+        return Err("Error: the lib target cannot be executed".to_string());
+    }
+    if let Some(path) = &cli.save_ssa {
+        std::fs::write(path, ssa.to_string())
+            .map_err(|e| format!("Error writing {:?}: {}", path, e))?;
+    }
+    let mut emitter = pipeline.emitter(lowerer, &ssa).map_err(|e| e.to_string())?;
+    if cli.listing {
+        emitter = emitter.with_source(&inp);
+    }
+    if cli.cfi {
+        emitter = emitter.with_cfi();
+    }
     emitter.emit_prog(&ssa);
     let asm = emitter.to_asm();
     let txt = instrs_to_string(&asm);
@@ -115,17 +445,70 @@ fn run_cli(cli: &Cli) -> Result<(), String> {
         }
         _ => {}
     }
+    if let (true, Some(arg)) = (cli.emulate, &cli.execute) {
+        let interp::Value::Int(arg) = interp::parse_arg::<String, String>(arg)
+            .map_err(|e| format!("Error emulating program: {}", e))?;
+        let mut machine = Machine::new().with_input(std::io::stdin().lock());
+        let value = machine
+            .run(&asm, arg, &mut std::io::stdout())
+            .map_err(|e| format!("Error emulating program: {}", e))?;
+        println!("{}", value);
+        return Ok(());
+    }
+    if cli.cfi {
+        return Err(
+            "Error: --cfi is only for assembly listings, since nasm cannot assemble it".to_string()
+        );
+    }
     // if the target is assembly and execute is true, we treat it the same as Exe execute.
     // target is Exe, may want to execute
     let rt = cli.runtime.clone().unwrap_or(PathBuf::from("runtime/stub.rs"));
-    let o_dir = PathBuf::from("runtime");
+    let o_dir = cli.keep_temps.clone().unwrap_or(PathBuf::from("runtime"));
+    if cli.keep_temps.is_some() {
+        std::fs::create_dir_all(&o_dir).map_err(|e| format!("Error creating {:?}: {}", o_dir, e))?;
+    }
+    let defaults = LinkOptions::default();
+    let options = LinkOptions {
+        assembler: cli.assembler.clone().unwrap_or(defaults.assembler),
+        archiver: cli.archiver.clone().unwrap_or(defaults.archiver),
+        rustc: cli.rustc.clone().unwrap_or(defaults.rustc),
+    };
+    if let Some(Lib) = cli.target {
+        let lib_fname = cli.output.clone().unwrap_or(Temps::in_dir(&o_dir).lib);
+        return build_archive_with_options(
+            &txt,
+            &o_dir,
+            &lib_fname,
+            cli.keep_temps.is_some(),
+            &options,
+        )
+        .map_err(|e| e.to_string());
+    }
     let exe_fname = cli.output.clone().unwrap_or(PathBuf::from("runtime/stub.exe"));
-    link(&txt, &rt, &o_dir, &exe_fname)?;
+    let res =
+        link_with_options(&txt, &rt, &o_dir, &exe_fname, cli.keep_temps.is_some(), &options);
+    if cli.keep_temps.is_some() {
+        let temps = Temps::in_dir(&o_dir);
+        for file in [temps.asm, temps.obj, temps.lib] {
+            if file.exists() {
+                eprintln!("kept {}", file.display());
+            }
+        }
+    }
+    res.map_err(|e| e.to_string())?;
     if let Some(ref arg) = cli.execute {
-        run(&exe_fname, arg, &mut std::io::stdout())?;
+        run(&exe_fname, arg, &mut std::io::stdout()).map_err(|e| e.to_string())?;
     }
     Ok(())
 }
+
+/// Parse the argument to `main` for the AST interpreter, as the other
+/// interpreters and the runtime do.
+fn parse_arg(arg: &str) -> Result<interp::Value, String> {
+    interp::parse_arg::<String, String>(arg)
+        .map_err(|e| format!("Error interpreting program: {}", e))
+}
+
 fn main() {
     let cli = Cli::parse();
 
@@ -1,8 +1,9 @@
 //! The middle "end" of our compiler is the part that transforms our well-formed
 //! source-language abstract syntax tree (AST) into the intermediate representation
 
-use crate::ast::{self, *};
+use crate::ast::*;
 use crate::ssa::{self, *};
+use crate::types;
 use crate::{frontend::Resolver, identifiers::*};
 use im::HashMap;
 use std::collections::HashSet;
@@ -11,8 +12,24 @@ pub struct Lowerer {
     pub vars: IdGen<VarName>,
     pub funs: IdGen<FunName>,
     pub blocks: IdGen<BlockName>,
+    /// whether the program lowered so far calls `ERROR_FUN`
+    calls_error: bool,
+    /// the number of local functions lifted to the top level so far
+    lifted: usize,
+    /// where the operations lowered so far came from
+    locs: std::collections::HashMap<VarName, SrcLoc>,
+    /// where the function lifted to each block was declared
+    declared: std::collections::HashMap<BlockName, SrcLoc>,
+    /// the variables known to hold 0 or 1, if the program was typed
+    bools: Option<HashSet<VarName>>,
 }
 
+/// A `match` with at least this many distinct patterns, spread over a
+/// range at most twice as long, is lowered to a jump table. Any other is
+/// lowered to a chain of comparisons.
+const MIN_SWITCH_ARMS: usize = 3;
+const MAX_SWITCH_SPREAD: usize = 2;
+
 /// Indicates whether the expression being compiled is in a tail position.
 #[derive(Clone, Debug)]
 enum Continuation {
@@ -49,13 +66,6 @@ impl Env {
         );
     }
 
-    fn is_extern(&self, fun_name: &FunName) -> bool {
-        match self.funs.get(fun_name) {
-            Some(FunType::Extern) => true,
-            _ => false,
-        }
-    }
-
     fn get(&self, fun_name: &FunName) -> Option<&FunType> {
         self.funs.get(fun_name)
     }
@@ -82,7 +92,16 @@ impl Env {
 impl From<Resolver> for Lowerer {
     fn from(resolver: Resolver) -> Self {
         let Resolver { vars, funs, .. } = resolver;
-        Lowerer { vars, funs, blocks: IdGen::new() }
+        Lowerer {
+            vars,
+            funs,
+            blocks: IdGen::new(),
+            calls_error: false,
+            lifted: 0,
+            locs: std::collections::HashMap::new(),
+            declared: std::collections::HashMap::new(),
+            bools: None,
+        }
     }
 }
 
@@ -101,18 +120,79 @@ impl Continuation {
     }
 }
 
-/// OPTIONAL:
-/// Determine which functions should be lambda lifted.
-/// If you choose not to implement this, then lift *all* functions
-fn should_lift(prog: &BoundProg) -> HashSet<FunName> {
-    todo!("should_lift not implemented")
+/// How a surface primitive is lowered to SSA operations on the values of
+/// its arguments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PrimLowering {
+    /// the operation applied to the two arguments
+    Prim2(Prim2),
+    /// the operation applied to the argument and a constant
+    Prim2Const(Prim2, i64),
+    /// the operation applied to the two arguments coerced to 0 or 1
+    Logical(Prim2),
+    /// the argument coerced to 0 or 1, then flipped
+    Not,
+    /// the second comparison subtracted from the first, giving -1, 0 or 1
+    Cmp(Prim2, Prim2),
+    /// a call to `ERROR_FUN` with the argument
+    Error,
+}
+
+impl Prim {
+    /// How this primitive is lowered, apart from the blocks the lowering
+    /// is built into.
+    pub fn to_ssa(&self) -> PrimLowering {
+        match self {
+            Prim::Add1 => PrimLowering::Prim2Const(Prim2::Add, 1),
+            Prim::Sub1 => PrimLowering::Prim2Const(Prim2::Sub, 1),
+            Prim::Add => PrimLowering::Prim2(Prim2::Add),
+            Prim::Sub => PrimLowering::Prim2(Prim2::Sub),
+            Prim::Mul => PrimLowering::Prim2(Prim2::Mul),
+            Prim::Not => PrimLowering::Not,
+            Prim::And => PrimLowering::Logical(Prim2::BitAnd),
+            Prim::Or => PrimLowering::Logical(Prim2::BitOr),
+            Prim::Lt => PrimLowering::Prim2(Prim2::Lt),
+            Prim::Le => PrimLowering::Prim2(Prim2::Le),
+            Prim::Gt => PrimLowering::Prim2(Prim2::Gt),
+            Prim::Ge => PrimLowering::Prim2(Prim2::Ge),
+            Prim::Eq => PrimLowering::Prim2(Prim2::Eq),
+            Prim::Neq => PrimLowering::Prim2(Prim2::Neq),
+            Prim::Cmp => PrimLowering::Cmp(Prim2::Gt, Prim2::Lt),
+            Prim::Error => PrimLowering::Error,
+        }
+    }
 }
 
 impl Lowerer {
+    /// The number of local functions lifted to the top level so far.
+    pub fn lifted_funs(&self) -> usize {
+        self.lifted
+    }
+
+    /// Mint block names with `blocks`, as `Resolver::with_ids` does for
+    /// the other names.
+    pub fn with_block_ids(mut self, blocks: IdGen<BlockName>) -> Self {
+        self.blocks = blocks;
+        self
+    }
+
+    /// Skip coercing the operands of `&&`, `||` and `!` to 0 or 1 when
+    /// they are boolean according to `bools`, the result of
+    /// `types::bool_vars` on the program to lower.
+    pub fn with_bool_types(mut self, bools: HashSet<VarName>) -> Self {
+        self.bools = Some(bools);
+        self
+    }
+
+    /// Lower `prog` to SSA. The function and block of `main` come first,
+    /// followed by those of the lifted functions in the order they are
+    /// declared in the source. So a function comes before the functions
+    /// declared in its body, and all of them before the functions declared
+    /// in the body of the `FunDefs` it belongs to.
     pub fn lower_prog(&mut self, prog: BoundProg) -> Program {
         let mut env = Env::new();
 
-        let externs = prog
+        let mut externs: Vec<_> = prog
             .externs
             .iter()
             .map(|ext| {
@@ -145,8 +225,18 @@ impl Lowerer {
         let mut funs = vec![main_fun_block];
         let mut blocks = vec![];
 
+        // The top-level functions are lowered as if main declared them
+        // first. The resolver made sure they do not use its parameter.
+        let body = match prog.funs.is_empty() {
+            true => prog.body,
+            false => Expr::FunDefs {
+                decls: prog.funs,
+                body: Box::new(prog.body),
+                loc: prog.loc,
+            },
+        };
         let main_block_body = self.lower_expr_kont(
-            prog.body,
+            body,
             Continuation::Return,
             &mut env,
             &mut funs,
@@ -159,13 +249,49 @@ impl Lowerer {
         };
         blocks.push(main_basic_block);
 
-        Program { externs, funs, blocks }
+        let error_fun = FunName::unmangled(ERROR_FUN);
+        if self.calls_error
+            && externs.iter().all(|ext| ext.name != error_fun)
+        {
+            externs.push(Extern {
+                name: error_fun,
+                params: vec![self.vars.fresh("code")],
+            });
+        }
+
+        // The lowering visits expressions in no particular order, so the
+        // lifted functions are put in source order after the fact.
+        let declared = std::mem::take(&mut self.declared);
+        let order =
+            |label: &BlockName| declared.get(label).map(|l| l.start_ix);
+        funs.sort_by_key(|fun| order(&fun.body.target));
+        blocks.sort_by_key(|block| order(&block.label));
+
+        let locs = SrcLocs(std::mem::take(&mut self.locs));
+        Program { externs, funs, blocks, locs }
+    }
+
+    /// Record that the operations at the start of `body`, up to the one
+    /// defining `last`, come from `loc`.
+    fn locate(&mut self, mut body: &BlockBody, last: &VarName, loc: SrcLoc) {
+        while let BlockBody::Operation { dest, next, .. } = body {
+            self.locs.insert(dest.clone(), loc);
+            if dest == last {
+                return;
+            }
+            body = next;
+        }
     }
 
     fn lower_expr_kont(
         &mut self, expr: BoundExpr, k: Continuation, env: &mut Env,
         funs: &mut Vec<FunBlock>, blocks: &mut Vec<BasicBlock>,
     ) -> BlockBody {
+        // the destination holds the value of this expression
+        let loc = expr.loc();
+        if let Continuation::Block(dest, _) = &k {
+            self.locs.insert(dest.clone(), loc);
+        }
         match expr {
             Expr::Num(n, _) => k.invoke(Immediate::Const(n)),
             Expr::Bool(b, _) => {
@@ -193,12 +319,23 @@ impl Lowerer {
                         (res.clone(), k.invoke(Immediate::Var(res)))
                     }
                 };
+                let last = dest.clone();
+
+                // Which arguments are already 0 or 1, and need no coercion
+                let known_bools: Vec<_> = args
+                    .iter()
+                    .map(|arg| match &self.bools {
+                        Some(bools) => types::is_bool(arg, bools),
+                        None => false,
+                    })
+                    .collect();
 
                 // Helper functions for different categories of Prim. Each
                 // helper handles that type of function and returns the
                 // BlockBody corresponding to that operation.
 
-                // prim1 handles Add1 and Sub1 operations
+                // prim1 handles operations with a constant operand, such
+                // as Add1 and Sub1
                 let prim1 = |prim: ssa::Prim2, imm: Immediate, next| {
                     let dest = dest.clone();
                     let op =
@@ -222,57 +359,60 @@ impl Lowerer {
                 let mut prim2_logical = |prim: ssa::Prim2, next| {
                     let dest = dest.clone();
 
-                    // Create the VarNames and corresponding Immediates
-                    // for the type-converted versions of the arguments
-                    let (type_checked_args, type_checked_imms): (
-                        Vec<_>,
-                        Vec<_>,
-                    ) = args
+                    // Create the VarNames for the type-converted versions
+                    // of the arguments not known to be 0 or 1, and the
+                    // Immediates the operation is applied to
+                    let mut coercions = Vec::new();
+                    let type_checked_imms: Vec<_> = args_imm
                         .iter()
-                        .enumerate()
-                        .map(|(i, _)| {
+                        .zip(&known_bools)
+                        .map(|(imm, &known)| {
+                            if known {
+                                return imm.clone();
+                            }
                             let var = self.vars.fresh("itob_res");
-                            (var.clone(), Immediate::Var(var))
+                            coercions.push((var.clone(), imm.clone()));
+                            Immediate::Var(var)
                         })
                         .collect();
 
-                    BlockBody::Operation {
-                        dest: type_checked_args[0].clone(),
-                        op: Operation::Prim1(
-                            Prim1::IntToBool,
-                            args_imm[0].clone(),
+                    let op = BlockBody::Operation {
+                        dest,
+                        op: Operation::Prim2(
+                            prim,
+                            type_checked_imms[0].clone(),
+                            type_checked_imms[1].clone(),
                         ),
-                        next: Box::new(BlockBody::Operation {
-                            dest: type_checked_args[1].clone(),
-                            op: Operation::Prim1(
-                                Prim1::IntToBool,
-                                args_imm[1].clone(),
-                            ),
-                            next: Box::new(BlockBody::Operation {
-                                dest,
-                                op: Operation::Prim2(
-                                    prim,
-                                    type_checked_imms[0].clone(),
-                                    type_checked_imms[1].clone(),
-                                ),
-                                next: Box::new(next),
-                            }),
-                        }),
-                    }
+                        next: Box::new(next),
+                    };
+                    coercions.into_iter().rev().fold(op, |next, (var, imm)| {
+                        BlockBody::Operation {
+                            dest: var,
+                            op: Operation::Prim1(Prim1::IntToBool, imm),
+                            next: Box::new(next),
+                        }
+                    })
                 };
 
                 // Create the BlockBody for the final operation
-                let block = match prim {
-                    Prim::Add1 => {
-                        prim1(Prim2::Add, Immediate::Const(1), next)
+                let block = match prim.to_ssa() {
+                    PrimLowering::Prim2Const(prim, n) => {
+                        prim1(prim, Immediate::Const(n), next)
                     }
-                    Prim::Sub1 => {
-                        prim1(Prim2::Sub, Immediate::Const(1), next)
+                    PrimLowering::Prim2(prim) => prim2(prim, next),
+                    PrimLowering::Logical(prim) => prim2_logical(prim, next),
+                    PrimLowering::Not if known_bools[0] => {
+                        BlockBody::Operation {
+                            dest,
+                            op: Operation::Prim2(
+                                Prim2::BitXor,
+                                args_imm[0].clone(),
+                                Immediate::Const(1),
+                            ),
+                            next: Box::new(next),
+                        }
                     }
-                    Prim::Add => prim2(Prim2::Add, next),
-                    Prim::Sub => prim2(Prim2::Sub, next),
-                    Prim::Mul => prim2(Prim2::Mul, next),
-                    Prim::Not => {
+                    PrimLowering::Not => {
                         let tmp = self.vars.fresh("itob_res");
                         BlockBody::Operation {
                             dest: tmp.clone(),
@@ -291,16 +431,57 @@ impl Lowerer {
                             }),
                         }
                     }
-                    Prim::And => prim2_logical(Prim2::BitAnd, next),
-                    Prim::Or => prim2_logical(Prim2::BitOr, next),
-                    Prim::Lt => prim2(Prim2::Lt, next),
-                    Prim::Le => prim2(Prim2::Le, next),
-                    Prim::Gt => prim2(Prim2::Gt, next),
-                    Prim::Ge => prim2(Prim2::Ge, next),
-                    Prim::Eq => prim2(Prim2::Eq, next),
-                    Prim::Neq => prim2(Prim2::Neq, next),
+                    PrimLowering::Cmp(above, below) => {
+                        let mut compare = |prim, name| {
+                            let var = self.vars.fresh(name);
+                            let op = Operation::Prim2(
+                                prim,
+                                args_imm[0].clone(),
+                                args_imm[1].clone(),
+                            );
+                            (var, op)
+                        };
+                        let (gt, gt_op) = compare(above, "cmp_gt");
+                        let (lt, lt_op) = compare(below, "cmp_lt");
+                        BlockBody::Operation {
+                            dest: gt.clone(),
+                            op: gt_op,
+                            next: Box::new(BlockBody::Operation {
+                                dest: lt.clone(),
+                                op: lt_op,
+                                next: Box::new(BlockBody::Operation {
+                                    dest,
+                                    op: Operation::Prim2(
+                                        Prim2::Sub,
+                                        Immediate::Var(gt),
+                                        Immediate::Var(lt),
+                                    ),
+                                    next: Box::new(next),
+                                }),
+                            }),
+                        }
+                    }
+                    // the call never returns, so whatever would use its
+                    // result is dropped
+                    PrimLowering::Error => {
+                        self.calls_error = true;
+                        BlockBody::Operation {
+                            dest,
+                            op: Operation::Call {
+                                fun: FunName::unmangled(ERROR_FUN),
+                                args: vec![args_imm[0].clone()],
+                            },
+                            next: Box::new(BlockBody::Terminator(
+                                Terminator::Unreachable,
+                            )),
+                        }
+                    }
                 };
 
+                // the temporaries computing the result come from this
+                // primitive too
+                self.locate(&block, &last, loc);
+
                 // Use fold() to build up the surrounding expression
                 // evaluations over the current block.
                 args.into_iter().zip(args_var).rev().fold(
@@ -317,7 +498,10 @@ impl Lowerer {
                 )
             }
 
-            Expr::Let { bindings, body, loc } => {
+            // Resolved names are unique, so a parallel `let` can be
+            // lowered like a sequential one. The resolver rejects
+            // `letrec`.
+            Expr::Let { bindings, body, .. } => {
                 // The binding variables will be in scope when evaluating the body
                 for Binding { var, .. } in &bindings {
                     env.locals.push(var.0.clone());
@@ -337,7 +521,7 @@ impl Lowerer {
                 })
             }
 
-            Expr::If { cond, thn, els, loc } => {
+            Expr::If { cond, thn, els, loc: _ } => {
                 let cond_var = self.vars.fresh("cond");
                 let thn_label = self.blocks.fresh("thn");
                 let els_label = self.blocks.fresh("els");
@@ -440,9 +624,175 @@ impl Lowerer {
                     }
                 }
             }
-            Expr::FunDefs { decls, body, loc } => {
+            Expr::Match { scrutinee, arms, default, loc } => {
+                let default = *default.expect(
+                    "the resolver rejects a match without a default",
+                );
+                // only the first arm with a pattern can be taken
+                let mut seen = HashSet::new();
+                let arms: Vec<_> = arms
+                    .into_iter()
+                    .filter(|arm| seen.insert(arm.pattern.0))
+                    .collect();
+                // the smallest pattern and how many values the table covers
+                let min = arms.iter().map(|arm| arm.pattern.0).min();
+                let max = arms.iter().map(|arm| arm.pattern.0).max();
+                let table = match (min, max) {
+                    (Some(min), Some(max))
+                        if arms.len() >= MIN_SWITCH_ARMS
+                            && max.abs_diff(min)
+                                < (MAX_SWITCH_SPREAD * arms.len())
+                                    as u64 =>
+                    {
+                        Some((min, max.abs_diff(min) as usize + 1))
+                    }
+                    _ => None,
+                };
+                if let Some((min, spread)) = table {
+                    // Branch through a table indexed by the scrutinee
+                    // minus the smallest pattern.
+                    let scrutinee_var = self.vars.fresh("scrutinee");
+                    let index_var = self.vars.fresh("index");
+                    let default_label = self.blocks.fresh("default");
+                    let arm_labels: Vec<_> = arms
+                        .iter()
+                        .map(|_| self.blocks.fresh("arm"))
+                        .collect();
+                    let mut targets = vec![default_label.clone(); spread];
+                    for (arm, label) in arms.iter().zip(&arm_labels) {
+                        targets[arm.pattern.0.abs_diff(min) as usize] =
+                            label.clone();
+                    }
+                    let switch = BlockBody::Terminator(Terminator::Switch {
+                        scrutinee: Immediate::Var(index_var.clone()),
+                        targets,
+                        default: default_label.clone(),
+                    });
+                    self.locs.insert(index_var.clone(), loc);
+                    let index = BlockBody::Operation {
+                        dest: index_var,
+                        op: Operation::Prim2(
+                            Prim2::Sub,
+                            Immediate::Var(scrutinee_var.clone()),
+                            Immediate::Const(min),
+                        ),
+                        next: Box::new(switch),
+                    };
+                    let scrutinee_branch = Box::new(self.lower_expr_kont(
+                        *scrutinee,
+                        Continuation::Block(scrutinee_var, index),
+                        env,
+                        funs,
+                        blocks,
+                    ));
+
+                    let branches = arm_labels
+                        .into_iter()
+                        .zip(arms.into_iter().map(|arm| arm.body))
+                        .chain([(default_label, default)]);
+                    return match k {
+                        Continuation::Return => BlockBody::SubBlocks {
+                            blocks: branches
+                                .map(|(label, body)| BasicBlock {
+                                    label,
+                                    params: Vec::new(),
+                                    body: self.lower_expr_kont(
+                                        body,
+                                        Continuation::Return,
+                                        env,
+                                        funs,
+                                        blocks,
+                                    ),
+                                })
+                                .collect(),
+                            next: scrutinee_branch,
+                        },
+                        // as for `if`, the arms meet at a join point
+                        Continuation::Block(dest, body) => {
+                            let join_label = self.blocks.fresh("jn");
+                            let mut arm_blocks: Vec<_> = branches
+                                .map(|(label, expr)| {
+                                    let var = self.vars.fresh("arm_res");
+                                    let to_join = BlockBody::Terminator(
+                                        Terminator::Branch(Branch {
+                                            target: join_label.clone(),
+                                            args: vec![Immediate::Var(
+                                                var.clone(),
+                                            )],
+                                        }),
+                                    );
+                                    BasicBlock {
+                                        label,
+                                        params: Vec::new(),
+                                        body: self.lower_expr_kont(
+                                            expr,
+                                            Continuation::Block(
+                                                var, to_join,
+                                            ),
+                                            env,
+                                            funs,
+                                            blocks,
+                                        ),
+                                    }
+                                })
+                                .collect();
+                            arm_blocks.push(BasicBlock {
+                                label: join_label,
+                                params: vec![dest],
+                                body,
+                            });
+                            BlockBody::SubBlocks {
+                                blocks: arm_blocks,
+                                next: scrutinee_branch,
+                            }
+                        }
+                    };
+                }
+
+                // let scrutinee = ... in
+                // if scrutinee == n1: e1 else: if ... else: default
+                let var = self.vars.fresh("scrutinee");
+                let scrutinee_loc = scrutinee.loc();
+                let body =
+                    arms.into_iter().rev().fold(default, |els, arm| {
+                        let (n, pattern_loc) = arm.pattern;
+                        Expr::If {
+                            cond: Box::new(Expr::Prim {
+                                prim: Prim::Eq,
+                                args: vec![
+                                    Expr::Var(var.clone(), scrutinee_loc),
+                                    Expr::Num(n, pattern_loc),
+                                ],
+                                loc: pattern_loc,
+                            }),
+                            thn: Box::new(arm.body),
+                            els: Box::new(els),
+                            loc,
+                        }
+                    });
+                let chain = Expr::Let {
+                    bindings: vec![Binding {
+                        var: (var, scrutinee_loc),
+                        expr: *scrutinee,
+                    }],
+                    body: Box::new(body),
+                    kind: LetKind::Sequential,
+                    loc,
+                };
+                self.lower_expr_kont(chain, k, env, funs, blocks)
+            }
+            Expr::FunDefs { mut decls, mut body, loc: _ } => {
                 // We are not using SubBlocks because we are lambda-lifting every function.
 
+                // Sibling groups may call each other, and they capture the
+                // same locals, so they are lifted together.
+                while let Expr::FunDefs { decls: more, body: next, .. } =
+                    *body
+                {
+                    decls.extend(more);
+                    body = next;
+                }
+
                 // To lift, first add every function to the environment
                 for decl in &decls {
                     let block_name = self
@@ -466,12 +816,14 @@ impl Lowerer {
                     let label = env
                         .get_block_name(&decl.name)
                         .expect("function should be local");
+                    self.declared.insert(label.clone(), decl.loc);
                     let basic_block = BasicBlock {
                         label: label.clone(),
                         params: basic_block_params.clone(),
                         body: {
+                            // the captured variables are locals already
                             let mut env = env.clone();
-                            for param in &basic_block_params {
+                            for param in &params {
                                 env.locals.push(param.clone());
                             }
                             self.lower_expr_kont(
@@ -497,9 +849,11 @@ impl Lowerer {
                         .map(|var| (var.clone(), Immediate::Var(var)))
                         .unzip();
 
-                    let name = self.funs.fresh(decl.name.hint());
+                    // Calls that are not in tail position call the lifted
+                    // function by the name the resolver gave it.
+                    self.lifted += 1;
                     let fun_block = FunBlock {
-                        name,
+                        name: decl.name,
                         params,
                         body: Branch { target: label.clone(), args },
                     };
@@ -528,12 +882,22 @@ impl Lowerer {
                     )
                 }) {
                     FunType::Extern => {
-                        let res =
-                            self.vars.fresh(format!("{}_res", fun.hint()));
+                        // bind the result directly to the destination, so
+                        // that the backend can tell when it is never used
+                        let (dest, next) = match k {
+                            Continuation::Block(dest, next) => (dest, next),
+                            Continuation::Return => {
+                                let res = self
+                                    .vars
+                                    .fresh(format!("{}_res", fun.hint()));
+                                self.locs.insert(res.clone(), loc);
+                                (res.clone(), k.invoke(Immediate::Var(res)))
+                            }
+                        };
                         BlockBody::Operation {
-                            dest: res.clone(),
+                            dest,
                             op: Operation::Call { fun, args: args_imm },
-                            next: Box::new(k.invoke(Immediate::Var(res))),
+                            next: Box::new(next),
                         }
                     }
                     FunType::Local { captured, block_name } => {
@@ -1,8 +1,8 @@
 // auto-generated: "lalrpop 0.22.1"
-// sha3: cf96f7af4053d752f65d9ab8893d0ad7139af48d86f3dd4893c32e3ec1d0484c
+// sha3: 6864be901a1bf1c98d1989d48d03ed1e3dee95b6c3e900cdc0e7273c72d6b85e
 use crate::ast::{
-    Binding, Expr, ExtDecl, FunDecl, Prim, Prog, SurfBinding, SurfExpr, SurfExtDecl, SurfFunDecl,
-    SurfProg,
+    Binding, Expr, ExtDecl, FunDecl, LetKind, MatchArm, Prim, Prog, SurfBinding, SurfExpr,
+    SurfExtDecl, SurfFunDecl, SurfMatchArm, SurfProg,
 };
 use crate::span::SrcLoc;
 use lalrpop_util::ParseError;
@@ -20,8 +20,8 @@ mod __parse__Expr {
 
     use std::str::FromStr;
     use crate::ast::{
-    SurfProg, SurfExpr, SurfBinding, SurfFunDecl, SurfExtDecl,
-    Prog, Expr, Binding, FunDecl, ExtDecl, Prim,
+    SurfProg, SurfExpr, SurfBinding, SurfFunDecl, SurfExtDecl, SurfMatchArm,
+    Prog, Expr, Binding, FunDecl, ExtDecl, MatchArm, Prim, LetKind,
 };
     use crate::span::SrcLoc;
     use lalrpop_util::ParseError;
@@ -36,255 +36,337 @@ mod __parse__Expr {
     pub(crate) enum __Symbol<'input>
      {
         Variant0(&'input str),
-        Variant1(SurfBinding),
-        Variant2(alloc::vec::Vec<SurfBinding>),
-        Variant3(SurfExpr),
-        Variant4(alloc::vec::Vec<SurfExpr>),
-        Variant5(SurfFunDecl),
-        Variant6(alloc::vec::Vec<SurfFunDecl>),
-        Variant7((String, SrcLoc)),
-        Variant8(alloc::vec::Vec<(String, SrcLoc)>),
-        Variant9(usize),
-        Variant10(Vec<SurfBinding>),
-        Variant11(bool),
-        Variant12(Box<SurfExpr>),
-        Variant13(Prim),
-        Variant14(Vec<SurfExpr>),
-        Variant15(Vec<(String, SrcLoc)>),
-        Variant16(Option<SurfExpr>),
-        Variant17(SurfExtDecl),
-        Variant18(alloc::vec::Vec<SurfExtDecl>),
-        Variant19(String),
-        Variant20(i64),
-        Variant21(SurfProg),
-        Variant22(Option<(String, SrcLoc)>),
-    }
-    const __ACTION: &[i8] = &[
+        Variant1(((Option<i64>, SrcLoc), SurfExpr)),
+        Variant2(alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>),
+        Variant3(SurfBinding),
+        Variant4(alloc::vec::Vec<SurfBinding>),
+        Variant5(SurfExpr),
+        Variant6(alloc::vec::Vec<SurfExpr>),
+        Variant7(SurfFunDecl),
+        Variant8(alloc::vec::Vec<SurfFunDecl>),
+        Variant9((String, SrcLoc)),
+        Variant10(alloc::vec::Vec<(String, SrcLoc)>),
+        Variant11(usize),
+        Variant12(Option<((Option<i64>, SrcLoc), SurfExpr)>),
+        Variant13(Vec<SurfBinding>),
+        Variant14(bool),
+        Variant15(Box<SurfExpr>),
+        Variant16(Prim),
+        Variant17(Vec<((Option<i64>, SrcLoc), SurfExpr)>),
+        Variant18(Vec<SurfExpr>),
+        Variant19(Vec<(String, SrcLoc)>),
+        Variant20(Option<SurfExpr>),
+        Variant21(SurfExtDecl),
+        Variant22(alloc::vec::Vec<SurfExtDecl>),
+        Variant23(String),
+        Variant24(i64),
+        Variant25(Option<i64>),
+        Variant26(SurfProg),
+        Variant27(Option<(String, SrcLoc)>),
+        Variant28((Option<i64>, SrcLoc)),
+        Variant29((SrcLoc, SrcLoc, SurfFunDecl)),
+        Variant30(alloc::vec::Vec<(SrcLoc, SrcLoc, SurfFunDecl)>),
+    }
+    const __ACTION: &[i16] = &[
         // State 0
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 0, 53, 0, -89, 0, 0, -89, 0, -89, 0, 0, 0, 0, 0, 0, 0, -89, 0, -89, 0, 0, 0, -89, 0, 0, 0, 54,
+        0, 0, 0, 0, 67, 0, -114, 0, 0, -114, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0, -114, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, -114, 68, -114,
         // State 3
-        0, 0, 0, 56, -42, 0, -42, 0, 0, -42, 0, -42, 57, 58, 0, 59, 60, 61, 0, -42, 0, -42, 0, 0, 0, -42, 0, 0, 0, -42,
+        0, 0, 0, 70, -55, 0, -55, 0, 0, -55, 0, -55, 71, 72, 0, 73, 0, 74, 75, 0, -55, 0, 0, -55, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, -55, -55, -55,
         // State 4
-        0, 0, 0, -93, -93, 0, -93, 0, 62, -93, 63, -93, -93, -93, 0, -93, -93, -93, 0, -93, 0, -93, 0, 0, 0, -93, 0, 0, 0, -93,
+        0, 0, 0, -119, -119, 0, -119, 0, 76, -119, 77, -119, -119, -119, 0, -119, 0, -119, -119, 0, -119, 0, 0, -119, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, -119, -119, -119,
         // State 5
-        0, 0, 0, -85, -85, 0, -85, 64, -85, -85, -85, -85, -85, -85, 0, -85, -85, -85, 0, -85, 0, -85, 0, 0, 0, -85, 0, 0, 0, -85,
+        0, 0, 0, -110, -110, 0, -110, 78, -110, -110, -110, -110, -110, -110, 0, -110, 0, -110, -110, 0, -110, 0, 0, -110, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, -110, -110, -110,
         // State 6
-        50, 51, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 47, 0, 0, 0, 48, 49, 0,
+        64, 65, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 0, 0, 60, 0, 61, 0, 0, 0, 0, 13, 62, 63, 0, 0, 0,
         // State 7
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 8
-        0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 10
-        0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 47, 0, 0, 0, 48, 49, 0,
+        0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 12
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 13
-        50, 51, 7, 0, 0, 8, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 0, 0, 60, 0, 61, 0, 0, 0, 0, 13, 62, 63, 0, 0, 0,
         // State 14
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 47, 0, 0, 0, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 15
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 47, 0, 0, 0, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 16
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 47, 0, 0, 0, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 0, 0, 60, 0, 61, 0, 0, 0, 0, 13, 62, 63, 0, 0, 0,
         // State 17
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 0, 0, 60, 0, 61, 0, 0, 0, 0, 13, 62, 63, 0, 0, 0,
         // State 18
-        0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 0, 0, 60, 0, 61, 0, 0, 0, 0, 13, 62, 63, 0, 0, 0,
         // State 19
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 20
-        50, 51, 7, 0, 0, 8, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 21
-        0, 51, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 23
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 24
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 25
-        0, 51, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 65, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 27
-        50, 51, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 0, 47, 10, 0, 11, 48, 49, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 28
-        0, 0, 0, -79, -79, 0, -79, -79, -79, -79, -79, -79, -79, -79, 0, -79, -79, -79, 0, -79, 0, -79, 0, 0, 0, -79, 0, 0, 0, -79,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 29
-        0, 0, 0, 0, 0, 0, -54, 0, 0, -54, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, 0, -54, 0, 0, 0, -54, 0, 0, 0, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 30
-        0, 0, 0, -25, -25, 0, -25, -25, -25, -25, -25, -25, -25, -25, 0, -25, -25, -25, 0, -25, 0, -25, 0, 0, 0, -25, 0, 0, 0, -25,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 31
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        64, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57,
         // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 33
-        0, 0, 0, 0, 0, 0, -53, 0, 0, -53, 0, -53, 0, 0, 0, 0, 0, 0, 0, -53, 0, -53, 0, 0, 0, -53, 0, 0, 0, 0,
+        0, 65, 0, 0, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 34
-        0, 0, 0, -23, -23, 14, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, 0, -23, 0, -23, 0, 0, 0, -23, 0, 0, 0, -23,
+        64, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59,
         // State 35
-        0, 0, 0, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, 0, -65, 0, -65, 0, 0, 0, -65, 0, 0, 0, -65,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 36
-        0, 0, 0, 0, 0, 0, -52, 0, 0, -52, 0, -52, 0, 0, 0, 0, 0, 0, 0, -52, 0, -52, 0, 0, 0, -52, 0, 0, 0, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 37
-        0, 0, 0, 0, 0, 0, -51, 0, 0, -51, 0, -51, 0, 0, 0, 0, 0, 0, 0, -51, 0, -51, 0, 0, 0, -51, 0, 0, 0, 0,
+        64, 65, 7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 59, 9, 0, 60, 0, 61, 10, 0, 11, 12, 13, 62, 63, 0, 0, 0,
         // State 38
-        0, 0, 0, 0, 0, 0, -29, 0, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, -29, 0, -29, 0, 0, 0, -29, 0, 0, 0, 0,
+        0, 0, 0, -99, -99, 0, -99, -99, -99, -99, -99, -99, -99, -99, 0, -99, 0, -99, -99, 0, -99, 0, 0, -99, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, -99, -99, -99,
         // State 39
-        0, 0, 0, -73, -73, 0, -73, -73, -73, -73, -73, -73, -73, -73, 0, -73, -73, -73, 0, -73, 0, -73, 0, 0, 0, -73, 0, 0, 0, -73,
+        0, 0, 0, 0, 0, 0, -71, 0, 0, -71, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, -71, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, -71, 0, -71,
         // State 40
-        0, 0, 0, -24, -24, 0, -24, -24, -24, -24, -24, -24, -24, -24, 0, -24, -24, -24, 0, -24, 0, -24, 0, 0, 0, -24, 0, 0, 0, -24,
+        0, 0, 0, -36, -36, 0, -36, -36, -36, -36, -36, -36, -36, -36, 0, -36, 0, -36, -36, 0, -36, 0, 0, -36, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, -36, -36, -36,
         // State 41
-        0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 42
-        0, 0, 0, -71, -71, 0, -71, 0, -71, -71, -71, -71, -71, -71, 0, -71, -71, -71, 0, -71, 0, -71, 0, 0, 0, -71, 0, 0, 0, -71,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 43
-        0, 0, 0, 0, 0, 0, -77, 0, 0, -77, 0, -77, 0, 0, 0, 0, 0, 0, 0, -77, 0, -77, 0, 0, 0, -77, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -70, 0, 0, -70, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, -70, 0, -70,
         // State 44
-        0, 0, 0, -69, -69, 0, -69, 0, 0, -69, 0, -69, -69, -69, 0, -69, -69, -69, 0, -69, 0, -69, 0, 0, 0, -69, 0, 0, 0, -69,
+        0, 0, 0, -34, -34, 16, -34, -34, -34, -34, -34, -34, -34, -34, 0, -34, 0, -34, -34, 0, -34, 0, 0, -34, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, -34, -34, -34,
         // State 45
-        0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, 0, -82, -82, 0, -82, 0, 0, -82, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, -82, -82, -82,
         // State 46
-        0, 0, 0, -34, -34, 0, -34, -34, -34, -34, -34, -34, -34, -34, 0, -34, -34, -34, 0, -34, 0, -34, 0, 0, 0, -34, 0, 0, 0, -34,
+        0, 0, 0, 0, 0, 0, -69, 0, 0, -69, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, 0, -69, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, -69, 0, -69,
         // State 47
-        0, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -68, 0, 0, -68, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, 0, -68, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, -68, 0, -68,
         // State 48
-        0, 0, 0, -33, -33, 0, -33, -33, -33, -33, -33, -33, -33, -33, 0, -33, -33, -33, 0, -33, 0, -33, 0, 0, 0, -33, 0, 0, 0, -33,
+        0, 0, 0, 0, 0, 0, -42, 0, 0, -42, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, -42, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, -42, 0, -42,
         // State 49
-        0, 0, 0, -80, -80, 0, -80, -80, -80, -80, -80, -80, -80, -80, 0, -80, -80, -80, 0, -80, 0, -80, 0, 0, 0, -80, 0, 0, 0, -80,
+        0, 0, 0, -40, -40, 0, -40, -40, -40, -40, -40, -40, -40, -40, 0, -40, 0, -40, -40, 0, -40, 0, 0, -40, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, -40, -40, -40,
         // State 50
-        0, 0, 0, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, 0, -66, 0, -66, 0, 0, 0, -66, 0, 0, 0, -66,
+        0, 0, 0, -90, -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, 0, -90, 0, -90, -90, 0, -90, 0, 0, -90, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, -90, -90, -90,
         // State 51
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0,
+        0, 0, 0, -35, -35, 0, -35, -35, -35, -35, -35, -35, -35, -35, 0, -35, 0, -35, -35, 0, -35, 0, 0, -35, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, -35, -35, -35,
         // State 52
-        -75, -75, -75, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, -75, 0, 0, 0, -75, -75, 0,
+        0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 53
-        -76, -76, -76, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, -76, 0, 0, 0, -76, -76, 0,
+        0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -88, -88, 0, -88, 0, -88, -88, -88, -88, -88, -88, 0, -88, 0, -88, -88, 0, -88, 0, 0, -88, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, -88, -88, -88,
         // State 55
-        -41, -41, -41, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, -41, 0, 0, 0, -41, -41, 0,
+        0, 0, 0, 0, 0, 0, -96, 0, 0, -96, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, -96, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, -96, 0, -96,
         // State 56
-        -36, -36, -36, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, -36, 0, 0, 0, -36, -36, 0,
+        0, 0, 0, -86, -86, 0, -86, 0, 0, -86, 0, -86, -86, -86, 0, -86, 0, -86, -86, 0, -86, 0, 0, -86, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, -86, -86, -86,
         // State 57
-        -37, -37, -37, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, -37, 0, 0, 0, -37, -37, 0,
+        0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 58
-        -40, -40, -40, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, -40, 0, 0, 0, -40, -40, 0,
+        0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 59
-        -38, -38, -38, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, -38, 0, 0, 0, -38, -38, 0,
+        0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 60
-        -39, -39, -39, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, -39, 0, 0, 0, -39, -39, 0,
+        0, 0, 0, -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, 0, -47, 0, -47, -47, 0, -47, 0, 0, -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, -47, -47, -47,
         // State 61
-        -81, -81, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0, -81, 0, 0, 0, -81, -81, 0,
+        0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 62
-        -82, -82, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, -82, 0, 0, 0, -82, -82, 0,
+        0, 0, 0, -46, -46, 0, -46, -46, -46, -46, -46, -46, -46, -46, 0, -46, 0, -46, -46, 0, -46, 0, 0, -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, -46, -46, -46,
         // State 63
-        -94, -94, -94, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0, -94, 0, 0, 0, -94, -94, 0,
+        0, 0, 0, -100, -100, 0, -100, -100, -100, -100, -100, -100, -100, -100, 0, -100, -100, -100, -100, 0, -100, 0, 0, -100, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, -100, -100, -100,
         // State 64
-        0, 0, 0, -78, -78, 0, -78, -78, -78, -78, -78, -78, -78, -78, 0, -78, -78, -78, 0, -78, 0, -78, 0, 0, 0, -78, 0, 0, 0, -78,
+        0, 0, 0, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, 0, -83, 0, 0, -83, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, -83, -83, -83,
         // State 65
-        0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 66
-        0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -94, -94, -94, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, -94, 0, 0, -94, 0, -94, 0, 0, 0, 0, -94, -94, -94, 0, 0, 0,
         // State 67
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -95, -95, -95, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, -95, 0, 0, -95, 0, -95, 0, 0, 0, 0, -95, -95, -95, 0, 0, 0,
         // State 68
-        0, 0, 0, 0, 0, 0, -35, 0, 0, -35, 0, -35, 0, 0, 0, 0, 0, 0, 0, -35, 0, -35, 0, 0, 0, -35, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 69
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0,
+        -54, -54, -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, -54, 0, 0, -54, 0, -54, 0, 0, 0, 0, -54, -54, -54, 0, 0, 0,
         // State 70
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0,
+        -49, -49, -49, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, -49, 0, 0, -49, 0, -49, 0, 0, 0, 0, -49, -49, -49, 0, 0, 0,
         // State 71
-        0, 0, 0, 0, 0, 0, -90, 0, 0, -90, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -50, -50, -50, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, -50, 0, 0, -50, 0, -50, 0, 0, 0, 0, -50, -50, -50, 0, 0, 0,
         // State 72
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -53, -53, -53, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, -53, 0, 0, -53, 0, -53, 0, 0, 0, 0, -53, -53, -53, 0, 0, 0,
         // State 73
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -51, -51, -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, -51, 0, 0, -51, 0, -51, 0, 0, 0, 0, -51, -51, -51, 0, 0, 0,
         // State 74
-        0, 0, 0, 0, 0, 0, -88, 0, 0, -88, 0, -88, 0, 0, 0, 0, 0, 0, 0, -88, 0, -88, 0, 0, 0, -88, 0, 0, 0, 0,
+        -52, -52, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, -52, 0, 0, -52, 0, -52, 0, 0, 0, 0, -52, -52, -52, 0, 0, 0,
         // State 75
-        0, 0, 0, 0, 0, 0, -63, 0, 0, -63, 0, -63, 0, 0, 0, 0, 0, 0, 0, -63, 0, -63, 0, 0, 0, -63, 0, 0, 0, 0,
+        -104, -104, -104, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, -104, 0, 0, -104, 0, -104, 0, 0, 0, 0, -104, -104, -104, 0, 0, 0,
         // State 76
-        0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -105, -105, -105, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, -105, 0, 0, -105, 0, -105, 0, 0, 0, 0, -105, -105, -105, 0, 0, 0,
         // State 77
-        0, 0, 0, 0, 0, 0, -43, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -120, -120, -120, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, -120, 0, 0, -120, 0, -120, 0, 0, 0, 0, -120, -120, -120, 0, 0, 0,
         // State 78
-        0, 0, 0, -68, -68, 0, -68, 0, 0, -68, 0, -68, -68, -68, 0, -68, -68, -68, 0, -68, 0, -68, 0, 0, 0, -68, 0, 0, 0, -68,
+        0, 0, 0, -98, -98, 0, -98, -98, -98, -98, -98, -98, -98, -98, 0, -98, 0, -98, -98, 0, -98, 0, 0, -98, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, -98, -98, -98,
         // State 79
-        0, 0, 0, -70, -70, 0, -70, 0, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, -70, 0, -70, 0, 0, 0, -70, 0, 0, 0, -70,
+        0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 80
-        0, 0, 0, -72, -72, 0, -72, -72, -72, -72, -72, -72, -72, -72, 0, -72, -72, -72, 0, -72, 0, -72, 0, 0, 0, -72, 0, 0, 0, -72,
+        0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 81
-        0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 82
-        0, 0, 0, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, 0, -28, 0, -28, 0, 0, 0, -28, 0, 0, 0, -28,
+        0, 0, 0, 0, 0, 0, -48, 0, 0, -48, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, -48, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, -48, 0, -48,
         // State 83
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 84
-        0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 85
-        0, 0, 0, 0, 0, 0, -64, 0, 0, -64, 0, -64, 0, 0, 0, 0, 0, 0, 0, -64, 0, -64, 0, 0, 0, -64, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -115, 0, 0, -115, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 86
-        0, 0, 0, 0, 0, 0, -45, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 87
-        0, 0, 0, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, 0, -27, 0, -27, 0, 0, 0, -27, 0, 0, 0, -27,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 88
-        -9, -9, -9, 0, 0, -9, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, -9, 0, 0, -9, -9, 0, -9, -9, -9, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 89
-        0, 0, 0, -26, -26, 0, -26, -26, -26, -26, -26, -26, -26, -26, 0, -26, -26, -26, 0, -26, 0, -26, 0, 0, 0, -26, 0, 0, 0, -26,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 90
-        0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0,
         // State 91
-        0, 0, 0, 0, 0, 0, -47, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 92
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -113, 0, 0, -113, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, 0, -113, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, -113, 0, -113,
         // State 93
-        0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -80, 0, 0, -80, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, -80, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, -80, 0, -80,
         // State 94
-        0, 0, 0, 0, 0, 0, -74, 0, 0, -74, 0, -74, 0, 0, 0, 0, 0, 0, 0, -74, 0, -74, 0, 0, 0, -74, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 95
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -60, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 96
-        -10, -10, -10, 0, 0, -10, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, -10, 0, 0, -10, -10, 0, -10, -10, -10, 0,
+        0, 0, 0, -85, -85, 0, -85, 0, 0, -85, 0, -85, -85, -85, 0, -85, 0, -85, -85, 0, -85, 0, 0, -85, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, -85, -85, -85,
         // State 97
-        0, 0, 0, 0, 0, 0, -49, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -87, -87, 0, -87, 0, -87, -87, -87, -87, -87, -87, 0, -87, 0, -87, -87, 0, -87, 0, 0, -87, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, -87, -87, -87,
         // State 98
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -89, -89, 0, -89, -89, -89, -89, -89, -89, -89, -89, 0, -89, 0, -89, -89, 0, -89, 0, 0, -89, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, -89, -89, -89,
         // State 99
-        0, -19, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 100
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 101
-        0, -20, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -41, -41, 0, -41, -41, -41, -41, -41, -41, -41, -41, 0, -41, 0, -41, -41, 0, -41, 0, 0, -41, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, -41, -41, -41,
         // State 102
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 103
-        0, 0, 0, 0, 0, 0, -67, 0, 0, -67, 0, -67, 0, 0, 0, 0, 0, 0, 0, -67, 0, -67, 0, 0, 0, -67, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 104
+        0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 106
+        0, 0, 0, 0, 0, 0, -81, 0, 0, -81, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, -81, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, -81, 0, -81,
+        // State 107
+        0, 0, 0, 0, 0, 0, -62, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 108
+        0, 0, 0, -39, -39, 0, -39, -39, -39, -39, -39, -39, -39, -39, 0, -39, 0, -39, -39, 0, -39, 0, 0, -39, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, -39, -39, -39,
+        // State 109
+        -17, -17, -17, 0, 0, -17, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, -17, -17, 0, -17, 0, -17, -17, 0, -17, -17, -17, -17, -17, 0, 0, 0,
+        // State 110
+        0, 0, 0, -37, -37, 0, -37, -37, -37, -37, -37, -37, -37, -37, 0, -37, 0, -37, -37, 0, -37, 0, 0, -37, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, -37, -37, -37,
+        // State 111
+        0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 112
+        0, 0, 0, 0, 0, 0, -64, 0, 0, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 113
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 114
+        0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 115
+        0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 116
+        0, 0, 0, 0, 0, 0, -91, 0, 0, -91, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, -91, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, -91, 0, -91,
+        // State 117
+        0, 0, 0, 0, 0, 0, -92, 0, 0, -92, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, -92, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, -92, 0, -92,
+        // State 118
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 119
+        0, 0, 0, 0, 0, 0, -93, 0, 0, -93, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, -93, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, -93, 0, -93,
+        // State 120
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56,
+        // State 121
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135,
+        // State 122
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 124
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 125
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 126
+        -18, -18, -18, 0, 0, -18, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, -18, -18, 0, -18, 0, -18, -18, 0, -18, -18, -18, -18, -18, 0, 0, 0,
+        // State 127
+        0, 0, 0, 0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        0, 0, 0, 0, 0, 0, -66, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 129
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 130
+        0, -27, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 131
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58,
+        // State 133
+        -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4,
+        // State 134
+        0, 0, 0, -97, -97, 0, -97, -97, -97, -97, -97, -97, -97, -97, 0, -97, 0, -97, -97, 0, -97, 0, 0, -97, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, -97, -97, -97,
+        // State 135
+        0, 0, 0, -38, -38, 0, -38, -38, -38, -38, -38, -38, -38, -38, 0, -38, 0, -38, -38, 0, -38, 0, 0, -38, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, -38, -38, -38,
+        // State 136
+        0, -28, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 137
+        -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5,
+        // State 138
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31,
+        // State 139
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, -84, 0, 0, -84, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, -84, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, -84, 0, -84,
     ];
-    fn __action(state: i8, integer: usize) -> i8 {
-        __ACTION[(state as usize) * 30 + integer]
+    fn __action(state: i16, integer: usize) -> i16 {
+        __ACTION[(state as usize) * 37 + integer]
     }
-    const __EOF_ACTION: &[i8] = &[
+    const __EOF_ACTION: &[i16] = &[
         // State 0
         0,
         // State 1
         0,
         // State 2
-        -89,
+        -114,
         // State 3
-        -42,
+        -55,
         // State 4
-        -93,
+        -119,
         // State 5
-        -85,
+        -110,
         // State 6
         0,
         // State 7
@@ -330,63 +412,63 @@ mod __parse__Expr {
         // State 27
         0,
         // State 28
-        -79,
+        0,
         // State 29
-        -54,
+        0,
         // State 30
-        -25,
+        0,
         // State 31
-        -95,
+        0,
         // State 32
         0,
         // State 33
-        -53,
+        0,
         // State 34
-        -23,
+        0,
         // State 35
-        -65,
+        0,
         // State 36
-        -52,
+        0,
         // State 37
-        -51,
+        0,
         // State 38
-        -29,
+        -99,
         // State 39
-        -73,
+        -71,
         // State 40
-        -24,
+        -36,
         // State 41
-        0,
+        -124,
         // State 42
-        -71,
+        0,
         // State 43
-        -77,
+        -70,
         // State 44
-        -69,
+        -34,
         // State 45
-        0,
+        -82,
         // State 46
-        -34,
+        -69,
         // State 47
-        0,
+        -68,
         // State 48
-        -33,
+        -42,
         // State 49
-        -80,
+        -40,
         // State 50
-        -66,
+        -90,
         // State 51
-        0,
+        -35,
         // State 52
         0,
         // State 53
         0,
         // State 54
-        0,
+        -88,
         // State 55
-        0,
+        -96,
         // State 56
-        0,
+        -86,
         // State 57
         0,
         // State 58
@@ -394,15 +476,15 @@ mod __parse__Expr {
         // State 59
         0,
         // State 60
-        0,
+        -47,
         // State 61
         0,
         // State 62
-        0,
+        -46,
         // State 63
-        0,
+        -100,
         // State 64
-        -78,
+        -83,
         // State 65
         0,
         // State 66
@@ -410,7 +492,7 @@ mod __parse__Expr {
         // State 67
         0,
         // State 68
-        -35,
+        0,
         // State 69
         0,
         // State 70
@@ -422,148 +504,251 @@ mod __parse__Expr {
         // State 73
         0,
         // State 74
-        -88,
+        0,
         // State 75
-        -63,
+        0,
         // State 76
         0,
         // State 77
         0,
         // State 78
-        -68,
+        -98,
         // State 79
-        -70,
+        0,
         // State 80
-        -72,
+        0,
         // State 81
         0,
         // State 82
-        -28,
+        -48,
         // State 83
         0,
         // State 84
         0,
         // State 85
-        -64,
+        0,
         // State 86
         0,
         // State 87
-        -27,
+        0,
         // State 88
         0,
         // State 89
-        -26,
+        0,
         // State 90
         0,
         // State 91
         0,
         // State 92
-        0,
+        -113,
         // State 93
-        0,
+        -80,
         // State 94
-        -74,
+        0,
         // State 95
         0,
         // State 96
-        0,
+        -85,
         // State 97
-        0,
+        -87,
         // State 98
-        0,
+        -89,
         // State 99
         0,
         // State 100
         0,
         // State 101
-        0,
+        -41,
         // State 102
         0,
         // State 103
-        -67,
+        0,
+        // State 104
+        0,
+        // State 105
+        0,
+        // State 106
+        -81,
+        // State 107
+        0,
+        // State 108
+        -39,
+        // State 109
+        0,
+        // State 110
+        -37,
+        // State 111
+        0,
+        // State 112
+        0,
+        // State 113
+        0,
+        // State 114
+        0,
+        // State 115
+        0,
+        // State 116
+        -91,
+        // State 117
+        -92,
+        // State 118
+        0,
+        // State 119
+        -93,
+        // State 120
+        0,
+        // State 121
+        0,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        0,
+        // State 125
+        0,
+        // State 126
+        0,
+        // State 127
+        0,
+        // State 128
+        0,
+        // State 129
+        0,
+        // State 130
+        0,
+        // State 131
+        0,
+        // State 132
+        0,
+        // State 133
+        0,
+        // State 134
+        -97,
+        // State 135
+        -38,
+        // State 136
+        0,
+        // State 137
+        0,
+        // State 138
+        0,
+        // State 139
+        0,
+        // State 140
+        -84,
     ];
-    fn __goto(state: i8, nt: usize) -> i8 {
+    fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 18,
-            5 => 20,
-            8 => 1,
-            11 => 25,
-            14 => match state {
-                6 => 64,
-                _ => 28,
+            2 => 34,
+            5 => 21,
+            7 => 22,
+            10 => 24,
+            13 => 1,
+            16 => 33,
+            19 => match state {
+                34 => 132,
+                _ => 120,
             },
-            15 => 29,
-            16 => match state {
-                18 => 83,
-                _ => 69,
+            21 => match state {
+                6 => 78,
+                _ => 38,
             },
-            17 => 70,
-            18 => 30,
-            19 => match state {
-                12 => 75,
-                19 => 85,
-                22 => 92,
-                23 => 94,
-                27 => 103,
-                _ => 67,
+            22 => 39,
+            23 => match state {
+                11 => 88,
+                21 => 102,
+                22 => 103,
+                _ => 83,
             },
-            20 => 14,
-            21 => 2,
-            22 => 76,
-            23 => 90,
             24 => match state {
-                0 => 31,
-                7 => 65,
-                13 => 77,
-                17 => 81,
-                20 => 86,
-                24 => 95,
-                26 => 102,
-                _ => 68,
+                11 => 89,
+                _ => 84,
             },
-            29 => match state {
-                1 => 51,
-                _ => 32,
-            },
-            30 => 33,
-            31 => match state {
-                8 => 66,
-                10 | 18 | 21 | 25 => 71,
-                _ => 34,
+            25 => 40,
+            26 => match state {
+                12 => 90,
+                14 => 93,
+                23 => 106,
+                26 => 113,
+                27 => 116,
+                28 => 117,
+                30 => 119,
+                37 => 140,
+                _ => 81,
             },
-            32 => 35,
-            33 => 36,
-            34 => 3,
-            35 => 4,
-            36 => 5,
-            37 => 37,
-            38 => 11,
-            39 => 38,
-            40 => match state {
-                16 => 80,
-                _ => 39,
+            27 => 16,
+            28 => 2,
+            29 => 121,
+            30 => 94,
+            31 => 111,
+            32 => match state {
+                0 => 41,
+                7 => 79,
+                15 => 95,
+                19 => 99,
+                20 => 100,
+                24 => 107,
+                29 => 118,
+                32 => 127,
+                35 => 138,
+                36 => 139,
+                _ => 82,
             },
-            41 => 40,
-            42 => 15,
-            43 => 41,
-            44 => match state {
-                15 => 79,
+            37 => match state {
+                1 => 65,
                 _ => 42,
             },
-            46 => match state {
-                11 => 74,
-                _ => 43,
+            38 => 43,
+            39 => match state {
+                8 => 80,
+                10..=11 | 21..=22 | 25 | 33 => 85,
+                _ => 44,
             },
-            47 => match state {
-                21 => 91,
-                25 => 97,
-                _ => 72,
+            40 => match state {
+                31 | 34 => 122,
+                _ => 45,
             },
+            41 => 46,
+            42 => 3,
+            43 => 4,
+            44 => 5,
+            45 => 47,
+            46 => 13,
+            47 => 48,
+            48 => 49,
             49 => match state {
-                14 => 78,
-                _ => 44,
+                18 => 98,
+                _ => 50,
+            },
+            50 => match state {
+                31 | 34 => 123,
+                _ => 51,
+            },
+            51 => 86,
+            52 => 124,
+            53 => 17,
+            54 => 52,
+            55 => 53,
+            56 => match state {
+                17 => 97,
+                _ => 54,
+            },
+            58 => match state {
+                13 => 92,
+                _ => 55,
+            },
+            59 => match state {
+                25 => 112,
+                33 => 128,
+                _ => 87,
+            },
+            61 => 125,
+            62 => match state {
+                16 => 96,
+                _ => 56,
             },
-            50 => 16,
+            63 => 18,
             _ => 0,
         }
     }
@@ -585,22 +770,29 @@ mod __parse__Expr {
         r###""<=""###,
         r###""=""###,
         r###""==""###,
+        r###""=>""###,
         r###"">""###,
         r###"">=""###,
         r###""add1""###,
         r###""and""###,
+        r###""cmp""###,
         r###""def""###,
         r###""else""###,
+        r###""error""###,
         r###""extern""###,
         r###""false""###,
         r###""if""###,
         r###""in""###,
         r###""let""###,
+        r###""letrec""###,
+        r###""match""###,
         r###""sub1""###,
         r###""true""###,
+        r###""{""###,
         r###""||""###,
+        r###""}""###,
     ];
-    fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
+    fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
             let next_state = __action(__state, index);
             if next_state == 0 {
@@ -613,7 +805,7 @@ mod __parse__Expr {
     fn __expected_tokens_from_states<
         'input,
     >(
-        __states: &[i8],
+        __states: &[i16],
         _: core::marker::PhantomData<(&'input ())>,
     ) -> alloc::vec::Vec<alloc::string::String>
     {
@@ -640,9 +832,9 @@ mod __parse__Expr {
         type TokenIndex = usize;
         type Symbol = __Symbol<'input>;
         type Success = SurfExpr;
-        type StateIndex = i8;
-        type Action = i8;
-        type ReduceIndex = i8;
+        type StateIndex = i16;
+        type Action = i16;
+        type ReduceIndex = i16;
         type NonterminalIndex = usize;
 
         #[inline]
@@ -661,22 +853,22 @@ mod __parse__Expr {
         }
 
         #[inline]
-        fn action(&self, state: i8, integer: usize) -> i8 {
+        fn action(&self, state: i16, integer: usize) -> i16 {
             __action(state, integer)
         }
 
         #[inline]
-        fn error_action(&self, state: i8) -> i8 {
-            __action(state, 30 - 1)
+        fn error_action(&self, state: i16) -> i16 {
+            __action(state, 37 - 1)
         }
 
         #[inline]
-        fn eof_action(&self, state: i8) -> i8 {
+        fn eof_action(&self, state: i16) -> i16 {
             __EOF_ACTION[state as usize]
         }
 
         #[inline]
-        fn goto(&self, state: i8, nt: usize) -> i8 {
+        fn goto(&self, state: i16, nt: usize) -> i16 {
             __goto(state, nt)
         }
 
@@ -684,11 +876,11 @@ mod __parse__Expr {
             __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
         }
 
-        fn expected_tokens(&self, state: i8) -> alloc::vec::Vec<alloc::string::String> {
+        fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
             __expected_tokens(state)
         }
 
-        fn expected_tokens_from_states(&self, states: &[i8]) -> alloc::vec::Vec<alloc::string::String> {
+        fn expected_tokens_from_states(&self, states: &[i16]) -> alloc::vec::Vec<alloc::string::String> {
             __expected_tokens_from_states(states, core::marker::PhantomData::<(&())>)
         }
 
@@ -707,9 +899,9 @@ mod __parse__Expr {
 
         fn reduce(
             &mut self,
-            action: i8,
+            action: i16,
             start_location: Option<&Self::Location>,
-            states: &mut alloc::vec::Vec<i8>,
+            states: &mut alloc::vec::Vec<i16>,
             symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
         ) -> Option<__state_machine::ParseResult<Self>> {
             __reduce(
@@ -722,7 +914,7 @@ mod __parse__Expr {
             )
         }
 
-        fn simulate_reduce(&self, action: i8) -> __state_machine::SimulatedReduce<Self> {
+        fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
             __simulate_reduce(action, core::marker::PhantomData::<(&())>)
         }
     }
@@ -765,6 +957,13 @@ mod __parse__Expr {
             Token(27, _) if true => Some(27),
             Token(28, _) if true => Some(28),
             Token(29, _) if true => Some(29),
+            Token(30, _) if true => Some(30),
+            Token(31, _) if true => Some(31),
+            Token(32, _) if true => Some(32),
+            Token(33, _) if true => Some(33),
+            Token(34, _) if true => Some(34),
+            Token(35, _) if true => Some(35),
+            Token(36, _) if true => Some(36),
             _ => None,
         }
     }
@@ -777,8 +976,8 @@ mod __parse__Expr {
     ) -> __Symbol<'input>
     {
         #[allow(clippy::manual_range_patterns)]match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 => match __token {
-                Token(0, __tok0) | Token(1, __tok0) | Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) if true => __Symbol::Variant0(__tok0),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 => match __token {
+                Token(0, __tok0) | Token(1, __tok0) | Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) if true => __Symbol::Variant0(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -787,7 +986,7 @@ mod __parse__Expr {
     fn __simulate_reduce<
         'input,
     >(
-        __reduce_index: i8,
+        __reduce_index: i16,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> __state_machine::SimulatedReduce<__StateMachine<'input>>
     {
@@ -860,13 +1059,13 @@ mod __parse__Expr {
             }
             11 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 7,
                 }
             }
             12 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 7,
                 }
             }
@@ -878,25 +1077,25 @@ mod __parse__Expr {
             }
             14 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 8,
+                    states_to_pop: 0,
+                    nonterminal_produced: 9,
                 }
             }
             15 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 1,
                     nonterminal_produced: 9,
                 }
             }
             16 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 10,
                 }
             }
             17 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 10,
                 }
             }
@@ -908,513 +1107,687 @@ mod __parse__Expr {
             }
             19 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 11,
+                    states_to_pop: 0,
+                    nonterminal_produced: 12,
                 }
             }
             20 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 1,
                     nonterminal_produced: 12,
                 }
             }
             21 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 13,
                 }
             }
             22 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
                 }
             }
             23 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 14,
                 }
             }
             24 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    states_to_pop: 0,
+                    nonterminal_produced: 15,
                 }
             }
             25 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 14,
+                    states_to_pop: 1,
+                    nonterminal_produced: 15,
                 }
             }
             26 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 14,
+                    states_to_pop: 2,
+                    nonterminal_produced: 16,
                 }
             }
             27 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 14,
+                    nonterminal_produced: 16,
                 }
             }
             28 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 0,
+                    nonterminal_produced: 17,
                 }
             }
             29 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 16,
+                    states_to_pop: 0,
+                    nonterminal_produced: 18,
                 }
             }
             30 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 3,
+                    nonterminal_produced: 19,
                 }
             }
             31 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 17,
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
                 }
             }
             32 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 0,
+                    nonterminal_produced: 20,
                 }
             }
             33 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 21,
                 }
             }
             34 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    nonterminal_produced: 21,
                 }
             }
             35 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 21,
                 }
             }
             36 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    states_to_pop: 4,
+                    nonterminal_produced: 21,
                 }
             }
             37 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    states_to_pop: 6,
+                    nonterminal_produced: 21,
                 }
             }
             38 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    states_to_pop: 4,
+                    nonterminal_produced: 21,
                 }
             }
             39 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 21,
                 }
             }
             40 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    states_to_pop: 3,
+                    nonterminal_produced: 21,
                 }
             }
             41 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    nonterminal_produced: 22,
                 }
             }
             42 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    states_to_pop: 3,
+                    nonterminal_produced: 23,
                 }
             }
             43 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 22,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             44 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 24,
                 }
             }
             45 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 25,
                 }
             }
             46 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 25,
                 }
             }
             47 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 23,
+                    states_to_pop: 1,
+                    nonterminal_produced: 26,
                 }
             }
             48 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 23,
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
                 }
             }
             49 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 27,
                 }
             }
             50 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    nonterminal_produced: 27,
                 }
             }
             51 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    nonterminal_produced: 27,
                 }
             }
             52 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    nonterminal_produced: 27,
                 }
             }
             53 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    nonterminal_produced: 27,
                 }
             }
             54 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 25,
+                    nonterminal_produced: 28,
                 }
             }
             55 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 25,
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
                 }
             }
             56 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 26,
+                    states_to_pop: 0,
+                    nonterminal_produced: 29,
                 }
             }
             57 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 27,
+                    states_to_pop: 2,
+                    nonterminal_produced: 29,
                 }
             }
             58 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 27,
+                    nonterminal_produced: 29,
                 }
             }
             59 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 28,
+                    nonterminal_produced: 30,
                 }
             }
             60 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 28,
+                    states_to_pop: 0,
+                    nonterminal_produced: 30,
                 }
             }
             61 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 29,
+                    states_to_pop: 2,
+                    nonterminal_produced: 30,
                 }
             }
             62 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 1,
                     nonterminal_produced: 30,
                 }
             }
             63 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 30,
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
                 }
             }
             64 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 31,
                 }
             }
             65 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 32,
+                    states_to_pop: 2,
+                    nonterminal_produced: 31,
                 }
             }
             66 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 33,
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
                 }
             }
             67 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 34,
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
                 }
             }
             68 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 34,
+                    nonterminal_produced: 32,
                 }
             }
             69 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 35,
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
                 }
             }
             70 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 35,
+                    nonterminal_produced: 32,
                 }
             }
             71 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 36,
+                    states_to_pop: 1,
+                    nonterminal_produced: 33,
                 }
             }
             72 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 36,
+                    states_to_pop: 0,
+                    nonterminal_produced: 33,
                 }
             }
             73 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 37,
+                    states_to_pop: 5,
+                    nonterminal_produced: 34,
                 }
             }
             74 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    states_to_pop: 0,
+                    nonterminal_produced: 35,
                 }
             }
             75 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    nonterminal_produced: 35,
                 }
             }
             76 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 39,
+                    nonterminal_produced: 36,
                 }
             }
             77 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 40,
+                    nonterminal_produced: 36,
                 }
             }
             78 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 40,
+                    states_to_pop: 7,
+                    nonterminal_produced: 37,
                 }
             }
             79 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 41,
+                    states_to_pop: 3,
+                    nonterminal_produced: 38,
                 }
             }
             80 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 42,
+                    states_to_pop: 4,
+                    nonterminal_produced: 38,
                 }
             }
             81 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 42,
+                    nonterminal_produced: 39,
                 }
             }
             82 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 43,
+                    nonterminal_produced: 40,
                 }
             }
             83 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 41,
+                }
+            }
+            84 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 42,
+                }
+            }
+            85 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
+                    nonterminal_produced: 42,
+                }
+            }
+            86 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
                     nonterminal_produced: 43,
                 }
             }
-            84 => {
+            87 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
+                    nonterminal_produced: 43,
+                }
+            }
+            88 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
                     nonterminal_produced: 44,
                 }
             }
-            85 => {
+            89 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
+                    states_to_pop: 1,
+                    nonterminal_produced: 44,
+                }
+            }
+            90 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
                     nonterminal_produced: 45,
                 }
             }
-            86 => {
+            91 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 8,
+                    states_to_pop: 4,
                     nonterminal_produced: 45,
                 }
             }
-            87 => {
+            92 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 4,
+                    nonterminal_produced: 45,
+                }
+            }
+            93 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
                     nonterminal_produced: 46,
                 }
             }
-            88 => {
+            94 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
                     nonterminal_produced: 46,
                 }
             }
-            89 => {
+            95 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
                     nonterminal_produced: 47,
                 }
             }
-            90 => {
+            96 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 5,
                     nonterminal_produced: 48,
                 }
             }
-            91 => {
+            97 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 48,
+                    states_to_pop: 2,
+                    nonterminal_produced: 49,
                 }
             }
-            92 => {
+            98 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
                     nonterminal_produced: 49,
                 }
             }
-            93 => {
+            99 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
                     nonterminal_produced: 50,
                 }
             }
-            94 => __state_machine::SimulatedReduce::Accept,
-            95 => {
+            100 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 51,
+                }
+            }
+            101 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
                     nonterminal_produced: 52,
                 }
             }
-            96 => {
+            102 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            103 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
                     nonterminal_produced: 53,
                 }
             }
-            _ => panic!("invalid reduction index {}", __reduce_index)
-        }
-    }
-    pub struct ExprParser {
-        builder: __lalrpop_util::lexer::MatcherBuilder,
-        _priv: (),
-    }
-
-    impl Default for ExprParser { fn default() -> Self { Self::new() } }
-    impl ExprParser {
-        pub fn new() -> ExprParser {
-            let __builder = super::__intern_token::new_builder();
-            ExprParser {
-                builder: __builder,
-                _priv: (),
+            104 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 53,
+                }
             }
-        }
-
-        #[allow(dead_code)]
-        pub fn parse<
-            'input,
-        >(
-            &self,
-            input: &'input str,
-        ) -> Result<SurfExpr, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
-        {
-            let mut __tokens = self.builder.matcher(input);
-            __state_machine::Parser::drive(
-                __StateMachine {
-                    input,
-                    __phantom: core::marker::PhantomData::<(&())>,
-                },
-                __tokens,
-            )
-        }
-    }
-    fn __accepts<
-        'input,
-    >(
-        __error_state: Option<i8>,
-        __states: &[i8],
-        __opt_integer: Option<usize>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> bool
-    {
-        let mut __states = __states.to_vec();
+            105 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            106 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            107 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            108 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 55,
+                }
+            }
+            109 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 56,
+                }
+            }
+            110 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
+            }
+            111 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 57,
+                }
+            }
+            112 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 58,
+                }
+            }
+            113 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 58,
+                }
+            }
+            114 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
+                }
+            }
+            115 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 60,
+                }
+            }
+            116 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 60,
+                }
+            }
+            117 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 61,
+                }
+            }
+            118 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
+            }
+            119 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 63,
+                }
+            }
+            120 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 64,
+                }
+            }
+            121 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 65,
+                }
+            }
+            122 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 65,
+                }
+            }
+            123 => __state_machine::SimulatedReduce::Accept,
+            124 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 67,
+                }
+            }
+            125 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 68,
+                }
+            }
+            _ => panic!("invalid reduction index {}", __reduce_index)
+        }
+    }
+    pub struct ExprParser {
+        builder: __lalrpop_util::lexer::MatcherBuilder,
+        _priv: (),
+    }
+
+    impl Default for ExprParser { fn default() -> Self { Self::new() } }
+    impl ExprParser {
+        pub fn new() -> ExprParser {
+            let __builder = super::__intern_token::new_builder();
+            ExprParser {
+                builder: __builder,
+                _priv: (),
+            }
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'input,
+        >(
+            &self,
+            input: &'input str,
+        ) -> Result<SurfExpr, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+        {
+            let mut __tokens = self.builder.matcher(input);
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    input,
+                    __phantom: core::marker::PhantomData::<(&())>,
+                },
+                __tokens,
+            )
+        }
+    }
+    fn __accepts<
+        'input,
+    >(
+        __error_state: Option<i16>,
+        __states: &[i16],
+        __opt_integer: Option<usize>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
         __states.extend(__error_state);
         loop {
             let mut __states_len = __states.len();
@@ -1442,9 +1815,9 @@ mod __parse__Expr {
         'input,
     >(
         input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut alloc::vec::Vec<i8>,
+        __states: &mut alloc::vec::Vec<i16>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> Option<Result<SurfExpr,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
@@ -1619,21 +1992,7 @@ mod __parse__Expr {
                 __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             56 => {
-                // Extern = "extern", IdStr, "(", Comma<Spanned<Id>>, ")" => ActionFn(127);
-                assert!(__symbols.len() >= 5);
-                let __sym4 = __pop_Variant0(__symbols);
-                let __sym3 = __pop_Variant15(__symbols);
-                let __sym2 = __pop_Variant0(__symbols);
-                let __sym1 = __pop_Variant0(__symbols);
-                let __sym0 = __pop_Variant0(__symbols);
-                let __start = __sym0.0;
-                let __end = __sym4.2;
-                let __nt = match super::__action127::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4) {
-                    Ok(v) => v,
-                    Err(e) => return Some(Err(e)),
-                };
-                __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-                (5, 26)
+                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             57 => {
                 __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
@@ -1684,7 +2043,21 @@ mod __parse__Expr {
                 __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                // Extern = "extern", IdStr, "(", Comma<Spanned<Id>>, ")" => ActionFn(166);
+                assert!(__symbols.len() >= 5);
+                let __sym4 = __pop_Variant0(__symbols);
+                let __sym3 = __pop_Variant19(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant0(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym4.2;
+                let __nt = match super::__action166::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+                (5, 34)
             }
             74 => {
                 __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
@@ -1720,43 +2093,10 @@ mod __parse__Expr {
                 __reduce84(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             85 => {
-                // Prog = "def", IdStr, "(", Spanned<Id>, ")", ":", Expr => ActionFn(144);
-                assert!(__symbols.len() >= 7);
-                let __sym6 = __pop_Variant3(__symbols);
-                let __sym5 = __pop_Variant0(__symbols);
-                let __sym4 = __pop_Variant0(__symbols);
-                let __sym3 = __pop_Variant7(__symbols);
-                let __sym2 = __pop_Variant0(__symbols);
-                let __sym1 = __pop_Variant0(__symbols);
-                let __sym0 = __pop_Variant0(__symbols);
-                let __start = __sym0.0;
-                let __end = __sym6.2;
-                let __nt = match super::__action144::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6) {
-                    Ok(v) => v,
-                    Err(e) => return Some(Err(e)),
-                };
-                __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-                (7, 45)
+                __reduce85(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             86 => {
-                // Prog = Extern+, "def", IdStr, "(", Spanned<Id>, ")", ":", Expr => ActionFn(145);
-                assert!(__symbols.len() >= 8);
-                let __sym7 = __pop_Variant3(__symbols);
-                let __sym6 = __pop_Variant0(__symbols);
-                let __sym5 = __pop_Variant0(__symbols);
-                let __sym4 = __pop_Variant7(__symbols);
-                let __sym3 = __pop_Variant0(__symbols);
-                let __sym2 = __pop_Variant0(__symbols);
-                let __sym1 = __pop_Variant0(__symbols);
-                let __sym0 = __pop_Variant18(__symbols);
-                let __start = __sym0.0;
-                let __end = __sym7.2;
-                let __nt = match super::__action145::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7) {
-                    Ok(v) => v,
-                    Err(e) => return Some(Err(e)),
-                };
-                __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-                (8, 45)
+                __reduce86(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             87 => {
                 __reduce87(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
@@ -1780,18 +2120,148 @@ mod __parse__Expr {
                 __reduce93(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             94 => {
+                __reduce94(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            95 => {
+                __reduce95(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            96 => {
+                // Match = "match", Boxed<Expr>, "{", Comma<Arm>, "}" => ActionFn(177);
+                assert!(__symbols.len() >= 5);
+                let __sym4 = __pop_Variant0(__symbols);
+                let __sym3 = __pop_Variant17(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant15(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym4.2;
+                let __nt = match super::__action177::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+                (5, 48)
+            }
+            97 => {
+                __reduce97(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            98 => {
+                __reduce98(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            99 => {
+                __reduce99(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            100 => {
+                __reduce100(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            101 => {
+                __reduce101(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            102 => {
+                // Pattern = IdStr => ActionFn(179);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym0.2;
+                let __nt = match super::__action179::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+                (1, 52)
+            }
+            103 => {
+                __reduce103(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            104 => {
+                __reduce104(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            105 => {
+                __reduce105(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            106 => {
+                __reduce106(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            107 => {
+                __reduce107(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            108 => {
+                __reduce108(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            109 => {
+                __reduce109(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            110 => {
+                // Prog = TopFunDecl+ => ActionFn(192);
+                let __sym0 = __pop_Variant30(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym0.2;
+                let __nt = match super::__action192::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+                (1, 57)
+            }
+            111 => {
+                // Prog = Extern+, TopFunDecl+ => ActionFn(193);
+                assert!(__symbols.len() >= 2);
+                let __sym1 = __pop_Variant30(__symbols);
+                let __sym0 = __pop_Variant22(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym1.2;
+                let __nt = match super::__action193::<>(input, __sym0, __sym1) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+                (2, 57)
+            }
+            112 => {
+                __reduce112(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            113 => {
+                __reduce113(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            114 => {
+                __reduce114(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            115 => {
+                __reduce115(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            116 => {
+                __reduce116(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            117 => {
+                __reduce117(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            118 => {
+                __reduce118(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            119 => {
+                __reduce119(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            120 => {
+                __reduce120(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            121 => {
+                __reduce121(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            122 => {
+                __reduce122(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            123 => {
                 // __Expr = Expr => ActionFn(1);
-                let __sym0 = __pop_Variant3(__symbols);
+                let __sym0 = __pop_Variant5(__symbols);
                 let __start = __sym0.0;
                 let __end = __sym0.2;
                 let __nt = super::__action1::<>(input, __sym0);
                 return Some(Ok(__nt));
             }
-            95 => {
-                __reduce95(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            124 => {
+                __reduce124(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            96 => {
-                __reduce96(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            125 => {
+                __reduce125(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             _ => panic!("invalid action code {}", __action)
         };
@@ -1806,102 +2276,135 @@ mod __parse__Expr {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant7<
+    fn __pop_Variant1<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (String, SrcLoc), usize)
+    ) -> (usize, ((Option<i64>, SrcLoc), SurfExpr), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
+    fn __pop_Variant28<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<SurfExpr>, usize)
+    ) -> (usize, (Option<i64>, SrcLoc), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant22<
+    fn __pop_Variant29<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Option<(String, SrcLoc)>, usize)
+    ) -> (usize, (SrcLoc, SrcLoc, SurfFunDecl), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant16<
+    fn __pop_Variant9<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Option<SurfExpr>, usize)
+    ) -> (usize, (String, SrcLoc), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
+    fn __pop_Variant15<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Prim, usize)
+    ) -> (usize, Box<SurfExpr>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant19<
+    fn __pop_Variant12<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize)
+    ) -> (usize, Option<((Option<i64>, SrcLoc), SurfExpr)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant1<
+    fn __pop_Variant27<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfBinding, usize)
+    ) -> (usize, Option<(String, SrcLoc)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant3<
+    fn __pop_Variant20<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfExpr, usize)
+    ) -> (usize, Option<SurfExpr>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant17<
+    fn __pop_Variant25<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfExtDecl, usize)
+    ) -> (usize, Option<i64>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant16<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Prim, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant23<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfBinding, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -1909,7 +2412,7 @@ mod __parse__Expr {
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfFunDecl, usize)
+    ) -> (usize, SurfExpr, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
@@ -1920,131 +2423,186 @@ mod __parse__Expr {
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfProg, usize)
+    ) -> (usize, SurfExtDecl, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant15<
+    fn __pop_Variant7<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfFunDecl, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant26<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfProg, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant17<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant19<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<(String, SrcLoc)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant10<
+    fn __pop_Variant13<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<SurfBinding>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant14<
+    fn __pop_Variant18<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<SurfExpr>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant8<
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant30<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(SrcLoc, SrcLoc, SurfFunDecl)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<(String, SrcLoc)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant2<
+    fn __pop_Variant4<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<SurfBinding>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant4<
+    fn __pop_Variant6<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<SurfExpr>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant18<
+    fn __pop_Variant22<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<SurfExtDecl>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant6<
+    fn __pop_Variant8<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<SurfFunDecl>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant11<
+    fn __pop_Variant14<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, bool, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant20<
+    fn __pop_Variant24<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, i64, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant9<
+    fn __pop_Variant11<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, usize, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -2068,13 +2626,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",") = Binding, "," => ActionFn(61);
+        // (<Arm> ",") = Arm, "," => ActionFn(107);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action61::<>(input, __sym0, __sym1);
+        let __nt = super::__action107::<>(input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
         (2, 0)
     }
@@ -2087,10 +2645,10 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",")* =  => ActionFn(59);
+        // (<Arm> ",")* =  => ActionFn(105);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action59::<>(input, &__start, &__end);
+        let __nt = super::__action105::<>(input, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (0, 1)
     }
@@ -2103,11 +2661,11 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",")* = (<Binding> ",")+ => ActionFn(60);
+        // (<Arm> ",")* = (<Arm> ",")+ => ActionFn(106);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action60::<>(input, __sym0);
+        let __nt = super::__action106::<>(input, __sym0);
         __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (1, 1)
     }
@@ -2120,13 +2678,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",")+ = Binding, "," => ActionFn(88);
+        // (<Arm> ",")+ = Arm, "," => ActionFn(114);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action88::<>(input, __sym0, __sym1);
+        let __nt = super::__action114::<>(input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (2, 2)
     }
@@ -2139,14 +2697,14 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",")+ = (<Binding> ",")+, Binding, "," => ActionFn(89);
+        // (<Arm> ",")+ = (<Arm> ",")+, Arm, "," => ActionFn(115);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant1(__symbols);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action89::<>(input, __sym0, __sym1, __sym2);
+        let __nt = super::__action115::<>(input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (3, 2)
     }
@@ -2159,13 +2717,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",") = Expr, "," => ActionFn(83);
+        // (<Binding> ",") = Binding, "," => ActionFn(77);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action83::<>(input, __sym0, __sym1);
+        let __nt = super::__action77::<>(input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (2, 3)
     }
@@ -2178,10 +2736,10 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",")* =  => ActionFn(81);
+        // (<Binding> ",")* =  => ActionFn(75);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action81::<>(input, &__start, &__end);
+        let __nt = super::__action75::<>(input, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (0, 4)
     }
@@ -2194,11 +2752,11 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",")* = (<Expr> ",")+ => ActionFn(82);
+        // (<Binding> ",")* = (<Binding> ",")+ => ActionFn(76);
         let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action82::<>(input, __sym0);
+        let __nt = super::__action76::<>(input, __sym0);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (1, 4)
     }
@@ -2211,13 +2769,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",")+ = Expr, "," => ActionFn(92);
+        // (<Binding> ",")+ = Binding, "," => ActionFn(118);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action92::<>(input, __sym0, __sym1);
+        let __nt = super::__action118::<>(input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (2, 5)
     }
@@ -2230,14 +2788,14 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(93);
+        // (<Binding> ",")+ = (<Binding> ",")+, Binding, "," => ActionFn(119);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action93::<>(input, __sym0, __sym1, __sym2);
+        let __nt = super::__action119::<>(input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (3, 5)
     }
@@ -2250,14 +2808,14 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and") = FunDecl, "and" => ActionFn(58);
+        // (<Binding> "and") = Binding, "and" => ActionFn(74);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action58::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        let __nt = super::__action74::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
         (2, 6)
     }
     fn __reduce11<
@@ -2269,14 +2827,72 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and")* =  => ActionFn(56);
+        // (<Binding> "and")+ = Binding, "and" => ActionFn(122);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action122::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 7)
+    }
+    fn __reduce12<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> "and")+ = (<Binding> "and")+, Binding, "and" => ActionFn(123);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action123::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 7)
+    }
+    fn __reduce13<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expr> ",") = Expr, "," => ActionFn(102);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action102::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 8)
+    }
+    fn __reduce14<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expr> ",")* =  => ActionFn(100);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action56::<>(input, &__start, &__end);
+        let __nt = super::__action100::<>(input, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (0, 7)
+        (0, 9)
     }
-    fn __reduce12<
+    fn __reduce15<
         'input,
     >(
         input: &'input str,
@@ -2285,15 +2901,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and")* = (<FunDecl> "and")+ => ActionFn(57);
+        // (<Expr> ",")* = (<Expr> ",")+ => ActionFn(101);
         let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action57::<>(input, __sym0);
+        let __nt = super::__action101::<>(input, __sym0);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 7)
+        (1, 9)
     }
-    fn __reduce13<
+    fn __reduce16<
         'input,
     >(
         input: &'input str,
@@ -2302,17 +2918,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and")+ = FunDecl, "and" => ActionFn(96);
+        // (<Expr> ",")+ = Expr, "," => ActionFn(124);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action96::<>(input, __sym0, __sym1);
+        let __nt = super::__action124::<>(input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 8)
+        (2, 10)
     }
-    fn __reduce14<
+    fn __reduce17<
         'input,
     >(
         input: &'input str,
@@ -2321,18 +2937,18 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and")+ = (<FunDecl> "and")+, FunDecl, "and" => ActionFn(97);
+        // (<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(125);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant5(__symbols);
         let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action97::<>(input, __sym0, __sym1, __sym2);
+        let __nt = super::__action125::<>(input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 8)
+        (3, 10)
     }
-    fn __reduce15<
+    fn __reduce18<
         'input,
     >(
         input: &'input str,
@@ -2341,17 +2957,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",") = Spanned<Id>, "," => ActionFn(78);
+        // (<FunDecl> "and") = FunDecl, "and" => ActionFn(71);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action78::<>(input, __sym0, __sym1);
+        let __nt = super::__action71::<>(input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (2, 9)
+        (2, 11)
     }
-    fn __reduce16<
+    fn __reduce19<
         'input,
     >(
         input: &'input str,
@@ -2360,14 +2976,14 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",")* =  => ActionFn(76);
+        // (<FunDecl> "and")* =  => ActionFn(69);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action76::<>(input, &__start, &__end);
+        let __nt = super::__action69::<>(input, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (0, 10)
+        (0, 12)
     }
-    fn __reduce17<
+    fn __reduce20<
         'input,
     >(
         input: &'input str,
@@ -2376,15 +2992,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",")* = (<Spanned<Id>> ",")+ => ActionFn(77);
+        // (<FunDecl> "and")* = (<FunDecl> "and")+ => ActionFn(70);
         let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action77::<>(input, __sym0);
+        let __nt = super::__action70::<>(input, __sym0);
         __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 10)
+        (1, 12)
     }
-    fn __reduce18<
+    fn __reduce21<
         'input,
     >(
         input: &'input str,
@@ -2393,17 +3009,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",")+ = Spanned<Id>, "," => ActionFn(100);
+        // (<FunDecl> "and")+ = FunDecl, "and" => ActionFn(128);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action100::<>(input, __sym0, __sym1);
+        let __nt = super::__action128::<>(input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (2, 11)
+        (2, 13)
     }
-    fn __reduce19<
+    fn __reduce22<
         'input,
     >(
         input: &'input str,
@@ -2412,18 +3028,18 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",")+ = (<Spanned<Id>> ",")+, Spanned<Id>, "," => ActionFn(101);
+        // (<FunDecl> "and")+ = (<FunDecl> "and")+, FunDecl, "and" => ActionFn(129);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant7(__symbols);
         let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action101::<>(input, __sym0, __sym1, __sym2);
+        let __nt = super::__action129::<>(input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 11)
+        (3, 13)
     }
-    fn __reduce20<
+    fn __reduce23<
         'input,
     >(
         input: &'input str,
@@ -2432,14 +3048,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(65);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action65::<>(input, &__start, &__end);
+        // (<Spanned<Id>> ",") = Spanned<Id>, "," => ActionFn(93);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action93::<>(input, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (0, 12)
+        (2, 14)
     }
-    fn __reduce21<
+    fn __reduce24<
         'input,
     >(
         input: &'input str,
@@ -2448,14 +3067,14 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(63);
+        // (<Spanned<Id>> ",")* =  => ActionFn(91);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action63::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (0, 13)
+        let __nt = super::__action91::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 15)
     }
-    fn __reduce22<
+    fn __reduce25<
         'input,
     >(
         input: &'input str,
@@ -2464,15 +3083,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Id => ActionFn(122);
-        let __sym0 = __pop_Variant19(__symbols);
+        // (<Spanned<Id>> ",")* = (<Spanned<Id>> ",")+ => ActionFn(92);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action122::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        let __nt = super::__action92::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 15)
     }
-    fn __reduce23<
+    fn __reduce26<
         'input,
     >(
         input: &'input str,
@@ -2481,15 +3100,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Num => ActionFn(123);
-        let __sym0 = __pop_Variant20(__symbols);
+        // (<Spanned<Id>> ",")+ = Spanned<Id>, "," => ActionFn(132);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action123::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        let __end = __sym1.2;
+        let __nt = super::__action132::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 16)
     }
-    fn __reduce24<
+    fn __reduce27<
         'input,
     >(
         input: &'input str,
@@ -2498,15 +3119,18 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Bool => ActionFn(124);
-        let __sym0 = __pop_Variant11(__symbols);
+        // (<Spanned<Id>> ",")+ = (<Spanned<Id>> ",")+, Spanned<Id>, "," => ActionFn(133);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action124::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        let __end = __sym2.2;
+        let __nt = super::__action133::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 16)
     }
-    fn __reduce25<
+    fn __reduce28<
         'input,
     >(
         input: &'input str,
@@ -2515,60 +3139,14 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Prim1, "(", Expr, ")" => ActionFn(125);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action125::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 14)
-    }
-    fn __reduce26<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // BaseExpr = Id, "(", Comma<Expr>, ")" => ActionFn(126);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant14(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action126::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 14)
-    }
-    fn __reduce27<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // BaseExpr = "(", Expr, ")" => ActionFn(38);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action38::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 14)
+        // @L =  => ActionFn(82);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action82::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 17)
     }
-    fn __reduce28<
+    fn __reduce29<
         'input,
     >(
         input: &'input str,
@@ -2577,15 +3155,14 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BinOps = LogExpr => ActionFn(15);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 15)
+        // @R =  => ActionFn(79);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action79::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 18)
     }
-    fn __reduce29<
+    fn __reduce30<
         'input,
     >(
         input: &'input str,
@@ -2594,18 +3171,18 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Binding = Spanned<Id>, "=", Expr => ActionFn(10);
+        // Arm = Spanned<Pattern>, "=>", Expr => ActionFn(46);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant28(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action10::<>(input, __sym0, __sym1, __sym2);
+        let __nt = super::__action46::<>(input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 16)
+        (3, 19)
     }
-    fn __reduce30<
+    fn __reduce31<
         'input,
     >(
         input: &'input str,
@@ -2614,32 +3191,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bindings = Binding => ActionFn(90);
+        // Arm? = Arm => ActionFn(103);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action90::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 17)
-    }
-    fn __reduce31<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Bindings = (<Binding> ",")+, Binding => ActionFn(91);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action91::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (2, 17)
+        let __nt = super::__action103::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 20)
     }
     fn __reduce32<
         'input,
@@ -2650,13 +3208,12 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bool = "true" => ActionFn(44);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action44::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 18)
+        // Arm? =  => ActionFn(104);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action104::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (0, 20)
     }
     fn __reduce33<
         'input,
@@ -2667,13 +3224,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bool = "false" => ActionFn(45);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExpr = Id => ActionFn(160);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action45::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 18)
+        let __nt = super::__action160::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 21)
     }
     fn __reduce34<
         'input,
@@ -2684,13 +3241,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<Expr> = Expr => ActionFn(62);
-        let __sym0 = __pop_Variant3(__symbols);
+        // BaseExpr = Num => ActionFn(161);
+        let __sym0 = __pop_Variant24(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action62::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 19)
+        let __nt = super::__action161::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 21)
     }
     fn __reduce35<
         'input,
@@ -2701,13 +3258,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<" => ActionFn(22);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExpr = Bool => ActionFn(162);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action22::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __nt = super::__action162::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 21)
     }
     fn __reduce36<
         'input,
@@ -2718,13 +3275,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<=" => ActionFn(23);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExpr = Prim1, "(", Expr, ")" => ActionFn(163);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action23::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __end = __sym3.2;
+        let __nt = super::__action163::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 21)
     }
     fn __reduce37<
         'input,
@@ -2735,13 +3296,19 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">" => ActionFn(24);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExpr = Prim2, "(", Expr, ",", Expr, ")" => ActionFn(164);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant5(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action24::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __end = __sym5.2;
+        let __nt = super::__action164::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (6, 21)
     }
     fn __reduce38<
         'input,
@@ -2752,13 +3319,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">=" => ActionFn(25);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExpr = Id, "(", Comma<Expr>, ")" => ActionFn(165);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action25::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __end = __sym3.2;
+        let __nt = super::__action165::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 21)
     }
     fn __reduce39<
         'input,
@@ -2769,13 +3340,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "==" => ActionFn(26);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExpr = Match => ActionFn(43);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action26::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __nt = super::__action43::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 21)
     }
     fn __reduce40<
         'input,
@@ -2786,13 +3357,16 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "!=" => ActionFn(27);
+        // BaseExpr = "(", Expr, ")" => ActionFn(44);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action27::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __end = __sym2.2;
+        let __nt = super::__action44::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 21)
     }
     fn __reduce41<
         'input,
@@ -2803,13 +3377,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // CmpExpr = LAssoc<Cmp, SumExpr> => ActionFn(17);
-        let __sym0 = __pop_Variant3(__symbols);
+        // BinOps = LogExpr => ActionFn(19);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action17::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 21)
+        let __nt = super::__action19::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 22)
     }
     fn __reduce42<
         'input,
@@ -2820,13 +3394,16 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Expr> = Expr => ActionFn(140);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Binding = Spanned<Id>, "=", Expr => ActionFn(14);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action140::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 22)
+        let __end = __sym2.2;
+        let __nt = super::__action14::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 23)
     }
     fn __reduce43<
         'input,
@@ -2837,12 +3414,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Expr> =  => ActionFn(141);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action141::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 22)
+        // Bindings = Binding => ActionFn(120);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action120::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 24)
     }
     fn __reduce44<
         'input,
@@ -2853,15 +3431,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Expr> = (<Expr> ",")+, Expr => ActionFn(142);
+        // Bindings = (<Binding> ",")+, Binding => ActionFn(121);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant3(__symbols);
         let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action142::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (2, 22)
+        let __nt = super::__action121::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 24)
     }
     fn __reduce45<
         'input,
@@ -2872,13 +3450,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Expr> = (<Expr> ",")+ => ActionFn(143);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Bool = "true" => ActionFn(56);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action143::<>(input, __sym0);
+        let __nt = super::__action56::<>(input, __sym0);
         __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 22)
+        (1, 25)
     }
     fn __reduce46<
         'input,
@@ -2889,13 +3467,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Spanned<Id>> = Spanned<Id> => ActionFn(146);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Bool = "false" => ActionFn(57);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action146::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 23)
+        let __nt = super::__action57::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 25)
     }
     fn __reduce47<
         'input,
@@ -2906,12 +3484,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Spanned<Id>> =  => ActionFn(147);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action147::<>(input, &__start, &__end);
+        // Boxed<Expr> = Expr => ActionFn(78);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action78::<>(input, __sym0);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (0, 23)
+        (1, 26)
     }
     fn __reduce48<
         'input,
@@ -2922,15 +3501,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Spanned<Id>> = (<Spanned<Id>> ",")+, Spanned<Id> => ActionFn(148);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Cmp = "<" => ActionFn(26);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action148::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (2, 23)
+        let __end = __sym0.2;
+        let __nt = super::__action26::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
     fn __reduce49<
         'input,
@@ -2941,13 +3518,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Spanned<Id>> = (<Spanned<Id>> ",")+ => ActionFn(149);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Cmp = "<=" => ActionFn(27);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action149::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 23)
+        let __nt = super::__action27::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
     fn __reduce50<
         'input,
@@ -2958,13 +3535,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr = Let => ActionFn(4);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Cmp = ">" => ActionFn(28);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action4::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 24)
+        let __nt = super::__action28::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
     fn __reduce51<
         'input,
@@ -2975,13 +3552,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr = If => ActionFn(5);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Cmp = ">=" => ActionFn(29);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 24)
+        let __nt = super::__action29::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
     fn __reduce52<
         'input,
@@ -2992,13 +3569,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr = FunDefs => ActionFn(6);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Cmp = "==" => ActionFn(30);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action6::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 24)
+        let __nt = super::__action30::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
     fn __reduce53<
         'input,
@@ -3009,13 +3586,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr = BinOps => ActionFn(7);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Cmp = "!=" => ActionFn(31);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action7::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 24)
+        let __nt = super::__action31::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
     fn __reduce54<
         'input,
@@ -3026,13 +3603,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr? = Expr => ActionFn(79);
-        let __sym0 = __pop_Variant3(__symbols);
+        // CmpExpr = LAssoc<Cmp, SumExpr> => ActionFn(21);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action79::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 25)
+        let __nt = super::__action21::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 28)
     }
     fn __reduce55<
         'input,
@@ -3043,14 +3620,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr? =  => ActionFn(80);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action80::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 25)
+        // Comma<Arm> = Arm => ActionFn(184);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action184::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 29)
     }
-    fn __reduce57<
+    fn __reduce56<
         'input,
     >(
         input: &'input str,
@@ -3059,14 +3637,14 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Extern* =  => ActionFn(66);
+        // Comma<Arm> =  => ActionFn(185);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action66::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (0, 27)
+        let __nt = super::__action185::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (0, 29)
     }
-    fn __reduce58<
+    fn __reduce57<
         'input,
     >(
         input: &'input str,
@@ -3075,15 +3653,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Extern* = Extern+ => ActionFn(67);
-        let __sym0 = __pop_Variant18(__symbols);
+        // Comma<Arm> = (<Arm> ",")+, Arm => ActionFn(186);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action67::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 27)
+        let __end = __sym1.2;
+        let __nt = super::__action186::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (2, 29)
     }
-    fn __reduce59<
+    fn __reduce58<
         'input,
     >(
         input: &'input str,
@@ -3092,15 +3672,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Extern+ = Extern => ActionFn(68);
-        let __sym0 = __pop_Variant17(__symbols);
+        // Comma<Arm> = (<Arm> ",")+ => ActionFn(187);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action68::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 28)
+        let __nt = super::__action187::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 29)
     }
-    fn __reduce60<
+    fn __reduce59<
         'input,
     >(
         input: &'input str,
@@ -3109,17 +3689,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Extern+ = Extern+, Extern => ActionFn(69);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant18(__symbols);
+        // Comma<Expr> = Expr => ActionFn(188);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action69::<>(input, __sym0, __sym1);
+        let __end = __sym0.2;
+        let __nt = super::__action188::<>(input, __sym0);
         __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (2, 28)
+        (1, 30)
     }
-    fn __reduce61<
+    fn __reduce60<
         'input,
     >(
         input: &'input str,
@@ -3128,22 +3706,14 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FunDecl = "def", Id, "(", Comma<Spanned<Id>>, ")", ":", Expr => ActionFn(128);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant3(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant15(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant19(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action128::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (7, 29)
+        // Comma<Expr> =  => ActionFn(189);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action189::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 30)
     }
-    fn __reduce62<
+    fn __reduce61<
         'input,
     >(
         input: &'input str,
@@ -3152,18 +3722,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FunDefs = FunDecl, "in", Boxed<Expr> => ActionFn(129);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Comma<Expr> = (<Expr> ",")+, Expr => ActionFn(190);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action129::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 30)
+        let __end = __sym1.2;
+        let __nt = super::__action190::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (2, 30)
     }
-    fn __reduce63<
+    fn __reduce62<
         'input,
     >(
         input: &'input str,
@@ -3172,19 +3741,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FunDefs = (<FunDecl> "and")+, FunDecl, "in", Boxed<Expr> => ActionFn(130);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant5(__symbols);
+        // Comma<Expr> = (<Expr> ",")+ => ActionFn(191);
         let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action130::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 30)
+        let __end = __sym0.2;
+        let __nt = super::__action191::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 30)
     }
-    fn __reduce64<
+    fn __reduce63<
         'input,
     >(
         input: &'input str,
@@ -3193,15 +3758,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Id = IdStr => ActionFn(43);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Spanned<Id>> = Spanned<Id> => ActionFn(194);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action43::<>(input, __sym0);
+        let __nt = super::__action194::<>(input, __sym0);
         __symbols.push((__start, __Symbol::Variant19(__nt), __end));
         (1, 31)
     }
-    fn __reduce65<
+    fn __reduce64<
         'input,
     >(
         input: &'input str,
@@ -3210,15 +3775,14 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // IdStr = r#"[a-zA-Z_][a-zA-Z0-9_]*"# => ActionFn(42);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action42::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant0(__nt), __end));
-        (1, 32)
+        // Comma<Spanned<Id>> =  => ActionFn(195);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action195::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (0, 31)
     }
-    fn __reduce66<
+    fn __reduce65<
         'input,
     >(
         input: &'input str,
@@ -3227,22 +3791,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // If = "if", Boxed<Expr>, ":", Boxed<Expr>, "else", ":", Boxed<Expr> => ActionFn(131);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant12(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant12(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Spanned<Id>> = (<Spanned<Id>> ",")+, Spanned<Id> => ActionFn(196);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action131::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (7, 33)
+        let __end = __sym1.2;
+        let __nt = super::__action196::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (2, 31)
     }
-    fn __reduce67<
+    fn __reduce66<
         'input,
     >(
         input: &'input str,
@@ -3251,18 +3810,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, SumExpr> = LAssoc<Cmp, SumExpr>, Cmp, SumExpr => ActionFn(132);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Comma<Spanned<Id>> = (<Spanned<Id>> ",")+ => ActionFn(197);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action132::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 34)
+        let __end = __sym0.2;
+        let __nt = super::__action197::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 31)
     }
-    fn __reduce68<
+    fn __reduce67<
         'input,
     >(
         input: &'input str,
@@ -3271,15 +3827,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, SumExpr> = SumExpr => ActionFn(52);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Expr = Let => ActionFn(5);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 34)
+        let __nt = super::__action5::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 32)
     }
-    fn __reduce69<
+    fn __reduce68<
         'input,
     >(
         input: &'input str,
@@ -3288,18 +3844,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, ProdExpr> = LAssoc<PlusMinus, ProdExpr>, PlusMinus, ProdExpr => ActionFn(133);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Expr = If => ActionFn(6);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action133::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 35)
+        let __end = __sym0.2;
+        let __nt = super::__action6::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 32)
     }
-    fn __reduce70<
+    fn __reduce69<
         'input,
     >(
         input: &'input str,
@@ -3308,15 +3861,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, ProdExpr> = ProdExpr => ActionFn(50);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Expr = FunDefs => ActionFn(7);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action50::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 35)
+        let __nt = super::__action7::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 32)
     }
-    fn __reduce71<
+    fn __reduce70<
         'input,
     >(
         input: &'input str,
@@ -3325,18 +3878,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExpr> = LAssoc<Times, NotExpr>, Times, NotExpr => ActionFn(134);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Expr = BinOps => ActionFn(8);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action134::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 36)
+        let __end = __sym0.2;
+        let __nt = super::__action8::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 32)
     }
-    fn __reduce72<
+    fn __reduce71<
         'input,
     >(
         input: &'input str,
@@ -3345,15 +3895,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExpr> = NotExpr => ActionFn(48);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Expr? = Expr => ActionFn(98);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 36)
+        let __nt = super::__action98::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 33)
     }
-    fn __reduce73<
+    fn __reduce72<
         'input,
     >(
         input: &'input str,
@@ -3362,17 +3912,12 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Let = "let", Bindings, "in", Boxed<Expr> => ActionFn(135);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action135::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 37)
+        // Expr? =  => ActionFn(99);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action99::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 33)
     }
     fn __reduce74<
         'input,
@@ -3383,13 +3928,12 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Log = "&&" => ActionFn(20);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action20::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 38)
+        // Extern* =  => ActionFn(85);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action85::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (0, 35)
     }
     fn __reduce75<
         'input,
@@ -3400,13 +3944,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Log = "||" => ActionFn(21);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Extern* = Extern+ => ActionFn(86);
+        let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action21::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 38)
+        let __nt = super::__action86::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 35)
     }
     fn __reduce76<
         'input,
@@ -3417,13 +3961,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LogExpr = RAssoc<Log, CmpExpr> => ActionFn(16);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Extern+ = Extern => ActionFn(87);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 39)
+        let __nt = super::__action87::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 36)
     }
     fn __reduce77<
         'input,
@@ -3434,15 +3978,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // NotExpr = "!", BaseExpr => ActionFn(136);
+        // Extern+ = Extern+, Extern => ActionFn(88);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant21(__symbols);
+        let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action136::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 40)
+        let __nt = super::__action88::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (2, 36)
     }
     fn __reduce78<
         'input,
@@ -3453,13 +3997,20 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // NotExpr = BaseExpr => ActionFn(32);
-        let __sym0 = __pop_Variant3(__symbols);
+        // FunDecl = "def", Id, "(", Comma<Spanned<Id>>, ")", ":", Expr => ActionFn(167);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant5(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant19(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant23(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action32::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 40)
+        let __end = __sym6.2;
+        let __nt = super::__action167::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (7, 37)
     }
     fn __reduce79<
         'input,
@@ -3470,13 +4021,16 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Num = r#"[+-]?[0-9]+"# => ActionFn(41);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FunDefs = FunDecl, "in", Boxed<Expr> => ActionFn(168);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action41::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 41)
+        let __end = __sym2.2;
+        let __nt = super::__action168::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 38)
     }
     fn __reduce80<
         'input,
@@ -3487,13 +4041,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "+" => ActionFn(28);
-        let __sym0 = __pop_Variant0(__symbols);
+        // FunDefs = (<FunDecl> "and")+, FunDecl, "in", Boxed<Expr> => ActionFn(169);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 42)
+        let __end = __sym3.2;
+        let __nt = super::__action169::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 38)
     }
     fn __reduce81<
         'input,
@@ -3504,13 +4062,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "-" => ActionFn(29);
+        // Id = IdStr => ActionFn(55);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action29::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 42)
+        let __nt = super::__action55::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 39)
     }
     fn __reduce82<
         'input,
@@ -3521,13 +4079,13 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "add1" => ActionFn(39);
+        // IdStr = r#"[a-zA-Z_][a-zA-Z0-9_]*"# => ActionFn(54);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action39::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 43)
+        let __nt = super::__action54::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant0(__nt), __end));
+        (1, 40)
     }
     fn __reduce83<
         'input,
@@ -3538,13 +4096,20 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "sub1" => ActionFn(40);
+        // If = "if", Boxed<Expr>, ":", Boxed<Expr>, "else", ":", Boxed<Expr> => ActionFn(170);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant15(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action40::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 43)
+        let __end = __sym6.2;
+        let __nt = super::__action170::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (7, 41)
     }
     fn __reduce84<
         'input,
@@ -3555,15 +4120,18 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ProdExpr = LAssoc<Times, NotExpr> => ActionFn(19);
-        let __sym0 = __pop_Variant3(__symbols);
+        // LAssoc<Cmp, SumExpr> = LAssoc<Cmp, SumExpr>, Cmp, SumExpr => ActionFn(171);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 44)
+        let __end = __sym2.2;
+        let __nt = super::__action171::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 42)
     }
-    fn __reduce87<
+    fn __reduce85<
         'input,
     >(
         input: &'input str,
@@ -3572,18 +4140,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExpr> = CmpExpr, Log, RAssoc<Log, CmpExpr> => ActionFn(138);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // LAssoc<Cmp, SumExpr> = SumExpr => ActionFn(66);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action138::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 46)
+        let __end = __sym0.2;
+        let __nt = super::__action66::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 42)
     }
-    fn __reduce88<
+    fn __reduce86<
         'input,
     >(
         input: &'input str,
@@ -3592,15 +4157,18 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExpr> = CmpExpr => ActionFn(54);
-        let __sym0 = __pop_Variant3(__symbols);
+        // LAssoc<PlusMinus, ProdExpr> = LAssoc<PlusMinus, ProdExpr>, PlusMinus, ProdExpr => ActionFn(172);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 46)
+        let __end = __sym2.2;
+        let __nt = super::__action172::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 43)
     }
-    fn __reduce89<
+    fn __reduce87<
         'input,
     >(
         input: &'input str,
@@ -3609,15 +4177,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Spanned<Id> = Id => ActionFn(139);
-        let __sym0 = __pop_Variant19(__symbols);
+        // LAssoc<PlusMinus, ProdExpr> = ProdExpr => ActionFn(64);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action139::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 47)
+        let __nt = super::__action64::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 43)
     }
-    fn __reduce90<
+    fn __reduce88<
         'input,
     >(
         input: &'input str,
@@ -3626,13 +4194,54 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Spanned<Id>? = Spanned<Id> => ActionFn(74);
-        let __sym0 = __pop_Variant7(__symbols);
+        // LAssoc<Times, NotExpr> = LAssoc<Times, NotExpr>, Times, NotExpr => ActionFn(173);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action74::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (1, 48)
+        let __end = __sym2.2;
+        let __nt = super::__action173::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 44)
+    }
+    fn __reduce89<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Times, NotExpr> = NotExpr => ActionFn(62);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action62::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 44)
+    }
+    fn __reduce90<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Let = "let", Bindings, "in", Boxed<Expr> => ActionFn(174);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action174::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 45)
     }
     fn __reduce91<
         'input,
@@ -3643,12 +4252,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Spanned<Id>? =  => ActionFn(75);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action75::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (0, 48)
+        // Let = "let", ParallelBindings, "in", Boxed<Expr> => ActionFn(175);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action175::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 45)
     }
     fn __reduce92<
         'input,
@@ -3659,13 +4273,17 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // SumExpr = LAssoc<PlusMinus, ProdExpr> => ActionFn(18);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Let = "letrec", Bindings, "in", Boxed<Expr> => ActionFn(176);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action18::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 49)
+        let __end = __sym3.2;
+        let __nt = super::__action176::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 45)
     }
     fn __reduce93<
         'input,
@@ -3676,15 +4294,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Times = "*" => ActionFn(30);
+        // Log = "&&" => ActionFn(24);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action30::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 50)
+        let __nt = super::__action24::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 46)
     }
-    fn __reduce95<
+    fn __reduce94<
         'input,
     >(
         input: &'input str,
@@ -3693,15 +4311,15 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // __Extern = Extern => ActionFn(2);
-        let __sym0 = __pop_Variant17(__symbols);
+        // Log = "||" => ActionFn(25);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action2::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 52)
+        let __nt = super::__action25::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 46)
     }
-    fn __reduce96<
+    fn __reduce95<
         'input,
     >(
         input: &'input str,
@@ -3710,221 +4328,676 @@ mod __parse__Expr {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // __Prog = Prog => ActionFn(0);
-        let __sym0 = __pop_Variant21(__symbols);
+        // LogExpr = RAssoc<Log, CmpExpr> => ActionFn(20);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action0::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 53)
-    }
-}
-#[allow(unused_imports)]
-pub use self::__parse__Expr::ExprParser;
-
-#[rustfmt::skip]
-#[allow(explicit_outlives_requirements, non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::needless_lifetimes, clippy::type_complexity, clippy::needless_return, clippy::too_many_arguments, clippy::match_single_binding)]
-mod __parse__Extern {
-
-    use std::str::FromStr;
-    use crate::ast::{
-    SurfProg, SurfExpr, SurfBinding, SurfFunDecl, SurfExtDecl,
-    Prog, Expr, Binding, FunDecl, ExtDecl, Prim,
-};
-    use crate::span::SrcLoc;
-    use lalrpop_util::ParseError;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    #[allow(unused_extern_crates)]
-    extern crate alloc;
-    use self::__lalrpop_util::lexer::Token;
-    #[allow(dead_code)]
-    pub(crate) enum __Symbol<'input>
-     {
-        Variant0(&'input str),
-        Variant1(SurfBinding),
-        Variant2(alloc::vec::Vec<SurfBinding>),
-        Variant3(SurfExpr),
-        Variant4(alloc::vec::Vec<SurfExpr>),
-        Variant5(SurfFunDecl),
-        Variant6(alloc::vec::Vec<SurfFunDecl>),
-        Variant7((String, SrcLoc)),
-        Variant8(alloc::vec::Vec<(String, SrcLoc)>),
-        Variant9(usize),
-        Variant10(Vec<SurfBinding>),
-        Variant11(bool),
-        Variant12(Box<SurfExpr>),
-        Variant13(Prim),
-        Variant14(Vec<SurfExpr>),
-        Variant15(Vec<(String, SrcLoc)>),
-        Variant16(Option<SurfExpr>),
-        Variant17(SurfExtDecl),
-        Variant18(alloc::vec::Vec<SurfExtDecl>),
-        Variant19(String),
-        Variant20(i64),
-        Variant21(SurfProg),
-        Variant22(Option<(String, SrcLoc)>),
-    }
-    const __ACTION: &[i8] = &[
-        // State 0
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
-        // State 1
-        0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 2
-        0, 7, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 3
-        0, 7, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 5
-        0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 6
-        0, 0, 0, 0, 0, -66, -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 7
-        0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 8
-        0, 0, 0, 0, 0, 0, -90, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 9
-        0, 0, 0, 0, 0, 0, -65, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 10
-        0, 0, 0, 0, 0, 0, -47, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 11
-        0, 0, 0, 0, 0, 0, -49, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 13
-        0, -19, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 14
-        0, -20, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    fn __action(state: i8, integer: usize) -> i8 {
-        __ACTION[(state as usize) * 30 + integer]
-    }
-    const __EOF_ACTION: &[i8] = &[
-        // State 0
-        0,
-        // State 1
-        0,
-        // State 2
-        0,
-        // State 3
-        0,
-        // State 4
-        -96,
-        // State 5
-        0,
-        // State 6
-        0,
-        // State 7
-        0,
-        // State 8
-        0,
-        // State 9
-        0,
-        // State 10
-        0,
-        // State 11
-        0,
-        // State 12
-        -57,
-        // State 13
-        0,
-        // State 14
-        0,
-    ];
-    fn __goto(state: i8, nt: usize) -> i8 {
-        match nt {
-            11 => 3,
-            23 => 7,
-            26 => 4,
-            31 => 8,
-            32 => match state {
-                2..=3 => 9,
-                _ => 5,
-            },
-            47 => match state {
-                3 => 11,
-                _ => 10,
-            },
-            _ => 0,
-        }
-    }
-    #[allow(clippy::needless_raw_string_hashes)]
-    const __TERMINAL: &[&str] = &[
-        r###"r#"[+-]?[0-9]+"#"###,
-        r###"r#"[a-zA-Z_][a-zA-Z0-9_]*"#"###,
-        r###""!""###,
-        r###""!=""###,
-        r###""&&""###,
-        r###""(""###,
-        r###"")""###,
-        r###""*""###,
-        r###""+""###,
-        r###"",""###,
-        r###""-""###,
-        r###"":""###,
-        r###""<""###,
-        r###""<=""###,
-        r###""=""###,
-        r###""==""###,
-        r###"">""###,
-        r###"">=""###,
-        r###""add1""###,
-        r###""and""###,
-        r###""def""###,
-        r###""else""###,
-        r###""extern""###,
-        r###""false""###,
-        r###""if""###,
-        r###""in""###,
-        r###""let""###,
-        r###""sub1""###,
-        r###""true""###,
-        r###""||""###,
-    ];
-    fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
-        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
-            let next_state = __action(__state, index);
-            if next_state == 0 {
-                None
-            } else {
-                Some(alloc::string::ToString::to_string(terminal))
-            }
-        }).collect()
+        let __nt = super::__action20::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 47)
     }
-    fn __expected_tokens_from_states<
+    fn __reduce97<
         'input,
     >(
-        __states: &[i8],
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
-    ) -> alloc::vec::Vec<alloc::string::String>
+    ) -> (usize, usize)
     {
-        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
-            if __accepts(None, __states, Some(index), core::marker::PhantomData::<(&())>) {
-                Some(alloc::string::ToString::to_string(terminal))
-            } else {
-                None
-            }
-        }).collect()
+        // NotExpr = "!", BaseExpr => ActionFn(178);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action178::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 49)
     }
-    struct __StateMachine<'input>
-    where 
-    {
+    fn __reduce98<
+        'input,
+    >(
         input: &'input str,
-        __phantom: core::marker::PhantomData<(&'input ())>,
-    }
-    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
-    where 
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
     {
-        type Location = usize;
-        type Error = &'static str;
-        type Token = Token<'input>;
-        type TokenIndex = usize;
-        type Symbol = __Symbol<'input>;
-        type Success = SurfExtDecl;
-        type StateIndex = i8;
+        // NotExpr = BaseExpr => ActionFn(36);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action36::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 49)
+    }
+    fn __reduce99<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Num = r#"[+-]?[0-9]+"# => ActionFn(53);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action53::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 50)
+    }
+    fn __reduce100<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ParallelBindings = (<Binding> "and")+, Binding => ActionFn(13);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action13::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 51)
+    }
+    fn __reduce101<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Pattern = Num => ActionFn(47);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action47::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 52)
+    }
+    fn __reduce103<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PlusMinus = "+" => ActionFn(32);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action32::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 53)
+    }
+    fn __reduce104<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // PlusMinus = "-" => ActionFn(33);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action33::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 53)
+    }
+    fn __reduce105<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "add1" => ActionFn(49);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action49::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 54)
+    }
+    fn __reduce106<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "sub1" => ActionFn(50);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action50::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 54)
+    }
+    fn __reduce107<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "error" => ActionFn(51);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action51::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 54)
+    }
+    fn __reduce108<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Prim2 = "cmp" => ActionFn(52);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action52::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 55)
+    }
+    fn __reduce109<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // ProdExpr = LAssoc<Times, NotExpr> => ActionFn(23);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action23::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 56)
+    }
+    fn __reduce112<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // RAssoc<Log, CmpExpr> = CmpExpr, Log, RAssoc<Log, CmpExpr> => ActionFn(180);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action180::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 58)
+    }
+    fn __reduce113<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // RAssoc<Log, CmpExpr> = CmpExpr => ActionFn(68);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action68::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 58)
+    }
+    fn __reduce114<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Spanned<Id> = Id => ActionFn(181);
+        let __sym0 = __pop_Variant23(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action181::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 59)
+    }
+    fn __reduce115<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Spanned<Id>? = Spanned<Id> => ActionFn(89);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action89::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 60)
+    }
+    fn __reduce116<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Spanned<Id>? =  => ActionFn(90);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action90::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (0, 60)
+    }
+    fn __reduce117<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Spanned<Pattern> = Pattern => ActionFn(182);
+        let __sym0 = __pop_Variant25(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action182::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 61)
+    }
+    fn __reduce118<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // SumExpr = LAssoc<PlusMinus, ProdExpr> => ActionFn(22);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action22::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 62)
+    }
+    fn __reduce119<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Times = "*" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action34::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 63)
+    }
+    fn __reduce120<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // TopFunDecl = "def", Spanned<Id>, "(", Comma<Spanned<Id>>, ")", ":", Expr => ActionFn(183);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant5(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant19(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym6.2;
+        let __nt = super::__action183::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (7, 64)
+    }
+    fn __reduce121<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // TopFunDecl+ = TopFunDecl => ActionFn(83);
+        let __sym0 = __pop_Variant29(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action83::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 65)
+    }
+    fn __reduce122<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // TopFunDecl+ = TopFunDecl+, TopFunDecl => ActionFn(84);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant29(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action84::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (2, 65)
+    }
+    fn __reduce124<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // __Extern = Extern => ActionFn(2);
+        let __sym0 = __pop_Variant21(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action2::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 67)
+    }
+    fn __reduce125<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // __Prog = Prog => ActionFn(0);
+        let __sym0 = __pop_Variant26(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action0::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 68)
+    }
+}
+#[allow(unused_imports)]
+pub use self::__parse__Expr::ExprParser;
+
+#[rustfmt::skip]
+#[allow(explicit_outlives_requirements, non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::needless_lifetimes, clippy::type_complexity, clippy::needless_return, clippy::too_many_arguments, clippy::match_single_binding)]
+mod __parse__Extern {
+
+    use std::str::FromStr;
+    use crate::ast::{
+    SurfProg, SurfExpr, SurfBinding, SurfFunDecl, SurfExtDecl, SurfMatchArm,
+    Prog, Expr, Binding, FunDecl, ExtDecl, MatchArm, Prim, LetKind,
+};
+    use crate::span::SrcLoc;
+    use lalrpop_util::ParseError;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(unused_imports)]
+    use self::__lalrpop_util::state_machine as __state_machine;
+    #[allow(unused_extern_crates)]
+    extern crate alloc;
+    use self::__lalrpop_util::lexer::Token;
+    #[allow(dead_code)]
+    pub(crate) enum __Symbol<'input>
+     {
+        Variant0(&'input str),
+        Variant1(((Option<i64>, SrcLoc), SurfExpr)),
+        Variant2(alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>),
+        Variant3(SurfBinding),
+        Variant4(alloc::vec::Vec<SurfBinding>),
+        Variant5(SurfExpr),
+        Variant6(alloc::vec::Vec<SurfExpr>),
+        Variant7(SurfFunDecl),
+        Variant8(alloc::vec::Vec<SurfFunDecl>),
+        Variant9((String, SrcLoc)),
+        Variant10(alloc::vec::Vec<(String, SrcLoc)>),
+        Variant11(usize),
+        Variant12(Option<((Option<i64>, SrcLoc), SurfExpr)>),
+        Variant13(Vec<SurfBinding>),
+        Variant14(bool),
+        Variant15(Box<SurfExpr>),
+        Variant16(Prim),
+        Variant17(Vec<((Option<i64>, SrcLoc), SurfExpr)>),
+        Variant18(Vec<SurfExpr>),
+        Variant19(Vec<(String, SrcLoc)>),
+        Variant20(Option<SurfExpr>),
+        Variant21(SurfExtDecl),
+        Variant22(alloc::vec::Vec<SurfExtDecl>),
+        Variant23(String),
+        Variant24(i64),
+        Variant25(Option<i64>),
+        Variant26(SurfProg),
+        Variant27(Option<(String, SrcLoc)>),
+        Variant28((Option<i64>, SrcLoc)),
+        Variant29((SrcLoc, SrcLoc, SurfFunDecl)),
+        Variant30(alloc::vec::Vec<(SrcLoc, SrcLoc, SurfFunDecl)>),
+    }
+    const __ACTION: &[i8] = &[
+        // State 0
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 1
+        0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 2
+        0, 7, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 3
+        0, 7, 0, 0, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 4
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 5
+        0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 6
+        0, 0, 0, 0, 0, -83, -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 7
+        0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 8
+        0, 0, 0, 0, 0, 0, -115, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 9
+        0, 0, 0, 0, 0, 0, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 10
+        0, 0, 0, 0, 0, 0, -64, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 11
+        0, 0, 0, 0, 0, 0, -66, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 12
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 13
+        0, -27, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 14
+        0, -28, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    fn __action(state: i8, integer: usize) -> i8 {
+        __ACTION[(state as usize) * 37 + integer]
+    }
+    const __EOF_ACTION: &[i8] = &[
+        // State 0
+        0,
+        // State 1
+        0,
+        // State 2
+        0,
+        // State 3
+        0,
+        // State 4
+        -125,
+        // State 5
+        0,
+        // State 6
+        0,
+        // State 7
+        0,
+        // State 8
+        0,
+        // State 9
+        0,
+        // State 10
+        0,
+        // State 11
+        0,
+        // State 12
+        -74,
+        // State 13
+        0,
+        // State 14
+        0,
+    ];
+    fn __goto(state: i8, nt: usize) -> i8 {
+        match nt {
+            16 => 3,
+            31 => 7,
+            34 => 4,
+            39 => 8,
+            40 => match state {
+                2..=3 => 9,
+                _ => 5,
+            },
+            59 => match state {
+                3 => 11,
+                _ => 10,
+            },
+            _ => 0,
+        }
+    }
+    #[allow(clippy::needless_raw_string_hashes)]
+    const __TERMINAL: &[&str] = &[
+        r###"r#"[+-]?[0-9]+"#"###,
+        r###"r#"[a-zA-Z_][a-zA-Z0-9_]*"#"###,
+        r###""!""###,
+        r###""!=""###,
+        r###""&&""###,
+        r###""(""###,
+        r###"")""###,
+        r###""*""###,
+        r###""+""###,
+        r###"",""###,
+        r###""-""###,
+        r###"":""###,
+        r###""<""###,
+        r###""<=""###,
+        r###""=""###,
+        r###""==""###,
+        r###""=>""###,
+        r###"">""###,
+        r###"">=""###,
+        r###""add1""###,
+        r###""and""###,
+        r###""cmp""###,
+        r###""def""###,
+        r###""else""###,
+        r###""error""###,
+        r###""extern""###,
+        r###""false""###,
+        r###""if""###,
+        r###""in""###,
+        r###""let""###,
+        r###""letrec""###,
+        r###""match""###,
+        r###""sub1""###,
+        r###""true""###,
+        r###""{""###,
+        r###""||""###,
+        r###""}""###,
+    ];
+    fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
+        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
+            let next_state = __action(__state, index);
+            if next_state == 0 {
+                None
+            } else {
+                Some(alloc::string::ToString::to_string(terminal))
+            }
+        }).collect()
+    }
+    fn __expected_tokens_from_states<
+        'input,
+    >(
+        __states: &[i8],
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> alloc::vec::Vec<alloc::string::String>
+    {
+        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
+            if __accepts(None, __states, Some(index), core::marker::PhantomData::<(&())>) {
+                Some(alloc::string::ToString::to_string(terminal))
+            } else {
+                None
+            }
+        }).collect()
+    }
+    struct __StateMachine<'input>
+    where 
+    {
+        input: &'input str,
+        __phantom: core::marker::PhantomData<(&'input ())>,
+    }
+    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
+    where 
+    {
+        type Location = usize;
+        type Error = &'static str;
+        type Token = Token<'input>;
+        type TokenIndex = usize;
+        type Symbol = __Symbol<'input>;
+        type Success = SurfExtDecl;
+        type StateIndex = i8;
         type Action = i8;
         type ReduceIndex = i8;
         type NonterminalIndex = usize;
@@ -3951,7 +5024,7 @@ mod __parse__Extern {
 
         #[inline]
         fn error_action(&self, state: i8) -> i8 {
-            __action(state, 30 - 1)
+            __action(state, 37 - 1)
         }
 
         #[inline]
@@ -4049,6 +5122,13 @@ mod __parse__Extern {
             Token(27, _) if true => Some(27),
             Token(28, _) if true => Some(28),
             Token(29, _) if true => Some(29),
+            Token(30, _) if true => Some(30),
+            Token(31, _) if true => Some(31),
+            Token(32, _) if true => Some(32),
+            Token(33, _) if true => Some(33),
+            Token(34, _) if true => Some(34),
+            Token(35, _) if true => Some(35),
+            Token(36, _) if true => Some(36),
             _ => None,
         }
     }
@@ -4061,8 +5141,8 @@ mod __parse__Extern {
     ) -> __Symbol<'input>
     {
         #[allow(clippy::manual_range_patterns)]match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 => match __token {
-                Token(0, __tok0) | Token(1, __tok0) | Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) if true => __Symbol::Variant0(__tok0),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 => match __token {
+                Token(0, __tok0) | Token(1, __tok0) | Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) if true => __Symbol::Variant0(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -4144,13 +5224,13 @@ mod __parse__Extern {
             }
             11 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 7,
                 }
             }
             12 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 7,
                 }
             }
@@ -4162,25 +5242,25 @@ mod __parse__Extern {
             }
             14 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 8,
+                    states_to_pop: 0,
+                    nonterminal_produced: 9,
                 }
             }
             15 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 1,
                     nonterminal_produced: 9,
                 }
             }
             16 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 10,
                 }
             }
             17 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 10,
                 }
             }
@@ -4192,465 +5272,639 @@ mod __parse__Extern {
             }
             19 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 11,
+                    states_to_pop: 0,
+                    nonterminal_produced: 12,
                 }
             }
             20 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 1,
                     nonterminal_produced: 12,
                 }
             }
             21 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 13,
                 }
             }
             22 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
                 }
             }
             23 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 14,
                 }
             }
             24 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    states_to_pop: 0,
+                    nonterminal_produced: 15,
                 }
             }
             25 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 14,
+                    states_to_pop: 1,
+                    nonterminal_produced: 15,
                 }
             }
             26 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 14,
+                    states_to_pop: 2,
+                    nonterminal_produced: 16,
                 }
             }
             27 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 14,
+                    nonterminal_produced: 16,
                 }
             }
             28 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 0,
+                    nonterminal_produced: 17,
                 }
             }
             29 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 16,
+                    states_to_pop: 0,
+                    nonterminal_produced: 18,
                 }
             }
             30 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 3,
+                    nonterminal_produced: 19,
                 }
             }
             31 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 17,
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
                 }
             }
             32 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 0,
+                    nonterminal_produced: 20,
                 }
             }
             33 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 21,
                 }
             }
             34 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    nonterminal_produced: 21,
                 }
             }
             35 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 21,
                 }
             }
             36 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    states_to_pop: 4,
+                    nonterminal_produced: 21,
                 }
             }
             37 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    states_to_pop: 6,
+                    nonterminal_produced: 21,
                 }
             }
             38 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 21,
+                }
+            }
+            39 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 21,
+                }
+            }
+            40 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 21,
+                }
+            }
+            41 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
+            }
+            42 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 23,
+                }
+            }
+            43 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
+                }
+            }
+            44 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 24,
+                }
+            }
+            45 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 25,
+                }
+            }
+            46 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 25,
+                }
+            }
+            47 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 26,
+                }
+            }
+            48 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
+            }
+            49 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
+            }
+            50 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
+            }
+            51 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
+            }
+            52 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
+            }
+            53 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
+            }
+            54 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
+            }
+            55 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 29,
                 }
             }
-            39 => {
+            56 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    states_to_pop: 0,
+                    nonterminal_produced: 29,
                 }
             }
-            40 => {
+            57 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    states_to_pop: 2,
+                    nonterminal_produced: 29,
                 }
             }
-            41 => {
+            58 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    nonterminal_produced: 29,
                 }
             }
-            42 => {
+            59 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 30,
                 }
             }
-            43 => {
+            60 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 0,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 30,
                 }
             }
-            44 => {
+            61 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 30,
                 }
             }
-            45 => {
+            62 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 30,
                 }
             }
-            46 => {
+            63 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 31,
                 }
             }
-            47 => {
+            64 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 0,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 31,
                 }
             }
-            48 => {
+            65 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 31,
                 }
             }
-            49 => {
+            66 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 31,
                 }
             }
-            50 => {
+            67 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    nonterminal_produced: 32,
                 }
             }
-            51 => {
+            68 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    nonterminal_produced: 32,
                 }
             }
-            52 => {
+            69 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    nonterminal_produced: 32,
                 }
             }
-            53 => {
+            70 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    nonterminal_produced: 32,
                 }
             }
-            54 => {
+            71 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 25,
+                    nonterminal_produced: 33,
                 }
             }
-            55 => {
+            72 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 0,
-                    nonterminal_produced: 25,
+                    nonterminal_produced: 33,
                 }
             }
-            56 => {
+            73 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 5,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 34,
                 }
             }
-            57 => {
+            74 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 0,
-                    nonterminal_produced: 27,
+                    nonterminal_produced: 35,
                 }
             }
-            58 => {
+            75 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 27,
+                    nonterminal_produced: 35,
                 }
             }
-            59 => {
+            76 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 28,
+                    nonterminal_produced: 36,
                 }
             }
-            60 => {
+            77 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 28,
+                    nonterminal_produced: 36,
                 }
             }
-            61 => {
+            78 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 7,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 37,
                 }
             }
-            62 => {
+            79 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 30,
+                    nonterminal_produced: 38,
                 }
             }
-            63 => {
+            80 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 4,
-                    nonterminal_produced: 30,
+                    nonterminal_produced: 38,
                 }
             }
-            64 => {
+            81 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 31,
+                    nonterminal_produced: 39,
                 }
             }
-            65 => {
+            82 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 32,
+                    nonterminal_produced: 40,
                 }
             }
-            66 => {
+            83 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 7,
-                    nonterminal_produced: 33,
+                    nonterminal_produced: 41,
                 }
             }
-            67 => {
+            84 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 34,
+                    nonterminal_produced: 42,
                 }
             }
-            68 => {
+            85 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 34,
+                    nonterminal_produced: 42,
                 }
             }
-            69 => {
+            86 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 35,
+                    nonterminal_produced: 43,
                 }
             }
-            70 => {
+            87 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 35,
+                    nonterminal_produced: 43,
                 }
             }
-            71 => {
+            88 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 36,
+                    nonterminal_produced: 44,
                 }
             }
-            72 => {
+            89 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 36,
+                    nonterminal_produced: 44,
                 }
             }
-            73 => {
+            90 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 4,
-                    nonterminal_produced: 37,
+                    nonterminal_produced: 45,
                 }
             }
-            74 => {
+            91 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 45,
+                }
+            }
+            92 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 45,
+                }
+            }
+            93 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    nonterminal_produced: 46,
                 }
             }
-            75 => {
+            94 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    nonterminal_produced: 46,
                 }
             }
-            76 => {
+            95 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 39,
+                    nonterminal_produced: 47,
                 }
             }
-            77 => {
+            96 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 48,
+                }
+            }
+            97 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 40,
+                    nonterminal_produced: 49,
                 }
             }
-            78 => {
+            98 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 40,
+                    nonterminal_produced: 49,
                 }
             }
-            79 => {
+            99 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 41,
+                    nonterminal_produced: 50,
                 }
             }
-            80 => {
+            100 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 51,
+                }
+            }
+            101 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 42,
+                    nonterminal_produced: 52,
                 }
             }
-            81 => {
+            102 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 42,
+                    nonterminal_produced: 52,
                 }
             }
-            82 => {
+            103 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 43,
+                    nonterminal_produced: 53,
                 }
             }
-            83 => {
+            104 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 43,
+                    nonterminal_produced: 53,
                 }
             }
-            84 => {
+            105 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 44,
+                    nonterminal_produced: 54,
                 }
             }
-            85 => {
+            106 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 45,
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
                 }
             }
-            86 => {
+            107 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 8,
-                    nonterminal_produced: 45,
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
                 }
             }
-            87 => {
+            108 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 55,
+                }
+            }
+            109 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 56,
+                }
+            }
+            110 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
+            }
+            111 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 57,
+                }
+            }
+            112 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 46,
+                    nonterminal_produced: 58,
                 }
             }
-            88 => {
+            113 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 46,
+                    nonterminal_produced: 58,
                 }
             }
-            89 => {
+            114 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 47,
+                    nonterminal_produced: 59,
                 }
             }
-            90 => {
+            115 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 48,
+                    nonterminal_produced: 60,
                 }
             }
-            91 => {
+            116 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 0,
-                    nonterminal_produced: 48,
+                    nonterminal_produced: 60,
                 }
             }
-            92 => {
+            117 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 49,
+                    nonterminal_produced: 61,
+                }
+            }
+            118 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
+            }
+            119 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 63,
+                }
+            }
+            120 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 64,
+                }
+            }
+            121 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 65,
                 }
             }
-            93 => {
+            122 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 50,
+                    states_to_pop: 2,
+                    nonterminal_produced: 65,
                 }
             }
-            94 => {
+            123 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 51,
+                    nonterminal_produced: 66,
                 }
             }
-            95 => __state_machine::SimulatedReduce::Accept,
-            96 => {
+            124 => __state_machine::SimulatedReduce::Accept,
+            125 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 53,
+                    nonterminal_produced: 68,
                 }
             }
             _ => panic!("invalid reduction index {}", __reduce_index)
@@ -4903,21 +6157,7 @@ mod __parse__Extern {
                 __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             56 => {
-                // Extern = "extern", IdStr, "(", Comma<Spanned<Id>>, ")" => ActionFn(127);
-                assert!(__symbols.len() >= 5);
-                let __sym4 = __pop_Variant0(__symbols);
-                let __sym3 = __pop_Variant15(__symbols);
-                let __sym2 = __pop_Variant0(__symbols);
-                let __sym1 = __pop_Variant0(__symbols);
-                let __sym0 = __pop_Variant0(__symbols);
-                let __start = __sym0.0;
-                let __end = __sym4.2;
-                let __nt = match super::__action127::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4) {
-                    Ok(v) => v,
-                    Err(e) => return Some(Err(e)),
-                };
-                __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-                (5, 26)
+                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             57 => {
                 __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
@@ -4968,7 +6208,21 @@ mod __parse__Extern {
                 __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                // Extern = "extern", IdStr, "(", Comma<Spanned<Id>>, ")" => ActionFn(166);
+                assert!(__symbols.len() >= 5);
+                let __sym4 = __pop_Variant0(__symbols);
+                let __sym3 = __pop_Variant19(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant0(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym4.2;
+                let __nt = match super::__action166::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+                (5, 34)
             }
             74 => {
                 __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
@@ -5004,43 +6258,10 @@ mod __parse__Extern {
                 __reduce84(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             85 => {
-                // Prog = "def", IdStr, "(", Spanned<Id>, ")", ":", Expr => ActionFn(144);
-                assert!(__symbols.len() >= 7);
-                let __sym6 = __pop_Variant3(__symbols);
-                let __sym5 = __pop_Variant0(__symbols);
-                let __sym4 = __pop_Variant0(__symbols);
-                let __sym3 = __pop_Variant7(__symbols);
-                let __sym2 = __pop_Variant0(__symbols);
-                let __sym1 = __pop_Variant0(__symbols);
-                let __sym0 = __pop_Variant0(__symbols);
-                let __start = __sym0.0;
-                let __end = __sym6.2;
-                let __nt = match super::__action144::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6) {
-                    Ok(v) => v,
-                    Err(e) => return Some(Err(e)),
-                };
-                __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-                (7, 45)
+                __reduce85(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             86 => {
-                // Prog = Extern+, "def", IdStr, "(", Spanned<Id>, ")", ":", Expr => ActionFn(145);
-                assert!(__symbols.len() >= 8);
-                let __sym7 = __pop_Variant3(__symbols);
-                let __sym6 = __pop_Variant0(__symbols);
-                let __sym5 = __pop_Variant0(__symbols);
-                let __sym4 = __pop_Variant7(__symbols);
-                let __sym3 = __pop_Variant0(__symbols);
-                let __sym2 = __pop_Variant0(__symbols);
-                let __sym1 = __pop_Variant0(__symbols);
-                let __sym0 = __pop_Variant18(__symbols);
-                let __start = __sym0.0;
-                let __end = __sym7.2;
-                let __nt = match super::__action145::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7) {
-                    Ok(v) => v,
-                    Err(e) => return Some(Err(e)),
-                };
-                __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-                (8, 45)
+                __reduce86(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             87 => {
                 __reduce87(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
@@ -5067,15 +6288,145 @@ mod __parse__Extern {
                 __reduce94(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             95 => {
+                __reduce95(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            96 => {
+                // Match = "match", Boxed<Expr>, "{", Comma<Arm>, "}" => ActionFn(177);
+                assert!(__symbols.len() >= 5);
+                let __sym4 = __pop_Variant0(__symbols);
+                let __sym3 = __pop_Variant17(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant15(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym4.2;
+                let __nt = match super::__action177::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+                (5, 48)
+            }
+            97 => {
+                __reduce97(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            98 => {
+                __reduce98(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            99 => {
+                __reduce99(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            100 => {
+                __reduce100(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            101 => {
+                __reduce101(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            102 => {
+                // Pattern = IdStr => ActionFn(179);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym0.2;
+                let __nt = match super::__action179::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+                (1, 52)
+            }
+            103 => {
+                __reduce103(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            104 => {
+                __reduce104(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            105 => {
+                __reduce105(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            106 => {
+                __reduce106(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            107 => {
+                __reduce107(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            108 => {
+                __reduce108(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            109 => {
+                __reduce109(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            110 => {
+                // Prog = TopFunDecl+ => ActionFn(192);
+                let __sym0 = __pop_Variant30(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym0.2;
+                let __nt = match super::__action192::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+                (1, 57)
+            }
+            111 => {
+                // Prog = Extern+, TopFunDecl+ => ActionFn(193);
+                assert!(__symbols.len() >= 2);
+                let __sym1 = __pop_Variant30(__symbols);
+                let __sym0 = __pop_Variant22(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym1.2;
+                let __nt = match super::__action193::<>(input, __sym0, __sym1) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+                (2, 57)
+            }
+            112 => {
+                __reduce112(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            113 => {
+                __reduce113(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            114 => {
+                __reduce114(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            115 => {
+                __reduce115(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            116 => {
+                __reduce116(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            117 => {
+                __reduce117(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            118 => {
+                __reduce118(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            119 => {
+                __reduce119(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            120 => {
+                __reduce120(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            121 => {
+                __reduce121(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            122 => {
+                __reduce122(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            123 => {
+                __reduce123(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            124 => {
                 // __Extern = Extern => ActionFn(2);
-                let __sym0 = __pop_Variant17(__symbols);
+                let __sym0 = __pop_Variant21(__symbols);
                 let __start = __sym0.0;
                 let __end = __sym0.2;
                 let __nt = super::__action2::<>(input, __sym0);
                 return Some(Ok(__nt));
             }
-            96 => {
-                __reduce96(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            125 => {
+                __reduce125(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             _ => panic!("invalid action code {}", __action)
         };
@@ -5090,212 +6441,322 @@ mod __parse__Extern {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant7<
+    fn __pop_Variant1<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ((Option<i64>, SrcLoc), SurfExpr), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant28<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Option<i64>, SrcLoc), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant29<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (SrcLoc, SrcLoc, SurfFunDecl), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant9<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, (String, SrcLoc), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
+    fn __pop_Variant15<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Box<SurfExpr>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant12<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Option<((Option<i64>, SrcLoc), SurfExpr)>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant22<
+    fn __pop_Variant27<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Option<(String, SrcLoc)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant16<
+    fn __pop_Variant20<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Option<SurfExpr>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
+    fn __pop_Variant25<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Option<i64>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant16<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Prim, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant19<
+    fn __pop_Variant23<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, String, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant1<
+    fn __pop_Variant3<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, SurfBinding, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant3<
+    fn __pop_Variant5<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, SurfExpr, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant17<
+    fn __pop_Variant21<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, SurfExtDecl, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant5<
+    fn __pop_Variant7<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, SurfFunDecl, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant21<
+    fn __pop_Variant26<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, SurfProg, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant15<
+    fn __pop_Variant17<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant19<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<(String, SrcLoc)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant10<
+    fn __pop_Variant13<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<SurfBinding>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant14<
+    fn __pop_Variant18<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Vec<SurfExpr>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant8<
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant30<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(SrcLoc, SrcLoc, SurfFunDecl)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<(String, SrcLoc)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant2<
+    fn __pop_Variant4<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<SurfBinding>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant4<
+    fn __pop_Variant6<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<SurfExpr>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant18<
+    fn __pop_Variant22<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, alloc::vec::Vec<SurfExtDecl>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<SurfFunDecl>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant14<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, bool, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant6<
+    fn __pop_Variant24<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<SurfFunDecl>, usize)
+    ) -> (usize, i64, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
@@ -5303,47 +6764,356 @@ mod __parse__Extern {
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, bool, usize)
+    ) -> (usize, usize, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant20<
+    fn __pop_Variant0<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i64, usize)
+    ) -> (usize, &'input str, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant9<
-      'input,
+    fn __reduce0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Arm> ",") = Arm, "," => ActionFn(107);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action107::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (2, 0)
+    }
+    fn __reduce1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Arm> ",")* =  => ActionFn(105);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action105::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 1)
+    }
+    fn __reduce2<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Arm> ",")* = (<Arm> ",")+ => ActionFn(106);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action106::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 1)
+    }
+    fn __reduce3<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Arm> ",")+ = Arm, "," => ActionFn(114);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action114::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (2, 2)
+    }
+    fn __reduce4<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Arm> ",")+ = (<Arm> ",")+, Arm, "," => ActionFn(115);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action115::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (3, 2)
+    }
+    fn __reduce5<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> ",") = Binding, "," => ActionFn(77);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action77::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 3)
+    }
+    fn __reduce6<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> ",")* =  => ActionFn(75);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action75::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 4)
+    }
+    fn __reduce7<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> ",")* = (<Binding> ",")+ => ActionFn(76);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action76::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 4)
+    }
+    fn __reduce8<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> ",")+ = Binding, "," => ActionFn(118);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action118::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 5)
+    }
+    fn __reduce9<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> ",")+ = (<Binding> ",")+, Binding, "," => ActionFn(119);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action119::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 5)
+    }
+    fn __reduce10<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> "and") = Binding, "and" => ActionFn(74);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action74::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 6)
+    }
+    fn __reduce11<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> "and")+ = Binding, "and" => ActionFn(122);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action122::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 7)
+    }
+    fn __reduce12<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> "and")+ = (<Binding> "and")+, Binding, "and" => ActionFn(123);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action123::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 7)
+    }
+    fn __reduce13<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expr> ",") = Expr, "," => ActionFn(102);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action102::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 8)
+    }
+    fn __reduce14<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expr> ",")* =  => ActionFn(100);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action100::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 9)
+    }
+    fn __reduce15<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, usize, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expr> ",")* = (<Expr> ",")+ => ActionFn(101);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action101::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 9)
     }
-    fn __pop_Variant0<
-      'input,
+    fn __reduce16<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expr> ",")+ = Expr, "," => ActionFn(124);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action124::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 10)
     }
-    fn __reduce0<
+    fn __reduce17<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(125);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action125::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 10)
+    }
+    fn __reduce18<
         'input,
     >(
         input: &'input str,
@@ -5352,17 +7122,17 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",") = Binding, "," => ActionFn(61);
+        // (<FunDecl> "and") = FunDecl, "and" => ActionFn(71);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action61::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 0)
+        let __nt = super::__action71::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 11)
     }
-    fn __reduce1<
+    fn __reduce19<
         'input,
     >(
         input: &'input str,
@@ -5371,14 +7141,14 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",")* =  => ActionFn(59);
+        // (<FunDecl> "and")* =  => ActionFn(69);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action59::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (0, 1)
+        let __nt = super::__action69::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 12)
     }
-    fn __reduce2<
+    fn __reduce20<
         'input,
     >(
         input: &'input str,
@@ -5387,15 +7157,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",")* = (<Binding> ",")+ => ActionFn(60);
-        let __sym0 = __pop_Variant2(__symbols);
+        // (<FunDecl> "and")* = (<FunDecl> "and")+ => ActionFn(70);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action60::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 1)
+        let __nt = super::__action70::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 12)
     }
-    fn __reduce3<
+    fn __reduce21<
         'input,
     >(
         input: &'input str,
@@ -5404,17 +7174,17 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",")+ = Binding, "," => ActionFn(88);
+        // (<FunDecl> "and")+ = FunDecl, "and" => ActionFn(128);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action88::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 2)
+        let __nt = super::__action128::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (2, 13)
     }
-    fn __reduce4<
+    fn __reduce22<
         'input,
     >(
         input: &'input str,
@@ -5423,18 +7193,18 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",")+ = (<Binding> ",")+, Binding, "," => ActionFn(89);
+        // (<FunDecl> "and")+ = (<FunDecl> "and")+, FunDecl, "and" => ActionFn(129);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action89::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (3, 2)
+        let __nt = super::__action129::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 13)
     }
-    fn __reduce5<
+    fn __reduce23<
         'input,
     >(
         input: &'input str,
@@ -5443,17 +7213,17 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",") = Expr, "," => ActionFn(83);
+        // (<Spanned<Id>> ",") = Spanned<Id>, "," => ActionFn(93);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action83::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 3)
+        let __nt = super::__action93::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 14)
     }
-    fn __reduce6<
+    fn __reduce24<
         'input,
     >(
         input: &'input str,
@@ -5462,14 +7232,14 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",")* =  => ActionFn(81);
+        // (<Spanned<Id>> ",")* =  => ActionFn(91);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action81::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (0, 4)
+        let __nt = super::__action91::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 15)
     }
-    fn __reduce7<
+    fn __reduce25<
         'input,
     >(
         input: &'input str,
@@ -5478,15 +7248,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",")* = (<Expr> ",")+ => ActionFn(82);
-        let __sym0 = __pop_Variant4(__symbols);
+        // (<Spanned<Id>> ",")* = (<Spanned<Id>> ",")+ => ActionFn(92);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action82::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (1, 4)
+        let __nt = super::__action92::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 15)
     }
-    fn __reduce8<
+    fn __reduce26<
         'input,
     >(
         input: &'input str,
@@ -5495,17 +7265,17 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",")+ = Expr, "," => ActionFn(92);
+        // (<Spanned<Id>> ",")+ = Spanned<Id>, "," => ActionFn(132);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action92::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (2, 5)
+        let __nt = super::__action132::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 16)
     }
-    fn __reduce9<
+    fn __reduce27<
         'input,
     >(
         input: &'input str,
@@ -5514,18 +7284,18 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(93);
+        // (<Spanned<Id>> ",")+ = (<Spanned<Id>> ",")+, Spanned<Id>, "," => ActionFn(133);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action93::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (3, 5)
+        let __nt = super::__action133::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 16)
     }
-    fn __reduce10<
+    fn __reduce28<
         'input,
     >(
         input: &'input str,
@@ -5534,17 +7304,155 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and") = FunDecl, "and" => ActionFn(58);
-        assert!(__symbols.len() >= 2);
+        // @L =  => ActionFn(82);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action82::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 17)
+    }
+    fn __reduce29<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // @R =  => ActionFn(79);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action79::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 18)
+    }
+    fn __reduce30<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Arm = Spanned<Pattern>, "=>", Expr => ActionFn(46);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant28(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action58::<>(input, __sym0, __sym1);
+        let __end = __sym2.2;
+        let __nt = super::__action46::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (3, 19)
+    }
+    fn __reduce31<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Arm? = Arm => ActionFn(103);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action103::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce32<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // Arm? =  => ActionFn(104);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action104::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (0, 20)
+    }
+    fn __reduce33<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExpr = Id => ActionFn(160);
+        let __sym0 = __pop_Variant23(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action160::<>(input, __sym0);
         __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 6)
+        (1, 21)
     }
-    fn __reduce11<
+    fn __reduce34<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExpr = Num => ActionFn(161);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action161::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 21)
+    }
+    fn __reduce35<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExpr = Bool => ActionFn(162);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action162::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 21)
+    }
+    fn __reduce36<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExpr = Prim1, "(", Expr, ")" => ActionFn(163);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action163::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 21)
+    }
+    fn __reduce37<
         'input,
     >(
         input: &'input str,
@@ -5553,14 +7461,21 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and")* =  => ActionFn(56);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action56::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (0, 7)
+        // BaseExpr = Prim2, "(", Expr, ",", Expr, ")" => ActionFn(164);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant5(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action164::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (6, 21)
     }
-    fn __reduce12<
+    fn __reduce38<
         'input,
     >(
         input: &'input str,
@@ -5569,15 +7484,19 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and")* = (<FunDecl> "and")+ => ActionFn(57);
-        let __sym0 = __pop_Variant6(__symbols);
+        // BaseExpr = Id, "(", Comma<Expr>, ")" => ActionFn(165);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action57::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 7)
+        let __end = __sym3.2;
+        let __nt = super::__action165::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 21)
     }
-    fn __reduce13<
+    fn __reduce39<
         'input,
     >(
         input: &'input str,
@@ -5586,17 +7505,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and")+ = FunDecl, "and" => ActionFn(96);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
+        // BaseExpr = Match => ActionFn(43);
         let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action96::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 8)
+        let __end = __sym0.2;
+        let __nt = super::__action43::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 21)
     }
-    fn __reduce14<
+    fn __reduce40<
         'input,
     >(
         input: &'input str,
@@ -5605,18 +7522,18 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and")+ = (<FunDecl> "and")+, FunDecl, "and" => ActionFn(97);
+        // BaseExpr = "(", Expr, ")" => ActionFn(44);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action97::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 8)
+        let __nt = super::__action44::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 21)
     }
-    fn __reduce15<
+    fn __reduce41<
         'input,
     >(
         input: &'input str,
@@ -5625,17 +7542,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",") = Spanned<Id>, "," => ActionFn(78);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        // BinOps = LogExpr => ActionFn(19);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action78::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (2, 9)
+        let __end = __sym0.2;
+        let __nt = super::__action19::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 22)
     }
-    fn __reduce16<
+    fn __reduce42<
         'input,
     >(
         input: &'input str,
@@ -5644,14 +7559,18 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",")* =  => ActionFn(76);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action76::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (0, 10)
+        // Binding = Spanned<Id>, "=", Expr => ActionFn(14);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action14::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 23)
     }
-    fn __reduce17<
+    fn __reduce43<
         'input,
     >(
         input: &'input str,
@@ -5660,15 +7579,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",")* = (<Spanned<Id>> ",")+ => ActionFn(77);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Bindings = Binding => ActionFn(120);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action77::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 10)
+        let __nt = super::__action120::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 24)
     }
-    fn __reduce18<
+    fn __reduce44<
         'input,
     >(
         input: &'input str,
@@ -5677,17 +7596,17 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",")+ = Spanned<Id>, "," => ActionFn(100);
+        // Bindings = (<Binding> ",")+, Binding => ActionFn(121);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action100::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (2, 11)
+        let __nt = super::__action121::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 24)
     }
-    fn __reduce19<
+    fn __reduce45<
         'input,
     >(
         input: &'input str,
@@ -5696,18 +7615,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",")+ = (<Spanned<Id>> ",")+, Spanned<Id>, "," => ActionFn(101);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Bool = "true" => ActionFn(56);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action101::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 11)
+        let __end = __sym0.2;
+        let __nt = super::__action56::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 25)
     }
-    fn __reduce20<
+    fn __reduce46<
         'input,
     >(
         input: &'input str,
@@ -5716,14 +7632,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(65);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action65::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (0, 12)
+        // Bool = "false" => ActionFn(57);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action57::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 25)
     }
-    fn __reduce21<
+    fn __reduce47<
         'input,
     >(
         input: &'input str,
@@ -5732,14 +7649,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(63);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action63::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (0, 13)
+        // Boxed<Expr> = Expr => ActionFn(78);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action78::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 26)
     }
-    fn __reduce22<
+    fn __reduce48<
         'input,
     >(
         input: &'input str,
@@ -5748,15 +7666,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Id => ActionFn(122);
-        let __sym0 = __pop_Variant19(__symbols);
+        // Cmp = "<" => ActionFn(26);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action122::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        let __nt = super::__action26::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
-    fn __reduce23<
+    fn __reduce49<
         'input,
     >(
         input: &'input str,
@@ -5765,15 +7683,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Num => ActionFn(123);
-        let __sym0 = __pop_Variant20(__symbols);
+        // Cmp = "<=" => ActionFn(27);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action123::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        let __nt = super::__action27::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
-    fn __reduce24<
+    fn __reduce50<
         'input,
     >(
         input: &'input str,
@@ -5782,15 +7700,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Bool => ActionFn(124);
-        let __sym0 = __pop_Variant11(__symbols);
+        // Cmp = ">" => ActionFn(28);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action124::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        let __nt = super::__action28::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
-    fn __reduce25<
+    fn __reduce51<
         'input,
     >(
         input: &'input str,
@@ -5799,19 +7717,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Prim1, "(", Expr, ")" => ActionFn(125);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
+        // Cmp = ">=" => ActionFn(29);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action125::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 14)
+        let __end = __sym0.2;
+        let __nt = super::__action29::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
-    fn __reduce26<
+    fn __reduce52<
         'input,
     >(
         input: &'input str,
@@ -5820,19 +7734,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Id, "(", Comma<Expr>, ")" => ActionFn(126);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant14(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
+        // Cmp = "==" => ActionFn(30);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action126::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 14)
+        let __end = __sym0.2;
+        let __nt = super::__action30::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
-    fn __reduce27<
+    fn __reduce53<
         'input,
     >(
         input: &'input str,
@@ -5841,18 +7751,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = "(", Expr, ")" => ActionFn(38);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
+        // Cmp = "!=" => ActionFn(31);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action38::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 14)
+        let __end = __sym0.2;
+        let __nt = super::__action31::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
-    fn __reduce28<
+    fn __reduce54<
         'input,
     >(
         input: &'input str,
@@ -5861,15 +7768,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BinOps = LogExpr => ActionFn(15);
-        let __sym0 = __pop_Variant3(__symbols);
+        // CmpExpr = LAssoc<Cmp, SumExpr> => ActionFn(21);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 15)
+        let __nt = super::__action21::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 28)
     }
-    fn __reduce29<
+    fn __reduce55<
         'input,
     >(
         input: &'input str,
@@ -5878,18 +7785,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Binding = Spanned<Id>, "=", Expr => ActionFn(10);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Comma<Arm> = Arm => ActionFn(184);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action10::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 16)
+        let __end = __sym0.2;
+        let __nt = super::__action184::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 29)
     }
-    fn __reduce30<
+    fn __reduce56<
         'input,
     >(
         input: &'input str,
@@ -5898,15 +7802,14 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bindings = Binding => ActionFn(90);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action90::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 17)
+        // Comma<Arm> =  => ActionFn(185);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action185::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (0, 29)
     }
-    fn __reduce31<
+    fn __reduce57<
         'input,
     >(
         input: &'input str,
@@ -5915,17 +7818,17 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bindings = (<Binding> ",")+, Binding => ActionFn(91);
+        // Comma<Arm> = (<Arm> ",")+, Arm => ActionFn(186);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant1(__symbols);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action91::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (2, 17)
+        let __nt = super::__action186::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (2, 29)
     }
-    fn __reduce32<
+    fn __reduce58<
         'input,
     >(
         input: &'input str,
@@ -5934,15 +7837,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bool = "true" => ActionFn(44);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Arm> = (<Arm> ",")+ => ActionFn(187);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action44::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 18)
+        let __nt = super::__action187::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 29)
     }
-    fn __reduce33<
+    fn __reduce59<
         'input,
     >(
         input: &'input str,
@@ -5951,15 +7854,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bool = "false" => ActionFn(45);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Expr> = Expr => ActionFn(188);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action45::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 18)
+        let __nt = super::__action188::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 30)
     }
-    fn __reduce34<
+    fn __reduce60<
         'input,
     >(
         input: &'input str,
@@ -5968,15 +7871,14 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<Expr> = Expr => ActionFn(62);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action62::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 19)
+        // Comma<Expr> =  => ActionFn(189);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action189::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 30)
     }
-    fn __reduce35<
+    fn __reduce61<
         'input,
     >(
         input: &'input str,
@@ -5985,15 +7887,17 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<" => ActionFn(22);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Expr> = (<Expr> ",")+, Expr => ActionFn(190);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action22::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __end = __sym1.2;
+        let __nt = super::__action190::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (2, 30)
     }
-    fn __reduce36<
+    fn __reduce62<
         'input,
     >(
         input: &'input str,
@@ -6002,15 +7906,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<=" => ActionFn(23);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Expr> = (<Expr> ",")+ => ActionFn(191);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action23::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __nt = super::__action191::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 30)
     }
-    fn __reduce37<
+    fn __reduce63<
         'input,
     >(
         input: &'input str,
@@ -6019,15 +7923,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">" => ActionFn(24);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Spanned<Id>> = Spanned<Id> => ActionFn(194);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action24::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __nt = super::__action194::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 31)
     }
-    fn __reduce38<
+    fn __reduce64<
         'input,
     >(
         input: &'input str,
@@ -6036,15 +7940,14 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">=" => ActionFn(25);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action25::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        // Comma<Spanned<Id>> =  => ActionFn(195);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action195::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (0, 31)
     }
-    fn __reduce39<
+    fn __reduce65<
         'input,
     >(
         input: &'input str,
@@ -6053,15 +7956,17 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "==" => ActionFn(26);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Spanned<Id>> = (<Spanned<Id>> ",")+, Spanned<Id> => ActionFn(196);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action26::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __end = __sym1.2;
+        let __nt = super::__action196::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (2, 31)
     }
-    fn __reduce40<
+    fn __reduce66<
         'input,
     >(
         input: &'input str,
@@ -6070,15 +7975,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "!=" => ActionFn(27);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Spanned<Id>> = (<Spanned<Id>> ",")+ => ActionFn(197);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action27::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __nt = super::__action197::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 31)
     }
-    fn __reduce41<
+    fn __reduce67<
         'input,
     >(
         input: &'input str,
@@ -6087,15 +7992,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // CmpExpr = LAssoc<Cmp, SumExpr> => ActionFn(17);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Expr = Let => ActionFn(5);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action17::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 21)
+        let __nt = super::__action5::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 32)
     }
-    fn __reduce42<
+    fn __reduce68<
         'input,
     >(
         input: &'input str,
@@ -6104,15 +8009,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Expr> = Expr => ActionFn(140);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Expr = If => ActionFn(6);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action140::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 22)
+        let __nt = super::__action6::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 32)
     }
-    fn __reduce43<
+    fn __reduce69<
         'input,
     >(
         input: &'input str,
@@ -6121,14 +8026,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Expr> =  => ActionFn(141);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action141::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 22)
+        // Expr = FunDefs => ActionFn(7);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action7::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 32)
     }
-    fn __reduce44<
+    fn __reduce70<
         'input,
     >(
         input: &'input str,
@@ -6137,17 +8043,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Expr> = (<Expr> ",")+, Expr => ActionFn(142);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Expr = BinOps => ActionFn(8);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action142::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (2, 22)
+        let __end = __sym0.2;
+        let __nt = super::__action8::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 32)
     }
-    fn __reduce45<
+    fn __reduce71<
         'input,
     >(
         input: &'input str,
@@ -6156,15 +8060,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Expr> = (<Expr> ",")+ => ActionFn(143);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Expr? = Expr => ActionFn(98);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action143::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 22)
+        let __nt = super::__action98::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 33)
     }
-    fn __reduce46<
+    fn __reduce72<
         'input,
     >(
         input: &'input str,
@@ -6173,15 +8077,14 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Spanned<Id>> = Spanned<Id> => ActionFn(146);
-        let __sym0 = __pop_Variant7(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action146::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 23)
+        // Expr? =  => ActionFn(99);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action99::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 33)
     }
-    fn __reduce47<
+    fn __reduce74<
         'input,
     >(
         input: &'input str,
@@ -6190,14 +8093,14 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Spanned<Id>> =  => ActionFn(147);
+        // Extern* =  => ActionFn(85);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action147::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (0, 23)
+        let __nt = super::__action85::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (0, 35)
     }
-    fn __reduce48<
+    fn __reduce75<
         'input,
     >(
         input: &'input str,
@@ -6206,17 +8109,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Spanned<Id>> = (<Spanned<Id>> ",")+, Spanned<Id> => ActionFn(148);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Extern* = Extern+ => ActionFn(86);
+        let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action148::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (2, 23)
+        let __end = __sym0.2;
+        let __nt = super::__action86::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 35)
     }
-    fn __reduce49<
+    fn __reduce76<
         'input,
     >(
         input: &'input str,
@@ -6225,15 +8126,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Spanned<Id>> = (<Spanned<Id>> ",")+ => ActionFn(149);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Extern+ = Extern => ActionFn(87);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action149::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 23)
+        let __nt = super::__action87::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 36)
     }
-    fn __reduce50<
+    fn __reduce77<
         'input,
     >(
         input: &'input str,
@@ -6242,15 +8143,17 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr = Let => ActionFn(4);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Extern+ = Extern+, Extern => ActionFn(88);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant21(__symbols);
+        let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action4::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 24)
+        let __end = __sym1.2;
+        let __nt = super::__action88::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (2, 36)
     }
-    fn __reduce51<
+    fn __reduce78<
         'input,
     >(
         input: &'input str,
@@ -6259,15 +8162,22 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr = If => ActionFn(5);
-        let __sym0 = __pop_Variant3(__symbols);
+        // FunDecl = "def", Id, "(", Comma<Spanned<Id>>, ")", ":", Expr => ActionFn(167);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant5(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant19(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant23(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 24)
+        let __end = __sym6.2;
+        let __nt = super::__action167::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (7, 37)
     }
-    fn __reduce52<
+    fn __reduce79<
         'input,
     >(
         input: &'input str,
@@ -6276,15 +8186,18 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr = FunDefs => ActionFn(6);
-        let __sym0 = __pop_Variant3(__symbols);
+        // FunDefs = FunDecl, "in", Boxed<Expr> => ActionFn(168);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action6::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 24)
+        let __end = __sym2.2;
+        let __nt = super::__action168::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 38)
     }
-    fn __reduce53<
+    fn __reduce80<
         'input,
     >(
         input: &'input str,
@@ -6293,15 +8206,19 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr = BinOps => ActionFn(7);
-        let __sym0 = __pop_Variant3(__symbols);
+        // FunDefs = (<FunDecl> "and")+, FunDecl, "in", Boxed<Expr> => ActionFn(169);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action7::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 24)
+        let __end = __sym3.2;
+        let __nt = super::__action169::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 38)
     }
-    fn __reduce54<
+    fn __reduce81<
         'input,
     >(
         input: &'input str,
@@ -6310,15 +8227,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr? = Expr => ActionFn(79);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Id = IdStr => ActionFn(55);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action79::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 25)
+        let __nt = super::__action55::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 39)
     }
-    fn __reduce55<
+    fn __reduce82<
         'input,
     >(
         input: &'input str,
@@ -6327,14 +8244,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr? =  => ActionFn(80);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action80::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 25)
+        // IdStr = r#"[a-zA-Z_][a-zA-Z0-9_]*"# => ActionFn(54);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action54::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant0(__nt), __end));
+        (1, 40)
     }
-    fn __reduce57<
+    fn __reduce83<
         'input,
     >(
         input: &'input str,
@@ -6343,14 +8261,22 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Extern* =  => ActionFn(66);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action66::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (0, 27)
+        // If = "if", Boxed<Expr>, ":", Boxed<Expr>, "else", ":", Boxed<Expr> => ActionFn(170);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant15(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym6.2;
+        let __nt = super::__action170::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (7, 41)
     }
-    fn __reduce58<
+    fn __reduce84<
         'input,
     >(
         input: &'input str,
@@ -6359,15 +8285,18 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Extern* = Extern+ => ActionFn(67);
-        let __sym0 = __pop_Variant18(__symbols);
+        // LAssoc<Cmp, SumExpr> = LAssoc<Cmp, SumExpr>, Cmp, SumExpr => ActionFn(171);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action67::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 27)
+        let __end = __sym2.2;
+        let __nt = super::__action171::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 42)
     }
-    fn __reduce59<
+    fn __reduce85<
         'input,
     >(
         input: &'input str,
@@ -6376,15 +8305,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Extern+ = Extern => ActionFn(68);
-        let __sym0 = __pop_Variant17(__symbols);
+        // LAssoc<Cmp, SumExpr> = SumExpr => ActionFn(66);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action68::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 28)
+        let __nt = super::__action66::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 42)
     }
-    fn __reduce60<
+    fn __reduce86<
         'input,
     >(
         input: &'input str,
@@ -6393,17 +8322,18 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Extern+ = Extern+, Extern => ActionFn(69);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant18(__symbols);
+        // LAssoc<PlusMinus, ProdExpr> = LAssoc<PlusMinus, ProdExpr>, PlusMinus, ProdExpr => ActionFn(172);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action69::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (2, 28)
+        let __end = __sym2.2;
+        let __nt = super::__action172::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 43)
     }
-    fn __reduce61<
+    fn __reduce87<
         'input,
     >(
         input: &'input str,
@@ -6412,22 +8342,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FunDecl = "def", Id, "(", Comma<Spanned<Id>>, ")", ":", Expr => ActionFn(128);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant3(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant15(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant19(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<PlusMinus, ProdExpr> = ProdExpr => ActionFn(64);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action128::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __end = __sym0.2;
+        let __nt = super::__action64::<>(input, __sym0);
         __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (7, 29)
+        (1, 43)
     }
-    fn __reduce62<
+    fn __reduce88<
         'input,
     >(
         input: &'input str,
@@ -6436,18 +8359,35 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FunDefs = FunDecl, "in", Boxed<Expr> => ActionFn(129);
+        // LAssoc<Times, NotExpr> = LAssoc<Times, NotExpr>, Times, NotExpr => ActionFn(173);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
         let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action129::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 30)
+        let __nt = super::__action173::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 44)
     }
-    fn __reduce63<
+    fn __reduce89<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Times, NotExpr> = NotExpr => ActionFn(62);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action62::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 44)
+    }
+    fn __reduce90<
         'input,
     >(
         input: &'input str,
@@ -6456,19 +8396,19 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FunDefs = (<FunDecl> "and")+, FunDecl, "in", Boxed<Expr> => ActionFn(130);
+        // Let = "let", Bindings, "in", Boxed<Expr> => ActionFn(174);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action130::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 30)
+        let __nt = super::__action174::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 45)
     }
-    fn __reduce64<
+    fn __reduce91<
         'input,
     >(
         input: &'input str,
@@ -6477,15 +8417,19 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Id = IdStr => ActionFn(43);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action43::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 31)
+        // Let = "let", ParallelBindings, "in", Boxed<Expr> => ActionFn(175);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action175::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 45)
     }
-    fn __reduce65<
+    fn __reduce92<
         'input,
     >(
         input: &'input str,
@@ -6494,15 +8438,19 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // IdStr = r#"[a-zA-Z_][a-zA-Z0-9_]*"# => ActionFn(42);
+        // Let = "letrec", Bindings, "in", Boxed<Expr> => ActionFn(176);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action42::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant0(__nt), __end));
-        (1, 32)
+        let __end = __sym3.2;
+        let __nt = super::__action176::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 45)
     }
-    fn __reduce66<
+    fn __reduce93<
         'input,
     >(
         input: &'input str,
@@ -6511,22 +8459,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // If = "if", Boxed<Expr>, ":", Boxed<Expr>, "else", ":", Boxed<Expr> => ActionFn(131);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant12(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant12(__symbols);
+        // Log = "&&" => ActionFn(24);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action131::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (7, 33)
+        let __end = __sym0.2;
+        let __nt = super::__action24::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 46)
     }
-    fn __reduce67<
+    fn __reduce94<
         'input,
     >(
         input: &'input str,
@@ -6535,18 +8476,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, SumExpr> = LAssoc<Cmp, SumExpr>, Cmp, SumExpr => ActionFn(132);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Log = "||" => ActionFn(25);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action132::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 34)
+        let __end = __sym0.2;
+        let __nt = super::__action25::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 46)
     }
-    fn __reduce68<
+    fn __reduce95<
         'input,
     >(
         input: &'input str,
@@ -6555,15 +8493,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, SumExpr> = SumExpr => ActionFn(52);
-        let __sym0 = __pop_Variant3(__symbols);
+        // LogExpr = RAssoc<Log, CmpExpr> => ActionFn(20);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 34)
+        let __nt = super::__action20::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 47)
     }
-    fn __reduce69<
+    fn __reduce97<
         'input,
     >(
         input: &'input str,
@@ -6572,18 +8510,17 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, ProdExpr> = LAssoc<PlusMinus, ProdExpr>, PlusMinus, ProdExpr => ActionFn(133);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // NotExpr = "!", BaseExpr => ActionFn(178);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action133::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 35)
+        let __end = __sym1.2;
+        let __nt = super::__action178::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 49)
     }
-    fn __reduce70<
+    fn __reduce98<
         'input,
     >(
         input: &'input str,
@@ -6592,15 +8529,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, ProdExpr> = ProdExpr => ActionFn(50);
-        let __sym0 = __pop_Variant3(__symbols);
+        // NotExpr = BaseExpr => ActionFn(36);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action50::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 35)
+        let __nt = super::__action36::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 49)
     }
-    fn __reduce71<
+    fn __reduce99<
         'input,
     >(
         input: &'input str,
@@ -6609,18 +8546,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExpr> = LAssoc<Times, NotExpr>, Times, NotExpr => ActionFn(134);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Num = r#"[+-]?[0-9]+"# => ActionFn(53);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action134::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 36)
+        let __end = __sym0.2;
+        let __nt = super::__action53::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 50)
     }
-    fn __reduce72<
+    fn __reduce100<
         'input,
     >(
         input: &'input str,
@@ -6629,15 +8563,17 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExpr> = NotExpr => ActionFn(48);
-        let __sym0 = __pop_Variant3(__symbols);
+        // ParallelBindings = (<Binding> "and")+, Binding => ActionFn(13);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 36)
+        let __end = __sym1.2;
+        let __nt = super::__action13::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 51)
     }
-    fn __reduce73<
+    fn __reduce101<
         'input,
     >(
         input: &'input str,
@@ -6646,19 +8582,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Let = "let", Bindings, "in", Boxed<Expr> => ActionFn(135);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Pattern = Num => ActionFn(47);
+        let __sym0 = __pop_Variant24(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action135::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 37)
+        let __end = __sym0.2;
+        let __nt = super::__action47::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 52)
     }
-    fn __reduce74<
+    fn __reduce103<
         'input,
     >(
         input: &'input str,
@@ -6667,15 +8599,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Log = "&&" => ActionFn(20);
+        // PlusMinus = "+" => ActionFn(32);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action20::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 38)
+        let __nt = super::__action32::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 53)
     }
-    fn __reduce75<
+    fn __reduce104<
         'input,
     >(
         input: &'input str,
@@ -6684,15 +8616,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Log = "||" => ActionFn(21);
+        // PlusMinus = "-" => ActionFn(33);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action21::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 38)
+        let __nt = super::__action33::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 53)
     }
-    fn __reduce76<
+    fn __reduce105<
         'input,
     >(
         input: &'input str,
@@ -6701,15 +8633,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LogExpr = RAssoc<Log, CmpExpr> => ActionFn(16);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Prim1 = "add1" => ActionFn(49);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 39)
+        let __nt = super::__action49::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 54)
     }
-    fn __reduce77<
+    fn __reduce106<
         'input,
     >(
         input: &'input str,
@@ -6718,17 +8650,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // NotExpr = "!", BaseExpr => ActionFn(136);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
+        // Prim1 = "sub1" => ActionFn(50);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action136::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 40)
+        let __end = __sym0.2;
+        let __nt = super::__action50::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 54)
     }
-    fn __reduce78<
+    fn __reduce107<
         'input,
     >(
         input: &'input str,
@@ -6737,15 +8667,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // NotExpr = BaseExpr => ActionFn(32);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Prim1 = "error" => ActionFn(51);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action32::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 40)
+        let __nt = super::__action51::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 54)
     }
-    fn __reduce79<
+    fn __reduce108<
         'input,
     >(
         input: &'input str,
@@ -6754,15 +8684,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Num = r#"[+-]?[0-9]+"# => ActionFn(41);
+        // Prim2 = "cmp" => ActionFn(52);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action41::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 41)
+        let __nt = super::__action52::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 55)
     }
-    fn __reduce80<
+    fn __reduce109<
         'input,
     >(
         input: &'input str,
@@ -6771,15 +8701,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "+" => ActionFn(28);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ProdExpr = LAssoc<Times, NotExpr> => ActionFn(23);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 42)
+        let __nt = super::__action23::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 56)
     }
-    fn __reduce81<
+    fn __reduce112<
         'input,
     >(
         input: &'input str,
@@ -6788,15 +8718,18 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "-" => ActionFn(29);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RAssoc<Log, CmpExpr> = CmpExpr, Log, RAssoc<Log, CmpExpr> => ActionFn(180);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action29::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 42)
+        let __end = __sym2.2;
+        let __nt = super::__action180::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 58)
     }
-    fn __reduce82<
+    fn __reduce113<
         'input,
     >(
         input: &'input str,
@@ -6805,15 +8738,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "add1" => ActionFn(39);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RAssoc<Log, CmpExpr> = CmpExpr => ActionFn(68);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action39::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 43)
+        let __nt = super::__action68::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 58)
     }
-    fn __reduce83<
+    fn __reduce114<
         'input,
     >(
         input: &'input str,
@@ -6822,15 +8755,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "sub1" => ActionFn(40);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Spanned<Id> = Id => ActionFn(181);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action40::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 43)
+        let __nt = super::__action181::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 59)
     }
-    fn __reduce84<
+    fn __reduce115<
         'input,
     >(
         input: &'input str,
@@ -6839,15 +8772,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ProdExpr = LAssoc<Times, NotExpr> => ActionFn(19);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Spanned<Id>? = Spanned<Id> => ActionFn(89);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 44)
+        let __nt = super::__action89::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 60)
     }
-    fn __reduce87<
+    fn __reduce116<
         'input,
     >(
         input: &'input str,
@@ -6856,18 +8789,14 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExpr> = CmpExpr, Log, RAssoc<Log, CmpExpr> => ActionFn(138);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action138::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 46)
+        // Spanned<Id>? =  => ActionFn(90);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action90::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (0, 60)
     }
-    fn __reduce88<
+    fn __reduce117<
         'input,
     >(
         input: &'input str,
@@ -6876,15 +8805,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExpr> = CmpExpr => ActionFn(54);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Spanned<Pattern> = Pattern => ActionFn(182);
+        let __sym0 = __pop_Variant25(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 46)
+        let __nt = super::__action182::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 61)
     }
-    fn __reduce89<
+    fn __reduce118<
         'input,
     >(
         input: &'input str,
@@ -6893,15 +8822,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Spanned<Id> = Id => ActionFn(139);
-        let __sym0 = __pop_Variant19(__symbols);
+        // SumExpr = LAssoc<PlusMinus, ProdExpr> => ActionFn(22);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action139::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 47)
+        let __nt = super::__action22::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 62)
     }
-    fn __reduce90<
+    fn __reduce119<
         'input,
     >(
         input: &'input str,
@@ -6910,15 +8839,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Spanned<Id>? = Spanned<Id> => ActionFn(74);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Times = "*" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action74::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (1, 48)
+        let __nt = super::__action34::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 63)
     }
-    fn __reduce91<
+    fn __reduce120<
         'input,
     >(
         input: &'input str,
@@ -6927,14 +8856,22 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Spanned<Id>? =  => ActionFn(75);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action75::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (0, 48)
+        // TopFunDecl = "def", Spanned<Id>, "(", Comma<Spanned<Id>>, ")", ":", Expr => ActionFn(183);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant5(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant19(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym6.2;
+        let __nt = super::__action183::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (7, 64)
     }
-    fn __reduce92<
+    fn __reduce121<
         'input,
     >(
         input: &'input str,
@@ -6943,15 +8880,15 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // SumExpr = LAssoc<PlusMinus, ProdExpr> => ActionFn(18);
-        let __sym0 = __pop_Variant3(__symbols);
+        // TopFunDecl+ = TopFunDecl => ActionFn(83);
+        let __sym0 = __pop_Variant29(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action18::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 49)
+        let __nt = super::__action83::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 65)
     }
-    fn __reduce93<
+    fn __reduce122<
         'input,
     >(
         input: &'input str,
@@ -6960,15 +8897,17 @@ mod __parse__Extern {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Times = "*" => ActionFn(30);
-        let __sym0 = __pop_Variant0(__symbols);
+        // TopFunDecl+ = TopFunDecl+, TopFunDecl => ActionFn(84);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant29(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action30::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 50)
+        let __end = __sym1.2;
+        let __nt = super::__action84::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (2, 65)
     }
-    fn __reduce94<
+    fn __reduce123<
         'input,
     >(
         input: &'input str,
@@ -6978,14 +8917,14 @@ mod __parse__Extern {
     ) -> (usize, usize)
     {
         // __Expr = Expr => ActionFn(1);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action1::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 51)
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 66)
     }
-    fn __reduce96<
+    fn __reduce125<
         'input,
     >(
         input: &'input str,
@@ -6995,12 +8934,12 @@ mod __parse__Extern {
     ) -> (usize, usize)
     {
         // __Prog = Prog => ActionFn(0);
-        let __sym0 = __pop_Variant21(__symbols);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action0::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 53)
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 68)
     }
 }
 #[allow(unused_imports)]
@@ -7012,8 +8951,8 @@ mod __parse__Prog {
 
     use std::str::FromStr;
     use crate::ast::{
-    SurfProg, SurfExpr, SurfBinding, SurfFunDecl, SurfExtDecl,
-    Prog, Expr, Binding, FunDecl, ExtDecl, Prim,
+    SurfProg, SurfExpr, SurfBinding, SurfFunDecl, SurfExtDecl, SurfMatchArm,
+    Prog, Expr, Binding, FunDecl, ExtDecl, MatchArm, Prim, LetKind,
 };
     use crate::span::SrcLoc;
     use lalrpop_util::ParseError;
@@ -7028,299 +8967,375 @@ mod __parse__Prog {
     pub(crate) enum __Symbol<'input>
      {
         Variant0(&'input str),
-        Variant1(SurfBinding),
-        Variant2(alloc::vec::Vec<SurfBinding>),
-        Variant3(SurfExpr),
-        Variant4(alloc::vec::Vec<SurfExpr>),
-        Variant5(SurfFunDecl),
-        Variant6(alloc::vec::Vec<SurfFunDecl>),
-        Variant7((String, SrcLoc)),
-        Variant8(alloc::vec::Vec<(String, SrcLoc)>),
-        Variant9(usize),
-        Variant10(Vec<SurfBinding>),
-        Variant11(bool),
-        Variant12(Box<SurfExpr>),
-        Variant13(Prim),
-        Variant14(Vec<SurfExpr>),
-        Variant15(Vec<(String, SrcLoc)>),
-        Variant16(Option<SurfExpr>),
-        Variant17(SurfExtDecl),
-        Variant18(alloc::vec::Vec<SurfExtDecl>),
-        Variant19(String),
-        Variant20(i64),
-        Variant21(SurfProg),
-        Variant22(Option<(String, SrcLoc)>),
-    }
-    const __ACTION: &[i8] = &[
+        Variant1(((Option<i64>, SrcLoc), SurfExpr)),
+        Variant2(alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>),
+        Variant3(SurfBinding),
+        Variant4(alloc::vec::Vec<SurfBinding>),
+        Variant5(SurfExpr),
+        Variant6(alloc::vec::Vec<SurfExpr>),
+        Variant7(SurfFunDecl),
+        Variant8(alloc::vec::Vec<SurfFunDecl>),
+        Variant9((String, SrcLoc)),
+        Variant10(alloc::vec::Vec<(String, SrcLoc)>),
+        Variant11(usize),
+        Variant12(Option<((Option<i64>, SrcLoc), SurfExpr)>),
+        Variant13(Vec<SurfBinding>),
+        Variant14(bool),
+        Variant15(Box<SurfExpr>),
+        Variant16(Prim),
+        Variant17(Vec<((Option<i64>, SrcLoc), SurfExpr)>),
+        Variant18(Vec<SurfExpr>),
+        Variant19(Vec<(String, SrcLoc)>),
+        Variant20(Option<SurfExpr>),
+        Variant21(SurfExtDecl),
+        Variant22(alloc::vec::Vec<SurfExtDecl>),
+        Variant23(String),
+        Variant24(i64),
+        Variant25(Option<i64>),
+        Variant26(SurfProg),
+        Variant27(Option<(String, SrcLoc)>),
+        Variant28((Option<i64>, SrcLoc)),
+        Variant29((SrcLoc, SrcLoc, SurfFunDecl)),
+        Variant30(alloc::vec::Vec<(SrcLoc, SrcLoc, SurfFunDecl)>),
+    }
+    const __ACTION: &[i16] = &[
         // State 0
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 42, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 55, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 55, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 42, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 55, 0, 0, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 10
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 91, 0, -114, 0, 0, -114, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, -114, -114, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, -114, 92, -114,
         // State 12
-        0, 0, 0, 0, 80, 0, -89, 0, 0, -89, 0, -89, 0, 0, 0, 0, 0, 0, 0, -89, 0, -89, 0, 0, 0, -89, 0, 0, 0, 81,
+        0, 0, 0, 94, -55, 0, -55, 0, 0, -55, 0, -55, 95, 96, 0, 97, 0, 98, 99, 0, -55, 0, -55, -55, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, -55, -55, -55,
         // State 13
-        0, 0, 0, 83, -42, 0, -42, 0, 0, -42, 0, -42, 84, 85, 0, 86, 87, 88, 0, -42, 0, -42, 0, 0, 0, -42, 0, 0, 0, -42,
+        0, 0, 0, -119, -119, 0, -119, 0, 100, -119, 101, -119, -119, -119, 0, -119, 0, -119, -119, 0, -119, 0, -119, -119, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, -119, -119, -119,
         // State 14
-        0, 0, 0, -93, -93, 0, -93, 0, 89, -93, 90, -93, -93, -93, 0, -93, -93, -93, 0, -93, 0, -93, 0, 0, 0, -93, 0, 0, 0, -93,
+        0, 0, 0, -110, -110, 0, -110, 102, -110, -110, -110, -110, -110, -110, 0, -110, 0, -110, -110, 0, -110, 0, -110, -110, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, -110, -110, -110,
         // State 15
-        0, 0, 0, -85, -85, 0, -85, 91, -85, -85, -85, -85, -85, -85, 0, -85, -85, -85, 0, -85, 0, -85, 0, 0, 0, -85, 0, 0, 0, -85,
+        89, 55, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 0, 0, 85, 0, 86, 0, 0, 0, 0, 22, 87, 88, 0, 0, 0,
         // State 16
-        77, 42, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 74, 0, 0, 0, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 17
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 19
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 20
-        0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 21
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 74, 0, 0, 0, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 22
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 0, 0, 85, 0, 86, 0, 0, 0, 0, 22, 87, 88, 0, 0, 0,
         // State 23
-        77, 42, 17, 0, 0, 18, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 24
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 74, 0, 0, 0, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 25
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 74, 0, 0, 0, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 0, 0, 85, 0, 86, 0, 0, 0, 0, 22, 87, 88, 0, 0, 0,
         // State 26
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 74, 0, 0, 0, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 0, 0, 85, 0, 86, 0, 0, 0, 0, 22, 87, 88, 0, 0, 0,
         // State 27
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 0, 0, 85, 0, 86, 0, 0, 0, 0, 22, 87, 88, 0, 0, 0,
         // State 28
-        0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 29
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 30
-        77, 42, 17, 0, 0, 18, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 31
-        0, 42, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 32
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 33
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 34
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        0, 55, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 35
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 36
-        77, 42, 17, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 19, 0, 0, 74, 20, 0, 21, 75, 76, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 37
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, -60, 0, 0, 0, 0, 0, 0, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, -61, 0, 0, 0, 0, 0, 0, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 40
-        0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        89, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57,
         // State 41
-        0, 0, 0, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, 0, -66, 0, -66, 0, 0, 0, -66, 0, 0, 0, -66,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 42
-        0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        89, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59,
         // State 43
-        0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 44
-        0, 0, 0, 0, 0, 0, -90, 0, 0, -90, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 45
-        0, 0, 0, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, 0, -65, 0, -65, 0, 0, 0, -65, 0, 0, 0, -65,
+        89, 55, 16, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 84, 18, 0, 85, 0, 86, 19, 0, 20, 21, 22, 87, 88, 0, 0, 0,
         // State 46
-        0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 47
-        0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 48
-        0, 0, 0, 0, 0, 0, -47, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 49
-        0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 51
-        0, 0, 0, 0, 0, 0, -49, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -115, -115, 0, 0, -115, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, -57, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, 0, -82, -82, 0, -82, 0, -82, -82, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, -82, -82, -82,
         // State 53
-        0, -19, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, 0, -83, 0, -83, -83, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, -83, -83, -83,
         // State 55
-        0, -20, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 56
-        0, 0, 0, -79, -79, 0, -79, -79, -79, -79, -79, -79, -79, -79, 0, -79, -79, -79, 0, -79, 0, -79, 0, 0, 0, -79, 0, 0, 0, -79,
+        0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 57
-        0, 0, 0, 0, 0, 0, -54, 0, 0, -54, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, 0, -54, 0, 0, 0, -54, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -64, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 58
-        0, 0, 0, -25, -25, 0, -25, -25, -25, -25, -25, -25, -25, -25, 0, -25, -25, -25, 0, -25, 0, -25, 0, 0, 0, -25, 0, 0, 0, -25,
+        0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 59
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -66, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 60
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 61
-        0, 0, 0, 0, 0, 0, -53, 0, 0, -53, 0, -53, 0, 0, 0, 0, 0, 0, 0, -53, 0, -53, 0, 0, 0, -53, 0, 0, 0, 0,
+        0, -27, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 62
-        0, 0, 0, -23, -23, 24, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, 0, -23, 0, -23, 0, 0, 0, -23, 0, 0, 0, -23,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 63
-        0, 0, 0, 0, 0, 0, -52, 0, 0, -52, 0, -52, 0, 0, 0, 0, 0, 0, 0, -52, 0, -52, 0, 0, 0, -52, 0, 0, 0, 0,
+        0, -28, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 64
-        0, 0, 0, 0, 0, 0, -51, 0, 0, -51, 0, -51, 0, 0, 0, 0, 0, 0, 0, -51, 0, -51, 0, 0, 0, -51, 0, 0, 0, 0,
+        0, 0, 0, -99, -99, 0, -99, -99, -99, -99, -99, -99, -99, -99, 0, -99, 0, -99, -99, 0, -99, 0, -99, -99, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, -99, -99, -99,
         // State 65
-        0, 0, 0, 0, 0, 0, -29, 0, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, -29, 0, -29, 0, 0, 0, -29, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -71, 0, 0, -71, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, -71, -71, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, -71, 0, -71,
         // State 66
-        0, 0, 0, -73, -73, 0, -73, -73, -73, -73, -73, -73, -73, -73, 0, -73, -73, -73, 0, -73, 0, -73, 0, 0, 0, -73, 0, 0, 0, -73,
+        0, 0, 0, -36, -36, 0, -36, -36, -36, -36, -36, -36, -36, -36, 0, -36, 0, -36, -36, 0, -36, 0, -36, -36, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, -36, -36, -36,
         // State 67
-        0, 0, 0, -24, -24, 0, -24, -24, -24, -24, -24, -24, -24, -24, 0, -24, -24, -24, 0, -24, 0, -24, 0, 0, 0, -24, 0, 0, 0, -24,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 68
-        0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 69
-        0, 0, 0, -71, -71, 0, -71, 0, -71, -71, -71, -71, -71, -71, 0, -71, -71, -71, 0, -71, 0, -71, 0, 0, 0, -71, 0, 0, 0, -71,
+        0, 0, 0, 0, 0, 0, -70, 0, 0, -70, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, -70, -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, -70, 0, -70,
         // State 70
-        0, 0, 0, 0, 0, 0, -77, 0, 0, -77, 0, -77, 0, 0, 0, 0, 0, 0, 0, -77, 0, -77, 0, 0, 0, -77, 0, 0, 0, 0,
+        0, 0, 0, -34, -34, 25, -34, -34, -34, -34, -34, -34, -34, -34, 0, -34, 0, -34, -34, 0, -34, 0, -34, -34, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, -34, -34, -34,
         // State 71
-        0, 0, 0, -69, -69, 0, -69, 0, 0, -69, 0, -69, -69, -69, 0, -69, -69, -69, 0, -69, 0, -69, 0, 0, 0, -69, 0, 0, 0, -69,
+        0, 0, 0, 0, 0, 0, -69, 0, 0, -69, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, -69, 0, -69, -69, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, -69, 0, -69,
         // State 72
-        0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -68, 0, 0, -68, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, -68, -68, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, -68, 0, -68,
         // State 73
-        0, 0, 0, -34, -34, 0, -34, -34, -34, -34, -34, -34, -34, -34, 0, -34, -34, -34, 0, -34, 0, -34, 0, 0, 0, -34, 0, 0, 0, -34,
+        0, 0, 0, 0, 0, 0, -42, 0, 0, -42, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, -42, -42, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, -42, 0, -42,
         // State 74
-        0, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -40, -40, 0, -40, -40, -40, -40, -40, -40, -40, -40, 0, -40, 0, -40, -40, 0, -40, 0, -40, -40, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, -40, -40, -40,
         // State 75
-        0, 0, 0, -33, -33, 0, -33, -33, -33, -33, -33, -33, -33, -33, 0, -33, -33, -33, 0, -33, 0, -33, 0, 0, 0, -33, 0, 0, 0, -33,
+        0, 0, 0, -90, -90, 0, -90, -90, -90, -90, -90, -90, -90, -90, 0, -90, 0, -90, -90, 0, -90, 0, -90, -90, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, -90, -90, -90,
         // State 76
-        0, 0, 0, -80, -80, 0, -80, -80, -80, -80, -80, -80, -80, -80, 0, -80, -80, -80, 0, -80, 0, -80, 0, 0, 0, -80, 0, 0, 0, -80,
+        0, 0, 0, -35, -35, 0, -35, -35, -35, -35, -35, -35, -35, -35, 0, -35, 0, -35, -35, 0, -35, 0, -35, -35, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, -35, -35, -35,
         // State 77
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 78
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 79
-        -75, -75, -75, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, -75, 0, 0, 0, -75, -75, 0,
+        0, 0, 0, -88, -88, 0, -88, 0, -88, -88, -88, -88, -88, -88, 0, -88, 0, -88, -88, 0, -88, 0, -88, -88, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, -88, -88, -88,
         // State 80
-        -76, -76, -76, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, -76, 0, 0, 0, -76, -76, 0,
+        0, 0, 0, 0, 0, 0, -96, 0, 0, -96, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, -96, -96, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, -96, 0, -96,
         // State 81
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -86, -86, 0, -86, 0, 0, -86, 0, -86, -86, -86, 0, -86, 0, -86, -86, 0, -86, 0, -86, -86, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, -86, -86, -86,
         // State 82
-        -41, -41, -41, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, -41, 0, 0, 0, -41, -41, 0,
+        0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 83
-        -36, -36, -36, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, -36, 0, 0, 0, -36, -36, 0,
+        0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 84
-        -37, -37, -37, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, -37, 0, 0, 0, -37, -37, 0,
+        0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 85
-        -40, -40, -40, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, -40, 0, 0, 0, -40, -40, 0,
+        0, 0, 0, -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, 0, -47, 0, -47, -47, 0, -47, 0, -47, -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, -47, -47, -47,
         // State 86
-        -38, -38, -38, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, -38, 0, 0, 0, -38, -38, 0,
+        0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 87
-        -39, -39, -39, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, -39, 0, 0, 0, -39, -39, 0,
+        0, 0, 0, -46, -46, 0, -46, -46, -46, -46, -46, -46, -46, -46, 0, -46, 0, -46, -46, 0, -46, 0, -46, -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, -46, -46, -46,
         // State 88
-        -81, -81, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0, -81, 0, 0, 0, -81, -81, 0,
+        0, 0, 0, -100, -100, 0, -100, -100, -100, -100, -100, -100, -100, -100, 0, -100, -100, -100, -100, 0, -100, 0, -100, -100, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, -100, -100, -100,
         // State 89
-        -82, -82, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, -82, 0, 0, 0, -82, -82, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 90
-        -94, -94, -94, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0, -94, 0, 0, 0, -94, -94, 0,
+        -94, -94, -94, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, -94, 0, 0, -94, 0, -94, 0, 0, 0, 0, -94, -94, -94, 0, 0, 0,
         // State 91
-        0, 0, 0, -78, -78, 0, -78, -78, -78, -78, -78, -78, -78, -78, 0, -78, -78, -78, 0, -78, 0, -78, 0, 0, 0, -78, 0, 0, 0, -78,
+        -95, -95, -95, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, -95, 0, 0, -95, 0, -95, 0, 0, 0, 0, -95, -95, -95, 0, 0, 0,
         // State 92
-        0, 0, 0, 0, 0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 93
-        0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -54, -54, -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, -54, 0, 0, -54, 0, -54, 0, 0, 0, 0, -54, -54, -54, 0, 0, 0,
         // State 94
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -49, -49, -49, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, -49, 0, 0, -49, 0, -49, 0, 0, 0, 0, -49, -49, -49, 0, 0, 0,
         // State 95
-        0, 0, 0, 0, 0, 0, -35, 0, 0, -35, 0, -35, 0, 0, 0, 0, 0, 0, 0, -35, 0, -35, 0, 0, 0, -35, 0, 0, 0, 0,
+        -50, -50, -50, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, -50, 0, 0, -50, 0, -50, 0, 0, 0, 0, -50, -50, -50, 0, 0, 0,
         // State 96
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0,
+        -53, -53, -53, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, -53, 0, 0, -53, 0, -53, 0, 0, 0, 0, -53, -53, -53, 0, 0, 0,
         // State 97
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0,
+        -51, -51, -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, -51, 0, 0, -51, 0, -51, 0, 0, 0, 0, -51, -51, -51, 0, 0, 0,
         // State 98
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -52, -52, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, -52, 0, 0, -52, 0, -52, 0, 0, 0, 0, -52, -52, -52, 0, 0, 0,
         // State 99
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -104, -104, -104, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, -104, 0, 0, -104, 0, -104, 0, 0, 0, 0, -104, -104, -104, 0, 0, 0,
         // State 100
-        0, 0, 0, 0, 0, 0, -88, 0, 0, -88, 0, -88, 0, 0, 0, 0, 0, 0, 0, -88, 0, -88, 0, 0, 0, -88, 0, 0, 0, 0,
+        -105, -105, -105, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, -105, 0, 0, -105, 0, -105, 0, 0, 0, 0, -105, -105, -105, 0, 0, 0,
         // State 101
-        0, 0, 0, 0, 0, 0, -63, 0, 0, -63, 0, -63, 0, 0, 0, 0, 0, 0, 0, -63, 0, -63, 0, 0, 0, -63, 0, 0, 0, 0,
+        -120, -120, -120, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, 0, -120, 0, 0, -120, 0, -120, 0, 0, 0, 0, -120, -120, -120, 0, 0, 0,
         // State 102
-        0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -98, -98, 0, -98, -98, -98, -98, -98, -98, -98, -98, 0, -98, 0, -98, -98, 0, -98, 0, -98, -98, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, -98, -98, -98,
         // State 103
-        0, 0, 0, 0, 0, 0, -43, 0, 0, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 104
-        0, 0, 0, -68, -68, 0, -68, 0, 0, -68, 0, -68, -68, -68, 0, -68, -68, -68, 0, -68, 0, -68, 0, 0, 0, -68, 0, 0, 0, -68,
+        0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 105
-        0, 0, 0, -70, -70, 0, -70, 0, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, -70, 0, -70, 0, 0, 0, -70, 0, 0, 0, -70,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 106
-        0, 0, 0, -72, -72, 0, -72, -72, -72, -72, -72, -72, -72, -72, 0, -72, -72, -72, 0, -72, 0, -72, 0, 0, 0, -72, 0, 0, 0, -72,
+        0, 0, 0, 0, 0, 0, -48, 0, 0, -48, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, -48, -48, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, -48, 0, -48,
         // State 107
-        0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 108
-        0, 0, 0, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, 0, -28, 0, -28, 0, 0, 0, -28, 0, 0, 0, -28,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 109
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 110
-        0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 111
-        0, 0, 0, 0, 0, 0, -64, 0, 0, -64, 0, -64, 0, 0, 0, 0, 0, 0, 0, -64, 0, -64, 0, 0, 0, -64, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 112
-        0, 0, 0, 0, 0, 0, -45, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 113
-        0, 0, 0, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, 0, -27, 0, -27, 0, 0, 0, -27, 0, 0, 0, -27,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0,
         // State 114
-        -9, -9, -9, 0, 0, -9, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, -9, 0, 0, -9, -9, 0, -9, -9, -9, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 115
-        0, 0, 0, -26, -26, 0, -26, -26, -26, -26, -26, -26, -26, -26, 0, -26, -26, -26, 0, -26, 0, -26, 0, 0, 0, -26, 0, 0, 0, -26,
+        0, 0, 0, 0, 0, 0, -113, 0, 0, -113, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, -113, -113, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, -113, 0, -113,
         // State 116
-        0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -80, 0, 0, -80, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, -80, 0, -80, -80, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, -80, 0, -80,
         // State 117
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 118
-        0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -60, 0, 0, 133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 119
-        0, 0, 0, 0, 0, 0, -74, 0, 0, -74, 0, -74, 0, 0, 0, 0, 0, 0, 0, -74, 0, -74, 0, 0, 0, -74, 0, 0, 0, 0,
+        0, 0, 0, -85, -85, 0, -85, 0, 0, -85, 0, -85, -85, -85, 0, -85, 0, -85, -85, 0, -85, 0, -85, -85, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, -85, -85, -85,
         // State 120
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0,
+        0, 0, 0, -87, -87, 0, -87, 0, -87, -87, -87, -87, -87, -87, 0, -87, 0, -87, -87, 0, -87, 0, -87, -87, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, -87, -87, -87,
         // State 121
-        -10, -10, -10, 0, 0, -10, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, -10, 0, 0, -10, -10, 0, -10, -10, -10, 0,
+        0, 0, 0, -89, -89, 0, -89, -89, -89, -89, -89, -89, -89, -89, 0, -89, 0, -89, -89, 0, -89, 0, -89, -89, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, -89, -89, -89,
         // State 122
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 123
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 124
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0,
+        0, 0, 0, -41, -41, 0, -41, -41, -41, -41, -41, -41, -41, -41, 0, -41, 0, -41, -41, 0, -41, 0, -41, -41, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, -41, -41, -41,
         // State 125
-        0, 0, 0, 0, 0, 0, -67, 0, 0, -67, 0, -67, 0, 0, 0, 0, 0, 0, 0, -67, 0, -67, 0, 0, 0, -67, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 126
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 127
+        0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 129
+        0, 0, 0, 0, 0, 0, -81, 0, 0, -81, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0, -81, -81, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, -81, 0, -81,
+        // State 130
+        0, 0, 0, 0, 0, 0, -62, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 131
+        0, 0, 0, -39, -39, 0, -39, -39, -39, -39, -39, -39, -39, -39, 0, -39, 0, -39, -39, 0, -39, 0, -39, -39, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, -39, -39, -39,
+        // State 132
+        -17, -17, -17, 0, 0, -17, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, -17, -17, 0, -17, 0, -17, -17, 0, -17, -17, -17, -17, -17, 0, 0, 0,
+        // State 133
+        0, 0, 0, -37, -37, 0, -37, -37, -37, -37, -37, -37, -37, -37, 0, -37, 0, -37, -37, 0, -37, 0, -37, -37, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, -37, -37, -37,
+        // State 134
+        0, 0, 0, 0, 0, 0, 151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 135
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 136
+        0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 137
+        0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 138
+        0, 0, 0, 0, 0, 0, -91, 0, 0, -91, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, -91, -91, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, -91, 0, -91,
+        // State 139
+        0, 0, 0, 0, 0, 0, -92, 0, 0, -92, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, -92, -92, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, -92, 0, -92,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, -93, 0, 0, -93, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, -93, -93, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, -93, 0, -93,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56,
+        // State 143
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 155,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        -18, -18, -18, 0, 0, -18, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, -18, -18, 0, -18, 0, -18, -18, 0, -18, -18, -18, -18, -18, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58,
+        // State 153
+        -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4,
+        // State 154
+        0, 0, 0, -97, -97, 0, -97, -97, -97, -97, -97, -97, -97, -97, 0, -97, 0, -97, -97, 0, -97, 0, -97, -97, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, -97, -97, -97,
+        // State 155
+        0, 0, 0, -38, -38, 0, -38, -38, -38, -38, -38, -38, -38, -38, 0, -38, 0, -38, -38, 0, -38, 0, -38, -38, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, -38, -38, -38,
+        // State 156
+        -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5,
+        // State 157
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31,
+        // State 158
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 159
+        0, 0, 0, 0, 0, 0, -84, 0, 0, -84, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, -84, -84, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, -84, 0, -84,
     ];
-    fn __action(state: i8, integer: usize) -> i8 {
-        __ACTION[(state as usize) * 30 + integer]
+    fn __action(state: i16, integer: usize) -> i16 {
+        __ACTION[(state as usize) * 37 + integer]
     }
-    const __EOF_ACTION: &[i8] = &[
+    const __EOF_ACTION: &[i16] = &[
         // State 0
         0,
         // State 1
         0,
         // State 2
-        0,
+        -111,
         // State 3
         0,
         // State 4
         0,
         // State 5
-        0,
+        -112,
         // State 6
         0,
         // State 7
@@ -7332,15 +9347,15 @@ mod __parse__Prog {
         // State 10
         0,
         // State 11
-        0,
+        -114,
         // State 12
-        -89,
+        -55,
         // State 13
-        -42,
+        -119,
         // State 14
-        -93,
+        -110,
         // State 15
-        -85,
+        0,
         // State 16
         0,
         // State 17
@@ -7386,13 +9401,13 @@ mod __parse__Prog {
         // State 37
         0,
         // State 38
-        -97,
+        0,
         // State 39
         0,
         // State 40
         0,
         // State 41
-        -66,
+        0,
         // State 42
         0,
         // State 43
@@ -7400,79 +9415,79 @@ mod __parse__Prog {
         // State 44
         0,
         // State 45
-        -65,
+        0,
         // State 46
         0,
         // State 47
-        0,
+        -126,
         // State 48
-        0,
+        -122,
         // State 49
         0,
         // State 50
-        0,
+        -123,
         // State 51
         0,
         // State 52
-        0,
+        -82,
         // State 53
         0,
         // State 54
-        0,
+        -83,
         // State 55
         0,
         // State 56
-        -79,
+        0,
         // State 57
-        -54,
+        0,
         // State 58
-        -25,
+        0,
         // State 59
-        -86,
+        0,
         // State 60
         0,
         // State 61
-        -53,
+        0,
         // State 62
-        -23,
+        0,
         // State 63
-        -52,
+        0,
         // State 64
-        -51,
+        -99,
         // State 65
-        -29,
+        -71,
         // State 66
-        -73,
+        -36,
         // State 67
-        -24,
+        -121,
         // State 68
         0,
         // State 69
-        -71,
+        -70,
         // State 70
-        -77,
+        -34,
         // State 71
         -69,
         // State 72
-        0,
+        -68,
         // State 73
-        -34,
+        -42,
         // State 74
-        0,
+        -40,
         // State 75
-        -33,
+        -90,
         // State 76
-        -80,
+        -35,
         // State 77
-        -87,
+        0,
         // State 78
         0,
         // State 79
-        0,
+        -88,
         // State 80
-        0,
+        -96,
         // State 81
-        0,
+        -86,
         // State 82
         0,
         // State 83
@@ -7480,19 +9495,19 @@ mod __parse__Prog {
         // State 84
         0,
         // State 85
-        0,
+        -47,
         // State 86
         0,
         // State 87
-        0,
+        -46,
         // State 88
-        0,
+        -100,
         // State 89
         0,
         // State 90
         0,
         // State 91
-        -78,
+        0,
         // State 92
         0,
         // State 93
@@ -7500,7 +9515,7 @@ mod __parse__Prog {
         // State 94
         0,
         // State 95
-        -35,
+        0,
         // State 96
         0,
         // State 97
@@ -7510,157 +9525,257 @@ mod __parse__Prog {
         // State 99
         0,
         // State 100
-        -88,
+        0,
         // State 101
-        -63,
-        // State 102
         0,
+        // State 102
+        -98,
         // State 103
         0,
         // State 104
-        -68,
+        0,
         // State 105
-        -70,
+        0,
         // State 106
-        -72,
+        -48,
         // State 107
         0,
         // State 108
-        -28,
+        0,
         // State 109
         0,
         // State 110
         0,
         // State 111
-        -64,
+        0,
         // State 112
         0,
         // State 113
-        -27,
+        0,
         // State 114
         0,
         // State 115
-        -26,
+        -113,
         // State 116
-        0,
+        -80,
         // State 117
         0,
         // State 118
         0,
         // State 119
-        -74,
+        -85,
         // State 120
-        0,
+        -87,
         // State 121
-        0,
+        -89,
         // State 122
         0,
         // State 123
         0,
         // State 124
-        0,
+        -41,
         // State 125
-        -67,
+        0,
+        // State 126
+        0,
+        // State 127
+        0,
+        // State 128
+        0,
+        // State 129
+        -81,
+        // State 130
+        0,
+        // State 131
+        -39,
+        // State 132
+        0,
+        // State 133
+        -37,
+        // State 134
+        0,
+        // State 135
+        0,
+        // State 136
+        0,
+        // State 137
+        0,
+        // State 138
+        -91,
+        // State 139
+        -92,
+        // State 140
+        0,
+        // State 141
+        -93,
+        // State 142
+        0,
+        // State 143
+        0,
+        // State 144
+        0,
+        // State 145
+        0,
+        // State 146
+        0,
+        // State 147
+        0,
+        // State 148
+        0,
+        // State 149
+        0,
+        // State 150
+        0,
+        // State 151
+        0,
+        // State 152
+        0,
+        // State 153
+        0,
+        // State 154
+        -97,
+        // State 155
+        -38,
+        // State 156
+        0,
+        // State 157
+        0,
+        // State 158
+        0,
+        // State 159
+        -84,
     ];
-    fn __goto(state: i8, nt: usize) -> i8 {
+    fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 28,
+            2 => 42,
             5 => 30,
-            8 => 11,
-            11 => 8,
-            14 => match state {
-                16 => 91,
-                _ => 56,
-            },
-            15 => 57,
-            16 => match state {
-                28 => 109,
-                _ => 96,
-            },
-            17 => 97,
-            18 => 58,
+            7 => 31,
+            10 => 33,
+            13 => 10,
+            16 => 8,
             19 => match state {
-                22 => 101,
-                29 => 111,
-                32 => 117,
-                33 => 119,
-                36 => 125,
-                _ => 94,
+                42 => 152,
+                _ => 142,
             },
-            20 => 24,
-            21 => 12,
-            22 => 102,
+            21 => match state {
+                15 => 102,
+                _ => 64,
+            },
+            22 => 65,
             23 => match state {
-                31 => 116,
-                _ => 47,
+                20 => 111,
+                30 => 125,
+                31 => 126,
+                _ => 107,
             },
             24 => match state {
-                9 => 59,
-                10 => 77,
-                17 => 92,
-                23 => 103,
-                27 => 107,
-                30 => 112,
-                34 => 120,
-                35 => 124,
-                _ => 95,
+                20 => 112,
+                _ => 108,
             },
+            25 => 66,
             26 => match state {
-                1 => 39,
-                _ => 37,
-            },
-            28 => 1,
-            29 => match state {
-                11 => 78,
-                _ => 60,
+                21 => 113,
+                23 => 116,
+                32 => 129,
+                35 => 135,
+                36 => 138,
+                37 => 139,
+                39 => 141,
+                45 => 159,
+                _ => 105,
             },
-            30 => 61,
+            27 => 25,
+            28 => 11,
+            29 => 143,
+            30 => 117,
             31 => match state {
-                5..=8 | 20 | 28 | 31 => 44,
-                18 => 93,
-                _ => 62,
+                7 => 58,
+                34 => 134,
+                _ => 56,
             },
             32 => match state {
-                2 => 40,
-                3 => 42,
-                4 => 43,
-                _ => 45,
+                9 => 67,
+                16 => 103,
+                24 => 118,
+                28 => 122,
+                29 => 123,
+                33 => 130,
+                38 => 140,
+                41 => 149,
+                43 => 157,
+                44 => 158,
+                _ => 106,
             },
-            33 => 63,
-            34 => 13,
-            35 => 14,
-            36 => 15,
-            37 => 64,
-            38 => 21,
-            39 => 65,
-            40 => match state {
-                26 => 106,
-                _ => 66,
+            34 => match state {
+                1 => 49,
+                _ => 46,
             },
-            41 => 67,
-            42 => 25,
-            43 => 68,
-            44 => match state {
-                25 => 105,
-                _ => 69,
+            36 => 1,
+            37 => match state {
+                10 => 89,
+                _ => 68,
             },
-            45 => 38,
-            46 => match state {
-                21 => 100,
+            38 => 69,
+            39 => match state {
+                3 | 6..=8 | 19..=20 | 30..=31 | 34 => 51,
+                17 => 104,
                 _ => 70,
             },
-            47 => match state {
-                5 => 46,
-                7 => 49,
-                8 => 51,
-                20 | 28 => 98,
-                _ => 48,
+            40 => match state {
+                4 => 55,
+                40 | 42 => 144,
+                _ => 52,
             },
+            41 => 71,
+            42 => 12,
+            43 => 13,
+            44 => 14,
+            45 => 72,
+            46 => 22,
+            47 => 73,
+            48 => 74,
             49 => match state {
-                24 => 104,
-                _ => 71,
+                27 => 121,
+                _ => 75,
+            },
+            50 => match state {
+                40 | 42 => 145,
+                _ => 76,
+            },
+            51 => 109,
+            52 => 146,
+            53 => 26,
+            54 => 77,
+            55 => 78,
+            56 => match state {
+                26 => 120,
+                _ => 79,
+            },
+            57 => 47,
+            58 => match state {
+                22 => 115,
+                _ => 80,
+            },
+            59 => match state {
+                3 => 53,
+                8 => 59,
+                19..=20 | 30..=31 => 110,
+                _ => 57,
+            },
+            61 => 147,
+            62 => match state {
+                25 => 119,
+                _ => 81,
+            },
+            63 => 27,
+            64 => match state {
+                0..=1 => 48,
+                _ => 50,
+            },
+            65 => match state {
+                1 => 5,
+                _ => 2,
             },
-            50 => 26,
             _ => 0,
         }
     }
@@ -7682,22 +9797,29 @@ mod __parse__Prog {
         r###""<=""###,
         r###""=""###,
         r###""==""###,
+        r###""=>""###,
         r###"">""###,
         r###"">=""###,
         r###""add1""###,
         r###""and""###,
+        r###""cmp""###,
         r###""def""###,
         r###""else""###,
+        r###""error""###,
         r###""extern""###,
         r###""false""###,
         r###""if""###,
         r###""in""###,
         r###""let""###,
+        r###""letrec""###,
+        r###""match""###,
         r###""sub1""###,
         r###""true""###,
+        r###""{""###,
         r###""||""###,
+        r###""}""###,
     ];
-    fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
+    fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
             let next_state = __action(__state, index);
             if next_state == 0 {
@@ -7710,7 +9832,7 @@ mod __parse__Prog {
     fn __expected_tokens_from_states<
         'input,
     >(
-        __states: &[i8],
+        __states: &[i16],
         _: core::marker::PhantomData<(&'input ())>,
     ) -> alloc::vec::Vec<alloc::string::String>
     {
@@ -7737,9 +9859,9 @@ mod __parse__Prog {
         type TokenIndex = usize;
         type Symbol = __Symbol<'input>;
         type Success = SurfProg;
-        type StateIndex = i8;
-        type Action = i8;
-        type ReduceIndex = i8;
+        type StateIndex = i16;
+        type Action = i16;
+        type ReduceIndex = i16;
         type NonterminalIndex = usize;
 
         #[inline]
@@ -7758,22 +9880,22 @@ mod __parse__Prog {
         }
 
         #[inline]
-        fn action(&self, state: i8, integer: usize) -> i8 {
+        fn action(&self, state: i16, integer: usize) -> i16 {
             __action(state, integer)
         }
 
         #[inline]
-        fn error_action(&self, state: i8) -> i8 {
-            __action(state, 30 - 1)
+        fn error_action(&self, state: i16) -> i16 {
+            __action(state, 37 - 1)
         }
 
         #[inline]
-        fn eof_action(&self, state: i8) -> i8 {
+        fn eof_action(&self, state: i16) -> i16 {
             __EOF_ACTION[state as usize]
         }
 
         #[inline]
-        fn goto(&self, state: i8, nt: usize) -> i8 {
+        fn goto(&self, state: i16, nt: usize) -> i16 {
             __goto(state, nt)
         }
 
@@ -7781,11 +9903,11 @@ mod __parse__Prog {
             __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
         }
 
-        fn expected_tokens(&self, state: i8) -> alloc::vec::Vec<alloc::string::String> {
+        fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
             __expected_tokens(state)
         }
 
-        fn expected_tokens_from_states(&self, states: &[i8]) -> alloc::vec::Vec<alloc::string::String> {
+        fn expected_tokens_from_states(&self, states: &[i16]) -> alloc::vec::Vec<alloc::string::String> {
             __expected_tokens_from_states(states, core::marker::PhantomData::<(&())>)
         }
 
@@ -7804,9 +9926,9 @@ mod __parse__Prog {
 
         fn reduce(
             &mut self,
-            action: i8,
+            action: i16,
             start_location: Option<&Self::Location>,
-            states: &mut alloc::vec::Vec<i8>,
+            states: &mut alloc::vec::Vec<i16>,
             symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
         ) -> Option<__state_machine::ParseResult<Self>> {
             __reduce(
@@ -7819,7 +9941,7 @@ mod __parse__Prog {
             )
         }
 
-        fn simulate_reduce(&self, action: i8) -> __state_machine::SimulatedReduce<Self> {
+        fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
             __simulate_reduce(action, core::marker::PhantomData::<(&())>)
         }
     }
@@ -7862,6 +9984,13 @@ mod __parse__Prog {
             Token(27, _) if true => Some(27),
             Token(28, _) if true => Some(28),
             Token(29, _) if true => Some(29),
+            Token(30, _) if true => Some(30),
+            Token(31, _) if true => Some(31),
+            Token(32, _) if true => Some(32),
+            Token(33, _) if true => Some(33),
+            Token(34, _) if true => Some(34),
+            Token(35, _) if true => Some(35),
+            Token(36, _) if true => Some(36),
             _ => None,
         }
     }
@@ -7874,8 +10003,8 @@ mod __parse__Prog {
     ) -> __Symbol<'input>
     {
         #[allow(clippy::manual_range_patterns)]match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 => match __token {
-                Token(0, __tok0) | Token(1, __tok0) | Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) if true => __Symbol::Variant0(__tok0),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 => match __token {
+                Token(0, __tok0) | Token(1, __tok0) | Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(32, __tok0) | Token(33, __tok0) | Token(34, __tok0) | Token(35, __tok0) | Token(36, __tok0) if true => __Symbol::Variant0(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -7884,7 +10013,7 @@ mod __parse__Prog {
     fn __simulate_reduce<
         'input,
     >(
-        __reduce_index: i8,
+        __reduce_index: i16,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> __state_machine::SimulatedReduce<__StateMachine<'input>>
     {
@@ -7921,551 +10050,725 @@ mod __parse__Prog {
             }
             5 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 3,
+                    states_to_pop: 2,
+                    nonterminal_produced: 3,
+                }
+            }
+            6 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 4,
+                }
+            }
+            7 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 4,
+                }
+            }
+            8 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 5,
+                }
+            }
+            9 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 5,
+                }
+            }
+            10 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 6,
+                }
+            }
+            11 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 7,
+                }
+            }
+            12 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 7,
+                }
+            }
+            13 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 8,
+                }
+            }
+            14 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 9,
+                }
+            }
+            15 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 9,
+                }
+            }
+            16 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 10,
+                }
+            }
+            17 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 10,
+                }
+            }
+            18 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 11,
+                }
+            }
+            19 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 12,
+                }
+            }
+            20 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 12,
+                }
+            }
+            21 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 13,
+                }
+            }
+            22 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
+                }
+            }
+            23 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 14,
+                }
+            }
+            24 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 15,
+                }
+            }
+            25 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 15,
+                }
+            }
+            26 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 16,
+                }
+            }
+            27 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 16,
+                }
+            }
+            28 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 17,
+                }
+            }
+            29 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 18,
+                }
+            }
+            30 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 19,
+                }
+            }
+            31 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
                 }
             }
-            6 => {
+            32 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 0,
-                    nonterminal_produced: 4,
+                    nonterminal_produced: 20,
                 }
             }
-            7 => {
+            33 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 4,
+                    nonterminal_produced: 21,
                 }
             }
-            8 => {
+            34 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 5,
+                    states_to_pop: 1,
+                    nonterminal_produced: 21,
                 }
             }
-            9 => {
+            35 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 5,
+                    states_to_pop: 1,
+                    nonterminal_produced: 21,
                 }
             }
-            10 => {
+            36 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 6,
+                    states_to_pop: 4,
+                    nonterminal_produced: 21,
                 }
             }
-            11 => {
+            37 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 7,
+                    states_to_pop: 6,
+                    nonterminal_produced: 21,
                 }
             }
-            12 => {
+            38 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 7,
+                    states_to_pop: 4,
+                    nonterminal_produced: 21,
                 }
             }
-            13 => {
+            39 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 8,
+                    states_to_pop: 1,
+                    nonterminal_produced: 21,
                 }
             }
-            14 => {
+            40 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 8,
+                    nonterminal_produced: 21,
                 }
             }
-            15 => {
+            41 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 9,
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
                 }
             }
-            16 => {
+            42 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 10,
+                    states_to_pop: 3,
+                    nonterminal_produced: 23,
                 }
             }
-            17 => {
+            43 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 10,
+                    nonterminal_produced: 24,
                 }
             }
-            18 => {
+            44 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 11,
+                    nonterminal_produced: 24,
                 }
             }
-            19 => {
+            45 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 11,
+                    states_to_pop: 1,
+                    nonterminal_produced: 25,
                 }
             }
-            20 => {
+            46 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 12,
+                    states_to_pop: 1,
+                    nonterminal_produced: 25,
                 }
             }
-            21 => {
+            47 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 13,
+                    states_to_pop: 1,
+                    nonterminal_produced: 26,
                 }
             }
-            22 => {
+            48 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    nonterminal_produced: 27,
                 }
             }
-            23 => {
+            49 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    nonterminal_produced: 27,
                 }
             }
-            24 => {
+            50 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    nonterminal_produced: 27,
                 }
             }
-            25 => {
+            51 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 14,
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
                 }
             }
-            26 => {
+            52 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 14,
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
                 }
             }
-            27 => {
+            53 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 14,
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
                 }
             }
-            28 => {
+            54 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 28,
                 }
             }
-            29 => {
+            55 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
                 }
             }
-            30 => {
+            56 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 0,
+                    nonterminal_produced: 29,
                 }
             }
-            31 => {
+            57 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 17,
+                    nonterminal_produced: 29,
                 }
             }
-            32 => {
+            58 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 29,
                 }
             }
-            33 => {
+            59 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    nonterminal_produced: 30,
                 }
             }
-            34 => {
+            60 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    states_to_pop: 0,
+                    nonterminal_produced: 30,
                 }
             }
-            35 => {
+            61 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 30,
+                }
+            }
+            62 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 30,
                 }
             }
-            36 => {
+            63 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 31,
                 }
             }
-            37 => {
+            64 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 31,
+                }
+            }
+            65 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 31,
+                }
+            }
+            66 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 31,
                 }
             }
-            38 => {
+            67 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 32,
                 }
             }
-            39 => {
+            68 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 32,
                 }
             }
-            40 => {
+            69 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 32,
                 }
             }
-            41 => {
+            70 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    nonterminal_produced: 32,
                 }
             }
-            42 => {
+            71 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 33,
                 }
             }
-            43 => {
+            72 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 0,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 33,
                 }
             }
-            44 => {
+            73 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 22,
+                    states_to_pop: 5,
+                    nonterminal_produced: 34,
                 }
             }
-            45 => {
+            74 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    states_to_pop: 0,
+                    nonterminal_produced: 35,
                 }
             }
-            46 => {
+            75 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 35,
                 }
             }
-            47 => {
+            76 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 23,
+                    states_to_pop: 1,
+                    nonterminal_produced: 36,
                 }
             }
-            48 => {
+            77 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 36,
                 }
             }
-            49 => {
+            78 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    states_to_pop: 7,
+                    nonterminal_produced: 37,
                 }
             }
-            50 => {
+            79 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    states_to_pop: 3,
+                    nonterminal_produced: 38,
                 }
             }
-            51 => {
+            80 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    states_to_pop: 4,
+                    nonterminal_produced: 38,
                 }
             }
-            52 => {
+            81 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    nonterminal_produced: 39,
                 }
             }
-            53 => {
+            82 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    nonterminal_produced: 40,
                 }
             }
-            54 => {
+            83 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 25,
+                    states_to_pop: 7,
+                    nonterminal_produced: 41,
                 }
             }
-            55 => {
+            84 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 25,
+                    states_to_pop: 3,
+                    nonterminal_produced: 42,
                 }
             }
-            56 => {
+            85 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 26,
+                    states_to_pop: 1,
+                    nonterminal_produced: 42,
                 }
             }
-            57 => {
+            86 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 27,
+                    states_to_pop: 3,
+                    nonterminal_produced: 43,
                 }
             }
-            58 => {
+            87 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 27,
+                    nonterminal_produced: 43,
                 }
             }
-            59 => {
+            88 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 28,
+                    states_to_pop: 3,
+                    nonterminal_produced: 44,
                 }
             }
-            60 => {
+            89 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 28,
+                    states_to_pop: 1,
+                    nonterminal_produced: 44,
                 }
             }
-            61 => {
+            90 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 29,
+                    states_to_pop: 4,
+                    nonterminal_produced: 45,
                 }
             }
-            62 => {
+            91 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 30,
+                    states_to_pop: 4,
+                    nonterminal_produced: 45,
                 }
             }
-            63 => {
+            92 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 4,
-                    nonterminal_produced: 30,
+                    nonterminal_produced: 45,
                 }
             }
-            64 => {
+            93 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 31,
+                    nonterminal_produced: 46,
                 }
             }
-            65 => {
+            94 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 32,
+                    nonterminal_produced: 46,
                 }
             }
-            66 => {
+            95 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 33,
+                    states_to_pop: 1,
+                    nonterminal_produced: 47,
                 }
             }
-            67 => {
+            96 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 34,
+                    states_to_pop: 5,
+                    nonterminal_produced: 48,
                 }
             }
-            68 => {
+            97 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 34,
+                    states_to_pop: 2,
+                    nonterminal_produced: 49,
                 }
             }
-            69 => {
+            98 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 35,
+                    states_to_pop: 1,
+                    nonterminal_produced: 49,
                 }
             }
-            70 => {
+            99 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 35,
+                    nonterminal_produced: 50,
                 }
             }
-            71 => {
+            100 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 36,
+                    states_to_pop: 2,
+                    nonterminal_produced: 51,
                 }
             }
-            72 => {
+            101 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 36,
+                    nonterminal_produced: 52,
                 }
             }
-            73 => {
+            102 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 37,
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
                 }
             }
-            74 => {
+            103 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    nonterminal_produced: 53,
                 }
             }
-            75 => {
+            104 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    nonterminal_produced: 53,
                 }
             }
-            76 => {
+            105 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 39,
+                    nonterminal_produced: 54,
                 }
             }
-            77 => {
+            106 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 40,
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
                 }
             }
-            78 => {
+            107 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 40,
+                    nonterminal_produced: 54,
                 }
             }
-            79 => {
+            108 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 41,
+                    nonterminal_produced: 55,
                 }
             }
-            80 => {
+            109 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 42,
+                    nonterminal_produced: 56,
                 }
             }
-            81 => {
+            110 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 42,
+                    nonterminal_produced: 57,
                 }
             }
-            82 => {
+            111 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 43,
+                    states_to_pop: 2,
+                    nonterminal_produced: 57,
                 }
             }
-            83 => {
+            112 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 43,
+                    states_to_pop: 3,
+                    nonterminal_produced: 58,
                 }
             }
-            84 => {
+            113 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 44,
+                    nonterminal_produced: 58,
                 }
             }
-            85 => {
+            114 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 45,
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
                 }
             }
-            86 => {
+            115 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 8,
-                    nonterminal_produced: 45,
+                    states_to_pop: 1,
+                    nonterminal_produced: 60,
                 }
             }
-            87 => {
+            116 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 46,
+                    states_to_pop: 0,
+                    nonterminal_produced: 60,
                 }
             }
-            88 => {
+            117 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 46,
+                    nonterminal_produced: 61,
                 }
             }
-            89 => {
+            118 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 47,
+                    nonterminal_produced: 62,
                 }
             }
-            90 => {
+            119 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 48,
+                    nonterminal_produced: 63,
                 }
             }
-            91 => {
+            120 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 48,
+                    states_to_pop: 7,
+                    nonterminal_produced: 64,
                 }
             }
-            92 => {
+            121 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 49,
+                    nonterminal_produced: 65,
                 }
             }
-            93 => {
+            122 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 50,
+                    states_to_pop: 2,
+                    nonterminal_produced: 65,
                 }
             }
-            94 => {
+            123 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 51,
+                    nonterminal_produced: 66,
                 }
             }
-            95 => {
+            124 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 52,
+                    nonterminal_produced: 67,
                 }
             }
-            96 => __state_machine::SimulatedReduce::Accept,
+            125 => __state_machine::SimulatedReduce::Accept,
             _ => panic!("invalid reduction index {}", __reduce_index)
         }
     }
@@ -8505,8 +10808,8 @@ mod __parse__Prog {
     fn __accepts<
         'input,
     >(
-        __error_state: Option<i8>,
-        __states: &[i8],
+        __error_state: Option<i16>,
+        __states: &[i16],
         __opt_integer: Option<usize>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> bool
@@ -8539,9 +10842,9 @@ mod __parse__Prog {
         'input,
     >(
         input: &'input str,
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut alloc::vec::Vec<i8>,
+        __states: &mut alloc::vec::Vec<i16>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: core::marker::PhantomData<(&'input ())>,
     ) -> Option<Result<SurfProg,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
@@ -8716,21 +11019,7 @@ mod __parse__Prog {
                 __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             56 => {
-                // Extern = "extern", IdStr, "(", Comma<Spanned<Id>>, ")" => ActionFn(127);
-                assert!(__symbols.len() >= 5);
-                let __sym4 = __pop_Variant0(__symbols);
-                let __sym3 = __pop_Variant15(__symbols);
-                let __sym2 = __pop_Variant0(__symbols);
-                let __sym1 = __pop_Variant0(__symbols);
-                let __sym0 = __pop_Variant0(__symbols);
-                let __start = __sym0.0;
-                let __end = __sym4.2;
-                let __nt = match super::__action127::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4) {
-                    Ok(v) => v,
-                    Err(e) => return Some(Err(e)),
-                };
-                __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-                (5, 26)
+                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             57 => {
                 __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
@@ -8781,7 +11070,21 @@ mod __parse__Prog {
                 __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                // Extern = "extern", IdStr, "(", Comma<Spanned<Id>>, ")" => ActionFn(166);
+                assert!(__symbols.len() >= 5);
+                let __sym4 = __pop_Variant0(__symbols);
+                let __sym3 = __pop_Variant19(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant0(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym4.2;
+                let __nt = match super::__action166::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+                (5, 34)
             }
             74 => {
                 __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
@@ -8810,81 +11113,178 @@ mod __parse__Prog {
             82 => {
                 __reduce82(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            83 => {
-                __reduce83(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            83 => {
+                __reduce83(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            84 => {
+                __reduce84(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            85 => {
+                __reduce85(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            86 => {
+                __reduce86(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            87 => {
+                __reduce87(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            88 => {
+                __reduce88(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            89 => {
+                __reduce89(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            90 => {
+                __reduce90(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            91 => {
+                __reduce91(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            92 => {
+                __reduce92(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            93 => {
+                __reduce93(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            94 => {
+                __reduce94(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            95 => {
+                __reduce95(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            96 => {
+                // Match = "match", Boxed<Expr>, "{", Comma<Arm>, "}" => ActionFn(177);
+                assert!(__symbols.len() >= 5);
+                let __sym4 = __pop_Variant0(__symbols);
+                let __sym3 = __pop_Variant17(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant15(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym4.2;
+                let __nt = match super::__action177::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+                (5, 48)
+            }
+            97 => {
+                __reduce97(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            98 => {
+                __reduce98(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            99 => {
+                __reduce99(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            100 => {
+                __reduce100(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            101 => {
+                __reduce101(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            102 => {
+                // Pattern = IdStr => ActionFn(179);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym0.2;
+                let __nt = match super::__action179::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+                (1, 52)
+            }
+            103 => {
+                __reduce103(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            104 => {
+                __reduce104(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            105 => {
+                __reduce105(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            106 => {
+                __reduce106(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            107 => {
+                __reduce107(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            84 => {
-                __reduce84(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            108 => {
+                __reduce108(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            85 => {
-                // Prog = "def", IdStr, "(", Spanned<Id>, ")", ":", Expr => ActionFn(144);
-                assert!(__symbols.len() >= 7);
-                let __sym6 = __pop_Variant3(__symbols);
-                let __sym5 = __pop_Variant0(__symbols);
-                let __sym4 = __pop_Variant0(__symbols);
-                let __sym3 = __pop_Variant7(__symbols);
-                let __sym2 = __pop_Variant0(__symbols);
-                let __sym1 = __pop_Variant0(__symbols);
-                let __sym0 = __pop_Variant0(__symbols);
+            109 => {
+                __reduce109(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            110 => {
+                // Prog = TopFunDecl+ => ActionFn(192);
+                let __sym0 = __pop_Variant30(__symbols);
                 let __start = __sym0.0;
-                let __end = __sym6.2;
-                let __nt = match super::__action144::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6) {
+                let __end = __sym0.2;
+                let __nt = match super::__action192::<>(input, __sym0) {
                     Ok(v) => v,
                     Err(e) => return Some(Err(e)),
                 };
-                __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-                (7, 45)
-            }
-            86 => {
-                // Prog = Extern+, "def", IdStr, "(", Spanned<Id>, ")", ":", Expr => ActionFn(145);
-                assert!(__symbols.len() >= 8);
-                let __sym7 = __pop_Variant3(__symbols);
-                let __sym6 = __pop_Variant0(__symbols);
-                let __sym5 = __pop_Variant0(__symbols);
-                let __sym4 = __pop_Variant7(__symbols);
-                let __sym3 = __pop_Variant0(__symbols);
-                let __sym2 = __pop_Variant0(__symbols);
-                let __sym1 = __pop_Variant0(__symbols);
-                let __sym0 = __pop_Variant18(__symbols);
+                __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+                (1, 57)
+            }
+            111 => {
+                // Prog = Extern+, TopFunDecl+ => ActionFn(193);
+                assert!(__symbols.len() >= 2);
+                let __sym1 = __pop_Variant30(__symbols);
+                let __sym0 = __pop_Variant22(__symbols);
                 let __start = __sym0.0;
-                let __end = __sym7.2;
-                let __nt = match super::__action145::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7) {
+                let __end = __sym1.2;
+                let __nt = match super::__action193::<>(input, __sym0, __sym1) {
                     Ok(v) => v,
                     Err(e) => return Some(Err(e)),
                 };
-                __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-                (8, 45)
+                __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+                (2, 57)
             }
-            87 => {
-                __reduce87(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            112 => {
+                __reduce112(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            88 => {
-                __reduce88(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            113 => {
+                __reduce113(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            89 => {
-                __reduce89(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            114 => {
+                __reduce114(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            90 => {
-                __reduce90(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            115 => {
+                __reduce115(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            91 => {
-                __reduce91(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            116 => {
+                __reduce116(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            92 => {
-                __reduce92(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            117 => {
+                __reduce117(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            93 => {
-                __reduce93(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            118 => {
+                __reduce118(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            94 => {
-                __reduce94(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            119 => {
+                __reduce119(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            95 => {
-                __reduce95(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            120 => {
+                __reduce120(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
-            96 => {
+            121 => {
+                __reduce121(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            122 => {
+                __reduce122(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            123 => {
+                __reduce123(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            124 => {
+                __reduce124(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            125 => {
                 // __Prog = Prog => ActionFn(0);
-                let __sym0 = __pop_Variant21(__symbols);
+                let __sym0 = __pop_Variant26(__symbols);
                 let __start = __sym0.0;
                 let __end = __sym0.2;
                 let __nt = super::__action0::<>(input, __sym0);
@@ -8903,260 +11303,822 @@ mod __parse__Prog {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant7<
+    fn __pop_Variant1<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ((Option<i64>, SrcLoc), SurfExpr), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant28<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Option<i64>, SrcLoc), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant29<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (SrcLoc, SrcLoc, SurfFunDecl), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant9<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, (String, SrcLoc), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
+    fn __pop_Variant15<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Box<SurfExpr>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant12<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Option<((Option<i64>, SrcLoc), SurfExpr)>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant22<
+    fn __pop_Variant27<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Option<(String, SrcLoc)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant16<
+    fn __pop_Variant20<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Option<SurfExpr>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
+    fn __pop_Variant25<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Option<i64>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant16<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, Prim, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant19<
+    fn __pop_Variant23<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, String, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant1<
+    fn __pop_Variant3<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, SurfBinding, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant3<
+    fn __pop_Variant5<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfExpr, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant21<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfExtDecl, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant7<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfFunDecl, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant26<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfProg, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant17<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant19<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<(String, SrcLoc)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<SurfBinding>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant18<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<SurfExpr>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant30<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(SrcLoc, SrcLoc, SurfFunDecl)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(String, SrcLoc)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<SurfBinding>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<SurfExpr>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant22<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<SurfExtDecl>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfExpr, usize)
+    ) -> (usize, alloc::vec::Vec<SurfFunDecl>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant17<
+    fn __pop_Variant14<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfExtDecl, usize)
+    ) -> (usize, bool, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant5<
+    fn __pop_Variant24<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfFunDecl, usize)
+    ) -> (usize, i64, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant21<
+    fn __pop_Variant11<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfProg, usize)
+    ) -> (usize, usize, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant15<
+    fn __pop_Variant0<
       'input,
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<(String, SrcLoc)>, usize)
+    ) -> (usize, &'input str, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant10<
-      'input,
+    fn __reduce0<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Arm> ",") = Arm, "," => ActionFn(107);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action107::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (2, 0)
+    }
+    fn __reduce1<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Arm> ",")* =  => ActionFn(105);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action105::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (0, 1)
+    }
+    fn __reduce2<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Arm> ",")* = (<Arm> ",")+ => ActionFn(106);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action106::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 1)
+    }
+    fn __reduce3<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Arm> ",")+ = Arm, "," => ActionFn(114);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action114::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (2, 2)
+    }
+    fn __reduce4<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Arm> ",")+ = (<Arm> ",")+, Arm, "," => ActionFn(115);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action115::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (3, 2)
+    }
+    fn __reduce5<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> ",") = Binding, "," => ActionFn(77);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action77::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 3)
+    }
+    fn __reduce6<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> ",")* =  => ActionFn(75);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action75::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 4)
+    }
+    fn __reduce7<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> ",")* = (<Binding> ",")+ => ActionFn(76);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action76::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 4)
+    }
+    fn __reduce8<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> ",")+ = Binding, "," => ActionFn(118);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action118::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 5)
+    }
+    fn __reduce9<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> ",")+ = (<Binding> ",")+, Binding, "," => ActionFn(119);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action119::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 5)
+    }
+    fn __reduce10<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> "and") = Binding, "and" => ActionFn(74);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action74::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 6)
+    }
+    fn __reduce11<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> "and")+ = Binding, "and" => ActionFn(122);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action122::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 7)
+    }
+    fn __reduce12<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Binding> "and")+ = (<Binding> "and")+, Binding, "and" => ActionFn(123);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action123::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 7)
+    }
+    fn __reduce13<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expr> ",") = Expr, "," => ActionFn(102);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action102::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 8)
+    }
+    fn __reduce14<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<SurfBinding>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expr> ",")* =  => ActionFn(100);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action100::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 9)
     }
-    fn __pop_Variant14<
-      'input,
+    fn __reduce15<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<SurfExpr>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expr> ",")* = (<Expr> ",")+ => ActionFn(101);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action101::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 9)
     }
-    fn __pop_Variant8<
-      'input,
+    fn __reduce16<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(String, SrcLoc)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expr> ",")+ = Expr, "," => ActionFn(124);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action124::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 10)
     }
-    fn __pop_Variant2<
-      'input,
+    fn __reduce17<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<SurfBinding>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(125);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action125::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 10)
     }
-    fn __pop_Variant4<
-      'input,
+    fn __reduce18<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<SurfExpr>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<FunDecl> "and") = FunDecl, "and" => ActionFn(71);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action71::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 11)
     }
-    fn __pop_Variant18<
-      'input,
+    fn __reduce19<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<SurfExtDecl>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<FunDecl> "and")* =  => ActionFn(69);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action69::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 12)
     }
-    fn __pop_Variant6<
-      'input,
+    fn __reduce20<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<SurfFunDecl>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<FunDecl> "and")* = (<FunDecl> "and")+ => ActionFn(70);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action70::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 12)
     }
-    fn __pop_Variant11<
-      'input,
+    fn __reduce21<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, bool, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<FunDecl> "and")+ = FunDecl, "and" => ActionFn(128);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action128::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (2, 13)
     }
-    fn __pop_Variant20<
-      'input,
+    fn __reduce22<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i64, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<FunDecl> "and")+ = (<FunDecl> "and")+, FunDecl, "and" => ActionFn(129);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action129::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 13)
     }
-    fn __pop_Variant9<
-      'input,
+    fn __reduce23<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, usize, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Spanned<Id>> ",") = Spanned<Id>, "," => ActionFn(93);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action93::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 14)
     }
-    fn __pop_Variant0<
-      'input,
+    fn __reduce24<
+        'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Spanned<Id>> ",")* =  => ActionFn(91);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action91::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 15)
+    }
+    fn __reduce25<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // (<Spanned<Id>> ",")* = (<Spanned<Id>> ",")+ => ActionFn(92);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action92::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 15)
     }
-    fn __reduce0<
+    fn __reduce26<
         'input,
     >(
         input: &'input str,
@@ -9165,17 +12127,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",") = Binding, "," => ActionFn(61);
+        // (<Spanned<Id>> ",")+ = Spanned<Id>, "," => ActionFn(132);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action61::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 0)
+        let __nt = super::__action132::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 16)
     }
-    fn __reduce1<
+    fn __reduce27<
         'input,
     >(
         input: &'input str,
@@ -9184,14 +12146,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",")* =  => ActionFn(59);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action59::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (0, 1)
+        // (<Spanned<Id>> ",")+ = (<Spanned<Id>> ",")+, Spanned<Id>, "," => ActionFn(133);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action133::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 16)
     }
-    fn __reduce2<
+    fn __reduce28<
         'input,
     >(
         input: &'input str,
@@ -9200,15 +12166,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",")* = (<Binding> ",")+ => ActionFn(60);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action60::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 1)
+        // @L =  => ActionFn(82);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action82::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 17)
     }
-    fn __reduce3<
+    fn __reduce29<
         'input,
     >(
         input: &'input str,
@@ -9217,17 +12182,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",")+ = Binding, "," => ActionFn(88);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action88::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 2)
+        // @R =  => ActionFn(79);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action79::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 18)
     }
-    fn __reduce4<
+    fn __reduce30<
         'input,
     >(
         input: &'input str,
@@ -9236,18 +12198,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Binding> ",")+ = (<Binding> ",")+, Binding, "," => ActionFn(89);
+        // Arm = Spanned<Pattern>, "=>", Expr => ActionFn(46);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant28(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action89::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (3, 2)
+        let __nt = super::__action46::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (3, 19)
     }
-    fn __reduce5<
+    fn __reduce31<
         'input,
     >(
         input: &'input str,
@@ -9256,17 +12218,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",") = Expr, "," => ActionFn(83);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Arm? = Arm => ActionFn(103);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action83::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 3)
+        let __end = __sym0.2;
+        let __nt = super::__action103::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 20)
     }
-    fn __reduce6<
+    fn __reduce32<
         'input,
     >(
         input: &'input str,
@@ -9275,14 +12235,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",")* =  => ActionFn(81);
+        // Arm? =  => ActionFn(104);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action81::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (0, 4)
+        let __nt = super::__action104::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (0, 20)
     }
-    fn __reduce7<
+    fn __reduce33<
         'input,
     >(
         input: &'input str,
@@ -9291,15 +12251,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",")* = (<Expr> ",")+ => ActionFn(82);
-        let __sym0 = __pop_Variant4(__symbols);
+        // BaseExpr = Id => ActionFn(160);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action82::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (1, 4)
+        let __nt = super::__action160::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 21)
     }
-    fn __reduce8<
+    fn __reduce34<
         'input,
     >(
         input: &'input str,
@@ -9308,17 +12268,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",")+ = Expr, "," => ActionFn(92);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // BaseExpr = Num => ActionFn(161);
+        let __sym0 = __pop_Variant24(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action92::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (2, 5)
+        let __end = __sym0.2;
+        let __nt = super::__action161::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 21)
     }
-    fn __reduce9<
+    fn __reduce35<
         'input,
     >(
         input: &'input str,
@@ -9327,18 +12285,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Expr> ",")+ = (<Expr> ",")+, Expr, "," => ActionFn(93);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // BaseExpr = Bool => ActionFn(162);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action93::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (3, 5)
+        let __end = __sym0.2;
+        let __nt = super::__action162::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 21)
     }
-    fn __reduce10<
+    fn __reduce36<
         'input,
     >(
         input: &'input str,
@@ -9347,17 +12302,19 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and") = FunDecl, "and" => ActionFn(58);
-        assert!(__symbols.len() >= 2);
+        // BaseExpr = Prim1, "(", Expr, ")" => ActionFn(163);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action58::<>(input, __sym0, __sym1);
+        let __end = __sym3.2;
+        let __nt = super::__action163::<>(input, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 6)
+        (4, 21)
     }
-    fn __reduce11<
+    fn __reduce37<
         'input,
     >(
         input: &'input str,
@@ -9366,14 +12323,21 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and")* =  => ActionFn(56);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action56::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (0, 7)
+        // BaseExpr = Prim2, "(", Expr, ",", Expr, ")" => ActionFn(164);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant5(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action164::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (6, 21)
     }
-    fn __reduce12<
+    fn __reduce38<
         'input,
     >(
         input: &'input str,
@@ -9382,15 +12346,19 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and")* = (<FunDecl> "and")+ => ActionFn(57);
-        let __sym0 = __pop_Variant6(__symbols);
+        // BaseExpr = Id, "(", Comma<Expr>, ")" => ActionFn(165);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action57::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 7)
+        let __end = __sym3.2;
+        let __nt = super::__action165::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 21)
     }
-    fn __reduce13<
+    fn __reduce39<
         'input,
     >(
         input: &'input str,
@@ -9399,17 +12367,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and")+ = FunDecl, "and" => ActionFn(96);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
+        // BaseExpr = Match => ActionFn(43);
         let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action96::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 8)
+        let __end = __sym0.2;
+        let __nt = super::__action43::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 21)
     }
-    fn __reduce14<
+    fn __reduce40<
         'input,
     >(
         input: &'input str,
@@ -9418,18 +12384,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<FunDecl> "and")+ = (<FunDecl> "and")+, FunDecl, "and" => ActionFn(97);
+        // BaseExpr = "(", Expr, ")" => ActionFn(44);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action97::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 8)
+        let __nt = super::__action44::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 21)
     }
-    fn __reduce15<
+    fn __reduce41<
         'input,
     >(
         input: &'input str,
@@ -9438,17 +12404,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",") = Spanned<Id>, "," => ActionFn(78);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        // BinOps = LogExpr => ActionFn(19);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action78::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (2, 9)
+        let __end = __sym0.2;
+        let __nt = super::__action19::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 22)
     }
-    fn __reduce16<
+    fn __reduce42<
         'input,
     >(
         input: &'input str,
@@ -9457,14 +12421,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",")* =  => ActionFn(76);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action76::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (0, 10)
+        // Binding = Spanned<Id>, "=", Expr => ActionFn(14);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action14::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (3, 23)
     }
-    fn __reduce17<
+    fn __reduce43<
         'input,
     >(
         input: &'input str,
@@ -9473,15 +12441,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",")* = (<Spanned<Id>> ",")+ => ActionFn(77);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Bindings = Binding => ActionFn(120);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action77::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 10)
+        let __nt = super::__action120::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 24)
     }
-    fn __reduce18<
+    fn __reduce44<
         'input,
     >(
         input: &'input str,
@@ -9490,17 +12458,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",")+ = Spanned<Id>, "," => ActionFn(100);
+        // Bindings = (<Binding> ",")+, Binding => ActionFn(121);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action100::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (2, 11)
+        let __nt = super::__action121::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 24)
     }
-    fn __reduce19<
+    fn __reduce45<
         'input,
     >(
         input: &'input str,
@@ -9509,18 +12477,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // (<Spanned<Id>> ",")+ = (<Spanned<Id>> ",")+, Spanned<Id>, "," => ActionFn(101);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Bool = "true" => ActionFn(56);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action101::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 11)
+        let __end = __sym0.2;
+        let __nt = super::__action56::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 25)
     }
-    fn __reduce20<
+    fn __reduce46<
         'input,
     >(
         input: &'input str,
@@ -9529,14 +12494,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(65);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action65::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (0, 12)
+        // Bool = "false" => ActionFn(57);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action57::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 25)
     }
-    fn __reduce21<
+    fn __reduce47<
         'input,
     >(
         input: &'input str,
@@ -9545,14 +12511,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(63);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action63::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (0, 13)
+        // Boxed<Expr> = Expr => ActionFn(78);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action78::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 26)
     }
-    fn __reduce22<
+    fn __reduce48<
         'input,
     >(
         input: &'input str,
@@ -9561,15 +12528,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Id => ActionFn(122);
-        let __sym0 = __pop_Variant19(__symbols);
+        // Cmp = "<" => ActionFn(26);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action122::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        let __nt = super::__action26::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
-    fn __reduce23<
+    fn __reduce49<
         'input,
     >(
         input: &'input str,
@@ -9578,15 +12545,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Num => ActionFn(123);
-        let __sym0 = __pop_Variant20(__symbols);
+        // Cmp = "<=" => ActionFn(27);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action123::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        let __nt = super::__action27::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
-    fn __reduce24<
+    fn __reduce50<
         'input,
     >(
         input: &'input str,
@@ -9595,15 +12562,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Bool => ActionFn(124);
-        let __sym0 = __pop_Variant11(__symbols);
+        // Cmp = ">" => ActionFn(28);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action124::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        let __nt = super::__action28::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
-    fn __reduce25<
+    fn __reduce51<
         'input,
     >(
         input: &'input str,
@@ -9612,19 +12579,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Prim1, "(", Expr, ")" => ActionFn(125);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant13(__symbols);
+        // Cmp = ">=" => ActionFn(29);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action125::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 14)
+        let __end = __sym0.2;
+        let __nt = super::__action29::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
-    fn __reduce26<
+    fn __reduce52<
         'input,
     >(
         input: &'input str,
@@ -9633,19 +12596,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = Id, "(", Comma<Expr>, ")" => ActionFn(126);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant14(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant19(__symbols);
+        // Cmp = "==" => ActionFn(30);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action126::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 14)
+        let __end = __sym0.2;
+        let __nt = super::__action30::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
-    fn __reduce27<
+    fn __reduce53<
         'input,
     >(
         input: &'input str,
@@ -9654,18 +12613,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BaseExpr = "(", Expr, ")" => ActionFn(38);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
+        // Cmp = "!=" => ActionFn(31);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action38::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 14)
+        let __end = __sym0.2;
+        let __nt = super::__action31::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 27)
     }
-    fn __reduce28<
+    fn __reduce54<
         'input,
     >(
         input: &'input str,
@@ -9674,15 +12630,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // BinOps = LogExpr => ActionFn(15);
-        let __sym0 = __pop_Variant3(__symbols);
+        // CmpExpr = LAssoc<Cmp, SumExpr> => ActionFn(21);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 15)
+        let __nt = super::__action21::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 28)
     }
-    fn __reduce29<
+    fn __reduce55<
         'input,
     >(
         input: &'input str,
@@ -9691,18 +12647,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Binding = Spanned<Id>, "=", Expr => ActionFn(10);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Comma<Arm> = Arm => ActionFn(184);
+        let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action10::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 16)
+        let __end = __sym0.2;
+        let __nt = super::__action184::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 29)
     }
-    fn __reduce30<
+    fn __reduce56<
         'input,
     >(
         input: &'input str,
@@ -9711,15 +12664,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bindings = Binding => ActionFn(90);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action90::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 17)
+        // Comma<Arm> =  => ActionFn(185);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action185::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (0, 29)
     }
-    fn __reduce31<
+    fn __reduce57<
         'input,
     >(
         input: &'input str,
@@ -9728,17 +12680,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bindings = (<Binding> ",")+, Binding => ActionFn(91);
+        // Comma<Arm> = (<Arm> ",")+, Arm => ActionFn(186);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant1(__symbols);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action91::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (2, 17)
+        let __nt = super::__action186::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (2, 29)
     }
-    fn __reduce32<
+    fn __reduce58<
         'input,
     >(
         input: &'input str,
@@ -9747,15 +12699,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bool = "true" => ActionFn(44);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Arm> = (<Arm> ",")+ => ActionFn(187);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action44::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 18)
+        let __nt = super::__action187::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 29)
     }
-    fn __reduce33<
+    fn __reduce59<
         'input,
     >(
         input: &'input str,
@@ -9764,15 +12716,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Bool = "false" => ActionFn(45);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Expr> = Expr => ActionFn(188);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action45::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 18)
+        let __nt = super::__action188::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 30)
     }
-    fn __reduce34<
+    fn __reduce60<
         'input,
     >(
         input: &'input str,
@@ -9781,15 +12733,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Boxed<Expr> = Expr => ActionFn(62);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action62::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 19)
+        // Comma<Expr> =  => ActionFn(189);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action189::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 30)
     }
-    fn __reduce35<
+    fn __reduce61<
         'input,
     >(
         input: &'input str,
@@ -9798,15 +12749,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<" => ActionFn(22);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Expr> = (<Expr> ",")+, Expr => ActionFn(190);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action22::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __end = __sym1.2;
+        let __nt = super::__action190::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (2, 30)
     }
-    fn __reduce36<
+    fn __reduce62<
         'input,
     >(
         input: &'input str,
@@ -9815,15 +12768,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<=" => ActionFn(23);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Expr> = (<Expr> ",")+ => ActionFn(191);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action23::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __nt = super::__action191::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 30)
     }
-    fn __reduce37<
+    fn __reduce63<
         'input,
     >(
         input: &'input str,
@@ -9832,15 +12785,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">" => ActionFn(24);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Spanned<Id>> = Spanned<Id> => ActionFn(194);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action24::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __nt = super::__action194::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 31)
     }
-    fn __reduce38<
+    fn __reduce64<
         'input,
     >(
         input: &'input str,
@@ -9849,15 +12802,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">=" => ActionFn(25);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action25::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        // Comma<Spanned<Id>> =  => ActionFn(195);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action195::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (0, 31)
     }
-    fn __reduce39<
+    fn __reduce65<
         'input,
     >(
         input: &'input str,
@@ -9866,15 +12818,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "==" => ActionFn(26);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Spanned<Id>> = (<Spanned<Id>> ",")+, Spanned<Id> => ActionFn(196);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action26::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __end = __sym1.2;
+        let __nt = super::__action196::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (2, 31)
     }
-    fn __reduce40<
+    fn __reduce66<
         'input,
     >(
         input: &'input str,
@@ -9883,15 +12837,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "!=" => ActionFn(27);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Spanned<Id>> = (<Spanned<Id>> ",")+ => ActionFn(197);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action27::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 20)
+        let __nt = super::__action197::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (1, 31)
     }
-    fn __reduce41<
+    fn __reduce67<
         'input,
     >(
         input: &'input str,
@@ -9900,15 +12854,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // CmpExpr = LAssoc<Cmp, SumExpr> => ActionFn(17);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Expr = Let => ActionFn(5);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action17::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 21)
+        let __nt = super::__action5::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 32)
     }
-    fn __reduce42<
+    fn __reduce68<
         'input,
     >(
         input: &'input str,
@@ -9917,15 +12871,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Expr> = Expr => ActionFn(140);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Expr = If => ActionFn(6);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action140::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 22)
+        let __nt = super::__action6::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 32)
     }
-    fn __reduce43<
+    fn __reduce69<
         'input,
     >(
         input: &'input str,
@@ -9934,14 +12888,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Expr> =  => ActionFn(141);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action141::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 22)
+        // Expr = FunDefs => ActionFn(7);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action7::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 32)
     }
-    fn __reduce44<
+    fn __reduce70<
         'input,
     >(
         input: &'input str,
@@ -9950,17 +12905,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Expr> = (<Expr> ",")+, Expr => ActionFn(142);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Expr = BinOps => ActionFn(8);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action142::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (2, 22)
+        let __end = __sym0.2;
+        let __nt = super::__action8::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 32)
     }
-    fn __reduce45<
+    fn __reduce71<
         'input,
     >(
         input: &'input str,
@@ -9969,15 +12922,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Expr> = (<Expr> ",")+ => ActionFn(143);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Expr? = Expr => ActionFn(98);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action143::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 22)
+        let __nt = super::__action98::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 33)
     }
-    fn __reduce46<
+    fn __reduce72<
         'input,
     >(
         input: &'input str,
@@ -9986,15 +12939,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Spanned<Id>> = Spanned<Id> => ActionFn(146);
-        let __sym0 = __pop_Variant7(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action146::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 23)
+        // Expr? =  => ActionFn(99);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action99::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 33)
     }
-    fn __reduce47<
+    fn __reduce74<
         'input,
     >(
         input: &'input str,
@@ -10003,14 +12955,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Spanned<Id>> =  => ActionFn(147);
+        // Extern* =  => ActionFn(85);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action147::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (0, 23)
+        let __nt = super::__action85::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (0, 35)
     }
-    fn __reduce48<
+    fn __reduce75<
         'input,
     >(
         input: &'input str,
@@ -10019,17 +12971,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Spanned<Id>> = (<Spanned<Id>> ",")+, Spanned<Id> => ActionFn(148);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Extern* = Extern+ => ActionFn(86);
+        let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action148::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (2, 23)
+        let __end = __sym0.2;
+        let __nt = super::__action86::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 35)
     }
-    fn __reduce49<
+    fn __reduce76<
         'input,
     >(
         input: &'input str,
@@ -10038,15 +12988,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Comma<Spanned<Id>> = (<Spanned<Id>> ",")+ => ActionFn(149);
-        let __sym0 = __pop_Variant8(__symbols);
+        // Extern+ = Extern => ActionFn(87);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action149::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 23)
+        let __nt = super::__action87::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 36)
     }
-    fn __reduce50<
+    fn __reduce77<
         'input,
     >(
         input: &'input str,
@@ -10055,15 +13005,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr = Let => ActionFn(4);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Extern+ = Extern+, Extern => ActionFn(88);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant21(__symbols);
+        let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action4::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 24)
+        let __end = __sym1.2;
+        let __nt = super::__action88::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (2, 36)
     }
-    fn __reduce51<
+    fn __reduce78<
         'input,
     >(
         input: &'input str,
@@ -10072,15 +13024,22 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr = If => ActionFn(5);
-        let __sym0 = __pop_Variant3(__symbols);
+        // FunDecl = "def", Id, "(", Comma<Spanned<Id>>, ")", ":", Expr => ActionFn(167);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant5(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant19(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant23(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 24)
+        let __end = __sym6.2;
+        let __nt = super::__action167::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (7, 37)
     }
-    fn __reduce52<
+    fn __reduce79<
         'input,
     >(
         input: &'input str,
@@ -10089,15 +13048,39 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr = FunDefs => ActionFn(6);
-        let __sym0 = __pop_Variant3(__symbols);
+        // FunDefs = FunDecl, "in", Boxed<Expr> => ActionFn(168);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action6::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 24)
+        let __end = __sym2.2;
+        let __nt = super::__action168::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 38)
+    }
+    fn __reduce80<
+        'input,
+    >(
+        input: &'input str,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input ())>,
+    ) -> (usize, usize)
+    {
+        // FunDefs = (<FunDecl> "and")+, FunDecl, "in", Boxed<Expr> => ActionFn(169);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action169::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 38)
     }
-    fn __reduce53<
+    fn __reduce81<
         'input,
     >(
         input: &'input str,
@@ -10106,15 +13089,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr = BinOps => ActionFn(7);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Id = IdStr => ActionFn(55);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action7::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 24)
+        let __nt = super::__action55::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 39)
     }
-    fn __reduce54<
+    fn __reduce82<
         'input,
     >(
         input: &'input str,
@@ -10123,15 +13106,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr? = Expr => ActionFn(79);
-        let __sym0 = __pop_Variant3(__symbols);
+        // IdStr = r#"[a-zA-Z_][a-zA-Z0-9_]*"# => ActionFn(54);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action79::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 25)
+        let __nt = super::__action54::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant0(__nt), __end));
+        (1, 40)
     }
-    fn __reduce55<
+    fn __reduce83<
         'input,
     >(
         input: &'input str,
@@ -10140,14 +13123,22 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Expr? =  => ActionFn(80);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action80::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 25)
+        // If = "if", Boxed<Expr>, ":", Boxed<Expr>, "else", ":", Boxed<Expr> => ActionFn(170);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant15(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym6.2;
+        let __nt = super::__action170::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (7, 41)
     }
-    fn __reduce57<
+    fn __reduce84<
         'input,
     >(
         input: &'input str,
@@ -10156,14 +13147,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Extern* =  => ActionFn(66);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action66::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (0, 27)
+        // LAssoc<Cmp, SumExpr> = LAssoc<Cmp, SumExpr>, Cmp, SumExpr => ActionFn(171);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action171::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 42)
     }
-    fn __reduce58<
+    fn __reduce85<
         'input,
     >(
         input: &'input str,
@@ -10172,15 +13167,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Extern* = Extern+ => ActionFn(67);
-        let __sym0 = __pop_Variant18(__symbols);
+        // LAssoc<Cmp, SumExpr> = SumExpr => ActionFn(66);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action67::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 27)
+        let __nt = super::__action66::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 42)
     }
-    fn __reduce59<
+    fn __reduce86<
         'input,
     >(
         input: &'input str,
@@ -10189,15 +13184,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Extern+ = Extern => ActionFn(68);
-        let __sym0 = __pop_Variant17(__symbols);
+        // LAssoc<PlusMinus, ProdExpr> = LAssoc<PlusMinus, ProdExpr>, PlusMinus, ProdExpr => ActionFn(172);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action68::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 28)
+        let __end = __sym2.2;
+        let __nt = super::__action172::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 43)
     }
-    fn __reduce60<
+    fn __reduce87<
         'input,
     >(
         input: &'input str,
@@ -10206,17 +13204,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Extern+ = Extern+, Extern => ActionFn(69);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant17(__symbols);
-        let __sym0 = __pop_Variant18(__symbols);
+        // LAssoc<PlusMinus, ProdExpr> = ProdExpr => ActionFn(64);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action69::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (2, 28)
+        let __end = __sym0.2;
+        let __nt = super::__action64::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 43)
     }
-    fn __reduce61<
+    fn __reduce88<
         'input,
     >(
         input: &'input str,
@@ -10225,22 +13221,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FunDecl = "def", Id, "(", Comma<Spanned<Id>>, ")", ":", Expr => ActionFn(128);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant3(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant15(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant19(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // LAssoc<Times, NotExpr> = LAssoc<Times, NotExpr>, Times, NotExpr => ActionFn(173);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action128::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __end = __sym2.2;
+        let __nt = super::__action173::<>(input, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (7, 29)
+        (3, 44)
     }
-    fn __reduce62<
+    fn __reduce89<
         'input,
     >(
         input: &'input str,
@@ -10249,18 +13241,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FunDefs = FunDecl, "in", Boxed<Expr> => ActionFn(129);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // LAssoc<Times, NotExpr> = NotExpr => ActionFn(62);
         let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action129::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 30)
+        let __end = __sym0.2;
+        let __nt = super::__action62::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 44)
     }
-    fn __reduce63<
+    fn __reduce90<
         'input,
     >(
         input: &'input str,
@@ -10269,19 +13258,19 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // FunDefs = (<FunDecl> "and")+, FunDecl, "in", Boxed<Expr> => ActionFn(130);
+        // Let = "let", Bindings, "in", Boxed<Expr> => ActionFn(174);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action130::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 30)
+        let __nt = super::__action174::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 45)
     }
-    fn __reduce64<
+    fn __reduce91<
         'input,
     >(
         input: &'input str,
@@ -10290,15 +13279,19 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Id = IdStr => ActionFn(43);
+        // Let = "let", ParallelBindings, "in", Boxed<Expr> => ActionFn(175);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action43::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 31)
+        let __end = __sym3.2;
+        let __nt = super::__action175::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 45)
     }
-    fn __reduce65<
+    fn __reduce92<
         'input,
     >(
         input: &'input str,
@@ -10307,15 +13300,19 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // IdStr = r#"[a-zA-Z_][a-zA-Z0-9_]*"# => ActionFn(42);
+        // Let = "letrec", Bindings, "in", Boxed<Expr> => ActionFn(176);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action42::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant0(__nt), __end));
-        (1, 32)
+        let __end = __sym3.2;
+        let __nt = super::__action176::<>(input, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 45)
     }
-    fn __reduce66<
+    fn __reduce93<
         'input,
     >(
         input: &'input str,
@@ -10324,22 +13321,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // If = "if", Boxed<Expr>, ":", Boxed<Expr>, "else", ":", Boxed<Expr> => ActionFn(131);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant12(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant12(__symbols);
+        // Log = "&&" => ActionFn(24);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action131::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (7, 33)
+        let __end = __sym0.2;
+        let __nt = super::__action24::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 46)
     }
-    fn __reduce67<
+    fn __reduce94<
         'input,
     >(
         input: &'input str,
@@ -10348,18 +13338,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, SumExpr> = LAssoc<Cmp, SumExpr>, Cmp, SumExpr => ActionFn(132);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Log = "||" => ActionFn(25);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action132::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 34)
+        let __end = __sym0.2;
+        let __nt = super::__action25::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 46)
     }
-    fn __reduce68<
+    fn __reduce95<
         'input,
     >(
         input: &'input str,
@@ -10368,15 +13355,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, SumExpr> = SumExpr => ActionFn(52);
-        let __sym0 = __pop_Variant3(__symbols);
+        // LogExpr = RAssoc<Log, CmpExpr> => ActionFn(20);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 34)
+        let __nt = super::__action20::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 47)
     }
-    fn __reduce69<
+    fn __reduce97<
         'input,
     >(
         input: &'input str,
@@ -10385,18 +13372,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, ProdExpr> = LAssoc<PlusMinus, ProdExpr>, PlusMinus, ProdExpr => ActionFn(133);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // NotExpr = "!", BaseExpr => ActionFn(178);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action133::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 35)
+        let __end = __sym1.2;
+        let __nt = super::__action178::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 49)
     }
-    fn __reduce70<
+    fn __reduce98<
         'input,
     >(
         input: &'input str,
@@ -10405,15 +13391,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, ProdExpr> = ProdExpr => ActionFn(50);
-        let __sym0 = __pop_Variant3(__symbols);
+        // NotExpr = BaseExpr => ActionFn(36);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action50::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 35)
+        let __nt = super::__action36::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 49)
     }
-    fn __reduce71<
+    fn __reduce99<
         'input,
     >(
         input: &'input str,
@@ -10422,18 +13408,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExpr> = LAssoc<Times, NotExpr>, Times, NotExpr => ActionFn(134);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Num = r#"[+-]?[0-9]+"# => ActionFn(53);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action134::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 36)
+        let __end = __sym0.2;
+        let __nt = super::__action53::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 50)
     }
-    fn __reduce72<
+    fn __reduce100<
         'input,
     >(
         input: &'input str,
@@ -10442,15 +13425,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExpr> = NotExpr => ActionFn(48);
-        let __sym0 = __pop_Variant3(__symbols);
+        // ParallelBindings = (<Binding> "and")+, Binding => ActionFn(13);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 36)
+        let __end = __sym1.2;
+        let __nt = super::__action13::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 51)
     }
-    fn __reduce73<
+    fn __reduce101<
         'input,
     >(
         input: &'input str,
@@ -10459,19 +13444,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Let = "let", Bindings, "in", Boxed<Expr> => ActionFn(135);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant12(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Pattern = Num => ActionFn(47);
+        let __sym0 = __pop_Variant24(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action135::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (4, 37)
+        let __end = __sym0.2;
+        let __nt = super::__action47::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 52)
     }
-    fn __reduce74<
+    fn __reduce103<
         'input,
     >(
         input: &'input str,
@@ -10480,15 +13461,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Log = "&&" => ActionFn(20);
+        // PlusMinus = "+" => ActionFn(32);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action20::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 38)
+        let __nt = super::__action32::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 53)
     }
-    fn __reduce75<
+    fn __reduce104<
         'input,
     >(
         input: &'input str,
@@ -10497,15 +13478,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Log = "||" => ActionFn(21);
+        // PlusMinus = "-" => ActionFn(33);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action21::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 38)
+        let __nt = super::__action33::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 53)
     }
-    fn __reduce76<
+    fn __reduce105<
         'input,
     >(
         input: &'input str,
@@ -10514,15 +13495,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // LogExpr = RAssoc<Log, CmpExpr> => ActionFn(16);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Prim1 = "add1" => ActionFn(49);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 39)
+        let __nt = super::__action49::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 54)
     }
-    fn __reduce77<
+    fn __reduce106<
         'input,
     >(
         input: &'input str,
@@ -10531,17 +13512,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // NotExpr = "!", BaseExpr => ActionFn(136);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
+        // Prim1 = "sub1" => ActionFn(50);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action136::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 40)
+        let __end = __sym0.2;
+        let __nt = super::__action50::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 54)
     }
-    fn __reduce78<
+    fn __reduce107<
         'input,
     >(
         input: &'input str,
@@ -10550,15 +13529,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // NotExpr = BaseExpr => ActionFn(32);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Prim1 = "error" => ActionFn(51);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action32::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 40)
+        let __nt = super::__action51::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 54)
     }
-    fn __reduce79<
+    fn __reduce108<
         'input,
     >(
         input: &'input str,
@@ -10567,15 +13546,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Num = r#"[+-]?[0-9]+"# => ActionFn(41);
+        // Prim2 = "cmp" => ActionFn(52);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action41::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 41)
+        let __nt = super::__action52::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 55)
     }
-    fn __reduce80<
+    fn __reduce109<
         'input,
     >(
         input: &'input str,
@@ -10584,15 +13563,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "+" => ActionFn(28);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ProdExpr = LAssoc<Times, NotExpr> => ActionFn(23);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 42)
+        let __nt = super::__action23::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 56)
     }
-    fn __reduce81<
+    fn __reduce112<
         'input,
     >(
         input: &'input str,
@@ -10601,15 +13580,18 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "-" => ActionFn(29);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RAssoc<Log, CmpExpr> = CmpExpr, Log, RAssoc<Log, CmpExpr> => ActionFn(180);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action29::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 42)
+        let __end = __sym2.2;
+        let __nt = super::__action180::<>(input, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 58)
     }
-    fn __reduce82<
+    fn __reduce113<
         'input,
     >(
         input: &'input str,
@@ -10618,15 +13600,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "add1" => ActionFn(39);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RAssoc<Log, CmpExpr> = CmpExpr => ActionFn(68);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action39::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 43)
+        let __nt = super::__action68::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 58)
     }
-    fn __reduce83<
+    fn __reduce114<
         'input,
     >(
         input: &'input str,
@@ -10635,15 +13617,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "sub1" => ActionFn(40);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Spanned<Id> = Id => ActionFn(181);
+        let __sym0 = __pop_Variant23(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action40::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 43)
+        let __nt = super::__action181::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 59)
     }
-    fn __reduce84<
+    fn __reduce115<
         'input,
     >(
         input: &'input str,
@@ -10652,15 +13634,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // ProdExpr = LAssoc<Times, NotExpr> => ActionFn(19);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Spanned<Id>? = Spanned<Id> => ActionFn(89);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 44)
+        let __nt = super::__action89::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 60)
     }
-    fn __reduce87<
+    fn __reduce116<
         'input,
     >(
         input: &'input str,
@@ -10669,18 +13651,14 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExpr> = CmpExpr, Log, RAssoc<Log, CmpExpr> => ActionFn(138);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action138::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 46)
+        // Spanned<Id>? =  => ActionFn(90);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action90::<>(input, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (0, 60)
     }
-    fn __reduce88<
+    fn __reduce117<
         'input,
     >(
         input: &'input str,
@@ -10689,15 +13667,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExpr> = CmpExpr => ActionFn(54);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Spanned<Pattern> = Pattern => ActionFn(182);
+        let __sym0 = __pop_Variant25(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 46)
+        let __nt = super::__action182::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 61)
     }
-    fn __reduce89<
+    fn __reduce118<
         'input,
     >(
         input: &'input str,
@@ -10706,15 +13684,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Spanned<Id> = Id => ActionFn(139);
-        let __sym0 = __pop_Variant19(__symbols);
+        // SumExpr = LAssoc<PlusMinus, ProdExpr> => ActionFn(22);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action139::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 47)
+        let __nt = super::__action22::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 62)
     }
-    fn __reduce90<
+    fn __reduce119<
         'input,
     >(
         input: &'input str,
@@ -10723,15 +13701,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Spanned<Id>? = Spanned<Id> => ActionFn(74);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Times = "*" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action74::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (1, 48)
+        let __nt = super::__action34::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 63)
     }
-    fn __reduce91<
+    fn __reduce120<
         'input,
     >(
         input: &'input str,
@@ -10740,14 +13718,22 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Spanned<Id>? =  => ActionFn(75);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action75::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (0, 48)
+        // TopFunDecl = "def", Spanned<Id>, "(", Comma<Spanned<Id>>, ")", ":", Expr => ActionFn(183);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant5(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant19(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym6.2;
+        let __nt = super::__action183::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (7, 64)
     }
-    fn __reduce92<
+    fn __reduce121<
         'input,
     >(
         input: &'input str,
@@ -10756,15 +13742,15 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // SumExpr = LAssoc<PlusMinus, ProdExpr> => ActionFn(18);
-        let __sym0 = __pop_Variant3(__symbols);
+        // TopFunDecl+ = TopFunDecl => ActionFn(83);
+        let __sym0 = __pop_Variant29(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action18::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 49)
+        let __nt = super::__action83::<>(input, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 65)
     }
-    fn __reduce93<
+    fn __reduce122<
         'input,
     >(
         input: &'input str,
@@ -10773,15 +13759,17 @@ mod __parse__Prog {
         _: core::marker::PhantomData<(&'input ())>,
     ) -> (usize, usize)
     {
-        // Times = "*" => ActionFn(30);
-        let __sym0 = __pop_Variant0(__symbols);
+        // TopFunDecl+ = TopFunDecl+, TopFunDecl => ActionFn(84);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant29(__symbols);
+        let __sym0 = __pop_Variant30(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action30::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 50)
+        let __end = __sym1.2;
+        let __nt = super::__action84::<>(input, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (2, 65)
     }
-    fn __reduce94<
+    fn __reduce123<
         'input,
     >(
         input: &'input str,
@@ -10791,14 +13779,14 @@ mod __parse__Prog {
     ) -> (usize, usize)
     {
         // __Expr = Expr => ActionFn(1);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action1::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 51)
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 66)
     }
-    fn __reduce95<
+    fn __reduce124<
         'input,
     >(
         input: &'input str,
@@ -10808,12 +13796,12 @@ mod __parse__Prog {
     ) -> (usize, usize)
     {
         // __Extern = Extern => ActionFn(2);
-        let __sym0 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
         let __nt = super::__action2::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 52)
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 67)
     }
 }
 #[allow(unused_imports)]
@@ -10823,8 +13811,8 @@ mod __intern_token {
     #![allow(unused_imports)]
     use std::str::FromStr;
     use crate::ast::{
-    SurfProg, SurfExpr, SurfBinding, SurfFunDecl, SurfExtDecl,
-    Prog, Expr, Binding, FunDecl, ExtDecl, Prim,
+    SurfProg, SurfExpr, SurfBinding, SurfFunDecl, SurfExtDecl, SurfMatchArm,
+    Prog, Expr, Binding, FunDecl, ExtDecl, MatchArm, Prim, LetKind,
 };
     use crate::span::SrcLoc;
     use lalrpop_util::ParseError;
@@ -10852,26 +13840,244 @@ mod __intern_token {
             ("(?:<=)", false),
             ("=", false),
             ("(?:==)", false),
+            ("(?:=>)", false),
             (">", false),
             ("(?:>=)", false),
             ("(?:add1)", false),
             ("(?:and)", false),
+            ("(?:cmp)", false),
             ("(?:def)", false),
             ("(?:else)", false),
+            ("(?:error)", false),
             ("(?:extern)", false),
             ("(?:false)", false),
             ("(?:if)", false),
             ("(?:in)", false),
             ("(?:let)", false),
+            ("(?:letrec)", false),
+            ("(?:match)", false),
             ("(?:sub1)", false),
             ("(?:true)", false),
+            ("\\{", false),
             ("(?:\\|\\|)", false),
+            ("\\}", false),
             (r"\s+", true),
         ];
         __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
     }
 }
-pub(crate) use self::__lalrpop_util::lexer::Token;
+pub(crate) use self::__lalrpop_util::lexer::Token;
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action0<'input>(input: &'input str, (_, __0, _): (usize, SurfProg, usize)) -> SurfProg {
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action1<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action2<'input>(input: &'input str, (_, __0, _): (usize, SurfExtDecl, usize)) -> SurfExtDecl {
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action3<'input>(
+    input: &'input str, (_, externs, _): (usize, alloc::vec::Vec<SurfExtDecl>, usize),
+    (_, mut funs, _): (usize, alloc::vec::Vec<(SrcLoc, SrcLoc, SurfFunDecl)>, usize),
+) -> Result<SurfProg, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    {
+        let unexpected = |loc: SrcLoc, expected: &str| ParseError::UnrecognizedToken {
+            token: (loc.start_ix, lalrpop_util::lexer::Token(loc.start_ix, ""), loc.end_ix),
+            expected: vec![expected.to_string()],
+        };
+        // the last function is main, which takes a single parameter
+        let (name_loc, params_loc, mut main) = funs.pop().unwrap();
+        if main.name != "main" {
+            return Err(unexpected(name_loc, "main"));
+        }
+        if main.params.len() != 1 {
+            return Err(unexpected(params_loc, "a single parameter"));
+        }
+        Ok(Prog {
+            externs,
+            funs: funs.into_iter().map(|(_, _, decl)| decl).collect(),
+            name: main.name,
+            param: main.params.pop().unwrap(),
+            body: main.body,
+            loc: main.loc,
+        })
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action4<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
+    (_, name, _): (usize, (String, SrcLoc), usize), (_, pl, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize), (_, params, _): (usize, Vec<(String, SrcLoc)>, usize),
+    (_, _, _): (usize, &'input str, usize), (_, pr, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize), (_, body, _): (usize, SurfExpr, usize),
+    (_, r, _): (usize, usize, usize),
+) -> (SrcLoc, SrcLoc, SurfFunDecl) {
+    {
+        let decl = FunDecl { name: name.0, params, body, loc: SrcLoc::new(l, r) };
+        (name.1, SrcLoc::new(pl, pr), decl)
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action5<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action6<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action7<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action8<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action9<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
+    (_, bindings, _): (usize, Vec<SurfBinding>, usize), (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<SurfExpr>, usize), (_, r, _): (usize, usize, usize),
+) -> SurfExpr {
+    Expr::Let { bindings, body, kind: LetKind::Sequential, loc: SrcLoc::new(l, r) }
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action10<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
+    (_, bindings, _): (usize, Vec<SurfBinding>, usize), (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<SurfExpr>, usize), (_, r, _): (usize, usize, usize),
+) -> SurfExpr {
+    Expr::Let { bindings, body, kind: LetKind::Parallel, loc: SrcLoc::new(l, r) }
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action11<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
+    (_, bindings, _): (usize, Vec<SurfBinding>, usize), (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<SurfExpr>, usize), (_, r, _): (usize, usize, usize),
+) -> SurfExpr {
+    Expr::Let { bindings, body, kind: LetKind::Recursive, loc: SrcLoc::new(l, r) }
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action12<'input>(
+    input: &'input str, (_, mut bs, _): (usize, alloc::vec::Vec<SurfBinding>, usize),
+    (_, b, _): (usize, SurfBinding, usize),
+) -> Vec<SurfBinding> {
+    {
+        bs.push(b);
+        bs
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action13<'input>(
+    input: &'input str, (_, mut bs, _): (usize, alloc::vec::Vec<SurfBinding>, usize),
+    (_, b, _): (usize, SurfBinding, usize),
+) -> Vec<SurfBinding> {
+    {
+        bs.push(b);
+        bs
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action14<'input>(
+    input: &'input str, (_, var, _): (usize, (String, SrcLoc), usize),
+    (_, _, _): (usize, &'input str, usize), (_, expr, _): (usize, SurfExpr, usize),
+) -> SurfBinding {
+    Binding { var, expr }
+}
 
 #[allow(unused_variables)]
 #[allow(
@@ -10879,8 +14085,14 @@ pub(crate) use self::__lalrpop_util::lexer::Token;
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action0<'input>(input: &'input str, (_, __0, _): (usize, SurfProg, usize)) -> SurfProg {
-    __0
+fn __action15<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
+    (_, cond, _): (usize, Box<SurfExpr>, usize), (_, _, _): (usize, &'input str, usize),
+    (_, thn, _): (usize, Box<SurfExpr>, usize), (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize), (_, els, _): (usize, Box<SurfExpr>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SurfExpr {
+    Expr::If { cond, thn, els, loc: SrcLoc::new(l, r) }
 }
 
 #[allow(unused_variables)]
@@ -10889,8 +14101,16 @@ fn __action0<'input>(input: &'input str, (_, __0, _): (usize, SurfProg, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action1<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
-    __0
+fn __action16<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize),
+    (_, mut decls, _): (usize, alloc::vec::Vec<SurfFunDecl>, usize),
+    (_, last, _): (usize, SurfFunDecl, usize), (_, _, _): (usize, &'input str, usize),
+    (_, body, _): (usize, Box<SurfExpr>, usize), (_, r, _): (usize, usize, usize),
+) -> SurfExpr {
+    {
+        decls.push(last);
+        Expr::FunDefs { decls, body, loc: SrcLoc::new(l, r) }
+    }
 }
 
 #[allow(unused_variables)]
@@ -10899,8 +14119,16 @@ fn __action1<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action2<'input>(input: &'input str, (_, __0, _): (usize, SurfExtDecl, usize)) -> SurfExtDecl {
-    __0
+fn __action17<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
+    (_, fun, _): (usize, String, usize), (_, _, _): (usize, &'input str, usize),
+    (_, params, _): (usize, Vec<(String, SrcLoc)>, usize), (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize), (_, body, _): (usize, SurfExpr, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SurfFunDecl {
+    {
+        FunDecl { name: fun, params, body, loc: SrcLoc::new(l, r) }
+    }
 }
 
 #[allow(unused_variables)]
@@ -10909,22 +14137,20 @@ fn __action2<'input>(input: &'input str, (_, __0, _): (usize, SurfExtDecl, usize
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action3<'input>(
-    input: &'input str, (_, externs, _): (usize, alloc::vec::Vec<SurfExtDecl>, usize),
-    (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
+fn __action18<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
     (_, name, _): (usize, &'input str, usize), (_, _, _): (usize, &'input str, usize),
-    (_, param, _): (usize, (String, SrcLoc), usize), (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize), (_, body, _): (usize, SurfExpr, usize),
+    (_, params, _): (usize, Vec<(String, SrcLoc)>, usize), (_, _, _): (usize, &'input str, usize),
     (_, r, _): (usize, usize, usize),
-) -> Result<SurfProg, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+) -> Result<SurfExtDecl, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
     {
-        if name == "main" {
-            Ok(Prog { externs, name: name.to_string(), param, body, loc: SrcLoc::new(l, r) })
-        } else {
+        if name == "entry" {
             Err(ParseError::UnrecognizedToken {
                 token: (l, lalrpop_util::lexer::Token(l, name), r),
-                expected: vec!["main".to_string()],
+                expected: vec!["!entry".to_string()],
             })
+        } else {
+            Ok(ExtDecl { name: name.to_string(), params, loc: SrcLoc::new(l, r) })
         }
     }
 }
@@ -10935,7 +14161,7 @@ fn __action3<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action4<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+fn __action19<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
     __0
 }
 
@@ -10945,7 +14171,7 @@ fn __action4<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action5<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+fn __action20<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
     __0
 }
 
@@ -10955,7 +14181,7 @@ fn __action5<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action6<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+fn __action21<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
     __0
 }
 
@@ -10965,7 +14191,7 @@ fn __action6<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action7<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+fn __action22<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
     __0
 }
 
@@ -10975,12 +14201,8 @@ fn __action7<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action8<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
-    (_, bindings, _): (usize, Vec<SurfBinding>, usize), (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<SurfExpr>, usize), (_, r, _): (usize, usize, usize),
-) -> SurfExpr {
-    Expr::Let { bindings, body, loc: SrcLoc::new(l, r) }
+fn __action23<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+    __0
 }
 
 #[allow(unused_variables)]
@@ -10989,14 +14211,8 @@ fn __action8<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action9<'input>(
-    input: &'input str, (_, mut bs, _): (usize, alloc::vec::Vec<SurfBinding>, usize),
-    (_, b, _): (usize, SurfBinding, usize),
-) -> Vec<SurfBinding> {
-    {
-        bs.push(b);
-        bs
-    }
+fn __action24<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::And
 }
 
 #[allow(unused_variables)]
@@ -11005,11 +14221,8 @@ fn __action9<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action10<'input>(
-    input: &'input str, (_, var, _): (usize, (String, SrcLoc), usize),
-    (_, _, _): (usize, &'input str, usize), (_, expr, _): (usize, SurfExpr, usize),
-) -> SurfBinding {
-    Binding { var, expr }
+fn __action25<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Or
 }
 
 #[allow(unused_variables)]
@@ -11018,14 +14231,101 @@ fn __action10<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action11<'input>(
+fn __action26<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Lt
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action27<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Le
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action28<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Gt
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action29<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Ge
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action30<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Eq
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action31<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Neq
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action32<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Add
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action33<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Sub
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action34<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Mul
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action35<'input>(
     input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
-    (_, cond, _): (usize, Box<SurfExpr>, usize), (_, _, _): (usize, &'input str, usize),
-    (_, thn, _): (usize, Box<SurfExpr>, usize), (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize), (_, els, _): (usize, Box<SurfExpr>, usize),
-    (_, r, _): (usize, usize, usize),
+    (_, e, _): (usize, SurfExpr, usize), (_, r, _): (usize, usize, usize),
 ) -> SurfExpr {
-    Expr::If { cond, thn, els, loc: SrcLoc::new(l, r) }
+    Expr::Prim { prim: Prim::Not, args: vec![e], loc: SrcLoc::new(l, r) }
 }
 
 #[allow(unused_variables)]
@@ -11034,16 +14334,8 @@ fn __action11<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action12<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize),
-    (_, mut decls, _): (usize, alloc::vec::Vec<SurfFunDecl>, usize),
-    (_, last, _): (usize, SurfFunDecl, usize), (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<SurfExpr>, usize), (_, r, _): (usize, usize, usize),
-) -> SurfExpr {
-    {
-        decls.push(last);
-        Expr::FunDefs { decls, body, loc: SrcLoc::new(l, r) }
-    }
+fn __action36<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+    __0
 }
 
 #[allow(unused_variables)]
@@ -11052,16 +14344,11 @@ fn __action12<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action13<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
-    (_, fun, _): (usize, String, usize), (_, _, _): (usize, &'input str, usize),
-    (_, params, _): (usize, Vec<(String, SrcLoc)>, usize), (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize), (_, body, _): (usize, SurfExpr, usize),
+fn __action37<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, var, _): (usize, String, usize),
     (_, r, _): (usize, usize, usize),
-) -> SurfFunDecl {
-    {
-        FunDecl { name: fun, params, body, loc: SrcLoc::new(l, r) }
-    }
+) -> SurfExpr {
+    Expr::Var(var, SrcLoc::new(l, r))
 }
 
 #[allow(unused_variables)]
@@ -11070,22 +14357,11 @@ fn __action13<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action14<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
-    (_, name, _): (usize, &'input str, usize), (_, _, _): (usize, &'input str, usize),
-    (_, params, _): (usize, Vec<(String, SrcLoc)>, usize), (_, _, _): (usize, &'input str, usize),
+fn __action38<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, num, _): (usize, i64, usize),
     (_, r, _): (usize, usize, usize),
-) -> Result<SurfExtDecl, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
-    {
-        if name == "entry" {
-            Err(ParseError::UnrecognizedToken {
-                token: (l, lalrpop_util::lexer::Token(l, name), r),
-                expected: vec!["!entry".to_string()],
-            })
-        } else {
-            Ok(ExtDecl { name: name.to_string(), params, loc: SrcLoc::new(l, r) })
-        }
-    }
+) -> SurfExpr {
+    Expr::Num(num, SrcLoc::new(l, r))
 }
 
 #[allow(unused_variables)]
@@ -11094,8 +14370,11 @@ fn __action14<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action15<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
-    __0
+fn __action39<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, bool, _): (usize, bool, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SurfExpr {
+    Expr::Bool(bool, SrcLoc::new(l, r))
 }
 
 #[allow(unused_variables)]
@@ -11104,8 +14383,12 @@ fn __action15<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action16<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
-    __0
+fn __action40<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, prim, _): (usize, Prim, usize),
+    (_, _, _): (usize, &'input str, usize), (_, e, _): (usize, SurfExpr, usize),
+    (_, _, _): (usize, &'input str, usize), (_, r, _): (usize, usize, usize),
+) -> SurfExpr {
+    Expr::Prim { prim, args: vec![e], loc: SrcLoc::new(l, r) }
 }
 
 #[allow(unused_variables)]
@@ -11114,8 +14397,13 @@ fn __action16<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action17<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
-    __0
+fn __action41<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, prim, _): (usize, Prim, usize),
+    (_, _, _): (usize, &'input str, usize), (_, e1, _): (usize, SurfExpr, usize),
+    (_, _, _): (usize, &'input str, usize), (_, e2, _): (usize, SurfExpr, usize),
+    (_, _, _): (usize, &'input str, usize), (_, r, _): (usize, usize, usize),
+) -> SurfExpr {
+    Expr::Prim { prim, args: vec![e1, e2], loc: SrcLoc::new(l, r) }
 }
 
 #[allow(unused_variables)]
@@ -11124,8 +14412,12 @@ fn __action17<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action18<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
-    __0
+fn __action42<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, fun, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize), (_, args, _): (usize, Vec<SurfExpr>, usize),
+    (_, _, _): (usize, &'input str, usize), (_, r, _): (usize, usize, usize),
+) -> SurfExpr {
+    Expr::Call { fun, args, loc: SrcLoc::new(l, r) }
 }
 
 #[allow(unused_variables)]
@@ -11134,7 +14426,7 @@ fn __action18<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action19<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+fn __action43<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
     __0
 }
 
@@ -11144,8 +14436,11 @@ fn __action19<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action20<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::And
+fn __action44<'input>(
+    input: &'input str, (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, SurfExpr, usize), (_, _, _): (usize, &'input str, usize),
+) -> SurfExpr {
+    __0
 }
 
 #[allow(unused_variables)]
@@ -11154,8 +14449,29 @@ fn __action20<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action21<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::Or
+fn __action45<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
+    (_, scrutinee, _): (usize, Box<SurfExpr>, usize), (_, _, _): (usize, &'input str, usize),
+    (_, all_arms, _): (usize, Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+    (_, _, _): (usize, &'input str, usize), (_, r, _): (usize, usize, usize),
+) -> Result<SurfExpr, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    {
+        let mut arms: Vec<SurfMatchArm> = Vec::new();
+        let mut default = None;
+        for ((pattern, loc), body) in all_arms {
+            if default.is_some() {
+                return Err(ParseError::UnrecognizedToken {
+                    token: (loc.start_ix, lalrpop_util::lexer::Token(loc.start_ix, ""), loc.end_ix),
+                    expected: vec!["\"}\"".to_string()],
+                });
+            }
+            match pattern {
+                Some(n) => arms.push(MatchArm { pattern: (n, loc), body }),
+                None => default = Some(Box::new(body)),
+            }
+        }
+        Ok(Expr::Match { scrutinee, arms, default, loc: SrcLoc::new(l, r) })
+    }
 }
 
 #[allow(unused_variables)]
@@ -11164,8 +14480,11 @@ fn __action21<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action22<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::Lt
+fn __action46<'input>(
+    input: &'input str, (_, pattern, _): (usize, (Option<i64>, SrcLoc), usize),
+    (_, _, _): (usize, &'input str, usize), (_, body, _): (usize, SurfExpr, usize),
+) -> ((Option<i64>, SrcLoc), SurfExpr) {
+    (pattern, body)
 }
 
 #[allow(unused_variables)]
@@ -11174,8 +14493,8 @@ fn __action22<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action23<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::Le
+fn __action47<'input>(input: &'input str, (_, n, _): (usize, i64, usize)) -> Option<i64> {
+    Some(n)
 }
 
 #[allow(unused_variables)]
@@ -11184,8 +14503,20 @@ fn __action23<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action24<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::Gt
+fn __action48<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, id, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Result<Option<i64>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    {
+        if id == "_" {
+            Ok(None)
+        } else {
+            Err(ParseError::UnrecognizedToken {
+                token: (l, lalrpop_util::lexer::Token(l, id), r),
+                expected: vec!["\"_\"".to_string()],
+            })
+        }
+    }
 }
 
 #[allow(unused_variables)]
@@ -11194,8 +14525,8 @@ fn __action24<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action25<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::Ge
+fn __action49<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Add1
 }
 
 #[allow(unused_variables)]
@@ -11204,8 +14535,8 @@ fn __action25<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action26<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::Eq
+fn __action50<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Sub1
 }
 
 #[allow(unused_variables)]
@@ -11214,8 +14545,8 @@ fn __action26<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action27<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::Neq
+fn __action51<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Error
 }
 
 #[allow(unused_variables)]
@@ -11224,8 +14555,8 @@ fn __action27<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action28<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::Add
+fn __action52<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
+    Prim::Cmp
 }
 
 #[allow(unused_variables)]
@@ -11234,8 +14565,8 @@ fn __action28<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action29<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::Sub
+fn __action53<'input>(input: &'input str, (_, s, _): (usize, &'input str, usize)) -> i64 {
+    i64::from_str(s).unwrap()
 }
 
 #[allow(unused_variables)]
@@ -11244,8 +14575,8 @@ fn __action29<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action30<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::Mul
+fn __action54<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> &'input str {
+    __0
 }
 
 #[allow(unused_variables)]
@@ -11254,11 +14585,8 @@ fn __action30<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action31<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, SurfExpr, usize), (_, r, _): (usize, usize, usize),
-) -> SurfExpr {
-    Expr::Prim { prim: Prim::Not, args: vec![e], loc: SrcLoc::new(l, r) }
+fn __action55<'input>(input: &'input str, (_, s, _): (usize, &'input str, usize)) -> String {
+    String::from(s)
 }
 
 #[allow(unused_variables)]
@@ -11267,8 +14595,8 @@ fn __action31<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action32<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
-    __0
+fn __action56<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> bool {
+    true
 }
 
 #[allow(unused_variables)]
@@ -11277,11 +14605,8 @@ fn __action32<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action33<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, var, _): (usize, String, usize),
-    (_, r, _): (usize, usize, usize),
-) -> SurfExpr {
-    Expr::Var(var, SrcLoc::new(l, r))
+fn __action57<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> bool {
+    false
 }
 
 #[allow(unused_variables)]
@@ -11290,11 +14615,11 @@ fn __action33<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action34<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, num, _): (usize, i64, usize),
-    (_, r, _): (usize, usize, usize),
-) -> SurfExpr {
-    Expr::Num(num, SrcLoc::new(l, r))
+fn __action58<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize),
+    (_, value, _): (usize, Option<i64>, usize), (_, r, _): (usize, usize, usize),
+) -> (Option<i64>, SrcLoc) {
+    (value, SrcLoc::new(l, r))
 }
 
 #[allow(unused_variables)]
@@ -11303,11 +14628,20 @@ fn __action34<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action35<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, bool, _): (usize, bool, usize),
-    (_, r, _): (usize, usize, usize),
-) -> SurfExpr {
-    Expr::Bool(bool, SrcLoc::new(l, r))
+fn __action59<'input>(
+    input: &'input str,
+    (_, mut v, _): (usize, alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+    (_, last, _): (usize, Option<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+) -> Vec<((Option<i64>, SrcLoc), SurfExpr)> {
+    {
+        match last {
+            None => {}
+            Some(t) => {
+                v.push(t);
+            }
+        };
+        v
+    }
 }
 
 #[allow(unused_variables)]
@@ -11316,12 +14650,19 @@ fn __action35<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action36<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, prim, _): (usize, Prim, usize),
-    (_, _, _): (usize, &'input str, usize), (_, e, _): (usize, SurfExpr, usize),
-    (_, _, _): (usize, &'input str, usize), (_, r, _): (usize, usize, usize),
-) -> SurfExpr {
-    Expr::Prim { prim, args: vec![e], loc: SrcLoc::new(l, r) }
+fn __action60<'input>(
+    input: &'input str, (_, mut v, _): (usize, alloc::vec::Vec<SurfExpr>, usize),
+    (_, last, _): (usize, Option<SurfExpr>, usize),
+) -> Vec<SurfExpr> {
+    {
+        match last {
+            None => {}
+            Some(t) => {
+                v.push(t);
+            }
+        };
+        v
+    }
 }
 
 #[allow(unused_variables)]
@@ -11330,12 +14671,12 @@ fn __action36<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action37<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, fun, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize), (_, args, _): (usize, Vec<SurfExpr>, usize),
-    (_, _, _): (usize, &'input str, usize), (_, r, _): (usize, usize, usize),
+fn __action61<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, e1, _): (usize, SurfExpr, usize),
+    (_, prim, _): (usize, Prim, usize), (_, e2, _): (usize, SurfExpr, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> SurfExpr {
-    Expr::Call { fun, args, loc: SrcLoc::new(l, r) }
+    Expr::Prim { prim, args: vec![e1, e2], loc: SrcLoc::new(l, r) }
 }
 
 #[allow(unused_variables)]
@@ -11344,10 +14685,7 @@ fn __action37<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action38<'input>(
-    input: &'input str, (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, SurfExpr, usize), (_, _, _): (usize, &'input str, usize),
-) -> SurfExpr {
+fn __action62<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
     __0
 }
 
@@ -11357,8 +14695,12 @@ fn __action38<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action39<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::Add1
+fn __action63<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, e1, _): (usize, SurfExpr, usize),
+    (_, prim, _): (usize, Prim, usize), (_, e2, _): (usize, SurfExpr, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SurfExpr {
+    Expr::Prim { prim, args: vec![e1, e2], loc: SrcLoc::new(l, r) }
 }
 
 #[allow(unused_variables)]
@@ -11367,8 +14709,8 @@ fn __action39<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action40<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> Prim {
-    Prim::Sub1
+fn __action64<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+    __0
 }
 
 #[allow(unused_variables)]
@@ -11377,8 +14719,12 @@ fn __action40<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action41<'input>(input: &'input str, (_, s, _): (usize, &'input str, usize)) -> i64 {
-    i64::from_str(s).unwrap()
+fn __action65<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, e1, _): (usize, SurfExpr, usize),
+    (_, prim, _): (usize, Prim, usize), (_, e2, _): (usize, SurfExpr, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SurfExpr {
+    Expr::Prim { prim, args: vec![e1, e2], loc: SrcLoc::new(l, r) }
 }
 
 #[allow(unused_variables)]
@@ -11387,7 +14733,7 @@ fn __action41<'input>(input: &'input str, (_, s, _): (usize, &'input str, usize)
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action42<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> &'input str {
+fn __action66<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
     __0
 }
 
@@ -11397,8 +14743,12 @@ fn __action42<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action43<'input>(input: &'input str, (_, s, _): (usize, &'input str, usize)) -> String {
-    String::from(s)
+fn __action67<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, e1, _): (usize, SurfExpr, usize),
+    (_, prim, _): (usize, Prim, usize), (_, e2, _): (usize, SurfExpr, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SurfExpr {
+    Expr::Prim { prim, args: vec![e1, e2], loc: SrcLoc::new(l, r) }
 }
 
 #[allow(unused_variables)]
@@ -11407,8 +14757,8 @@ fn __action43<'input>(input: &'input str, (_, s, _): (usize, &'input str, usize)
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action44<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> bool {
-    true
+fn __action68<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+    __0
 }
 
 #[allow(unused_variables)]
@@ -11417,8 +14767,10 @@ fn __action44<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action45<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usize)) -> bool {
-    false
+fn __action69<'input>(
+    input: &'input str, __lookbehind: &usize, __lookahead: &usize,
+) -> alloc::vec::Vec<SurfFunDecl> {
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
@@ -11427,19 +14779,10 @@ fn __action45<'input>(input: &'input str, (_, __0, _): (usize, &'input str, usiz
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action46<'input>(
-    input: &'input str, (_, mut v, _): (usize, alloc::vec::Vec<SurfExpr>, usize),
-    (_, last, _): (usize, Option<SurfExpr>, usize),
-) -> Vec<SurfExpr> {
-    {
-        match last {
-            None => {}
-            Some(t) => {
-                v.push(t);
-            }
-        };
-        v
-    }
+fn __action70<'input>(
+    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfFunDecl>, usize),
+) -> alloc::vec::Vec<SurfFunDecl> {
+    v
 }
 
 #[allow(unused_variables)]
@@ -11448,12 +14791,11 @@ fn __action46<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action47<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, e1, _): (usize, SurfExpr, usize),
-    (_, prim, _): (usize, Prim, usize), (_, e2, _): (usize, SurfExpr, usize),
-    (_, r, _): (usize, usize, usize),
-) -> SurfExpr {
-    Expr::Prim { prim, args: vec![e1, e2], loc: SrcLoc::new(l, r) }
+fn __action71<'input>(
+    input: &'input str, (_, __0, _): (usize, SurfFunDecl, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> SurfFunDecl {
+    __0
 }
 
 #[allow(unused_variables)]
@@ -11462,8 +14804,10 @@ fn __action47<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action48<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
-    __0
+fn __action72<'input>(
+    input: &'input str, (_, __0, _): (usize, SurfBinding, usize),
+) -> alloc::vec::Vec<SurfBinding> {
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
@@ -11472,12 +14816,15 @@ fn __action48<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action49<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, e1, _): (usize, SurfExpr, usize),
-    (_, prim, _): (usize, Prim, usize), (_, e2, _): (usize, SurfExpr, usize),
-    (_, r, _): (usize, usize, usize),
-) -> SurfExpr {
-    Expr::Prim { prim, args: vec![e1, e2], loc: SrcLoc::new(l, r) }
+fn __action73<'input>(
+    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfBinding>, usize),
+    (_, e, _): (usize, SurfBinding, usize),
+) -> alloc::vec::Vec<SurfBinding> {
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
 }
 
 #[allow(unused_variables)]
@@ -11486,7 +14833,10 @@ fn __action49<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action50<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
+fn __action74<'input>(
+    input: &'input str, (_, __0, _): (usize, SurfBinding, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> SurfBinding {
     __0
 }
 
@@ -11496,12 +14846,10 @@ fn __action50<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action51<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, e1, _): (usize, SurfExpr, usize),
-    (_, prim, _): (usize, Prim, usize), (_, e2, _): (usize, SurfExpr, usize),
-    (_, r, _): (usize, usize, usize),
-) -> SurfExpr {
-    Expr::Prim { prim, args: vec![e1, e2], loc: SrcLoc::new(l, r) }
+fn __action75<'input>(
+    input: &'input str, __lookbehind: &usize, __lookahead: &usize,
+) -> alloc::vec::Vec<SurfBinding> {
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
@@ -11510,8 +14858,10 @@ fn __action51<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action52<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
-    __0
+fn __action76<'input>(
+    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfBinding>, usize),
+) -> alloc::vec::Vec<SurfBinding> {
+    v
 }
 
 #[allow(unused_variables)]
@@ -11520,12 +14870,11 @@ fn __action52<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action53<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, e1, _): (usize, SurfExpr, usize),
-    (_, prim, _): (usize, Prim, usize), (_, e2, _): (usize, SurfExpr, usize),
-    (_, r, _): (usize, usize, usize),
-) -> SurfExpr {
-    Expr::Prim { prim, args: vec![e1, e2], loc: SrcLoc::new(l, r) }
+fn __action77<'input>(
+    input: &'input str, (_, __0, _): (usize, SurfBinding, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> SurfBinding {
+    __0
 }
 
 #[allow(unused_variables)]
@@ -11534,8 +14883,14 @@ fn __action53<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action54<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> SurfExpr {
-    __0
+fn __action78<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> Box<SurfExpr> {
+    Box::new(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::needless_lifetimes)]
+fn __action79<'input>(input: &'input str, __lookbehind: &usize, __lookahead: &usize) -> usize {
+    *__lookbehind
 }
 
 #[allow(unused_variables)]
@@ -11544,7 +14899,7 @@ fn __action54<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action55<'input>(
+fn __action80<'input>(
     input: &'input str, (_, mut v, _): (usize, alloc::vec::Vec<(String, SrcLoc)>, usize),
     (_, last, _): (usize, Option<(String, SrcLoc)>, usize),
 ) -> Vec<(String, SrcLoc)> {
@@ -11565,10 +14920,17 @@ fn __action55<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action56<'input>(
-    input: &'input str, __lookbehind: &usize, __lookahead: &usize,
-) -> alloc::vec::Vec<SurfFunDecl> {
-    alloc::vec![]
+fn __action81<'input>(
+    input: &'input str, (_, l, _): (usize, usize, usize), (_, value, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> (String, SrcLoc) {
+    (value, SrcLoc::new(l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::needless_lifetimes)]
+fn __action82<'input>(input: &'input str, __lookbehind: &usize, __lookahead: &usize) -> usize {
+    *__lookahead
 }
 
 #[allow(unused_variables)]
@@ -11577,10 +14939,10 @@ fn __action56<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action57<'input>(
-    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfFunDecl>, usize),
-) -> alloc::vec::Vec<SurfFunDecl> {
-    v
+fn __action83<'input>(
+    input: &'input str, (_, __0, _): (usize, (SrcLoc, SrcLoc, SurfFunDecl), usize),
+) -> alloc::vec::Vec<(SrcLoc, SrcLoc, SurfFunDecl)> {
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
@@ -11589,11 +14951,15 @@ fn __action57<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action58<'input>(
-    input: &'input str, (_, __0, _): (usize, SurfFunDecl, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> SurfFunDecl {
-    __0
+fn __action84<'input>(
+    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<(SrcLoc, SrcLoc, SurfFunDecl)>, usize),
+    (_, e, _): (usize, (SrcLoc, SrcLoc, SurfFunDecl), usize),
+) -> alloc::vec::Vec<(SrcLoc, SrcLoc, SurfFunDecl)> {
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
 }
 
 #[allow(unused_variables)]
@@ -11602,9 +14968,9 @@ fn __action58<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action59<'input>(
+fn __action85<'input>(
     input: &'input str, __lookbehind: &usize, __lookahead: &usize,
-) -> alloc::vec::Vec<SurfBinding> {
+) -> alloc::vec::Vec<SurfExtDecl> {
     alloc::vec![]
 }
 
@@ -11614,9 +14980,9 @@ fn __action59<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action60<'input>(
-    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfBinding>, usize),
-) -> alloc::vec::Vec<SurfBinding> {
+fn __action86<'input>(
+    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfExtDecl>, usize),
+) -> alloc::vec::Vec<SurfExtDecl> {
     v
 }
 
@@ -11626,11 +14992,10 @@ fn __action60<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action61<'input>(
-    input: &'input str, (_, __0, _): (usize, SurfBinding, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> SurfBinding {
-    __0
+fn __action87<'input>(
+    input: &'input str, (_, __0, _): (usize, SurfExtDecl, usize),
+) -> alloc::vec::Vec<SurfExtDecl> {
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
@@ -11639,14 +15004,15 @@ fn __action61<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action62<'input>(input: &'input str, (_, __0, _): (usize, SurfExpr, usize)) -> Box<SurfExpr> {
-    Box::new(__0)
-}
-
-#[allow(unused_variables)]
-#[allow(clippy::needless_lifetimes)]
-fn __action63<'input>(input: &'input str, __lookbehind: &usize, __lookahead: &usize) -> usize {
-    *__lookbehind
+fn __action88<'input>(
+    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfExtDecl>, usize),
+    (_, e, _): (usize, SurfExtDecl, usize),
+) -> alloc::vec::Vec<SurfExtDecl> {
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
 }
 
 #[allow(unused_variables)]
@@ -11655,17 +15021,10 @@ fn __action63<'input>(input: &'input str, __lookbehind: &usize, __lookahead: &us
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action64<'input>(
-    input: &'input str, (_, l, _): (usize, usize, usize), (_, value, _): (usize, String, usize),
-    (_, r, _): (usize, usize, usize),
-) -> (String, SrcLoc) {
-    (value, SrcLoc::new(l, r))
-}
-
-#[allow(unused_variables)]
-#[allow(clippy::needless_lifetimes)]
-fn __action65<'input>(input: &'input str, __lookbehind: &usize, __lookahead: &usize) -> usize {
-    *__lookahead
+fn __action89<'input>(
+    input: &'input str, (_, __0, _): (usize, (String, SrcLoc), usize),
+) -> Option<(String, SrcLoc)> {
+    Some(__0)
 }
 
 #[allow(unused_variables)]
@@ -11674,10 +15033,10 @@ fn __action65<'input>(input: &'input str, __lookbehind: &usize, __lookahead: &us
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action66<'input>(
+fn __action90<'input>(
     input: &'input str, __lookbehind: &usize, __lookahead: &usize,
-) -> alloc::vec::Vec<SurfExtDecl> {
-    alloc::vec![]
+) -> Option<(String, SrcLoc)> {
+    None
 }
 
 #[allow(unused_variables)]
@@ -11686,10 +15045,10 @@ fn __action66<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action67<'input>(
-    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfExtDecl>, usize),
-) -> alloc::vec::Vec<SurfExtDecl> {
-    v
+fn __action91<'input>(
+    input: &'input str, __lookbehind: &usize, __lookahead: &usize,
+) -> alloc::vec::Vec<(String, SrcLoc)> {
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
@@ -11698,10 +15057,10 @@ fn __action67<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action68<'input>(
-    input: &'input str, (_, __0, _): (usize, SurfExtDecl, usize),
-) -> alloc::vec::Vec<SurfExtDecl> {
-    alloc::vec![__0]
+fn __action92<'input>(
+    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<(String, SrcLoc)>, usize),
+) -> alloc::vec::Vec<(String, SrcLoc)> {
+    v
 }
 
 #[allow(unused_variables)]
@@ -11710,15 +15069,11 @@ fn __action68<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action69<'input>(
-    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfExtDecl>, usize),
-    (_, e, _): (usize, SurfExtDecl, usize),
-) -> alloc::vec::Vec<SurfExtDecl> {
-    {
-        let mut v = v;
-        v.push(e);
-        v
-    }
+fn __action93<'input>(
+    input: &'input str, (_, __0, _): (usize, (String, SrcLoc), usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> (String, SrcLoc) {
+    __0
 }
 
 #[allow(unused_variables)]
@@ -11727,7 +15082,7 @@ fn __action69<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action70<'input>(
+fn __action94<'input>(
     input: &'input str, (_, __0, _): (usize, SurfBinding, usize),
 ) -> alloc::vec::Vec<SurfBinding> {
     alloc::vec![__0]
@@ -11739,7 +15094,7 @@ fn __action70<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action71<'input>(
+fn __action95<'input>(
     input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfBinding>, usize),
     (_, e, _): (usize, SurfBinding, usize),
 ) -> alloc::vec::Vec<SurfBinding> {
@@ -11756,7 +15111,7 @@ fn __action71<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action72<'input>(
+fn __action96<'input>(
     input: &'input str, (_, __0, _): (usize, SurfFunDecl, usize),
 ) -> alloc::vec::Vec<SurfFunDecl> {
     alloc::vec![__0]
@@ -11768,7 +15123,7 @@ fn __action72<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action73<'input>(
+fn __action97<'input>(
     input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfFunDecl>, usize),
     (_, e, _): (usize, SurfFunDecl, usize),
 ) -> alloc::vec::Vec<SurfFunDecl> {
@@ -11785,9 +15140,9 @@ fn __action73<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action74<'input>(
-    input: &'input str, (_, __0, _): (usize, (String, SrcLoc), usize),
-) -> Option<(String, SrcLoc)> {
+fn __action98<'input>(
+    input: &'input str, (_, __0, _): (usize, SurfExpr, usize),
+) -> Option<SurfExpr> {
     Some(__0)
 }
 
@@ -11797,9 +15152,9 @@ fn __action74<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action75<'input>(
+fn __action99<'input>(
     input: &'input str, __lookbehind: &usize, __lookahead: &usize,
-) -> Option<(String, SrcLoc)> {
+) -> Option<SurfExpr> {
     None
 }
 
@@ -11809,9 +15164,9 @@ fn __action75<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action76<'input>(
+fn __action100<'input>(
     input: &'input str, __lookbehind: &usize, __lookahead: &usize,
-) -> alloc::vec::Vec<(String, SrcLoc)> {
+) -> alloc::vec::Vec<SurfExpr> {
     alloc::vec![]
 }
 
@@ -11821,9 +15176,9 @@ fn __action76<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action77<'input>(
-    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<(String, SrcLoc)>, usize),
-) -> alloc::vec::Vec<(String, SrcLoc)> {
+fn __action101<'input>(
+    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfExpr>, usize),
+) -> alloc::vec::Vec<SurfExpr> {
     v
 }
 
@@ -11833,10 +15188,10 @@ fn __action77<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action78<'input>(
-    input: &'input str, (_, __0, _): (usize, (String, SrcLoc), usize),
+fn __action102<'input>(
+    input: &'input str, (_, __0, _): (usize, SurfExpr, usize),
     (_, _, _): (usize, &'input str, usize),
-) -> (String, SrcLoc) {
+) -> SurfExpr {
     __0
 }
 
@@ -11846,9 +15201,9 @@ fn __action78<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action79<'input>(
-    input: &'input str, (_, __0, _): (usize, SurfExpr, usize),
-) -> Option<SurfExpr> {
+fn __action103<'input>(
+    input: &'input str, (_, __0, _): (usize, ((Option<i64>, SrcLoc), SurfExpr), usize),
+) -> Option<((Option<i64>, SrcLoc), SurfExpr)> {
     Some(__0)
 }
 
@@ -11858,9 +15213,9 @@ fn __action79<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action80<'input>(
+fn __action104<'input>(
     input: &'input str, __lookbehind: &usize, __lookahead: &usize,
-) -> Option<SurfExpr> {
+) -> Option<((Option<i64>, SrcLoc), SurfExpr)> {
     None
 }
 
@@ -11870,9 +15225,9 @@ fn __action80<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action81<'input>(
+fn __action105<'input>(
     input: &'input str, __lookbehind: &usize, __lookahead: &usize,
-) -> alloc::vec::Vec<SurfExpr> {
+) -> alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)> {
     alloc::vec![]
 }
 
@@ -11882,9 +15237,10 @@ fn __action81<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action82<'input>(
-    input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfExpr>, usize),
-) -> alloc::vec::Vec<SurfExpr> {
+fn __action106<'input>(
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+) -> alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)> {
     v
 }
 
@@ -11894,10 +15250,10 @@ fn __action82<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action83<'input>(
-    input: &'input str, (_, __0, _): (usize, SurfExpr, usize),
+fn __action107<'input>(
+    input: &'input str, (_, __0, _): (usize, ((Option<i64>, SrcLoc), SurfExpr), usize),
     (_, _, _): (usize, &'input str, usize),
-) -> SurfExpr {
+) -> ((Option<i64>, SrcLoc), SurfExpr) {
     __0
 }
 
@@ -11907,7 +15263,37 @@ fn __action83<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action84<'input>(
+fn __action108<'input>(
+    input: &'input str, (_, __0, _): (usize, ((Option<i64>, SrcLoc), SurfExpr), usize),
+) -> alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)> {
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action109<'input>(
+    input: &'input str,
+    (_, v, _): (usize, alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+    (_, e, _): (usize, ((Option<i64>, SrcLoc), SurfExpr), usize),
+) -> alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)> {
+    {
+        let mut v = v;
+        v.push(e);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action110<'input>(
     input: &'input str, (_, __0, _): (usize, SurfExpr, usize),
 ) -> alloc::vec::Vec<SurfExpr> {
     alloc::vec![__0]
@@ -11919,7 +15305,7 @@ fn __action84<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action85<'input>(
+fn __action111<'input>(
     input: &'input str, (_, v, _): (usize, alloc::vec::Vec<SurfExpr>, usize),
     (_, e, _): (usize, SurfExpr, usize),
 ) -> alloc::vec::Vec<SurfExpr> {
@@ -11936,7 +15322,7 @@ fn __action85<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action86<'input>(
+fn __action112<'input>(
     input: &'input str, (_, __0, _): (usize, (String, SrcLoc), usize),
 ) -> alloc::vec::Vec<(String, SrcLoc)> {
     alloc::vec![__0]
@@ -11948,7 +15334,7 @@ fn __action86<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action87<'input>(
+fn __action113<'input>(
     input: &'input str, (_, v, _): (usize, alloc::vec::Vec<(String, SrcLoc)>, usize),
     (_, e, _): (usize, (String, SrcLoc), usize),
 ) -> alloc::vec::Vec<(String, SrcLoc)> {
@@ -11965,14 +15351,81 @@ fn __action87<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action88<'input>(
+fn __action114<'input>(
+    input: &'input str, __0: (usize, ((Option<i64>, SrcLoc), SurfExpr), usize),
+    __1: (usize, &'input str, usize),
+) -> alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)> {
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action107(input, __0, __1);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action108(input, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action115<'input>(
+    input: &'input str, __0: (usize, alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+    __1: (usize, ((Option<i64>, SrcLoc), SurfExpr), usize), __2: (usize, &'input str, usize),
+) -> alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)> {
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action107(input, __1, __2);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action109(input, __0, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action116<'input>(
+    input: &'input str, __0: (usize, Option<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+) -> Vec<((Option<i64>, SrcLoc), SurfExpr)> {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action105(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action59(input, __temp0, __0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action117<'input>(
+    input: &'input str, __0: (usize, alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+    __1: (usize, Option<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+) -> Vec<((Option<i64>, SrcLoc), SurfExpr)> {
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action106(input, __0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action59(input, __temp0, __1)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action118<'input>(
     input: &'input str, __0: (usize, SurfBinding, usize), __1: (usize, &'input str, usize),
 ) -> alloc::vec::Vec<SurfBinding> {
     let __start0 = __0.0;
     let __end0 = __1.2;
-    let __temp0 = __action61(input, __0, __1);
+    let __temp0 = __action77(input, __0, __1);
     let __temp0 = (__start0, __temp0, __end0);
-    __action70(input, __temp0)
+    __action94(input, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -11981,15 +15434,15 @@ fn __action88<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action89<'input>(
+fn __action119<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<SurfBinding>, usize),
     __1: (usize, SurfBinding, usize), __2: (usize, &'input str, usize),
 ) -> alloc::vec::Vec<SurfBinding> {
     let __start0 = __1.0;
     let __end0 = __2.2;
-    let __temp0 = __action61(input, __1, __2);
+    let __temp0 = __action77(input, __1, __2);
     let __temp0 = (__start0, __temp0, __end0);
-    __action71(input, __0, __temp0)
+    __action95(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -11998,12 +15451,12 @@ fn __action89<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action90<'input>(input: &'input str, __0: (usize, SurfBinding, usize)) -> Vec<SurfBinding> {
+fn __action120<'input>(input: &'input str, __0: (usize, SurfBinding, usize)) -> Vec<SurfBinding> {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action59(input, &__start0, &__end0);
+    let __temp0 = __action75(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action9(input, __temp0, __0)
+    __action12(input, __temp0, __0)
 }
 
 #[allow(unused_variables)]
@@ -12012,15 +15465,15 @@ fn __action90<'input>(input: &'input str, __0: (usize, SurfBinding, usize)) -> V
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action91<'input>(
+fn __action121<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<SurfBinding>, usize),
     __1: (usize, SurfBinding, usize),
 ) -> Vec<SurfBinding> {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action60(input, __0);
+    let __temp0 = __action76(input, __0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action9(input, __temp0, __1)
+    __action12(input, __temp0, __1)
 }
 
 #[allow(unused_variables)]
@@ -12029,14 +15482,47 @@ fn __action91<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action92<'input>(
+fn __action122<'input>(
+    input: &'input str, __0: (usize, SurfBinding, usize), __1: (usize, &'input str, usize),
+) -> alloc::vec::Vec<SurfBinding> {
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action74(input, __0, __1);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action72(input, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action123<'input>(
+    input: &'input str, __0: (usize, alloc::vec::Vec<SurfBinding>, usize),
+    __1: (usize, SurfBinding, usize), __2: (usize, &'input str, usize),
+) -> alloc::vec::Vec<SurfBinding> {
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action74(input, __1, __2);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action73(input, __0, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action124<'input>(
     input: &'input str, __0: (usize, SurfExpr, usize), __1: (usize, &'input str, usize),
 ) -> alloc::vec::Vec<SurfExpr> {
     let __start0 = __0.0;
     let __end0 = __1.2;
-    let __temp0 = __action83(input, __0, __1);
+    let __temp0 = __action102(input, __0, __1);
     let __temp0 = (__start0, __temp0, __end0);
-    __action84(input, __temp0)
+    __action110(input, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12045,15 +15531,15 @@ fn __action92<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action93<'input>(
+fn __action125<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<SurfExpr>, usize),
     __1: (usize, SurfExpr, usize), __2: (usize, &'input str, usize),
 ) -> alloc::vec::Vec<SurfExpr> {
     let __start0 = __1.0;
     let __end0 = __2.2;
-    let __temp0 = __action83(input, __1, __2);
+    let __temp0 = __action102(input, __1, __2);
     let __temp0 = (__start0, __temp0, __end0);
-    __action85(input, __0, __temp0)
+    __action111(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12062,12 +15548,12 @@ fn __action93<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action94<'input>(input: &'input str, __0: (usize, Option<SurfExpr>, usize)) -> Vec<SurfExpr> {
+fn __action126<'input>(input: &'input str, __0: (usize, Option<SurfExpr>, usize)) -> Vec<SurfExpr> {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action81(input, &__start0, &__end0);
+    let __temp0 = __action100(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action46(input, __temp0, __0)
+    __action60(input, __temp0, __0)
 }
 
 #[allow(unused_variables)]
@@ -12076,15 +15562,15 @@ fn __action94<'input>(input: &'input str, __0: (usize, Option<SurfExpr>, usize))
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action95<'input>(
+fn __action127<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<SurfExpr>, usize),
     __1: (usize, Option<SurfExpr>, usize),
 ) -> Vec<SurfExpr> {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action82(input, __0);
+    let __temp0 = __action101(input, __0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action46(input, __temp0, __1)
+    __action60(input, __temp0, __1)
 }
 
 #[allow(unused_variables)]
@@ -12093,14 +15579,14 @@ fn __action95<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action96<'input>(
+fn __action128<'input>(
     input: &'input str, __0: (usize, SurfFunDecl, usize), __1: (usize, &'input str, usize),
 ) -> alloc::vec::Vec<SurfFunDecl> {
     let __start0 = __0.0;
     let __end0 = __1.2;
-    let __temp0 = __action58(input, __0, __1);
+    let __temp0 = __action71(input, __0, __1);
     let __temp0 = (__start0, __temp0, __end0);
-    __action72(input, __temp0)
+    __action96(input, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12109,15 +15595,15 @@ fn __action96<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action97<'input>(
+fn __action129<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<SurfFunDecl>, usize),
     __1: (usize, SurfFunDecl, usize), __2: (usize, &'input str, usize),
 ) -> alloc::vec::Vec<SurfFunDecl> {
     let __start0 = __1.0;
     let __end0 = __2.2;
-    let __temp0 = __action58(input, __1, __2);
+    let __temp0 = __action71(input, __1, __2);
     let __temp0 = (__start0, __temp0, __end0);
-    __action73(input, __0, __temp0)
+    __action97(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12126,16 +15612,16 @@ fn __action97<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action98<'input>(
+fn __action130<'input>(
     input: &'input str, __0: (usize, usize, usize), __1: (usize, SurfFunDecl, usize),
     __2: (usize, &'input str, usize), __3: (usize, Box<SurfExpr>, usize),
     __4: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.2;
     let __end0 = __1.0;
-    let __temp0 = __action56(input, &__start0, &__end0);
+    let __temp0 = __action69(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action12(input, __0, __temp0, __1, __2, __3, __4)
+    __action16(input, __0, __temp0, __1, __2, __3, __4)
 }
 
 #[allow(unused_variables)]
@@ -12144,7 +15630,7 @@ fn __action98<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action99<'input>(
+fn __action131<'input>(
     input: &'input str, __0: (usize, usize, usize),
     __1: (usize, alloc::vec::Vec<SurfFunDecl>, usize), __2: (usize, SurfFunDecl, usize),
     __3: (usize, &'input str, usize), __4: (usize, Box<SurfExpr>, usize),
@@ -12152,9 +15638,9 @@ fn __action99<'input>(
 ) -> SurfExpr {
     let __start0 = __1.0;
     let __end0 = __1.2;
-    let __temp0 = __action57(input, __1);
+    let __temp0 = __action70(input, __1);
     let __temp0 = (__start0, __temp0, __end0);
-    __action12(input, __0, __temp0, __2, __3, __4, __5)
+    __action16(input, __0, __temp0, __2, __3, __4, __5)
 }
 
 #[allow(unused_variables)]
@@ -12163,14 +15649,14 @@ fn __action99<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action100<'input>(
+fn __action132<'input>(
     input: &'input str, __0: (usize, (String, SrcLoc), usize), __1: (usize, &'input str, usize),
 ) -> alloc::vec::Vec<(String, SrcLoc)> {
     let __start0 = __0.0;
     let __end0 = __1.2;
-    let __temp0 = __action78(input, __0, __1);
+    let __temp0 = __action93(input, __0, __1);
     let __temp0 = (__start0, __temp0, __end0);
-    __action86(input, __temp0)
+    __action112(input, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12179,15 +15665,15 @@ fn __action100<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action101<'input>(
+fn __action133<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<(String, SrcLoc)>, usize),
     __1: (usize, (String, SrcLoc), usize), __2: (usize, &'input str, usize),
 ) -> alloc::vec::Vec<(String, SrcLoc)> {
     let __start0 = __1.0;
     let __end0 = __2.2;
-    let __temp0 = __action78(input, __1, __2);
+    let __temp0 = __action93(input, __1, __2);
     let __temp0 = (__start0, __temp0, __end0);
-    __action87(input, __0, __temp0)
+    __action113(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12196,14 +15682,14 @@ fn __action101<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action102<'input>(
+fn __action134<'input>(
     input: &'input str, __0: (usize, Option<(String, SrcLoc)>, usize),
 ) -> Vec<(String, SrcLoc)> {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action76(input, &__start0, &__end0);
+    let __temp0 = __action91(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action55(input, __temp0, __0)
+    __action80(input, __temp0, __0)
 }
 
 #[allow(unused_variables)]
@@ -12212,15 +15698,15 @@ fn __action102<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action103<'input>(
+fn __action135<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<(String, SrcLoc)>, usize),
     __1: (usize, Option<(String, SrcLoc)>, usize),
 ) -> Vec<(String, SrcLoc)> {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action77(input, __0);
+    let __temp0 = __action92(input, __0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action55(input, __temp0, __1)
+    __action80(input, __temp0, __1)
 }
 
 #[allow(unused_variables)]
@@ -12229,14 +15715,14 @@ fn __action103<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action104<'input>(
+fn __action136<'input>(
     input: &'input str, __0: (usize, String, usize), __1: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action33(input, __temp0, __0, __1)
+    __action37(input, __temp0, __0, __1)
 }
 
 #[allow(unused_variables)]
@@ -12245,14 +15731,14 @@ fn __action104<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action105<'input>(
+fn __action137<'input>(
     input: &'input str, __0: (usize, i64, usize), __1: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action34(input, __temp0, __0, __1)
+    __action38(input, __temp0, __0, __1)
 }
 
 #[allow(unused_variables)]
@@ -12261,14 +15747,31 @@ fn __action105<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action106<'input>(
+fn __action138<'input>(
     input: &'input str, __0: (usize, bool, usize), __1: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action39(input, __temp0, __0, __1)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action139<'input>(
+    input: &'input str, __0: (usize, Prim, usize), __1: (usize, &'input str, usize),
+    __2: (usize, SurfExpr, usize), __3: (usize, &'input str, usize), __4: (usize, usize, usize),
+) -> SurfExpr {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action35(input, __temp0, __0, __1)
+    __action40(input, __temp0, __0, __1, __2, __3, __4)
 }
 
 #[allow(unused_variables)]
@@ -12277,15 +15780,16 @@ fn __action106<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action107<'input>(
+fn __action140<'input>(
     input: &'input str, __0: (usize, Prim, usize), __1: (usize, &'input str, usize),
-    __2: (usize, SurfExpr, usize), __3: (usize, &'input str, usize), __4: (usize, usize, usize),
+    __2: (usize, SurfExpr, usize), __3: (usize, &'input str, usize), __4: (usize, SurfExpr, usize),
+    __5: (usize, &'input str, usize), __6: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action36(input, __temp0, __0, __1, __2, __3, __4)
+    __action41(input, __temp0, __0, __1, __2, __3, __4, __5, __6)
 }
 
 #[allow(unused_variables)]
@@ -12294,16 +15798,16 @@ fn __action107<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action108<'input>(
+fn __action141<'input>(
     input: &'input str, __0: (usize, String, usize), __1: (usize, &'input str, usize),
     __2: (usize, Vec<SurfExpr>, usize), __3: (usize, &'input str, usize),
     __4: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action37(input, __temp0, __0, __1, __2, __3, __4)
+    __action42(input, __temp0, __0, __1, __2, __3, __4)
 }
 
 #[allow(unused_variables)]
@@ -12312,16 +15816,16 @@ fn __action108<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action109<'input>(
+fn __action142<'input>(
     input: &'input str, __0: (usize, &'input str, usize), __1: (usize, &'input str, usize),
     __2: (usize, &'input str, usize), __3: (usize, Vec<(String, SrcLoc)>, usize),
     __4: (usize, &'input str, usize), __5: (usize, usize, usize),
 ) -> Result<SurfExtDecl, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action14(input, __temp0, __0, __1, __2, __3, __4, __5)
+    __action18(input, __temp0, __0, __1, __2, __3, __4, __5)
 }
 
 #[allow(unused_variables)]
@@ -12330,7 +15834,7 @@ fn __action109<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action110<'input>(
+fn __action143<'input>(
     input: &'input str, __0: (usize, &'input str, usize), __1: (usize, String, usize),
     __2: (usize, &'input str, usize), __3: (usize, Vec<(String, SrcLoc)>, usize),
     __4: (usize, &'input str, usize), __5: (usize, &'input str, usize),
@@ -12338,9 +15842,9 @@ fn __action110<'input>(
 ) -> SurfFunDecl {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action13(input, __temp0, __0, __1, __2, __3, __4, __5, __6, __7)
+    __action17(input, __temp0, __0, __1, __2, __3, __4, __5, __6, __7)
 }
 
 #[allow(unused_variables)]
@@ -12349,15 +15853,15 @@ fn __action110<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action111<'input>(
+fn __action144<'input>(
     input: &'input str, __0: (usize, SurfFunDecl, usize), __1: (usize, &'input str, usize),
     __2: (usize, Box<SurfExpr>, usize), __3: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action98(input, __temp0, __0, __1, __2, __3)
+    __action130(input, __temp0, __0, __1, __2, __3)
 }
 
 #[allow(unused_variables)]
@@ -12366,16 +15870,16 @@ fn __action111<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action112<'input>(
+fn __action145<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<SurfFunDecl>, usize),
     __1: (usize, SurfFunDecl, usize), __2: (usize, &'input str, usize),
     __3: (usize, Box<SurfExpr>, usize), __4: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action99(input, __temp0, __0, __1, __2, __3, __4)
+    __action131(input, __temp0, __0, __1, __2, __3, __4)
 }
 
 #[allow(unused_variables)]
@@ -12384,7 +15888,7 @@ fn __action112<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action113<'input>(
+fn __action146<'input>(
     input: &'input str, __0: (usize, &'input str, usize), __1: (usize, Box<SurfExpr>, usize),
     __2: (usize, &'input str, usize), __3: (usize, Box<SurfExpr>, usize),
     __4: (usize, &'input str, usize), __5: (usize, &'input str, usize),
@@ -12392,9 +15896,9 @@ fn __action113<'input>(
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action11(input, __temp0, __0, __1, __2, __3, __4, __5, __6, __7)
+    __action15(input, __temp0, __0, __1, __2, __3, __4, __5, __6, __7)
 }
 
 #[allow(unused_variables)]
@@ -12403,15 +15907,15 @@ fn __action113<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action114<'input>(
+fn __action147<'input>(
     input: &'input str, __0: (usize, SurfExpr, usize), __1: (usize, Prim, usize),
     __2: (usize, SurfExpr, usize), __3: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action51(input, __temp0, __0, __1, __2, __3)
+    __action65(input, __temp0, __0, __1, __2, __3)
 }
 
 #[allow(unused_variables)]
@@ -12420,15 +15924,15 @@ fn __action114<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action115<'input>(
+fn __action148<'input>(
     input: &'input str, __0: (usize, SurfExpr, usize), __1: (usize, Prim, usize),
     __2: (usize, SurfExpr, usize), __3: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action49(input, __temp0, __0, __1, __2, __3)
+    __action63(input, __temp0, __0, __1, __2, __3)
 }
 
 #[allow(unused_variables)]
@@ -12437,15 +15941,15 @@ fn __action115<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action116<'input>(
+fn __action149<'input>(
     input: &'input str, __0: (usize, SurfExpr, usize), __1: (usize, Prim, usize),
     __2: (usize, SurfExpr, usize), __3: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action47(input, __temp0, __0, __1, __2, __3)
+    __action61(input, __temp0, __0, __1, __2, __3)
 }
 
 #[allow(unused_variables)]
@@ -12454,16 +15958,16 @@ fn __action116<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action117<'input>(
+fn __action150<'input>(
     input: &'input str, __0: (usize, &'input str, usize), __1: (usize, Vec<SurfBinding>, usize),
     __2: (usize, &'input str, usize), __3: (usize, Box<SurfExpr>, usize),
     __4: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action8(input, __temp0, __0, __1, __2, __3, __4)
+    __action9(input, __temp0, __0, __1, __2, __3, __4)
 }
 
 #[allow(unused_variables)]
@@ -12472,15 +15976,69 @@ fn __action117<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action118<'input>(
+fn __action151<'input>(
+    input: &'input str, __0: (usize, &'input str, usize), __1: (usize, Vec<SurfBinding>, usize),
+    __2: (usize, &'input str, usize), __3: (usize, Box<SurfExpr>, usize),
+    __4: (usize, usize, usize),
+) -> SurfExpr {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action82(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(input, __temp0, __0, __1, __2, __3, __4)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action152<'input>(
+    input: &'input str, __0: (usize, &'input str, usize), __1: (usize, Vec<SurfBinding>, usize),
+    __2: (usize, &'input str, usize), __3: (usize, Box<SurfExpr>, usize),
+    __4: (usize, usize, usize),
+) -> SurfExpr {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action82(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(input, __temp0, __0, __1, __2, __3, __4)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action153<'input>(
+    input: &'input str, __0: (usize, &'input str, usize), __1: (usize, Box<SurfExpr>, usize),
+    __2: (usize, &'input str, usize), __3: (usize, Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+    __4: (usize, &'input str, usize), __5: (usize, usize, usize),
+) -> Result<SurfExpr, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action82(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action45(input, __temp0, __0, __1, __2, __3, __4, __5)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action154<'input>(
     input: &'input str, __0: (usize, &'input str, usize), __1: (usize, SurfExpr, usize),
     __2: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action31(input, __temp0, __0, __1, __2)
+    __action35(input, __temp0, __0, __1, __2)
 }
 
 #[allow(unused_variables)]
@@ -12489,18 +16047,14 @@ fn __action118<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action119<'input>(
-    input: &'input str, __0: (usize, alloc::vec::Vec<SurfExtDecl>, usize),
-    __1: (usize, &'input str, usize), __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize), __4: (usize, (String, SrcLoc), usize),
-    __5: (usize, &'input str, usize), __6: (usize, &'input str, usize),
-    __7: (usize, SurfExpr, usize), __8: (usize, usize, usize),
-) -> Result<SurfProg, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
-    let __start0 = __0.2;
-    let __end0 = __1.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+fn __action155<'input>(
+    input: &'input str, __0: (usize, &'input str, usize), __1: (usize, usize, usize),
+) -> Result<Option<i64>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action3(input, __0, __temp0, __1, __2, __3, __4, __5, __6, __7, __8)
+    __action48(input, __temp0, __0, __1)
 }
 
 #[allow(unused_variables)]
@@ -12509,15 +16063,15 @@ fn __action119<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action120<'input>(
+fn __action156<'input>(
     input: &'input str, __0: (usize, SurfExpr, usize), __1: (usize, Prim, usize),
     __2: (usize, SurfExpr, usize), __3: (usize, usize, usize),
 ) -> SurfExpr {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action53(input, __temp0, __0, __1, __2, __3)
+    __action67(input, __temp0, __0, __1, __2, __3)
 }
 
 #[allow(unused_variables)]
@@ -12526,14 +16080,53 @@ fn __action120<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action121<'input>(
+fn __action157<'input>(
     input: &'input str, __0: (usize, String, usize), __1: (usize, usize, usize),
 ) -> (String, SrcLoc) {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action65(input, &__start0, &__end0);
+    let __temp0 = __action82(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action81(input, __temp0, __0, __1)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action158<'input>(
+    input: &'input str, __0: (usize, Option<i64>, usize), __1: (usize, usize, usize),
+) -> (Option<i64>, SrcLoc) {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action82(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action58(input, __temp0, __0, __1)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action159<'input>(
+    input: &'input str, __0: (usize, &'input str, usize), __1: (usize, (String, SrcLoc), usize),
+    __2: (usize, &'input str, usize), __3: (usize, Vec<(String, SrcLoc)>, usize),
+    __4: (usize, &'input str, usize), __5: (usize, usize, usize), __6: (usize, &'input str, usize),
+    __7: (usize, SurfExpr, usize), __8: (usize, usize, usize),
+) -> (SrcLoc, SrcLoc, SurfFunDecl) {
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __start1 = __1.2;
+    let __end1 = __2.0;
+    let __temp0 = __action82(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action64(input, __temp0, __0, __1)
+    let __temp1 = __action82(input, &__start1, &__end1);
+    let __temp1 = (__start1, __temp1, __end1);
+    __action4(input, __temp0, __0, __1, __temp1, __2, __3, __4, __5, __6, __7, __8)
 }
 
 #[allow(unused_variables)]
@@ -12542,12 +16135,12 @@ fn __action121<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action122<'input>(input: &'input str, __0: (usize, String, usize)) -> SurfExpr {
+fn __action160<'input>(input: &'input str, __0: (usize, String, usize)) -> SurfExpr {
     let __start0 = __0.2;
     let __end0 = __0.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action104(input, __0, __temp0)
+    __action136(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12556,12 +16149,12 @@ fn __action122<'input>(input: &'input str, __0: (usize, String, usize)) -> SurfE
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action123<'input>(input: &'input str, __0: (usize, i64, usize)) -> SurfExpr {
+fn __action161<'input>(input: &'input str, __0: (usize, i64, usize)) -> SurfExpr {
     let __start0 = __0.2;
     let __end0 = __0.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action105(input, __0, __temp0)
+    __action137(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12570,12 +16163,12 @@ fn __action123<'input>(input: &'input str, __0: (usize, i64, usize)) -> SurfExpr
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action124<'input>(input: &'input str, __0: (usize, bool, usize)) -> SurfExpr {
+fn __action162<'input>(input: &'input str, __0: (usize, bool, usize)) -> SurfExpr {
     let __start0 = __0.2;
     let __end0 = __0.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action106(input, __0, __temp0)
+    __action138(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12584,15 +16177,33 @@ fn __action124<'input>(input: &'input str, __0: (usize, bool, usize)) -> SurfExp
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action125<'input>(
+fn __action163<'input>(
     input: &'input str, __0: (usize, Prim, usize), __1: (usize, &'input str, usize),
     __2: (usize, SurfExpr, usize), __3: (usize, &'input str, usize),
 ) -> SurfExpr {
     let __start0 = __3.2;
     let __end0 = __3.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action139(input, __0, __1, __2, __3, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action164<'input>(
+    input: &'input str, __0: (usize, Prim, usize), __1: (usize, &'input str, usize),
+    __2: (usize, SurfExpr, usize), __3: (usize, &'input str, usize), __4: (usize, SurfExpr, usize),
+    __5: (usize, &'input str, usize),
+) -> SurfExpr {
+    let __start0 = __5.2;
+    let __end0 = __5.2;
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action107(input, __0, __1, __2, __3, __temp0)
+    __action140(input, __0, __1, __2, __3, __4, __5, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12601,15 +16212,15 @@ fn __action125<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action126<'input>(
+fn __action165<'input>(
     input: &'input str, __0: (usize, String, usize), __1: (usize, &'input str, usize),
     __2: (usize, Vec<SurfExpr>, usize), __3: (usize, &'input str, usize),
 ) -> SurfExpr {
     let __start0 = __3.2;
     let __end0 = __3.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action108(input, __0, __1, __2, __3, __temp0)
+    __action141(input, __0, __1, __2, __3, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12618,16 +16229,16 @@ fn __action126<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action127<'input>(
+fn __action166<'input>(
     input: &'input str, __0: (usize, &'input str, usize), __1: (usize, &'input str, usize),
     __2: (usize, &'input str, usize), __3: (usize, Vec<(String, SrcLoc)>, usize),
     __4: (usize, &'input str, usize),
 ) -> Result<SurfExtDecl, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
     let __start0 = __4.2;
     let __end0 = __4.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action109(input, __0, __1, __2, __3, __4, __temp0)
+    __action142(input, __0, __1, __2, __3, __4, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12636,7 +16247,7 @@ fn __action127<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action128<'input>(
+fn __action167<'input>(
     input: &'input str, __0: (usize, &'input str, usize), __1: (usize, String, usize),
     __2: (usize, &'input str, usize), __3: (usize, Vec<(String, SrcLoc)>, usize),
     __4: (usize, &'input str, usize), __5: (usize, &'input str, usize),
@@ -12644,9 +16255,9 @@ fn __action128<'input>(
 ) -> SurfFunDecl {
     let __start0 = __6.2;
     let __end0 = __6.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action110(input, __0, __1, __2, __3, __4, __5, __6, __temp0)
+    __action143(input, __0, __1, __2, __3, __4, __5, __6, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12655,15 +16266,15 @@ fn __action128<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action129<'input>(
+fn __action168<'input>(
     input: &'input str, __0: (usize, SurfFunDecl, usize), __1: (usize, &'input str, usize),
     __2: (usize, Box<SurfExpr>, usize),
 ) -> SurfExpr {
     let __start0 = __2.2;
     let __end0 = __2.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action111(input, __0, __1, __2, __temp0)
+    __action144(input, __0, __1, __2, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12672,16 +16283,16 @@ fn __action129<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action130<'input>(
+fn __action169<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<SurfFunDecl>, usize),
     __1: (usize, SurfFunDecl, usize), __2: (usize, &'input str, usize),
     __3: (usize, Box<SurfExpr>, usize),
 ) -> SurfExpr {
     let __start0 = __3.2;
     let __end0 = __3.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action112(input, __0, __1, __2, __3, __temp0)
+    __action145(input, __0, __1, __2, __3, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12690,7 +16301,7 @@ fn __action130<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action131<'input>(
+fn __action170<'input>(
     input: &'input str, __0: (usize, &'input str, usize), __1: (usize, Box<SurfExpr>, usize),
     __2: (usize, &'input str, usize), __3: (usize, Box<SurfExpr>, usize),
     __4: (usize, &'input str, usize), __5: (usize, &'input str, usize),
@@ -12698,9 +16309,9 @@ fn __action131<'input>(
 ) -> SurfExpr {
     let __start0 = __6.2;
     let __end0 = __6.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action113(input, __0, __1, __2, __3, __4, __5, __6, __temp0)
+    __action146(input, __0, __1, __2, __3, __4, __5, __6, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12709,15 +16320,15 @@ fn __action131<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action132<'input>(
+fn __action171<'input>(
     input: &'input str, __0: (usize, SurfExpr, usize), __1: (usize, Prim, usize),
     __2: (usize, SurfExpr, usize),
 ) -> SurfExpr {
     let __start0 = __2.2;
     let __end0 = __2.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action114(input, __0, __1, __2, __temp0)
+    __action147(input, __0, __1, __2, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12726,15 +16337,15 @@ fn __action132<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action133<'input>(
+fn __action172<'input>(
     input: &'input str, __0: (usize, SurfExpr, usize), __1: (usize, Prim, usize),
     __2: (usize, SurfExpr, usize),
 ) -> SurfExpr {
     let __start0 = __2.2;
     let __end0 = __2.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action115(input, __0, __1, __2, __temp0)
+    __action148(input, __0, __1, __2, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12743,15 +16354,15 @@ fn __action133<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action134<'input>(
+fn __action173<'input>(
     input: &'input str, __0: (usize, SurfExpr, usize), __1: (usize, Prim, usize),
     __2: (usize, SurfExpr, usize),
 ) -> SurfExpr {
     let __start0 = __2.2;
     let __end0 = __2.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action116(input, __0, __1, __2, __temp0)
+    __action149(input, __0, __1, __2, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12760,15 +16371,15 @@ fn __action134<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action135<'input>(
+fn __action174<'input>(
     input: &'input str, __0: (usize, &'input str, usize), __1: (usize, Vec<SurfBinding>, usize),
     __2: (usize, &'input str, usize), __3: (usize, Box<SurfExpr>, usize),
 ) -> SurfExpr {
     let __start0 = __3.2;
     let __end0 = __3.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action117(input, __0, __1, __2, __3, __temp0)
+    __action150(input, __0, __1, __2, __3, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12777,14 +16388,66 @@ fn __action135<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action136<'input>(
+fn __action175<'input>(
+    input: &'input str, __0: (usize, &'input str, usize), __1: (usize, Vec<SurfBinding>, usize),
+    __2: (usize, &'input str, usize), __3: (usize, Box<SurfExpr>, usize),
+) -> SurfExpr {
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action79(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action151(input, __0, __1, __2, __3, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action176<'input>(
+    input: &'input str, __0: (usize, &'input str, usize), __1: (usize, Vec<SurfBinding>, usize),
+    __2: (usize, &'input str, usize), __3: (usize, Box<SurfExpr>, usize),
+) -> SurfExpr {
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action79(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action152(input, __0, __1, __2, __3, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action177<'input>(
+    input: &'input str, __0: (usize, &'input str, usize), __1: (usize, Box<SurfExpr>, usize),
+    __2: (usize, &'input str, usize), __3: (usize, Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+    __4: (usize, &'input str, usize),
+) -> Result<SurfExpr, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    let __start0 = __4.2;
+    let __end0 = __4.2;
+    let __temp0 = __action79(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action153(input, __0, __1, __2, __3, __4, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action178<'input>(
     input: &'input str, __0: (usize, &'input str, usize), __1: (usize, SurfExpr, usize),
 ) -> SurfExpr {
     let __start0 = __1.2;
     let __end0 = __1.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action118(input, __0, __1, __temp0)
+    __action154(input, __0, __1, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12793,18 +16456,14 @@ fn __action136<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action137<'input>(
-    input: &'input str, __0: (usize, alloc::vec::Vec<SurfExtDecl>, usize),
-    __1: (usize, &'input str, usize), __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize), __4: (usize, (String, SrcLoc), usize),
-    __5: (usize, &'input str, usize), __6: (usize, &'input str, usize),
-    __7: (usize, SurfExpr, usize),
-) -> Result<SurfProg, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
-    let __start0 = __7.2;
-    let __end0 = __7.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+fn __action179<'input>(
+    input: &'input str, __0: (usize, &'input str, usize),
+) -> Result<Option<i64>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action79(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action119(input, __0, __1, __2, __3, __4, __5, __6, __7, __temp0)
+    __action155(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12813,15 +16472,117 @@ fn __action137<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action138<'input>(
+fn __action180<'input>(
     input: &'input str, __0: (usize, SurfExpr, usize), __1: (usize, Prim, usize),
     __2: (usize, SurfExpr, usize),
 ) -> SurfExpr {
     let __start0 = __2.2;
     let __end0 = __2.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action79(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action156(input, __0, __1, __2, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action181<'input>(input: &'input str, __0: (usize, String, usize)) -> (String, SrcLoc) {
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action79(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action157(input, __0, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action182<'input>(
+    input: &'input str, __0: (usize, Option<i64>, usize),
+) -> (Option<i64>, SrcLoc) {
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action79(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action158(input, __0, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action183<'input>(
+    input: &'input str, __0: (usize, &'input str, usize), __1: (usize, (String, SrcLoc), usize),
+    __2: (usize, &'input str, usize), __3: (usize, Vec<(String, SrcLoc)>, usize),
+    __4: (usize, &'input str, usize), __5: (usize, &'input str, usize),
+    __6: (usize, SurfExpr, usize),
+) -> (SrcLoc, SrcLoc, SurfFunDecl) {
+    let __start0 = __4.2;
+    let __end0 = __5.0;
+    let __start1 = __6.2;
+    let __end1 = __6.2;
+    let __temp0 = __action79(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action79(input, &__start1, &__end1);
+    let __temp1 = (__start1, __temp1, __end1);
+    __action159(input, __0, __1, __2, __3, __4, __temp0, __5, __6, __temp1)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action184<'input>(
+    input: &'input str, __0: (usize, ((Option<i64>, SrcLoc), SurfExpr), usize),
+) -> Vec<((Option<i64>, SrcLoc), SurfExpr)> {
+    let __start0 = __0.0;
+    let __end0 = __0.2;
+    let __temp0 = __action103(input, __0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action116(input, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action185<'input>(
+    input: &'input str, __lookbehind: &usize, __lookahead: &usize,
+) -> Vec<((Option<i64>, SrcLoc), SurfExpr)> {
+    let __start0 = *__lookbehind;
+    let __end0 = *__lookahead;
+    let __temp0 = __action104(input, &__start0, &__end0);
+    let __temp0 = (__start0, __temp0, __end0);
+    __action116(input, __temp0)
+}
+
+#[allow(unused_variables)]
+#[allow(
+    clippy::too_many_arguments,
+    clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits
+)]
+fn __action186<'input>(
+    input: &'input str, __0: (usize, alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+    __1: (usize, ((Option<i64>, SrcLoc), SurfExpr), usize),
+) -> Vec<((Option<i64>, SrcLoc), SurfExpr)> {
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action103(input, __1);
     let __temp0 = (__start0, __temp0, __end0);
-    __action120(input, __0, __1, __2, __temp0)
+    __action117(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12830,12 +16591,14 @@ fn __action138<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action139<'input>(input: &'input str, __0: (usize, String, usize)) -> (String, SrcLoc) {
+fn __action187<'input>(
+    input: &'input str, __0: (usize, alloc::vec::Vec<((Option<i64>, SrcLoc), SurfExpr)>, usize),
+) -> Vec<((Option<i64>, SrcLoc), SurfExpr)> {
     let __start0 = __0.2;
     let __end0 = __0.2;
-    let __temp0 = __action63(input, &__start0, &__end0);
+    let __temp0 = __action104(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action121(input, __0, __temp0)
+    __action117(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12844,12 +16607,12 @@ fn __action139<'input>(input: &'input str, __0: (usize, String, usize)) -> (Stri
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action140<'input>(input: &'input str, __0: (usize, SurfExpr, usize)) -> Vec<SurfExpr> {
+fn __action188<'input>(input: &'input str, __0: (usize, SurfExpr, usize)) -> Vec<SurfExpr> {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action79(input, __0);
+    let __temp0 = __action98(input, __0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action94(input, __temp0)
+    __action126(input, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12858,14 +16621,14 @@ fn __action140<'input>(input: &'input str, __0: (usize, SurfExpr, usize)) -> Vec
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action141<'input>(
+fn __action189<'input>(
     input: &'input str, __lookbehind: &usize, __lookahead: &usize,
 ) -> Vec<SurfExpr> {
     let __start0 = *__lookbehind;
     let __end0 = *__lookahead;
-    let __temp0 = __action80(input, &__start0, &__end0);
+    let __temp0 = __action99(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action94(input, __temp0)
+    __action126(input, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12874,15 +16637,15 @@ fn __action141<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action142<'input>(
+fn __action190<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<SurfExpr>, usize),
     __1: (usize, SurfExpr, usize),
 ) -> Vec<SurfExpr> {
     let __start0 = __1.0;
     let __end0 = __1.2;
-    let __temp0 = __action79(input, __1);
+    let __temp0 = __action98(input, __1);
     let __temp0 = (__start0, __temp0, __end0);
-    __action95(input, __0, __temp0)
+    __action127(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12891,14 +16654,14 @@ fn __action142<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action143<'input>(
+fn __action191<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<SurfExpr>, usize),
 ) -> Vec<SurfExpr> {
     let __start0 = __0.2;
     let __end0 = __0.2;
-    let __temp0 = __action80(input, &__start0, &__end0);
+    let __temp0 = __action99(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action95(input, __0, __temp0)
+    __action127(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12907,17 +16670,14 @@ fn __action143<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action144<'input>(
-    input: &'input str, __0: (usize, &'input str, usize), __1: (usize, &'input str, usize),
-    __2: (usize, &'input str, usize), __3: (usize, (String, SrcLoc), usize),
-    __4: (usize, &'input str, usize), __5: (usize, &'input str, usize),
-    __6: (usize, SurfExpr, usize),
+fn __action192<'input>(
+    input: &'input str, __0: (usize, alloc::vec::Vec<(SrcLoc, SrcLoc, SurfFunDecl)>, usize),
 ) -> Result<SurfProg, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action66(input, &__start0, &__end0);
+    let __temp0 = __action85(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action137(input, __temp0, __0, __1, __2, __3, __4, __5, __6)
+    __action3(input, __temp0, __0)
 }
 
 #[allow(unused_variables)]
@@ -12926,18 +16686,15 @@ fn __action144<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action145<'input>(
+fn __action193<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<SurfExtDecl>, usize),
-    __1: (usize, &'input str, usize), __2: (usize, &'input str, usize),
-    __3: (usize, &'input str, usize), __4: (usize, (String, SrcLoc), usize),
-    __5: (usize, &'input str, usize), __6: (usize, &'input str, usize),
-    __7: (usize, SurfExpr, usize),
+    __1: (usize, alloc::vec::Vec<(SrcLoc, SrcLoc, SurfFunDecl)>, usize),
 ) -> Result<SurfProg, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action67(input, __0);
+    let __temp0 = __action86(input, __0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action137(input, __temp0, __1, __2, __3, __4, __5, __6, __7)
+    __action3(input, __temp0, __1)
 }
 
 #[allow(unused_variables)]
@@ -12946,14 +16703,14 @@ fn __action145<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action146<'input>(
+fn __action194<'input>(
     input: &'input str, __0: (usize, (String, SrcLoc), usize),
 ) -> Vec<(String, SrcLoc)> {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action74(input, __0);
+    let __temp0 = __action89(input, __0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action102(input, __temp0)
+    __action134(input, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12962,14 +16719,14 @@ fn __action146<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action147<'input>(
+fn __action195<'input>(
     input: &'input str, __lookbehind: &usize, __lookahead: &usize,
 ) -> Vec<(String, SrcLoc)> {
     let __start0 = *__lookbehind;
     let __end0 = *__lookahead;
-    let __temp0 = __action75(input, &__start0, &__end0);
+    let __temp0 = __action90(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action102(input, __temp0)
+    __action134(input, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12978,15 +16735,15 @@ fn __action147<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action148<'input>(
+fn __action196<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<(String, SrcLoc)>, usize),
     __1: (usize, (String, SrcLoc), usize),
 ) -> Vec<(String, SrcLoc)> {
     let __start0 = __1.0;
     let __end0 = __1.2;
-    let __temp0 = __action74(input, __1);
+    let __temp0 = __action89(input, __1);
     let __temp0 = (__start0, __temp0, __end0);
-    __action103(input, __0, __temp0)
+    __action135(input, __0, __temp0)
 }
 
 #[allow(unused_variables)]
@@ -12995,14 +16752,14 @@ fn __action148<'input>(
     clippy::needless_lifetimes,
     clippy::just_underscores_and_digits
 )]
-fn __action149<'input>(
+fn __action197<'input>(
     input: &'input str, __0: (usize, alloc::vec::Vec<(String, SrcLoc)>, usize),
 ) -> Vec<(String, SrcLoc)> {
     let __start0 = __0.2;
     let __end0 = __0.2;
-    let __temp0 = __action75(input, &__start0, &__end0);
+    let __temp0 = __action90(input, &__start0, &__end0);
     let __temp0 = (__start0, __temp0, __end0);
-    __action103(input, __0, __temp0)
+    __action135(input, __0, __temp0)
 }
 
 #[allow(clippy::type_complexity, dead_code)]
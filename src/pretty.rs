@@ -1,4 +1,10 @@
+use crate::ast::{
+    Binding, BoundExpr, BoundFunDecl, BoundProg, Expr, LetKind, SrcLoc,
+};
+use crate::identifiers::{FunName, VarName};
+use std::collections::HashMap;
 use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
 
 /* ------------------------------- Combinators ------------------------------ */
 
@@ -43,6 +49,149 @@ impl<T: Display, Iter: Iterator<Item = T> + Clone> Display for LineBreaks<'_, T,
     }
 }
 
+/* ------------------------------ Annotations ------------------------------ */
+
+/// The SSA program as it is displayed, with each block that may be branched to
+/// followed by a comment listing the blocks that branch to it, as in
+/// `block jn#5(x%9): ; preds: thn#3, els#4`.
+pub fn ssa_with_preds(prog: &crate::ssa::Program) -> String {
+    impl_ssa::WithPreds(prog, &prog.predecessors()).to_string()
+}
+
+/* ------------------------------ Source Names ----------------------------- */
+
+/// The resolved program written with the names from the source instead of
+/// the unique ones. A name bound while an earlier binding of it is still
+/// in scope is primed once for each such binding, so `let x = 1 in let x
+/// = x + 1 in x` reads as `let x = 1 in let x' = x + 1 in x'`, while
+/// bindings in scopes that do not overlap keep the bare name.
+pub fn readable(prog: &crate::ast::BoundProg) -> crate::ast::SurfProg {
+    let mut names = SourceNames::default();
+    names.prog(prog);
+    let SourceNames { vars, funs } = names;
+    prog.map_names(&mut |v| vars.name(v.hint(), v), &mut |f| funs.name(f.hint(), f))
+}
+
+/// The names given to the bindings seen so far, and how many bindings of
+/// each hint are in scope.
+struct Primes<Id> {
+    names: HashMap<Id, String>,
+    in_scope: HashMap<String, usize>,
+}
+impl<Id> Default for Primes<Id> {
+    fn default() -> Self {
+        Primes { names: Default::default(), in_scope: Default::default() }
+    }
+}
+impl<Id: Clone + Eq + Hash> Primes<Id> {
+    /// Name `id` after `hint`, primed once for each binding of `hint` in
+    /// scope, and bring it into scope.
+    fn bind(&mut self, hint: &str, id: &Id) {
+        let depth = self.in_scope.entry(hint.to_string()).or_default();
+        self.names.insert(id.clone(), format!("{}{}", hint, "'".repeat(*depth)));
+        *depth += 1;
+    }
+    fn unbind(&mut self, hint: &str) {
+        *self.in_scope.get_mut(hint).expect("unbinding an unbound name") -= 1;
+    }
+    fn name(&self, hint: &str, id: &Id) -> String {
+        self.names.get(id).cloned().unwrap_or_else(|| hint.to_string())
+    }
+}
+
+/// Walks a resolved program in scope order, naming each binding.
+#[derive(Default)]
+struct SourceNames {
+    vars: Primes<VarName>,
+    funs: Primes<FunName>,
+}
+impl SourceNames {
+    fn prog(&mut self, prog: &BoundProg) {
+        // Unmangled names are the ones the outside world knows, so they
+        // are bound first and keep their spelling even if a local
+        // function shares it.
+        self.funs.bind(prog.name.hint(), &prog.name);
+        for ext in &prog.externs {
+            self.funs.bind(ext.name.hint(), &ext.name);
+            self.params(&ext.params, |_| {});
+        }
+        self.decls(&prog.funs, |_| {});
+        let (param, _) = &prog.param;
+        self.vars.bind(param.hint(), param);
+        self.expr(&prog.body);
+    }
+
+    /// Name the parameters `params`, and `inside` them.
+    fn params(
+        &mut self, params: &[(VarName, SrcLoc)], inside: impl FnOnce(&mut Self),
+    ) {
+        for (param, _) in params {
+            self.vars.bind(param.hint(), param);
+        }
+        inside(self);
+        for (param, _) in params {
+            self.vars.unbind(param.hint());
+        }
+    }
+
+    /// Name the functions `decls`, which are in scope in each other's
+    /// bodies and `inside` them.
+    fn decls(&mut self, decls: &[BoundFunDecl], inside: impl FnOnce(&mut Self)) {
+        for decl in decls {
+            self.funs.bind(decl.name.hint(), &decl.name);
+        }
+        for decl in decls {
+            self.params(&decl.params, |names| names.expr(&decl.body));
+        }
+        inside(self);
+        for decl in decls {
+            self.funs.unbind(decl.name.hint());
+        }
+    }
+
+    fn expr(&mut self, expr: &BoundExpr) {
+        match expr {
+            Expr::Num(..) | Expr::Bool(..) | Expr::Var(..) => {}
+            Expr::Prim { args, .. } | Expr::Call { args, .. } => {
+                args.iter().for_each(|arg| self.expr(arg))
+            }
+            Expr::Let { bindings, body, kind, loc: _ } => {
+                if *kind == LetKind::Parallel {
+                    bindings.iter().for_each(|b| self.expr(&b.expr));
+                }
+                for Binding { var: (var, _), expr } in bindings {
+                    if *kind == LetKind::Sequential {
+                        self.expr(expr);
+                    }
+                    self.vars.bind(var.hint(), var);
+                }
+                if *kind == LetKind::Recursive {
+                    bindings.iter().for_each(|b| self.expr(&b.expr));
+                }
+                self.expr(body);
+                for Binding { var: (var, _), .. } in bindings {
+                    self.vars.unbind(var.hint());
+                }
+            }
+            Expr::If { cond, thn, els, loc: _ } => {
+                self.expr(cond);
+                self.expr(thn);
+                self.expr(els);
+            }
+            Expr::FunDefs { decls, body, loc: _ } => {
+                self.decls(decls, |names| names.expr(body))
+            }
+            Expr::Match { scrutinee, arms, default, loc: _ } => {
+                self.expr(scrutinee);
+                arms.iter().for_each(|arm| self.expr(&arm.body));
+                if let Some(default) = default {
+                    self.expr(default);
+                }
+            }
+        }
+    }
+}
+
 /* ----------------------------- Implementations ---------------------------- */
 
 /// Pretty ugly printing of the (Resolved) AST
@@ -52,8 +201,9 @@ mod impl_ast {
 
     impl<Var: Display, Fun: Display> Display for Prog<Var, Fun> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            let Prog { externs, name, param: (param, _), body, loc: _ } = self;
-            write!(f, "{}def {}({}): {}", LineBreaks(&externs.iter()), name, param, body)
+            let Prog { externs, funs, name, param: (param, _), body, loc: _ } = self;
+            write!(f, "{}{}", LineBreaks(&externs.iter()), LineBreaks(&funs.iter()))?;
+            write!(f, "def {}({}): {}", name, param, body)
         }
     }
 
@@ -69,20 +219,34 @@ mod impl_ast {
                 Expr::Num(n, _) => write!(f, "{}", n),
                 Expr::Bool(b, _) => write!(f, "{}", b),
                 Expr::Var(v, _) => write!(f, "{}", v),
-                Expr::Prim { prim, args, loc: _ } => match prim.arity() {
-                    1 => write!(f, "({} {})", prim, &args[0]),
-                    2 => write!(f, "({} {} {})", &args[0], prim, &args[1]),
-                    _ => unreachable!(),
+                Expr::Prim { prim, args, loc: _ } => match prim.info().fixity {
+                    Fixity::Call => write!(f, "{}({})", prim, Comma(&args.iter())),
+                    Fixity::Prefix => write!(f, "({}{})", prim, &args[0]),
+                    Fixity::Infix { .. } => write!(f, "({} {} {})", &args[0], prim, &args[1]),
                 },
-                Expr::Let { bindings, body, loc: _ } => {
+                Expr::Let { bindings, body, kind: LetKind::Sequential, loc: _ } => {
                     write!(f, "let {} in {}", Comma(&bindings.iter()), body)
                 }
+                Expr::Let { bindings, body, kind: LetKind::Parallel, loc: _ } => {
+                    write!(f, "let {} in {}", Separated(&bindings.iter(), " and "), body)
+                }
+                Expr::Let { bindings, body, kind: LetKind::Recursive, loc: _ } => {
+                    write!(f, "letrec {} in {}", Comma(&bindings.iter()), body)
+                }
                 Expr::If { cond, thn, els, loc: _ } => {
                     write!(f, "if {}: {} else: {}", cond, thn, els)
                 }
                 Expr::FunDefs { decls, body, loc: _ } => {
                     write!(f, "{} in {}", Separated(&decls.iter(), " and "), body)
                 }
+                Expr::Match { scrutinee, arms, default, loc: _ } => {
+                    write!(f, "match {} {{ {}", scrutinee, Comma(&arms.iter()))?;
+                    match default {
+                        Some(default) if arms.is_empty() => write!(f, "_ => {} }}", default),
+                        Some(default) => write!(f, ", _ => {} }}", default),
+                        None => write!(f, " }}"),
+                    }
+                }
                 Expr::Call { fun, args, loc: _ } => {
                     write!(f, "{}({})", fun, Comma(&args.iter()))
                 }
@@ -90,6 +254,12 @@ mod impl_ast {
         }
     }
 
+    impl<Var: Display, Fun: Display> Display for MatchArm<Var, Fun> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} => {}", self.pattern.0, self.body)
+        }
+    }
+
     impl<Var: Display, Fun: Display> Display for Binding<Var, Fun> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             write!(f, "{} = {}", self.var.0, self.expr)
@@ -125,39 +295,28 @@ mod impl_ast {
                 Self::Ge => write!(f, "ge"),
                 Self::Eq => write!(f, "eq"),
                 Self::Neq => write!(f, "neq"),
+                Self::Cmp => write!(f, "cmp"),
+                Self::Error => write!(f, "error"),
             }
         }
     }
 
     impl Display for Prim {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            match self {
-                Prim::Add1 => write!(f, "add1"),
-                Prim::Sub1 => write!(f, "sub1"),
-                Prim::Not => write!(f, "!"),
-                Prim::Add => write!(f, "+"),
-                Prim::Sub => write!(f, "-"),
-                Prim::Mul => write!(f, "*"),
-                Prim::And => write!(f, "&&"),
-                Prim::Or => write!(f, "||"),
-                Prim::Lt => write!(f, "<"),
-                Prim::Le => write!(f, "<="),
-                Prim::Gt => write!(f, ">"),
-                Prim::Ge => write!(f, ">="),
-                Prim::Eq => write!(f, "=="),
-                Prim::Neq => write!(f, "!="),
-            }
+            write!(f, "{}", self.info().spelling)
         }
     }
 }
 
 mod impl_ssa {
     use super::*;
+    use crate::identifiers::BlockName;
     use crate::ssa::*;
+    use std::collections::HashMap;
 
     impl Display for Program {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            let Program { externs, funs, blocks } = self;
+            let Program { externs, funs, blocks, .. } = self;
             write!(f, "{}", LineBreaks(&externs.iter()))?;
             write!(f, "{}", LineBreaks(&funs.iter()))?;
             write!(f, "{}", LineBreaks(&blocks.iter().map(|b| Indent(0, b))))?;
@@ -165,6 +324,56 @@ mod impl_ssa {
         }
     }
 
+    type Preds = HashMap<BlockName, Vec<BlockName>>;
+
+    /// A program, block or body displayed with the predecessors of each
+    /// block.
+    pub(super) struct WithPreds<'a, T: ?Sized>(pub &'a T, pub &'a Preds);
+
+    impl Display for WithPreds<'_, Program> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let WithPreds(Program { externs, funs, blocks, .. }, preds) = self;
+            write!(f, "{}", LineBreaks(&externs.iter()))?;
+            write!(f, "{}", LineBreaks(&funs.iter()))?;
+            for block in blocks {
+                writeln!(f, "{}", Indent(0, &WithPreds(block, preds)))?;
+            }
+            Ok(())
+        }
+    }
+
+    impl Display for Indent<'_, WithPreds<'_, BasicBlock>> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let (indent, WithPreds(BasicBlock { label, params, body }, preds)) = self.inner();
+            write!(f, "{}", "  ".repeat(indent))?;
+            write!(f, "block {}({}):", label, Comma(&params.iter()))?;
+            if let Some(preds) = preds.get(label) {
+                write!(f, " ; preds: {}", Comma(&preds.iter()))?;
+            }
+            write!(f, "\n{}", Indent(indent + 1, &WithPreds(body, preds)))
+        }
+    }
+
+    impl Display for Indent<'_, WithPreds<'_, BlockBody>> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let (indent, WithPreds(body, preds)) = self.inner();
+            match body {
+                BlockBody::Terminator(_) => write!(f, "{}", Indent(indent, *body)),
+                BlockBody::Operation { dest, op, next } => {
+                    write!(f, "{}", "  ".repeat(indent))?;
+                    writeln!(f, "{} = {}", dest, op)?;
+                    write!(f, "{}", Indent(indent, &WithPreds(next.as_ref(), preds)))
+                }
+                BlockBody::SubBlocks { blocks, next } => {
+                    for block in blocks {
+                        writeln!(f, "{}", Indent(indent, &WithPreds(block, preds)))?;
+                    }
+                    write!(f, "{}", Indent(indent, &WithPreds(next.as_ref(), preds)))
+                }
+            }
+        }
+    }
+
     impl Display for Extern {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             let Extern { name, params } = self;
@@ -216,6 +425,10 @@ mod impl_ssa {
                 Terminator::ConditionalBranch { cond, thn, els } => {
                     write!(f, "cbr {} {} {}", cond, thn, els)
                 }
+                Terminator::Switch { scrutinee, targets, default } => {
+                    write!(f, "switch {} [{}] {}", scrutinee, Comma(&targets.iter()), default)
+                }
+                Terminator::Unreachable => write!(f, "unreachable"),
             }
         }
     }
@@ -270,6 +483,10 @@ mod impl_ssa {
                 Self::Ge => write!(f, "ge"),
                 Self::Eq => write!(f, "eq"),
                 Self::Neq => write!(f, "neq"),
+                Self::ULt => write!(f, "ult"),
+                Self::ULe => write!(f, "ule"),
+                Self::UGt => write!(f, "ugt"),
+                Self::UGe => write!(f, "uge"),
             }
         }
     }
@@ -289,6 +506,10 @@ mod impl_ssa {
                 Prim2::Ge => write!(f, ">="),
                 Prim2::Eq => write!(f, "=="),
                 Prim2::Neq => write!(f, "!="),
+                Prim2::ULt => write!(f, "<u"),
+                Prim2::ULe => write!(f, "<=u"),
+                Prim2::UGt => write!(f, ">u"),
+                Prim2::UGe => write!(f, ">=u"),
             }
         }
     }
@@ -1,16 +1,21 @@
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::{BufReader, Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
 
 use crate::ast::BoundProg;
-use crate::compile;
+use crate::compile::{self, Error};
 use crate::frontend::Resolver;
+use crate::identifiers::IdGen;
+use crate::interp::{self, Value};
 use crate::middle_end::Lowerer;
+use crate::parser::ProgParser;
 use crate::ssa::Program;
+use crate::txt::FileInfo;
 
-fn handle_errs(r: Result<String, String>) {
+fn handle_errs(r: Result<String, Error>) {
     match r {
         Ok(s) => println!("{}", s),
         Err(e) => {
@@ -21,14 +26,13 @@ fn handle_errs(r: Result<String, String>) {
 }
 
 /// used in student tests
-pub fn emit_ast(p: &Path) -> Result<(Resolver, BoundProg), String> {
-    let (resolver, ast) =
-        compile::frontend(&read_file(p).map_err(|e| format!("Error reading file: {}", e))?)?;
+pub fn emit_ast(p: &Path) -> Result<(Resolver, BoundProg), Error> {
+    let (resolver, ast) = compile::frontend(&read_source(p)?)?;
     Ok((resolver, ast))
 }
 
 /// used in student tests
-pub fn emit_ssa(p: &Path) -> Result<(Lowerer, Program), String> {
+pub fn emit_ssa(p: &Path) -> Result<(Lowerer, Program), Error> {
     let (resolver, ast) = emit_ast(p)?;
     let (lowerer, ssa) = compile::middle_end(resolver, ast)?;
     Ok((lowerer, ssa))
@@ -38,16 +42,30 @@ pub fn emit_assembly(p: &Path) {
     handle_errs(compile_file(p))
 }
 
-pub fn compile_and_run_file<W>(p: &Path, dir: &Path, arg: &str, out: &mut W) -> Result<(), String>
+pub fn compile_and_run_file<W>(p: &Path, dir: &Path, arg: &str, out: &mut W) -> Result<(), Error>
+where
+    W: std::io::Write,
+{
+    compile_and_run_file_with(p, dir, arg, Path::new("runtime/stub.rs"), out)
+}
+
+/// Like `compile_and_run_file`, but links with the runtime in `runtime_file`.
+pub fn compile_and_run_file_with<W>(
+    p: &Path, dir: &Path, arg: &str, runtime_file: &Path, out: &mut W,
+) -> Result<(), Error>
 where
     W: std::io::Write,
 {
     let asm = compile_file(p)?;
-    link_and_run(&asm, Path::new("runtime/stub.rs"), dir, arg, out)
+    link_and_run(&asm, runtime_file, dir, arg, out)
+}
+
+fn compile_file(p: &Path) -> Result<String, Error> {
+    compile::compile(&read_source(p)?)
 }
 
-fn compile_file(p: &Path) -> Result<String, String> {
-    compile::compile(&read_file(p).map_err(|e| format!("Error reading file: {}", e))?)
+fn read_source(p: &Path) -> Result<String, Error> {
+    read_file(p).map_err(|e| Error::Io(format!("Error reading file: {}", e)))
 }
 
 pub fn read_file(p: &Path) -> Result<String, std::io::Error> {
@@ -57,35 +75,137 @@ pub fn read_file(p: &Path) -> Result<String, std::io::Error> {
     Ok(buf)
 }
 
+/// The intermediate files `link` writes into its directory.
+pub struct Temps {
+    pub asm: PathBuf,
+    pub obj: PathBuf,
+    pub lib: PathBuf,
+}
+
+impl Temps {
+    pub fn in_dir(dir: &Path) -> Self {
+        Temps {
+            asm: dir.join("compiled_code.s"),
+            obj: dir.join("compiled_code.o"),
+            lib: dir.join("libcompiled_code.a"),
+        }
+    }
+}
+
+/// The programs `link` runs, by name or path.
+#[derive(Clone, Debug)]
+pub struct LinkOptions {
+    pub assembler: String,
+    pub archiver: String,
+    pub rustc: String,
+}
+
+impl Default for LinkOptions {
+    fn default() -> Self {
+        LinkOptions {
+            assembler: "nasm".to_string(),
+            archiver: "ar".to_string(),
+            rustc: "rustc".to_string(),
+        }
+    }
+}
+
+/// Assemble and link `assembly` with the runtime into `exe_fname`, using
+/// `dir` for the intermediate files. Unless `keep_temps` is set, the
+/// intermediates are removed again afterwards, whether linking succeeded
+/// or not.
 pub fn link(
-    assembly: &str, runtime_file: &Path, dir: &Path, exe_fname: &Path,
-) -> Result<(), String> {
-    let (nasm_format, lib_name) = if cfg!(target_os = "linux") {
-        ("elf64", "libcompiled_code.a")
+    assembly: &str, runtime_file: &Path, dir: &Path, exe_fname: &Path, keep_temps: bool,
+) -> Result<(), Error> {
+    link_with_options(assembly, runtime_file, dir, exe_fname, keep_temps, &LinkOptions::default())
+}
+
+/// Like `link`, but runs the programs in `options`.
+pub fn link_with_options(
+    assembly: &str, runtime_file: &Path, dir: &Path, exe_fname: &Path, keep_temps: bool,
+    options: &LinkOptions,
+) -> Result<(), Error> {
+    let temps = Temps::in_dir(dir);
+    let res =
+        link_with(assembly, runtime_file, dir, &temps, exe_fname, options).map_err(Error::Link);
+    if !keep_temps {
+        for file in [&temps.asm, &temps.obj, &temps.lib] {
+            let _ = std::fs::remove_file(file);
+        }
+    }
+    res
+}
+
+/// Assemble `assembly` into the static library `out_path`, using `dir`
+/// for the intermediate files, without linking it with a runtime. The
+/// library exports `entry`, so a C or Rust program that provides the
+/// externs can link against it and call the compiled code itself.
+pub fn build_archive(assembly: &str, dir: &Path, out_path: &Path) -> Result<(), Error> {
+    build_archive_with_options(assembly, dir, out_path, false, &LinkOptions::default())
+}
+
+/// Like `build_archive`, but runs the programs in `options`, and keeps the
+/// intermediates if `keep_temps` is set.
+pub fn build_archive_with_options(
+    assembly: &str, dir: &Path, out_path: &Path, keep_temps: bool, options: &LinkOptions,
+) -> Result<(), Error> {
+    let temps = Temps::in_dir(dir);
+    let res = archive_with(assembly, &temps, options).and_then(|()| {
+        if out_path == temps.lib {
+            return Ok(());
+        }
+        std::fs::copy(&temps.lib, out_path)
+            .map(|_| ())
+            .map_err(|e| format!("Error writing {}: {}", out_path.display(), e))
+    });
+    if !keep_temps {
+        for file in [&temps.asm, &temps.obj, &temps.lib] {
+            if file != out_path {
+                let _ = std::fs::remove_file(file);
+            }
+        }
+    }
+    res.map_err(Error::Link)
+}
+
+/// Why `tool` could not be started. It is most often not installed, so
+/// that case says how to point at it instead.
+fn spawn_err(tool: &str, flag: &str, e: std::io::Error) -> String {
+    if e.kind() == std::io::ErrorKind::NotFound {
+        format!("{} not found in PATH; install it or pass {}", tool, flag)
+    } else {
+        format!("{} err: {}", tool, e)
+    }
+}
+
+/// Assemble `assembly` and archive it into `temps.lib`, writing the
+/// other intermediates to `temps` along the way.
+fn archive_with(assembly: &str, temps: &Temps, options: &LinkOptions) -> Result<(), String> {
+    let LinkOptions { assembler, archiver, .. } = options;
+    let nasm_format = if cfg!(target_os = "linux") {
+        "elf64"
     } else if cfg!(target_os = "macos") {
-        ("macho64", "libcompiled_code.a")
+        "macho64"
     } else {
         panic!("Runner script only supports linux and macos")
     };
 
-    let asm_fname = dir.join("compiled_code.s");
-    let obj_fname = dir.join("compiled_code.o");
-    let lib_fname = dir.join(lib_name);
+    let Temps { asm: asm_fname, obj: obj_fname, lib: lib_fname } = temps;
 
     // first put the assembly in a new file compiled_code.s
-    let mut asm_file = File::create(&asm_fname).map_err(|e| e.to_string())?;
+    let mut asm_file = File::create(asm_fname).map_err(|e| e.to_string())?;
     asm_file.write(assembly.as_bytes()).map_err(|e| e.to_string())?;
     asm_file.flush().map_err(|e| e.to_string())?;
 
     // nasm -fFORMAT -o compiled_code.o compiled_code.s
-    let nasm_out = Command::new("nasm")
+    let nasm_out = Command::new(assembler)
         .arg("-f")
         .arg(nasm_format)
         .arg("-o")
-        .arg(&obj_fname)
-        .arg(&asm_fname)
+        .arg(obj_fname)
+        .arg(asm_fname)
         .output()
-        .map_err(|e| format!("nasm err: {}", e))?;
+        .map_err(|e| spawn_err(assembler, "--assembler", e))?;
     if !nasm_out.status.success() {
         return Err(format!(
             "Failure in nasm call: {}\n{}",
@@ -95,12 +215,12 @@ pub fn link(
     }
 
     // ar r libcompiled_code.a compiled_code.o
-    let ar_out = Command::new("ar")
+    let ar_out = Command::new(archiver)
         .arg("rus")
         .arg(lib_fname)
-        .arg(&obj_fname)
+        .arg(obj_fname)
         .output()
-        .map_err(|e| (format!("ar err: {}", e)))?;
+        .map_err(|e| spawn_err(archiver, "--archiver", e))?;
     if !ar_out.status.success() {
         return Err(format!(
             "Failure in ar call:\n{}\n{}",
@@ -108,28 +228,37 @@ pub fn link(
             std::str::from_utf8(&ar_out.stderr).expect("ar produced invalid UTF-8")
         ));
     }
+    Ok(())
+}
+
+fn link_with(
+    assembly: &str, runtime_file: &Path, dir: &Path, temps: &Temps, exe_fname: &Path,
+    options: &LinkOptions,
+) -> Result<(), String> {
+    archive_with(assembly, temps, options)?;
+    let rustc = &options.rustc;
 
     // rustc stub.rs -L tmp
     let rustc_out = if cfg!(target_os = "macos") {
-        Command::new("rustc")
+        Command::new(rustc)
             .arg(runtime_file)
             .arg("--target")
             .arg("x86_64-apple-darwin")
             .arg("-L")
             .arg(dir)
             .arg("-o")
-            .arg(&exe_fname)
+            .arg(exe_fname)
             .output()
-            .map_err(|e| (format!("rustc err: {}", e)))?
+            .map_err(|e| spawn_err(rustc, "--rustc", e))?
     } else {
-        Command::new("rustc")
+        Command::new(rustc)
             .arg(runtime_file)
             .arg("-L")
             .arg(dir)
             .arg("-o")
-            .arg(&exe_fname)
+            .arg(exe_fname)
             .output()
-            .map_err(|e| (format!("rustc err: {}", e)))?
+            .map_err(|e| spawn_err(rustc, "--rustc", e))?
     };
     if !rustc_out.status.success() {
         Err(format!(
@@ -142,47 +271,199 @@ pub fn link(
     }
 }
 
-pub fn run<W>(exe_fname: &Path, arg: &str, out: &mut W) -> Result<(), String>
+pub fn run<W>(exe_fname: &Path, arg: &str, out: &mut W) -> Result<(), Error>
+where
+    W: std::io::Write,
+{
+    let child = spawn(exe_fname, arg, false)?;
+    wait_for(child, out).map(drop)
+}
+
+/// Like `run`, but also copies what the executable wrote to stderr to
+/// `err`, whether or not it succeeded.
+pub fn run_with_stderr<W, E>(
+    exe_fname: &Path, arg: &str, out: &mut W, err: &mut E,
+) -> Result<(), Error>
+where
+    W: std::io::Write,
+    E: std::io::Write,
+{
+    let child = spawn(exe_fname, arg, false)?;
+    let res = wait_for(child, out);
+    let stderr = match &res {
+        Ok(stderr) | Err(Error::Run { stderr, .. }) => stderr.as_str(),
+        Err(_) => "",
+    };
+    err.write_all(stderr.as_bytes()).map_err(|e| Error::Io(format!("I/O error: {}", e)))?;
+    res.map(drop)
+}
+
+/// Like `run`, but kills the executable if it is still running after
+/// `timeout`, so that a program that loops forever cannot hang the caller.
+/// Its output is written to `out` once it exits.
+pub fn run_with_timeout<W>(
+    exe_fname: &Path, arg: &str, timeout: Duration, out: &mut W,
+) -> Result<(), Error>
+where
+    W: std::io::Write,
+{
+    let mut child = spawn(exe_fname, arg, false)?;
+
+    // Child has no wait with a timeout, so its output is read on other
+    // threads while this one polls whether it exited.
+    let read_all = |mut pipe: Box<dyn Read + Send>| {
+        std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            pipe.read_to_end(&mut buf).map(|_| buf)
+        })
+    };
+    let stdout =
+        read_all(Box::new(child.stdout.take().expect("Failed to capture compiled code's stdout")));
+    let stderr =
+        read_all(Box::new(child.stderr.take().expect("Failed to capture compiled code's stderr")));
+
+    let start = Instant::now();
+    let status = loop {
+        let status = child
+            .try_wait()
+            .map_err(|e| Error::Io(format!("Error waiting for child process {}", e)))?;
+        if let Some(status) = status {
+            break status;
+        }
+        if start.elapsed() >= timeout {
+            // killing fails only if it exited in the meantime
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(Error::Timeout(timeout));
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    };
+
+    let join = |reader: std::thread::JoinHandle<std::io::Result<Vec<u8>>>| {
+        reader
+            .join()
+            .expect("reading the compiled code's output panicked")
+            .map_err(|e| Error::Io(format!("{}", e)))
+    };
+    let (stdout, stderr) = (join(stdout)?, join(stderr)?);
+    out.write_all(&stdout).map_err(|e| Error::Io(format!("I/O error: {}", e)))?;
+    if !status.success() {
+        let stderr = String::from_utf8_lossy(&stderr).into_owned();
+        return Err(Error::Run { code: status.code(), stderr });
+    }
+    Ok(())
+}
+
+/// Like `run`, but feeds `input` to the executable on stdin.
+pub fn run_with_input<W>(
+    exe_fname: &Path, arg: &str, input: &str, out: &mut W,
+) -> Result<(), Error>
+where
+    W: std::io::Write,
+{
+    let mut child = spawn(exe_fname, arg, true)?;
+    let mut stdin = child.stdin.take().expect("Failed to capture compiled code's stdin");
+    stdin.write_all(input.as_bytes()).map_err(|e| Error::Io(format!("I/O error: {}", e)))?;
+    drop(stdin);
+    wait_for(child, out).map(drop)
+}
+
+/// Start the executable on `arg` with its stdout and stderr piped, and
+/// its stdin too if `piped_stdin` is set.
+fn spawn(
+    exe_fname: &Path, arg: &str, piped_stdin: bool,
+) -> Result<std::process::Child, Error> {
+    let mut command = Command::new(exe_fname);
+    command.arg(arg).stdout(Stdio::piped()).stderr(Stdio::piped());
+    if piped_stdin {
+        command.stdin(Stdio::piped());
+    }
+    command.spawn().map_err(|e| Error::Io(format!("{}", e)))
+}
+
+/// Copy the output of a running executable to `out` and wait for it to
+/// exit, returning what it wrote to stderr.
+fn wait_for<W>(mut child: std::process::Child, out: &mut W) -> Result<String, Error>
 where
     W: std::io::Write,
 {
-    let mut child = Command::new(&exe_fname)
-        .arg(arg)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(|e| (format!("{}", e)))?;
     let compiled_out =
         BufReader::new(child.stdout.take().expect("Failed to capture compiled code's stdout"));
-    let compiled_err =
-        BufReader::new(child.stderr.take().expect("Failed to capture compiled code's stderr"));
+    let mut compiled_err =
+        child.stderr.take().expect("Failed to capture compiled code's stderr");
 
     for line in compiled_out.lines() {
-        let line = line.map_err(|e| (format!("{}", e)))?;
-        writeln!(out, "{}", line).map_err(|e| (format!("I/O error: {}", e)))?;
+        let line = line.map_err(|e| Error::Io(format!("{}", e)))?;
+        writeln!(out, "{}", line).map_err(|e| Error::Io(format!("I/O error: {}", e)))?;
     }
 
-    let status = child.wait().map_err(|e| (format!("Error waiting for child process {}", e)))?;
+    let status = child
+        .wait()
+        .map_err(|e| Error::Io(format!("Error waiting for child process {}", e)))?;
+    let mut stderr = Vec::new();
+    compiled_err.read_to_end(&mut stderr).map_err(|e| Error::Io(format!("{}", e)))?;
+    let stderr = String::from_utf8_lossy(&stderr).into_owned();
     if !status.success() {
-        let mut stderr = String::new();
-        for line in compiled_err.lines() {
-            stderr.push_str(&format!("{}\n", line.unwrap()));
-        }
-        return Err(format!(
-            "Error code {} when running compiled code Stderr:\n{}",
-            status, stderr
-        ));
+        return Err(Error::Run { code: status.code(), stderr });
     }
-    Ok(())
+    Ok(stderr)
 }
 
 pub fn link_and_run<W>(
     assembly: &str, runtime_file: &Path, dir: &Path, arg: &str, out: &mut W,
-) -> Result<(), String>
+) -> Result<(), Error>
 where
     W: std::io::Write,
 {
     let exe_fname = dir.join("main.exe");
-    link(assembly, runtime_file, dir, &exe_fname)?;
+    link(assembly, runtime_file, dir, &exe_fname, false)?;
     run(&exe_fname, arg, out)
 }
+
+/// Read expressions from `input` a line at a time, and write each one's
+/// value to `out`, as the body of a `main` whose argument `x` is 0. Lines
+/// starting with `def` declare a function instead, which the lines after
+/// it may call. Errors are written to `out` too, and do not stop the loop.
+pub fn repl<R, W>(input: R, out: &mut W) -> std::io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut defs: Vec<String> = Vec::new();
+    // every line gets fresh names, as if they were one growing program
+    let mut next_ids = (0, 0);
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let is_def = line.starts_with("def ");
+        let body = if is_def { format!("{} in 0", line) } else { line.to_string() };
+        let defs_in: String = defs.iter().map(|d| format!("{} in ", d)).collect();
+        let src = format!("def main(x): {}{}", defs_in, body);
+        match eval_line(&src, &mut next_ids) {
+            Ok(_) if is_def => defs.push(line.to_string()),
+            Ok(value) => writeln!(out, "{}", value)?,
+            Err(e) => writeln!(out, "{}", e)?,
+        }
+    }
+    Ok(())
+}
+
+fn eval_line(src: &str, (vars, funs): &mut (usize, usize)) -> Result<Value, String> {
+    let raw_ast = ProgParser::new()
+        .parse(src)
+        .map_err(|e| Error::Parse(FileInfo::new(src).report_parse_error(src, e)).to_string())?;
+    let mut resolver =
+        Resolver::new().with_ids(IdGen::starting_at(*vars), IdGen::starting_at(*funs));
+    let resolved_ast = resolver
+        .resolve_prog_all(raw_ast)
+        .map_err(|errs| Error::Resolve(errs, FileInfo::new(src)).to_string())?;
+    (*vars, *funs) = (resolver.vars.next_index(), resolver.funs.next_index());
+    let ssa = Lowerer::from(resolver).lower_prog(resolved_ast);
+    interp::ssa::Interp::new()
+        .with_input(std::io::empty())
+        .run_value(&ssa, Value::Int(0))
+        .map_err(|e| format!("Error interpreting program: {}", e))
+}
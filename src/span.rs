@@ -3,7 +3,7 @@
 /// 1-dimensional span of source locations.
 ///
 /// This is what the parser outputs.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct SrcLoc {
     pub start_ix: usize,
     pub end_ix: usize, // exclusive
@@ -12,6 +12,15 @@ impl SrcLoc {
     pub fn new(start_ix: usize, end_ix: usize) -> Self {
         Self { start_ix, end_ix }
     }
+
+    /// The smallest span covering both `self` and `other`, including
+    /// whatever lies between them.
+    pub fn merge(self, other: SrcLoc) -> SrcLoc {
+        SrcLoc {
+            start_ix: self.start_ix.min(other.start_ix),
+            end_ix: self.end_ix.max(other.end_ix),
+        }
+    }
 }
 
 /// 2-dimensional span of source locations.
@@ -25,6 +34,17 @@ pub struct Span2 {
     pub end_col: usize,  // exclusive
 }
 
+impl Span2 {
+    /// Like `SrcLoc::merge`, comparing positions line first.
+    pub fn merge(self, other: Span2) -> Span2 {
+        let (start_line, start_col) = (self.start_line, self.start_col)
+            .min((other.start_line, other.start_col));
+        let (end_line, end_col) = (self.end_line, self.end_col)
+            .max((other.end_line, other.end_col));
+        Span2 { start_line, start_col, end_line, end_col }
+    }
+}
+
 impl std::fmt::Display for Span2 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let Span2 { start_line, start_col, end_line, end_col } = self;
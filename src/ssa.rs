@@ -1,4 +1,6 @@
 use crate::identifiers::*;
+use crate::span::SrcLoc;
+use std::collections::{HashMap, HashSet};
 
 // A Program has a single input parameter, and a block of straightline code to execute
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -6,14 +8,30 @@ pub struct Program {
     pub externs: Vec<Extern>,
     pub funs: Vec<FunBlock>,
     pub blocks: Vec<BasicBlock>,
+    pub locs: SrcLocs,
 }
 
+/// The source each operation of a program was lowered from: the location
+/// of the expression whose value, or part of it, the operation's
+/// destination holds. Operations made up by later passes have none.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SrcLocs(pub HashMap<VarName, SrcLoc>);
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Extern {
     pub name: FunName,
     pub params: Vec<VarName>,
 }
 
+/// The runtime function that `error(code)` calls to abort the program.
+/// The lowerer declares it as an extern whenever the program uses it.
+pub const ERROR_FUN: &str = "snake_error";
+
+/// The symbol the compiled program is entered through, unless the
+/// resolver is given another. The runtime calls it with the argument of
+/// `main`, and its `link_name` must match.
+pub const ENTRY_FUN: &str = "entry";
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FunBlock {
     pub name: FunName,
@@ -39,7 +57,20 @@ pub enum BlockBody {
 pub enum Terminator {
     Return(Immediate),
     Branch(Branch),
-    ConditionalBranch { cond: Immediate, thn: BlockName, els: BlockName },
+    ConditionalBranch {
+        cond: Immediate,
+        thn: BlockName,
+        els: BlockName,
+    },
+    /// Branch to `targets[n]`, where `n` is the value of `scrutinee`, or
+    /// to `default` if `n` is not an index into `targets`.
+    Switch {
+        scrutinee: Immediate,
+        targets: Vec<BlockName>,
+        default: BlockName,
+    },
+    /// Control never reaches here, e.g. after a call that does not return.
+    Unreachable,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -79,6 +110,11 @@ pub enum Prim2 {
     Ge,
     Eq,
     Neq,
+    // unsigned comparison
+    ULt,
+    ULe,
+    UGt,
+    UGe,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -86,3 +122,702 @@ pub enum Immediate {
     Const(i64),
     Var(VarName),
 }
+
+/// How integer values are represented at runtime.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IntRepr {
+    /// Integers occupy the full 64 bits of a word.
+    #[default]
+    Untagged,
+    /// Integers are 63 bits wide and stored shifted left by one, leaving
+    /// the low bit free for a type tag.
+    Tagged,
+}
+
+impl IntRepr {
+    /// The runtime representation of the integer `n`. Panics if `n` is
+    /// not `in_range`, so values from outside the compiler should go
+    /// through `checked_tag` instead.
+    pub fn tag(self, n: i64) -> i64 {
+        self.checked_tag(n).unwrap_or_else(|| {
+            panic!("{} does not fit in a tagged integer", n)
+        })
+    }
+
+    /// The runtime representation of the integer `n`, or `None` if the
+    /// representation has no room for it.
+    pub fn checked_tag(self, n: i64) -> Option<i64> {
+        match self {
+            IntRepr::Untagged => Some(n),
+            IntRepr::Tagged => Some(n << 1).filter(|bits| bits >> 1 == n),
+        }
+    }
+
+    /// The integer denoted by the runtime representation `bits`.
+    pub fn untag(self, bits: i64) -> i64 {
+        match self {
+            IntRepr::Untagged => bits,
+            IntRepr::Tagged => bits >> 1,
+        }
+    }
+
+    /// Whether `n` can be represented without losing its high bits.
+    pub fn in_range(self, n: i64) -> bool {
+        self.checked_tag(n).is_some()
+    }
+
+    /// The integer that `n` becomes once its high bits are dropped to
+    /// fit the representation, as arithmetic on tagged values wraps.
+    pub fn wrap(self, n: i64) -> i64 {
+        match self {
+            IntRepr::Untagged => n,
+            IntRepr::Tagged => (n << 1) >> 1,
+        }
+    }
+}
+
+impl BlockBody {
+    /// The terminator that ends the straightline code of this body,
+    /// skipping over any operations and nested sub-block declarations.
+    pub fn terminator(&self) -> &Terminator {
+        match self {
+            BlockBody::Terminator(terminator) => terminator,
+            BlockBody::Operation { next, .. } | BlockBody::SubBlocks { next, .. } => {
+                next.terminator()
+            }
+        }
+    }
+
+    /// The number of operations in this body, those of nested blocks
+    /// included. Passes weighing whether to duplicate or merge code use
+    /// it as a measure of how much there is.
+    pub fn size(&self) -> usize {
+        match self {
+            BlockBody::Terminator(_) => 0,
+            BlockBody::Operation { next, .. } => 1 + next.size(),
+            BlockBody::SubBlocks { blocks, next } => {
+                blocks.iter().map(BasicBlock::size).sum::<usize>()
+                    + next.size()
+            }
+        }
+    }
+}
+
+impl BasicBlock {
+    /// The number of operations in this block and those nested in it.
+    pub fn size(&self) -> usize {
+        self.body.size()
+    }
+}
+
+impl Terminator {
+    /// The blocks this terminator may transfer control to, in the order
+    /// we would prefer to fall through to them.
+    pub fn targets(&self) -> Vec<&BlockName> {
+        match self {
+            Terminator::Return(_) | Terminator::Unreachable => Vec::new(),
+            Terminator::Branch(Branch { target, .. }) => vec![target],
+            Terminator::ConditionalBranch { thn, els, .. } => vec![els, thn],
+            Terminator::Switch { targets, default, .. } => {
+                std::iter::once(default).chain(targets).collect()
+            }
+        }
+    }
+}
+
+impl Program {
+    /// The function the program is entered through: the lowerer mangles
+    /// the names of all the others.
+    pub fn entry(&self) -> Option<&FunBlock> {
+        self.funs.iter().find(|f| f.name.is_unmangled())
+    }
+
+    /// The number of operations in the body of `fun`: the top-level block
+    /// it branches to, and the blocks nested in that one.
+    pub fn fun_size(&self, fun: &FunBlock) -> usize {
+        self.blocks
+            .iter()
+            .find(|b| b.label == fun.body.target)
+            .map_or(0, BasicBlock::size)
+    }
+
+    /// Every block of the program, including the ones nested in other
+    /// blocks, in the order they are printed: each block comes right
+    /// before the blocks nested in it.
+    pub fn blocks_recursive(&self) -> impl Iterator<Item = &BasicBlock> {
+        let mut out = Vec::new();
+        for block in &self.blocks {
+            block.collect_blocks(&mut out);
+        }
+        out.into_iter()
+    }
+
+    /// The blocks that every path to each block passes through, the block
+    /// itself included. Paths start at the blocks functions branch to, and
+    /// at blocks nothing transfers control to.
+    pub fn dominators(&self) -> HashMap<BlockName, HashSet<BlockName>> {
+        let preds = self.predecessors();
+        let roots: HashSet<&BlockName> =
+            self.funs.iter().map(|fun| &fun.body.target).collect();
+        let labels: Vec<&BlockName> =
+            self.blocks_recursive().map(|block| &block.label).collect();
+        let is_root = |label: &BlockName| {
+            roots.contains(label) || !preds.contains_key(label)
+        };
+        let all: HashSet<BlockName> =
+            labels.iter().map(|label| (*label).clone()).collect();
+        let mut doms: HashMap<BlockName, HashSet<BlockName>> = labels
+            .iter()
+            .map(|label| {
+                let doms = if is_root(label) {
+                    HashSet::from([(*label).clone()])
+                } else {
+                    all.clone()
+                };
+                ((*label).clone(), doms)
+            })
+            .collect();
+        loop {
+            let mut changed = false;
+            for label in labels.iter().filter(|label| !is_root(label)) {
+                let mut new = preds[*label]
+                    .iter()
+                    .map(|pred| &doms[pred])
+                    .fold(None, |acc: Option<HashSet<BlockName>>, pred| {
+                        Some(match acc {
+                            None => pred.clone(),
+                            Some(acc) => {
+                                acc.intersection(pred).cloned().collect()
+                            }
+                        })
+                    })
+                    .unwrap_or_default();
+                new.insert((*label).clone());
+                if new != doms[*label] {
+                    doms.insert((*label).clone(), new);
+                    changed = true;
+                }
+            }
+            if !changed {
+                return doms;
+            }
+        }
+    }
+
+    /// The blocks that may transfer control to each block, in the order
+    /// of `blocks_recursive`. Blocks only entered from a function, or not
+    /// at all, have none.
+    pub fn predecessors(&self) -> HashMap<BlockName, Vec<BlockName>> {
+        let mut preds: HashMap<BlockName, Vec<BlockName>> = HashMap::new();
+        for block in self.blocks_recursive() {
+            for target in block.body.terminator().targets() {
+                let preds = preds.entry(target.clone()).or_default();
+                if !preds.contains(&block.label) {
+                    preds.push(block.label.clone());
+                }
+            }
+        }
+        preds
+    }
+
+    /// The blocks reachable from `entry` by following branches, nested
+    /// blocks included.
+    pub fn reachable_from<'a>(
+        &'a self, entry: &'a BlockName,
+    ) -> HashSet<&'a BlockName> {
+        let blocks: HashMap<_, _> =
+            self.blocks_recursive().map(|b| (&b.label, b)).collect();
+        let mut seen = HashSet::new();
+        let mut todo = vec![entry];
+        while let Some(name) = todo.pop() {
+            if !seen.insert(name) {
+                continue;
+            }
+            let block = blocks
+                .get(name)
+                .unwrap_or_else(|| panic!("no block named '{}'", name));
+            todo.extend(block.body.terminator().targets());
+        }
+        seen
+    }
+
+    /// The natural loop of each back edge of the program: each branch to
+    /// a block from a block it dominates. Loops come in the order of
+    /// `blocks_recursive` by header, then by latch.
+    pub fn loops(&self) -> Vec<Loop> {
+        let preds = self.predecessors();
+        let doms = self.dominators();
+        let mut loops = Vec::new();
+        for header in self.blocks_recursive() {
+            let header = &header.label;
+            for latch in preds.get(header).into_iter().flatten() {
+                if !doms[latch].contains(header) {
+                    continue;
+                }
+                let mut body = HashSet::from([header.clone()]);
+                let mut stack = vec![latch];
+                while let Some(label) = stack.pop() {
+                    if body.insert(label.clone()) {
+                        stack.extend(preds.get(label).into_iter().flatten());
+                    }
+                }
+                loops.push(Loop {
+                    header: header.clone(),
+                    latch: latch.clone(),
+                    body,
+                });
+            }
+        }
+        loops
+    }
+
+    /// Mutable access to every block, in the same order as
+    /// `blocks_recursive`. Since a block owns the blocks nested in it,
+    /// each block is split into its own straightline code, which leaves
+    /// the nested blocks out.
+    pub fn blocks_recursive_mut(
+        &mut self,
+    ) -> impl Iterator<Item = BlockMut<'_>> {
+        let mut out = Vec::new();
+        for block in &mut self.blocks {
+            block.collect_blocks_mut(&mut out);
+        }
+        out.into_iter()
+    }
+
+    /// Every immediate the program reads: the arguments of its functions'
+    /// branches to their bodies, and the operands of its operations and
+    /// terminators.
+    pub fn immediates(&self) -> Vec<&Immediate> {
+        fn body<'a>(b: &'a BlockBody, out: &mut Vec<&'a Immediate>) {
+            match b {
+                BlockBody::Terminator(Terminator::Return(i)) => out.push(i),
+                BlockBody::Terminator(Terminator::Branch(br)) => {
+                    out.extend(&br.args)
+                }
+                BlockBody::Terminator(Terminator::ConditionalBranch {
+                    cond,
+                    ..
+                }) => out.push(cond),
+                BlockBody::Terminator(Terminator::Switch {
+                    scrutinee,
+                    ..
+                }) => out.push(scrutinee),
+                BlockBody::Terminator(Terminator::Unreachable) => {}
+                BlockBody::Operation { op, next, .. } => {
+                    match op {
+                        Operation::Immediate(i) | Operation::Prim1(_, i) => {
+                            out.push(i)
+                        }
+                        Operation::Prim2(_, i1, i2) => {
+                            out.push(i1);
+                            out.push(i2);
+                        }
+                        Operation::Call { args, .. } => out.extend(args),
+                    }
+                    body(next, out);
+                }
+                BlockBody::SubBlocks { blocks, next } => {
+                    for block in blocks {
+                        body(&block.body, out);
+                    }
+                    body(next, out);
+                }
+            }
+        }
+        let mut out = Vec::new();
+        for fun in &self.funs {
+            out.extend(&fun.body.args);
+        }
+        for block in &self.blocks {
+            body(&block.body, &mut out);
+        }
+        out
+    }
+
+    /// The variables read anywhere in the program.
+    pub fn used_vars(&self) -> HashSet<VarName> {
+        self.immediates()
+            .into_iter()
+            .filter_map(|imm| match imm {
+                Immediate::Var(v) => Some(v.clone()),
+                Immediate::Const(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// The natural loop of a back edge, from `latch` to `header`: the blocks
+/// that reach `latch` without passing through `header`, and `header`
+/// itself, which is the only way in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Loop {
+    pub header: BlockName,
+    pub latch: BlockName,
+    pub body: HashSet<BlockName>,
+}
+
+/// The parts of a `BasicBlock` that belong to it rather than to the
+/// blocks nested in it.
+pub struct BlockMut<'a> {
+    pub label: &'a mut BlockName,
+    pub params: &'a mut Vec<VarName>,
+    /// the destination and operation of every `BlockBody::Operation`
+    pub ops: Vec<(&'a mut VarName, &'a mut Operation)>,
+    pub terminator: &'a mut Terminator,
+}
+
+impl BasicBlock {
+    /// This block and every block nested in it, in the order they are
+    /// printed.
+    pub fn blocks_recursive(&self) -> impl Iterator<Item = &BasicBlock> {
+        let mut out = Vec::new();
+        self.collect_blocks(&mut out);
+        out.into_iter()
+    }
+
+    fn collect_blocks<'a>(&'a self, out: &mut Vec<&'a BasicBlock>) {
+        out.push(self);
+        let mut body = &self.body;
+        loop {
+            match body {
+                BlockBody::Terminator(_) => break,
+                BlockBody::Operation { next, .. } => body = next,
+                BlockBody::SubBlocks { blocks, next } => {
+                    for block in blocks {
+                        block.collect_blocks(out);
+                    }
+                    body = next;
+                }
+            }
+        }
+    }
+
+    fn collect_blocks_mut<'a>(&'a mut self, out: &mut Vec<BlockMut<'a>>) {
+        let BasicBlock { label, params, body } = self;
+        let mut ops = Vec::new();
+        let mut nested = Vec::new();
+        let mut body = body;
+        let terminator = loop {
+            match body {
+                BlockBody::Terminator(terminator) => break terminator,
+                BlockBody::Operation { dest, op, next } => {
+                    ops.push((dest, op));
+                    body = next;
+                }
+                BlockBody::SubBlocks { blocks, next } => {
+                    nested.extend(blocks.iter_mut());
+                    body = next;
+                }
+            }
+        };
+        out.push(BlockMut { label, params, ops, terminator });
+        for block in nested {
+            block.collect_blocks_mut(out);
+        }
+    }
+}
+
+/// A substitution for the identifiers of a program. Implementations may
+/// keep state, since `Program::map_names` visits identifiers in a fixed
+/// order: the order in which they are printed.
+pub trait NameMap {
+    fn var(&mut self, var: &VarName) -> VarName;
+    fn fun(&mut self, fun: &FunName) -> FunName;
+    fn block(&mut self, block: &BlockName) -> BlockName;
+}
+
+impl Program {
+    /// This program with every identifier replaced according to `names`.
+    pub fn map_names(&self, names: &mut impl NameMap) -> Program {
+        let Program { externs, funs, blocks, locs } = self;
+        Program {
+            externs: externs
+                .iter()
+                .map(|Extern { name, params }| Extern {
+                    name: names.fun(name),
+                    params: params.iter().map(|p| names.var(p)).collect(),
+                })
+                .collect(),
+            funs: funs
+                .iter()
+                .map(|FunBlock { name, params, body }| FunBlock {
+                    name: names.fun(name),
+                    params: params.iter().map(|p| names.var(p)).collect(),
+                    body: body.map_names(names),
+                })
+                .collect(),
+            blocks: blocks.iter().map(|b| b.map_names(names)).collect(),
+            // after everything else, so that the names the program shows
+            // are handed out in the order it shows them
+            locs: SrcLocs(
+                locs.0.iter().map(|(x, loc)| (names.var(x), *loc)).collect(),
+            ),
+        }
+    }
+}
+
+impl BasicBlock {
+    fn map_names(&self, names: &mut impl NameMap) -> BasicBlock {
+        let BasicBlock { label, params, body } = self;
+        BasicBlock {
+            label: names.block(label),
+            params: params.iter().map(|p| names.var(p)).collect(),
+            body: body.map_names(names),
+        }
+    }
+}
+
+impl BlockBody {
+    fn map_names(&self, names: &mut impl NameMap) -> BlockBody {
+        match self {
+            BlockBody::Terminator(terminator) => {
+                BlockBody::Terminator(terminator.map_names(names))
+            }
+            BlockBody::Operation { dest, op, next } => {
+                let dest = names.var(dest);
+                let op = op.map_names(names);
+                BlockBody::Operation {
+                    dest,
+                    op,
+                    next: Box::new(next.map_names(names)),
+                }
+            }
+            BlockBody::SubBlocks { blocks, next } => {
+                let blocks =
+                    blocks.iter().map(|b| b.map_names(names)).collect();
+                BlockBody::SubBlocks {
+                    blocks,
+                    next: Box::new(next.map_names(names)),
+                }
+            }
+        }
+    }
+}
+
+impl Terminator {
+    fn map_names(&self, names: &mut impl NameMap) -> Terminator {
+        match self {
+            Terminator::Return(imm) => {
+                Terminator::Return(imm.map_names(names))
+            }
+            Terminator::Branch(branch) => {
+                Terminator::Branch(branch.map_names(names))
+            }
+            Terminator::ConditionalBranch { cond, thn, els } => {
+                Terminator::ConditionalBranch {
+                    cond: cond.map_names(names),
+                    thn: names.block(thn),
+                    els: names.block(els),
+                }
+            }
+            Terminator::Switch { scrutinee, targets, default } => {
+                Terminator::Switch {
+                    scrutinee: scrutinee.map_names(names),
+                    targets: targets
+                        .iter()
+                        .map(|t| names.block(t))
+                        .collect(),
+                    default: names.block(default),
+                }
+            }
+            Terminator::Unreachable => Terminator::Unreachable,
+        }
+    }
+}
+
+impl Branch {
+    fn map_names(&self, names: &mut impl NameMap) -> Branch {
+        let Branch { target, args } = self;
+        Branch {
+            target: names.block(target),
+            args: args.iter().map(|a| a.map_names(names)).collect(),
+        }
+    }
+}
+
+impl Operation {
+    fn map_names(&self, names: &mut impl NameMap) -> Operation {
+        match self {
+            Operation::Immediate(imm) => {
+                Operation::Immediate(imm.map_names(names))
+            }
+            Operation::Prim1(prim, imm) => {
+                Operation::Prim1(prim.clone(), imm.map_names(names))
+            }
+            Operation::Prim2(prim, imm1, imm2) => {
+                let imm1 = imm1.map_names(names);
+                Operation::Prim2(prim.clone(), imm1, imm2.map_names(names))
+            }
+            Operation::Call { fun, args } => Operation::Call {
+                fun: names.fun(fun),
+                args: args.iter().map(|a| a.map_names(names)).collect(),
+            },
+        }
+    }
+}
+
+impl Immediate {
+    fn map_names(&self, names: &mut impl NameMap) -> Immediate {
+        match self {
+            Immediate::Const(n) => Immediate::Const(*n),
+            Immediate::Var(v) => Immediate::Var(names.var(v)),
+        }
+    }
+}
+
+/// A renaming of one kind of identifier, which renames each identifier
+/// the first time it sees it, and the same way every time after that.
+struct Renaming<Id> {
+    names: HashMap<Id, Id>,
+    gens: HashMap<String, IdGen<Id>>,
+}
+
+impl<Id> Default for Renaming<Id> {
+    fn default() -> Self {
+        Renaming { names: HashMap::new(), gens: HashMap::new() }
+    }
+}
+
+impl<Id: Identifier + Eq + std::hash::Hash> Renaming<Id> {
+    /// The new name of `id`. If it has none yet, it is the next index of
+    /// the counter named `counter`, with the hint `hint`.
+    fn rename(&mut self, id: &Id, counter: &str, hint: &str) -> Id {
+        let gens = &mut self.gens;
+        let fresh = || {
+            gens.entry(counter.to_string())
+                .or_insert_with(IdGen::new)
+                .fresh(hint)
+        };
+        self.names.entry(id.clone()).or_insert_with(fresh).clone()
+    }
+}
+
+/// Renames identifiers so that their indices only count the identifiers
+/// sharing their hint, in the order they first appear. Programs that
+/// differ only in how many identifiers were generated before or while
+/// lowering them get the same names.
+#[derive(Default)]
+pub struct StableNames {
+    vars: Renaming<VarName>,
+    funs: Renaming<FunName>,
+    blocks: Renaming<BlockName>,
+}
+
+impl NameMap for StableNames {
+    fn var(&mut self, var: &VarName) -> VarName {
+        self.vars.rename(var, var.hint(), var.hint())
+    }
+    fn fun(&mut self, fun: &FunName) -> FunName {
+        // unmangled names are fixed by the runtime
+        if fun.is_unmangled() {
+            return fun.clone();
+        }
+        self.funs.rename(fun, fun.hint(), fun.hint())
+    }
+    fn block(&mut self, block: &BlockName) -> BlockName {
+        self.blocks.rename(block, block.hint(), block.hint())
+    }
+}
+
+/// Renumbers identifiers densely from 0, in the order they first appear,
+/// with one counter for each kind of identifier. Unmangled function names
+/// are kept, and so are hints unless `without_hints` is set.
+#[derive(Default)]
+pub struct Renumber {
+    vars: Renaming<VarName>,
+    funs: Renaming<FunName>,
+    blocks: Renaming<BlockName>,
+    drop_hints: bool,
+}
+
+impl Renumber {
+    /// Give every renamed identifier the empty hint.
+    pub fn without_hints(mut self) -> Self {
+        self.drop_hints = true;
+        self
+    }
+
+    fn hint<'a>(&self, hint: &'a str) -> &'a str {
+        if self.drop_hints {
+            ""
+        } else {
+            hint
+        }
+    }
+}
+
+impl NameMap for Renumber {
+    fn var(&mut self, var: &VarName) -> VarName {
+        let hint = self.hint(var.hint());
+        self.vars.rename(var, "", hint)
+    }
+    fn fun(&mut self, fun: &FunName) -> FunName {
+        if fun.is_unmangled() {
+            return fun.clone();
+        }
+        let hint = self.hint(fun.hint());
+        self.funs.rename(fun, "", hint)
+    }
+    fn block(&mut self, block: &BlockName) -> BlockName {
+        let hint = self.hint(block.hint());
+        self.blocks.rename(block, "", hint)
+    }
+}
+
+impl Program {
+    /// The canonical form of this program: every identifier renumbered by
+    /// `Renumber`. Programs that only differ in the indices of their
+    /// identifiers have the same canonical form.
+    pub fn renumber(&self) -> Program {
+        self.map_names(&mut Renumber::default())
+    }
+}
+
+/// Whether `a` and `b` are equal up to a consistent renaming of their
+/// variables, blocks and mangled functions. Unmangled functions (externs
+/// and `entry`) have to match by name.
+pub fn alpha_eq(a: &Program, b: &Program) -> bool {
+    let canonical =
+        |p: &Program| p.map_names(&mut Renumber::default().without_hints());
+    canonical(a) == canonical(b)
+}
+
+/// Whether `fun` calls itself, and only ever as a tail call. The lowerer
+/// turns a self-call in tail position into a branch back to the block the
+/// function starts with, which runs in constant stack space, and any other
+/// self-call into an `Operation::Call`. A function that never calls
+/// itself is not tail-recursive.
+pub fn is_tail_recursive(prog: &Program, fun: &FunName) -> bool {
+    let Some(fun_block) = prog.funs.iter().find(|f| f.name == *fun) else {
+        return false;
+    };
+    let entry = &fun_block.body.target;
+    let Some(top) = prog.blocks.iter().find(|b| b.label == *entry) else {
+        return false;
+    };
+    let mut blocks = Vec::new();
+    top.collect_blocks(&mut blocks);
+
+    let mut loops = false;
+    for block in blocks {
+        let mut body = &block.body;
+        loop {
+            match body {
+                BlockBody::Operation {
+                    op: Operation::Call { fun: f, .. },
+                    ..
+                } if f == fun => {
+                    return false;
+                }
+                BlockBody::Operation { next, .. }
+                | BlockBody::SubBlocks { next, .. } => body = next,
+                BlockBody::Terminator(t) => {
+                    loops |= t.targets().contains(&entry);
+                    break;
+                }
+            }
+        }
+    }
+    loops
+}
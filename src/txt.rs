@@ -1,68 +1,224 @@
 use crate::frontend::CompileErr;
+use crate::lexer::describe_terminal;
 use crate::span::{Span2, SrcLoc};
+use lalrpop_util::lexer::Token;
+use lalrpop_util::ParseError;
 #[derive(Clone, Debug)]
 pub struct FileInfo {
     newlines: Vec<usize>,
-    len: usize,
+    tabs: Vec<usize>,
+    src: String,
+    tab_width: Option<usize>,
 }
 
 impl FileInfo {
     pub fn new(s: &str) -> Self {
         FileInfo {
             newlines: s.char_indices().filter(|(_i, c)| *c == '\n').map(|(i, _c)| i).collect(),
-            len: s.len(),
+            tabs: s.char_indices().filter(|(_i, c)| *c == '\t').map(|(i, _c)| i).collect(),
+            src: s.to_string(),
+            tab_width: None,
         }
     }
 
+    /// Count a tab as reaching the next multiple of `width` columns, as an
+    /// editor shows it, rather than as one column.
+    pub fn with_tab_width(mut self, width: usize) -> Self {
+        self.tab_width = Some(width.max(1));
+        self
+    }
+
     pub fn span1_to_span2(&self, offsets: SrcLoc) -> Span2 {
         let mut v = vec![0];
         v.extend(self.newlines.iter().map(|ix| ix + 1));
-        v.push(self.len);
+        v.push(self.src.len());
 
-        let (start_line, start_col) = Self::offset_to_line_col(&v, offsets.start_ix);
-        let (end_line, end_col) = Self::offset_to_line_col(&v, offsets.end_ix - 1);
+        let (start_line, start_col) = self.offset_to_line_col(&v, offsets.start_ix);
+        let (end_line, end_col) = self.offset_to_line_col(&v, offsets.end_ix - 1);
         Span2 { start_line, start_col, end_line, end_col: end_col + 1 }
     }
 
-    fn offset_to_line_col(newlines: &[usize], offset: usize) -> (usize, usize) {
+    /// The source text `loc` covers, or the empty string if it is not a
+    /// region of the source this was made from.
+    pub fn slice(&self, loc: SrcLoc) -> &str {
+        self.src.get(loc.start_ix..loc.end_ix).unwrap_or_default()
+    }
+
+    fn offset_to_line_col(&self, newlines: &[usize], offset: usize) -> (usize, usize) {
         let mut win = newlines.windows(2).enumerate();
         while let Some((line, &[start, end])) = win.next() {
             if start <= offset && offset < end {
-                return (line + 1, offset - start);
+                return (line + 1, self.column(start, offset));
             }
         }
         panic!("internal error: offset_to_line_col. Send this to the professor");
     }
 
+    /// The column of `offset` on the line starting at `line_start`.
+    fn column(&self, line_start: usize, offset: usize) -> usize {
+        let Some(width) = self.tab_width else {
+            return offset - line_start;
+        };
+        let first = self.tabs.partition_point(|&tab| tab < line_start);
+        let last = self.tabs.partition_point(|&tab| tab < offset);
+        let (mut col, mut pos) = (0, line_start);
+        for &tab in &self.tabs[first..last] {
+            col = (col + tab - pos) / width * width + width;
+            pos = tab + 1;
+        }
+        col + offset - pos
+    }
+
     pub fn report_error(&self, err: CompileErr) -> String {
+        self.diagnostic(err).to_string()
+    }
+
+    pub fn diagnostic(&self, err: CompileErr) -> Diagnostic {
         use CompileErr::*;
-        match err {
-            UnboundVariable(v, span1) => {
-                format!("variable \"{}\" unbound: {}", v, self.span1_to_span2(span1))
+        let (message, span1) = match err {
+            UnboundVariable(v, span1) => (format!("variable \"{}\" unbound", v), span1),
+            DuplicateVariable(v, span1) => {
+                (format!("variable \"{}\" defined twice in let-expression", v), span1)
             }
-            DuplicateVariable(v, span1) => format!(
-                "variable \"{}\" defined twice in let-expression: {}",
-                v,
-                self.span1_to_span2(span1)
+            UnboundFunction(f, span1) => (format!("function \"{}\" undefined", f), span1),
+            DuplicateFunction(f, span1) => {
+                (format!("multiple defined functions named \"{}\"", f), span1)
+            }
+            DuplicateParameter(p, span1) => {
+                (format!("multiple parameters named \"{}\"", p), span1)
+            }
+            NonFunctionRecursiveBinding(v, span1) => (
+                format!("letrec binding \"{}\" must be a function value", v),
+                span1,
+            ),
+            ArityMismatch { name, expected, found, loc } => (
+                format!(
+                    "function \"{}\" of arity {} called with {} arguments",
+                    name, expected, found
+                ),
+                loc,
             ),
-            UnboundFunction(f, span1) => {
-                format!("function \"{}\" undefined: {}", f, self.span1_to_span2(span1))
+            MatchWithoutDefault(span1) => {
+                ("match has no catch-all \"_\" arm".to_string(), span1)
             }
-            DuplicateFunction(f, span1) => format!(
-                "multiple defined functions named \"{}\": {}",
-                f,
-                self.span1_to_span2(span1)
+            ErrorsSuppressed(span1) => ("further errors suppressed".to_string(), span1),
+        };
+        Diagnostic { severity: Severity::Error, message, span: self.span1_to_span2(span1) }
+    }
+
+    /// An empty span at `offset`, which may be the end of the input.
+    fn point(&self, offset: usize) -> Span2 {
+        let len = self.src.len();
+        let (line, col) = if offset < len {
+            let span = self.span1_to_span2(SrcLoc::new(offset, offset + 1));
+            (span.start_line, span.start_col)
+        } else if len > 0 {
+            let span = self.span1_to_span2(SrcLoc::new(len - 1, len));
+            (span.end_line, span.end_col)
+        } else {
+            (1, 0)
+        };
+        Span2 { start_line: line, start_col: col, end_line: line, end_col: col }
+    }
+
+    pub fn report_parse_error(&self, src: &str, err: ParseError<usize, Token, &str>) -> String {
+        self.parse_diagnostic(src, err).to_string()
+    }
+
+    /// Describe why `src`, which this is the `FileInfo` of, failed to
+    /// parse, quoting the text of the offending token.
+    pub fn parse_diagnostic(&self, src: &str, err: ParseError<usize, Token, &str>) -> Diagnostic {
+        let (message, span) = match err {
+            ParseError::UnrecognizedToken { token: (l, _, r), expected } => (
+                format!("unexpected \"{}\", expected {}", &src[l..r], expected_list(&expected)),
+                self.span1_to_span2(SrcLoc::new(l, r)),
             ),
-            DuplicateParameter(p, span1) => {
-                format!("multiple parameters named \"{}\": {}", p, self.span1_to_span2(span1))
+            ParseError::UnrecognizedEof { location, expected } => (
+                format!("unexpected end of input, expected {}", expected_list(&expected)),
+                self.point(location),
+            ),
+            ParseError::InvalidToken { location } => {
+                let c = src[location..].chars().next().unwrap_or_default();
+                let loc = SrcLoc::new(location, location + c.len_utf8());
+                (format!("invalid token \"{}\"", c), self.span1_to_span2(loc))
             }
-            ArityMismatch { name, expected, found, loc } => format!(
-                "function \"{}\" of arity {} called with {} arguments: {}",
-                name,
-                expected,
-                found,
-                self.span1_to_span2(loc)
+            ParseError::ExtraToken { token: (l, _, r) } => (
+                format!("unexpected \"{}\" after the end of the program", &src[l..r]),
+                self.span1_to_span2(SrcLoc::new(l, r)),
             ),
+            ParseError::User { error } => (error.to_string(), self.point(0)),
+        };
+        Diagnostic { severity: Severity::Error, message, span }
+    }
+}
+
+/// The terminals lalrpop expected, as a list a user can read: its quoted
+/// literals stay as they are, and its regular expressions are named.
+fn expected_list(expected: &[String]) -> String {
+    let names: Vec<_> =
+        expected.iter().map(|t| describe_terminal(t)).collect();
+    match names.as_slice() {
+        [] => "nothing".to_string(),
+        [name] => name.clone(),
+        [init @ .., last] => format!("one of {} or {}", init.join(", "), last),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+}
+
+/// A message about a region of the source program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub span: Span2,
+}
+
+impl Diagnostic {
+    /// Render as a single-line JSON object, for consumption by editors.
+    pub fn to_json(&self) -> String {
+        let Span2 { start_line, start_col, end_line, end_col } = self.span;
+        format!(
+            "{{\"severity\":\"{}\",\"message\":{},\"start_line\":{},\"start_col\":{},\"end_line\":{},\"end_col\":{}}}",
+            self.severity,
+            json_string(&self.message),
+            start_line,
+            start_col,
+            end_line,
+            end_col
+        )
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.message, self.span)
+    }
+}
+
+/// Quote and escape a string as a JSON string literal.
+fn json_string(s: &str) -> String {
+    let mut buf = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            c if (c as u32) < 0x20 => buf.push_str(&format!("\\u{:04x}", c as u32)),
+            c => buf.push(c),
         }
     }
+    buf.push('"');
+    buf
 }
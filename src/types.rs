@@ -0,0 +1,86 @@
+//! Boolean typing of the resolved AST.
+//!
+//! An expression is boolean if it always evaluates to 0 or 1: a boolean
+//! literal, a comparison or logical primitive, or a variable bound to a
+//! boolean expression. Parameters may be passed anything, so they never
+//! are. The lowerer can use the result to skip coercing operands that are
+//! already 0 or 1.
+
+use crate::ast::*;
+use crate::identifiers::VarName;
+use std::collections::HashSet;
+
+/// The variables `prog` binds, anywhere in it, to boolean expressions.
+pub fn bool_vars(prog: &BoundProg) -> HashSet<VarName> {
+    let mut bools = HashSet::new();
+    for decl in &prog.funs {
+        collect(&decl.body, &mut bools);
+    }
+    collect(&prog.body, &mut bools);
+    bools
+}
+
+/// Whether `expr` always evaluates to 0 or 1, given the variables known
+/// to be boolean.
+pub fn is_bool(expr: &BoundExpr, bools: &HashSet<VarName>) -> bool {
+    match expr {
+        Expr::Bool(..) => true,
+        Expr::Num(..) | Expr::Call { .. } => false,
+        Expr::Var(v, _) => bools.contains(v),
+        Expr::Prim { prim, .. } => matches!(
+            prim,
+            Prim::Not
+                | Prim::And
+                | Prim::Or
+                | Prim::Lt
+                | Prim::Le
+                | Prim::Gt
+                | Prim::Ge
+                | Prim::Eq
+                | Prim::Neq
+        ),
+        Expr::Let { body, .. } | Expr::FunDefs { body, .. } => {
+            is_bool(body, bools)
+        }
+        Expr::If { thn, els, .. } => {
+            is_bool(thn, bools) && is_bool(els, bools)
+        }
+        Expr::Match { arms, default, .. } => {
+            arms.iter().all(|arm| is_bool(&arm.body, bools))
+                && default.iter().all(|default| is_bool(default, bools))
+        }
+    }
+}
+
+/// Add the variables `expr` binds to boolean expressions to `bools`.
+/// Names are unique after resolution, so a binding is typed once every
+/// variable it could read has been: those bound before it, or outside it.
+fn collect(expr: &BoundExpr, bools: &mut HashSet<VarName>) {
+    match expr {
+        Expr::Num(..) | Expr::Bool(..) | Expr::Var(..) => {}
+        Expr::Prim { args, .. } | Expr::Call { args, .. } => {
+            args.iter().for_each(|arg| collect(arg, bools))
+        }
+        Expr::Let { bindings, body, .. } => {
+            for Binding { var: (var, _), expr } in bindings {
+                collect(expr, bools);
+                if is_bool(expr, bools) {
+                    bools.insert(var.clone());
+                }
+            }
+            collect(body, bools);
+        }
+        Expr::If { cond, thn, els, .. } => {
+            [cond, thn, els].into_iter().for_each(|e| collect(e, bools))
+        }
+        Expr::Match { scrutinee, arms, default, .. } => {
+            collect(scrutinee, bools);
+            arms.iter().for_each(|arm| collect(&arm.body, bools));
+            default.iter().for_each(|default| collect(default, bools));
+        }
+        Expr::FunDefs { decls, body, .. } => {
+            decls.iter().for_each(|decl| collect(&decl.body, bools));
+            collect(body, bools);
+        }
+    }
+}
@@ -27,6 +27,26 @@ macro_rules! mk_middle_end_test {
     };
 }
 
+#[allow(unused)]
+macro_rules! mk_tagged_test {
+    ($test_name:ident, $file_name:expr, $inputs:expr) => {
+        #[test]
+        fn $test_name() -> std::io::Result<()> {
+            test_example_tagged($file_name, $inputs)
+        }
+    };
+}
+
+#[allow(unused)]
+macro_rules! mk_consistency_test {
+    ($test_name:ident, $file_name:expr, $input:expr) => {
+        #[test]
+        fn $test_name() -> std::io::Result<()> {
+            test_example_consistency($file_name, $input)
+        }
+    };
+}
+
 macro_rules! mk_fail_test {
     ($test_name:ident, $file_name:expr, $expected_output:expr) => {
         #[test]
@@ -56,6 +76,12 @@ mk_frontend_test!(test1_frontend, "add1.adder", "42", "43");
  */
 mk_middle_end_test!(test1_middleend, "add1.adder", "42", "43");
 
+/* This test runs both the compiled executable and the SSA interpreter on
+ * examples/add1.adder with input 42, and checks that they agree on the
+ * result, whatever it is.
+ */
+mk_consistency_test!(test1_consistency, "add1.adder", "42");
+
 /*
  * The following test checks that when run on exmaples/free.adder, the
  * compiler produces an error containing the substring "variable z unbound".
@@ -96,81 +122,4848 @@ x9: -9
     mk_test!(test_non_tail_recursion_3, "non_tail_factorial.cobra", "5", "120");
     // one for testing recursive internal call with capture
     mk_test!(test_rec_call_capture_3, "pow.cobra", "2", "256");
+
+    mk_consistency_test!(test_non_tail_recursion_consistent, "non_tail_factorial.cobra", "10");
+    mk_consistency_test!(test_rec_call_capture_consistent, "pow.cobra", "3");
 }
-/*
- * YOUR TESTS END HERE
- */
+/* ------------------------- Value-based Interpreters ---------------------- */
+mod run_value {
+    use super::*;
+    use snake::interp::Value;
 
-/* ----------------------- Test Implementation Details ---------------------- */
+    #[test]
+    fn ast_int() {
+        let (_, ast) = runner::emit_ast(Path::new("examples/add1.adder")).unwrap();
+        let v = interp::ast::Machine::run_prog_value(&ast, Value::Int(-7)).unwrap();
+        assert_eq!(v.to_string(), "-6");
+    }
 
-use snake::{interp, runner};
-use std::path::Path;
+    #[test]
+    fn ssa_int() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/add1.adder")).unwrap();
+        let v = interp::ssa::Interp::new().run_value(&ssa, Value::Int(i64::MAX - 1)).unwrap();
+        assert_eq!(v.to_string(), i64::MAX.to_string());
+    }
 
-fn test_example_file(f: &str, arg: &str, expected: &str) -> std::io::Result<()> {
-    let tmp_dir = tempfile::TempDir::new()?;
-    let mut buf = Vec::new();
-    match runner::compile_and_run_file(
-        &Path::new(&format!("examples/{}", f)),
-        tmp_dir.path(),
-        arg,
-        &mut buf,
-    ) {
-        Ok(()) => {
-            assert_eq!(String::from_utf8_lossy(&buf).trim(), expected.trim())
+    #[test]
+    fn ssa_tagged_out_of_range() {
+        use snake::ssa::IntRepr;
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/add1.adder")).unwrap();
+        let res = interp::ssa::Interp::new()
+            .with_repr(IntRepr::Tagged)
+            .run_value(&ssa, Value::Int(i64::MAX));
+        assert!(res.is_err(), "expected an out-of-range error");
+    }
+
+    #[test]
+    fn ast_prints_to_output() {
+        let (_, ast) = runner::emit_ast(Path::new("examples/extern_big_nine.cobra")).unwrap();
+        let mut out = Vec::new();
+        let v = interp::ast::Interp::new()
+            .with_output(&mut out)
+            .with_extern("big_fun_nine", 9, big_fun)
+            .run(&ast, "0".to_string())
+            .unwrap();
+        assert_eq!(v.to_string(), "-46");
+        let lines: Vec<_> = (1..=9).map(|i| format!("x{}: -{}\n", i, i)).collect();
+        assert_eq!(String::from_utf8(out).unwrap(), lines.concat());
+    }
+}
+
+/* --------------------------------- Spans -------------------------------- */
+mod spans {
+    use snake::ast::{spans, NodeKind::*, SrcLoc};
+    use snake::parser::ProgParser;
+
+    #[test]
+    fn small_program() {
+        let src = "def main(x): let y = add1(x) in if y: 5 else: x";
+        let prog = ProgParser::new().parse(src).unwrap();
+        // the location of the `n`th occurrence of `sub` in the source
+        let at = |sub: &str, n: usize| {
+            let start = src.match_indices(sub).nth(n).unwrap().0;
+            SrcLoc::new(start, start + sub.len())
+        };
+        assert_eq!(
+            spans(&prog),
+            vec![
+                (Prog, SrcLoc::new(0, src.len())),
+                (Param, at("x", 0)),
+                (Let, at("let y = add1(x) in if y: 5 else: x", 0)),
+                (Binding, at("y", 0)),
+                (Prim, at("add1(x)", 0)),
+                (Var, at("x", 1)),
+                (If, at("if y: 5 else: x", 0)),
+                (Var, at("y", 1)),
+                (Num, at("5", 0)),
+                (Var, at("x", 2)),
+            ]
+        );
+    }
+}
+
+/* -------------------------------- Tokens -------------------------------- */
+mod tokens {
+    use snake::ast::SrcLoc;
+    use snake::lexer::tokenize;
+
+    #[test]
+    fn add1_call() {
+        assert_eq!(
+            tokenize("add1(40)").unwrap(),
+            vec![
+                (SrcLoc::new(0, 4), "add1"),
+                (SrcLoc::new(4, 5), "("),
+                (SrcLoc::new(5, 7), "40"),
+                (SrcLoc::new(7, 8), ")"),
+            ]
+        );
+    }
+
+    #[test]
+    fn keywords_identifiers_and_signs() {
+        let toks: Vec<_> =
+            tokenize("let add1x = -5 in add1x-1").unwrap().into_iter().map(|(_, t)| t).collect();
+        assert_eq!(toks, vec!["let", "add1x", "=", "-5", "in", "add1x", "-1"]);
+    }
+
+    #[test]
+    fn invalid_token() {
+        assert!(tokenize("x $ y").is_err());
+    }
+
+    #[test]
+    fn terminals_match_the_generated_parser() {
+        let parser = std::fs::read_to_string("src/parser.rs").unwrap();
+        let start = parser.find("const __TERMINAL: &[&str] = &[").unwrap();
+        let end = start + parser[start..].find("];").unwrap();
+        let mut generated: Vec<_> = parser[start..end]
+            .lines()
+            .skip(1)
+            .map(|l| l.trim().trim_start_matches("r###\"").trim_end_matches("\"###,"))
+            .filter(|t| !t.is_empty())
+            .collect();
+        let mut names = snake::lexer::terminal_names();
+        generated.sort();
+        names.sort();
+        assert_eq!(names, generated);
+    }
+}
+
+/* ------------------------------ Diagnostics ----------------------------- */
+mod diagnostics {
+    use snake::frontend::Resolver;
+    use snake::parser::ProgParser;
+    use snake::txt::FileInfo;
+
+    #[test]
+    fn unbound_variable_json() {
+        let src = "def main(x):\n  add1(z)";
+        let prog = ProgParser::new().parse(src).unwrap();
+        let err = Resolver::new().resolve_prog(prog).unwrap_err();
+        assert_eq!(
+            FileInfo::new(src).diagnostic(err).to_json(),
+            r#"{"severity":"error","message":"variable \"z\" unbound","start_line":2,"start_col":7,"end_line":2,"end_col":8}"#
+        );
+    }
+
+    #[test]
+    fn tab_indented_columns() {
+        let src = "def main(x):\n\tlet y = 1 in\n\t\t add1(z)";
+        let prog = ProgParser::new().parse(src).unwrap();
+        let err = Resolver::new().resolve_prog(prog).unwrap_err();
+        assert_eq!(FileInfo::new(src).diagnostic(err.clone()).span.to_string(), "3:8-3:9");
+        let span = FileInfo::new(src).with_tab_width(8).diagnostic(err.clone()).span;
+        assert_eq!(span.to_string(), "3:22-3:23");
+        let span = FileInfo::new(src).with_tab_width(4).diagnostic(err).span;
+        assert_eq!(span.to_string(), "3:14-3:15");
+    }
+
+    #[test]
+    fn tab_after_text_reaches_next_stop() {
+        let src = "ab\tc";
+        let info = FileInfo::new(src).with_tab_width(4);
+        assert_eq!(info.span1_to_span2(snake::span::SrcLoc::new(3, 4)).to_string(), "1:4-1:5");
+    }
+}
+
+/* --------------------------- Go-to-definition --------------------------- */
+mod definitions {
+    use snake::ast::SrcLoc;
+    use snake::frontend::Resolver;
+    use snake::parser::ProgParser;
+
+    /// The location of the `n`th occurrence of `sub` in `src`.
+    fn at(src: &str, sub: &str, n: usize) -> SrcLoc {
+        let start = src.match_indices(sub).nth(n).unwrap().0;
+        SrcLoc::new(start, start + sub.len())
+    }
+
+    #[test]
+    fn let_body_use() {
+        let src = "def main(x): let y = x, z = 1 in y";
+        let prog = ProgParser::new().parse(src).unwrap();
+        let mut resolver = Resolver::new().with_definitions();
+        resolver.resolve_prog(prog).unwrap();
+        let defs = resolver.definitions.unwrap();
+        assert_eq!(defs.get(&at(src, "y", 1)), Some(&at(src, "y", 0)));
+        assert_eq!(defs.get(&at(src, "x", 1)), Some(&at(src, "x", 0)));
+    }
+
+    #[test]
+    fn call_to_local_function() {
+        let src = "def main(x): def f(p): p in f(x)";
+        let prog = ProgParser::new().parse(src).unwrap();
+        let mut resolver = Resolver::new().with_definitions();
+        resolver.resolve_prog(prog).unwrap();
+        let defs = resolver.definitions.unwrap();
+        assert_eq!(defs.get(&at(src, "f(x)", 0)), Some(&at(src, "def f(p): p", 0)));
+        assert_eq!(defs.get(&at(src, "p", 1)), Some(&at(src, "p", 0)));
+    }
+
+    #[test]
+    fn off_by_default() {
+        let prog = ProgParser::new().parse("def main(x): x").unwrap();
+        let mut resolver = Resolver::new();
+        resolver.resolve_prog(prog).unwrap();
+        assert!(resolver.definitions.is_none());
+    }
+}
+
+/* ---------------------------- Tagged Integers ---------------------------- */
+mod tagged {
+    use super::*;
+    mk_tagged_test!(tagged_arith, "tagged_arith.cobra", -10..=10);
+    mk_tagged_test!(tagged_factorial, "recursive_main_factorial.cobra", 0..=10);
+    mk_tagged_test!(tagged_not, "control-flow-examples/not.boa", -3..=3);
+    mk_tagged_test!(tagged_or, "control-flow-examples/or_4_2.boa", -3..=3);
+    mk_tagged_test!(tagged_corecursive, "control-flow-examples/corecursive.boa", 0..=10);
+
+    const TOO_BIG: &str = "def main(x):\n  x + 4611686018427387904";
+
+    #[test]
+    fn exe_agrees_with_interp() -> std::io::Result<()> {
+        use snake::compile::Pipeline;
+        use snake::ssa::IntRepr;
+        let src = runner::read_file(Path::new("examples/tagged_arith.cobra"))?;
+        let asm = Pipeline::new().with_repr(IntRepr::Tagged).compile(&src).unwrap();
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/tagged_arith.cobra")).unwrap();
+        let tmp_dir = tempfile::TempDir::new()?;
+        for arg in [-5, 0, 2, 7] {
+            let expected = interp::ssa::Interp::new().run(&ssa, arg.to_string()).unwrap();
+            let mut buf = Vec::new();
+            runner::link_and_run(
+                &asm,
+                Path::new("runtime/stub.rs"),
+                tmp_dir.path(),
+                &arg.to_string(),
+                &mut buf,
+            )
+            .unwrap_or_else(|e| panic!("on input {}: {}", arg, e));
+            assert_eq!(String::from_utf8_lossy(&buf).trim(), expected.to_string());
         }
-        Err(e) => {
-            assert!(false, "Expected {}, got an error: {}", expected, e)
+        Ok(())
+    }
+
+    #[test]
+    fn constant_out_of_range_is_an_error() {
+        use snake::compile::{Error, Pipeline};
+        use snake::ssa::IntRepr;
+        assert!(Pipeline::new().compile(TOO_BIG).is_ok());
+        let res = Pipeline::new().with_repr(IntRepr::Tagged).compile(TOO_BIG);
+        assert!(matches!(res, Err(Error::OutOfRange(4611686018427387904))), "{:?}", res);
+    }
+
+    #[test]
+    fn emitter_checks_the_constants() {
+        use snake::compile::{Error, Pipeline};
+        use snake::ssa::IntRepr;
+        let pipeline = Pipeline::new().with_repr(IntRepr::Tagged);
+        let (resolver, ast) = pipeline.frontend(TOO_BIG).unwrap();
+        // the interpreters can still run what the middle-end lowers
+        let (lowerer, ssa) = pipeline.middle_end(resolver, ast).unwrap();
+        let res = pipeline.emitter(lowerer, &ssa);
+        assert!(matches!(res, Err(Error::OutOfRange(4611686018427387904))));
+    }
+
+    #[test]
+    fn interp_rejects_constant_out_of_range() {
+        use snake::compile::Pipeline;
+        use snake::ssa::IntRepr;
+        let (resolver, ast) = Pipeline::new().frontend(TOO_BIG).unwrap();
+        let (_, ssa) = Pipeline::new().middle_end(resolver, ast).unwrap();
+        let res = interp::ssa::Interp::new().with_repr(IntRepr::Tagged).run(&ssa, "0".into());
+        assert!(matches!(res, Err(interp::InterpErr::OutOfRange(_))), "{:?}", res);
+        let (_, ast) = Pipeline::new().frontend(TOO_BIG).unwrap();
+        let res = interp::ast::Interp::new().with_repr(IntRepr::Tagged).run(&ast, "0".into());
+        assert!(matches!(res, Err(interp::InterpErr::OutOfRange(_))), "{:?}", res);
+    }
+
+    #[test]
+    fn ast_interp_wraps_like_ssa_interp() {
+        use snake::compile::Pipeline;
+        use snake::ssa::IntRepr;
+        let src = "def main(x):\n  x * 2 + 1";
+        let (resolver, ast) = Pipeline::new().frontend(src).unwrap();
+        let (_, ssa) = Pipeline::new().middle_end(resolver, ast.clone()).unwrap();
+        // the doubled values past 1 << 61 no longer fit in 63 bits
+        for arg in [3i64, 1 << 61, -(1 << 61), (1 << 62) - 1] {
+            let from_ast = interp::ast::Interp::new()
+                .with_repr(IntRepr::Tagged)
+                .run(&ast, arg.to_string())
+                .map(|v| v.to_string())
+                .map_err(|e| e.to_string());
+            let from_ssa = interp::ssa::Interp::new()
+                .with_repr(IntRepr::Tagged)
+                .run(&ssa, arg.to_string())
+                .map(|v| v.to_string())
+                .map_err(|e| e.to_string());
+            assert_eq!(from_ast, from_ssa, "on input {}", arg);
         }
+        let res = interp::ast::Interp::new().with_repr(IntRepr::Tagged).run(&ast, "0".into());
+        assert_eq!(res.unwrap().to_string(), "1");
+        let res = interp::ast::Interp::new()
+            .with_repr(IntRepr::Tagged)
+            .run(&ast, (1i64 << 62).to_string());
+        assert!(matches!(res, Err(interp::InterpErr::InvalidArg(_))), "{:?}", res);
     }
-    Ok(())
 }
 
-#[allow(unused)]
-fn test_example_frontend(f: &str, arg: &str, expected: &str) -> std::io::Result<()> {
-    let res = runner::emit_ast(&Path::new(&format!("examples/{}", f))).and_then(|(_, ast)| {
-        interp::ast::Machine::run_prog(&ast, arg.to_string()).map_err(|e| format!("{}", e))
-    });
-    match res {
-        Ok(v) => assert_eq!(v.to_string(), expected),
-        Err(e) => assert!(false, "Expected {}, got an error: {}", expected, e),
+/* ------------------------- Unsigned Comparisons -------------------------- */
+mod unsigned {
+    use super::*;
+    use snake::ssa::Prim2;
+
+    #[test]
+    fn negative_below_positive_only_when_signed() {
+        assert_eq!(run_prim2(Prim2::Lt, -1, 1), "1");
+        assert_eq!(run_prim2(Prim2::ULt, -1, 1), "0");
+        assert_eq!(run_prim2(Prim2::Le, -1, 1), "1");
+        assert_eq!(run_prim2(Prim2::ULe, -1, 1), "0");
+    }
+
+    #[test]
+    fn negative_above_positive_only_when_unsigned() {
+        assert_eq!(run_prim2(Prim2::Gt, -5, 3), "0");
+        assert_eq!(run_prim2(Prim2::UGt, -5, 3), "1");
+        assert_eq!(run_prim2(Prim2::Ge, -5, 3), "0");
+        assert_eq!(run_prim2(Prim2::UGe, -5, 3), "1");
+    }
+
+    #[test]
+    fn agree_on_non_negative() {
+        assert_eq!(run_prim2(Prim2::Lt, 2, 7), run_prim2(Prim2::ULt, 2, 7));
+        assert_eq!(run_prim2(Prim2::Ge, 7, 7), run_prim2(Prim2::UGe, 7, 7));
     }
-    Ok(())
 }
 
-#[allow(unused)]
-fn test_example_middle_end(f: &str, arg: &str, expected: &str) -> std::io::Result<()> {
-    let res = runner::emit_ssa(&Path::new(&format!("examples/{}", f))).and_then(|(_, ssa)| {
-        let mut interp = interp::ssa::Interp::new();
-        interp.run(&ssa, arg.to_string()).map_err(|e| format!("{}", e))
-    });
-    match res {
-        Ok(v) => assert_eq!(v.to_string(), expected),
-        Err(e) => assert!(false, "Expected {}, got an error: {}", expected, e),
+/* ---------------------------- Condition Codes ---------------------------- */
+mod condition_codes {
+    use snake::asm::ConditionCode::{self, *};
+
+    const ALL: [ConditionCode; 18] =
+        [E, NE, L, LE, G, GE, B, BE, A, AE, C, NC, S, NS, Z, NZ, O, NO];
+
+    #[test]
+    fn unsigned_display() {
+        assert_eq!(B.to_string(), "b");
+        assert_eq!(BE.to_string(), "be");
+        assert_eq!(A.to_string(), "a");
+        assert_eq!(AE.to_string(), "ae");
+        assert_eq!(C.to_string(), "c");
+        assert_eq!(NC.to_string(), "nc");
+    }
+
+    #[test]
+    fn invert_unsigned() {
+        assert_eq!(B.invert(), AE);
+        assert_eq!(BE.invert(), A);
+        assert_eq!(A.invert(), BE);
+        assert_eq!(AE.invert(), B);
+        assert_eq!(C.invert(), NC);
+        assert_eq!(NC.invert(), C);
+    }
+
+    #[test]
+    fn invert_is_an_involution() {
+        for cc in ALL {
+            assert_ne!(cc.invert(), cc);
+            assert_eq!(cc.invert().invert(), cc);
+        }
     }
-    Ok(())
 }
 
-fn test_example_fail(f: &str, includes: &str) -> std::io::Result<()> {
-    let tmp_dir = tempfile::TempDir::new()?;
-    let mut buf = Vec::new();
-    match runner::compile_and_run_file(
-        &Path::new(&format!("examples/{}", f)),
-        tmp_dir.path(),
-        "0",
-        &mut buf,
-    ) {
-        Ok(()) => {
-            assert!(false, "Expected a failure but got: {}", String::from_utf8_lossy(&buf).trim())
+/* ------------------------------ GNU Stack ------------------------------- */
+#[cfg(target_os = "linux")]
+mod gnu_stack {
+    use super::*;
+
+    #[test]
+    fn elf_marks_stack_non_executable() {
+        let (lowerer, ssa) = runner::emit_ssa(Path::new("examples/add1.adder")).unwrap();
+        let mut emitter = backend::Emitter::from(lowerer);
+        emitter.emit_prog(&ssa);
+        let txt = asm::instrs_to_string(&emitter.to_asm());
+        assert!(
+            txt.contains("section .note.GNU-stack noalloc noexec nowrite progbits"),
+            "no GNU-stack note in:\n{}",
+            txt
+        );
+    }
+
+    // linking still succeeds with the note present
+    mk_test!(links_with_note, "add1.adder", "1", "2");
+}
+
+/* ------------------------------ Block Layout ----------------------------- */
+mod layout {
+    use super::*;
+
+    #[test]
+    fn join_after_both_arms() {
+        let labels = emitted_labels("control-flow-examples/let_in_if_in_let.boa");
+        let pos = |hint: &str| {
+            labels
+                .iter()
+                .position(|l| l.starts_with(hint))
+                .unwrap_or_else(|| panic!("no label {} in {:?}", hint, labels))
+        };
+        assert!(pos("jn.b") > pos("thn.b"), "join before then-arm: {:?}", labels);
+        assert!(pos("jn.b") > pos("els.b"), "join before else-arm: {:?}", labels);
+    }
+}
+
+/* ---------------------------- SSA Interp Frames ------------------------- */
+mod frames {
+    use super::*;
+    use snake::ssa::*;
+
+    /// The frames `interp.dump_stack()` shows, innermost first, each with
+    /// its bindings as hint and value.
+    fn dumped_frames(interp: &interp::ssa::Interp) -> Vec<Vec<(String, String)>> {
+        let mut frames: Vec<Vec<(String, String)>> = Vec::new();
+        for line in interp.dump_stack().lines() {
+            match line.strip_prefix("  ").and_then(|b| b.split_once(" = ")) {
+                Some((var, val)) => {
+                    let hint = var.split('%').next().unwrap();
+                    frames.last_mut().unwrap().push((hint.to_string(), val.to_string()))
+                }
+                None => frames.push(Vec::new()),
+            }
         }
-        Err(e) => {
-            let msg = format!("{}", e);
-            assert!(
-                msg.contains(includes),
-                "Expected error message to include the string \"{}\" but got the error: {}",
-                includes,
-                msg
+        frames
+    }
+
+    #[test]
+    fn dump_shows_where_the_program_stopped() {
+        let src = "def main(x):\n  def f(a):\n    let b = a * 2 in error(b)\n  in\n  let y = x + 1 in\n  f(y) + y";
+        let (resolver, ast) = snake::compile::frontend(src).unwrap();
+        let (_, ssa) = snake::compile::middle_end(resolver, ast).unwrap();
+        let mut interp = interp::ssa::Interp::new();
+        assert!(interp.run(&ssa, "5".to_string()).is_err());
+        let frames = dumped_frames(&interp);
+        let binding = |hint: &str, val: &str| (hint.to_string(), val.to_string());
+        assert_eq!(frames.len(), 2, "{}", interp.dump_stack());
+        assert!(frames[0].contains(&binding("a", "6")), "{}", interp.dump_stack());
+        assert!(frames[0].contains(&binding("b", "12")), "{}", interp.dump_stack());
+        assert!(frames[1].contains(&binding("y", "6")), "{}", interp.dump_stack());
+        assert!(!frames[1].iter().any(|(hint, _)| hint == "b"), "{}", interp.dump_stack());
+    }
+
+    fn op(dest: &snake::identifiers::VarName, op: Operation, next: BlockBody) -> BlockBody {
+        BlockBody::Operation { dest: dest.clone(), op, next: Box::new(next) }
+    }
+    fn var(v: &snake::identifiers::VarName) -> Immediate {
+        Immediate::Var(v.clone())
+    }
+    fn jump(target: &snake::identifiers::BlockName, args: Vec<Immediate>) -> BlockBody {
+        BlockBody::Terminator(Terminator::Branch(Branch { target: target.clone(), args }))
+    }
+    fn cbr(
+        cond: &snake::identifiers::VarName, thn: &snake::identifiers::BlockName,
+        els: &snake::identifiers::BlockName,
+    ) -> BlockBody {
+        BlockBody::Terminator(Terminator::ConditionalBranch {
+            cond: var(cond),
+            thn: thn.clone(),
+            els: els.clone(),
+        })
+    }
+
+    /// main(x):
+    ///   v = 1
+    ///   v = 2            -- redefinition, hand-written non-SSA
+    ///   block outer():
+    ///     w = v + 10
+    ///     block inner():
+    ///       ret w
+    ///     br inner()
+    ///   br outer()
+    #[test]
+    fn redefined_before_nested_sub_blocks() {
+        let res = run_main_block(0, |names, _x| {
+            let (v, w) = (names.vars.fresh("v"), names.vars.fresh("w"));
+            let (outer, inner) = (names.blocks.fresh("outer"), names.blocks.fresh("inner"));
+            let br = |target: &snake::identifiers::BlockName| {
+                BlockBody::Terminator(Terminator::Branch(Branch {
+                    target: target.clone(),
+                    args: Vec::new(),
+                }))
+            };
+            let inner_block = BasicBlock {
+                label: inner.clone(),
+                params: Vec::new(),
+                body: BlockBody::Terminator(Terminator::Return(var(&w))),
+            };
+            let outer_block = BasicBlock {
+                label: outer.clone(),
+                params: Vec::new(),
+                body: op(
+                    &w,
+                    Operation::Prim2(Prim2::Add, var(&v), Immediate::Const(10)),
+                    BlockBody::SubBlocks { blocks: vec![inner_block], next: Box::new(br(&inner)) },
+                ),
+            };
+            op(
+                &v,
+                Operation::Immediate(Immediate::Const(1)),
+                op(
+                    &v,
+                    Operation::Immediate(Immediate::Const(2)),
+                    BlockBody::SubBlocks { blocks: vec![outer_block], next: Box::new(br(&outer)) },
+                ),
             )
+        });
+        assert_eq!(res, Ok("12".to_string()));
+    }
+
+    /// main(x):
+    ///   block loop(i, acc):
+    ///     done = i == 0
+    ///     block exit():
+    ///       ret acc
+    ///     block body():
+    ///       sum = acc + i
+    ///       j = i - 1
+    ///       br loop(j, sum)
+    ///     cbr done exit body
+    ///   br loop(x, 0)
+    #[test]
+    fn loop_branches_back_many_times() {
+        let res = run_main_block(10000, |names, x| {
+            let (i, acc) = (names.vars.fresh("i"), names.vars.fresh("acc"));
+            let (done, sum, j) =
+                (names.vars.fresh("done"), names.vars.fresh("sum"), names.vars.fresh("j"));
+            let lp = names.blocks.fresh("loop");
+            let (exit, body) = (names.blocks.fresh("exit"), names.blocks.fresh("body"));
+            let exit_block = BasicBlock {
+                label: exit.clone(),
+                params: Vec::new(),
+                body: BlockBody::Terminator(Terminator::Return(var(&acc))),
+            };
+            let body_block = BasicBlock {
+                label: body.clone(),
+                params: Vec::new(),
+                body: op(
+                    &sum,
+                    Operation::Prim2(Prim2::Add, var(&acc), var(&i)),
+                    op(
+                        &j,
+                        Operation::Prim2(Prim2::Sub, var(&i), Immediate::Const(1)),
+                        jump(&lp, vec![var(&j), var(&sum)]),
+                    ),
+                ),
+            };
+            let loop_block = BasicBlock {
+                label: lp.clone(),
+                params: vec![i.clone(), acc.clone()],
+                body: op(
+                    &done,
+                    Operation::Prim2(Prim2::Eq, var(&i), Immediate::Const(0)),
+                    BlockBody::SubBlocks {
+                        blocks: vec![exit_block, body_block],
+                        next: Box::new(cbr(&done, &exit, &body)),
+                    },
+                ),
+            };
+            BlockBody::SubBlocks {
+                blocks: vec![loop_block],
+                next: Box::new(jump(&lp, vec![var(&x), Immediate::Const(0)])),
+            }
+        });
+        assert_eq!(res, Ok("50005000".to_string()));
+    }
+
+    /// main(x):
+    ///   block top(i):
+    ///     block probe():
+    ///       ret t          -- t was only defined in the previous iteration
+    ///     block define():
+    ///       t = 5
+    ///       br top(0)
+    ///     cbr i define probe
+    ///   br top(1)
+    #[test]
+    fn branch_back_discards_inner_variables() {
+        let mut names_used = None;
+        let res = run_main_block(0, |names, _x| {
+            let (i, tv) = (names.vars.fresh("i"), names.vars.fresh("t"));
+            let top = names.blocks.fresh("top");
+            let (probe, define) = (names.blocks.fresh("probe"), names.blocks.fresh("define"));
+            names_used = Some((tv.clone(), define.clone()));
+            let probe_block = BasicBlock {
+                label: probe.clone(),
+                params: Vec::new(),
+                body: BlockBody::Terminator(Terminator::Return(var(&tv))),
+            };
+            let define_block = BasicBlock {
+                label: define.clone(),
+                params: Vec::new(),
+                body: op(
+                    &tv,
+                    Operation::Immediate(Immediate::Const(5)),
+                    jump(&top, vec![Immediate::Const(0)]),
+                ),
+            };
+            let top_block = BasicBlock {
+                label: top.clone(),
+                params: vec![i.clone()],
+                body: BlockBody::SubBlocks {
+                    blocks: vec![probe_block, define_block],
+                    next: Box::new(cbr(&i, &define, &probe)),
+                },
+            };
+            BlockBody::SubBlocks {
+                blocks: vec![top_block],
+                next: Box::new(jump(&top, vec![Immediate::Const(1)])),
+            }
+        });
+        let (t, define) = names_used.unwrap();
+        assert_eq!(
+            res,
+            Err(format!("variable {} is out of scope here (defined in block {})", t, define))
+        );
+    }
+
+    /// main(x):
+    ///   ret u              -- u is defined nowhere
+    #[test]
+    fn undefined_variable_is_unbound() {
+        let mut u = None;
+        let res = run_main_block(0, |names, _x| {
+            let uv = names.vars.fresh("u");
+            u = Some(uv.clone());
+            BlockBody::Terminator(Terminator::Return(var(&uv)))
+        });
+        assert_eq!(res, Err(format!("unbound variable: {}", u.unwrap())));
+    }
+}
+
+/* ------------------------- Garbage Collection ------------------------- */
+mod gc {
+    use super::heap::Heap;
+    use super::{field, set_field};
+    use std::cell::Cell;
+
+    #[test]
+    fn loop_allocating_pairs_does_not_run_out() {
+        let mut heap = Heap::new(64);
+        let live = Cell::new(0);
+        unsafe { heap.push_root(live.as_ptr()) };
+        for i in 0..10000 {
+            let _garbage = heap.alloc(2).expect("out of memory");
+            let pair = heap.alloc(2).expect("out of memory");
+            set_field(pair, 0, i << 1);
+            set_field(pair, 1, live.get());
+            // only the newest pair and its predecessor stay reachable
+            if live.get() & 1 == 1 {
+                set_field(live.get(), 1, 0);
+            }
+            live.set(pair);
         }
+        assert_eq!(field(live.get(), 0), 9999 << 1);
+        assert_eq!(field(field(live.get(), 1), 0), 9998 << 1);
+        heap.pop_roots(1);
     }
-    Ok(())
+
+    #[test]
+    fn collection_preserves_sharing() {
+        let mut heap = Heap::new(64);
+        let (a, b, head) = (Cell::new(0), Cell::new(0), Cell::new(0));
+        unsafe {
+            heap.push_root(a.as_ptr());
+            heap.push_root(b.as_ptr());
+            heap.push_root(head.as_ptr());
+        }
+        a.set(heap.alloc(1).unwrap());
+        set_field(a.get(), 0, 1 << 1);
+        b.set(heap.alloc(2).unwrap());
+        set_field(b.get(), 0, 2 << 1);
+        set_field(b.get(), 1, a.get());
+        head.set(heap.alloc(2).unwrap());
+        set_field(head.get(), 0, b.get());
+        set_field(head.get(), 1, a.get());
+        let before = head.get();
+        heap.collect();
+        assert_ne!(head.get(), before);
+        assert_eq!(field(head.get(), 1), a.get());
+        assert_eq!(field(field(head.get(), 0), 1), a.get());
+        assert_eq!(field(field(head.get(), 0), 0), 2 << 1);
+        assert_eq!(field(a.get(), 0), 1 << 1);
+        heap.pop_roots(3);
+    }
+
+    #[test]
+    fn live_objects_that_do_not_fit_run_out() {
+        let mut heap = Heap::new(8);
+        let live = Cell::new(0);
+        unsafe { heap.push_root(live.as_ptr()) };
+        live.set(heap.alloc(4).unwrap());
+        set_field(live.get(), 3, 7 << 1);
+        assert_eq!(heap.alloc(4), None);
+        assert_eq!(field(live.get(), 3), 7 << 1);
+        heap.pop_roots(1);
+    }
+}
+
+/* --------------------------- Reading Input ---------------------------- */
+mod read_int {
+    use super::*;
+    use snake::interp::Value;
+
+    const INPUT: &str = "10\n20\n-5\n";
+
+    fn run_frontend(input: &str) -> Result<String, String> {
+        let (_, ast) =
+            runner::emit_ast(Path::new("examples/sum_input.cobra")).map_err(|e| e.to_string())?;
+        interp::ast::Machine::run_prog_with_input(&ast, Value::Int(3), &mut input.as_bytes())
+            .map(|v| v.to_string())
+            .map_err(|e| e.to_string())
+    }
+
+    fn run_middle_end(input: &'static str) -> Result<String, String> {
+        let (_, ssa) =
+            runner::emit_ssa(Path::new("examples/sum_input.cobra")).map_err(|e| e.to_string())?;
+        interp::ssa::Interp::new()
+            .with_input(input.as_bytes())
+            .run(&ssa, "3".to_string())
+            .map(|v| v.to_string())
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn frontend_sums_input() {
+        assert_eq!(run_frontend(INPUT), Ok("25".to_string()));
+    }
+
+    #[test]
+    fn middle_end_sums_input() {
+        assert_eq!(run_middle_end(INPUT), Ok("25".to_string()));
+    }
+
+    #[test]
+    fn running_out_of_input_is_an_error() {
+        let err = Err("unexpected end of input".to_string());
+        assert_eq!(run_frontend("1\n2\n"), err);
+        assert_eq!(run_middle_end("1\n2\n"), err);
+    }
+
+    #[test]
+    fn exe_sums_input() -> std::io::Result<()> {
+        test_example_with_input("sum_input.cobra", "3", INPUT, "25")
+    }
+
+    #[test]
+    fn externs_are_called_by_name() {
+        let src = "extern snake_error(code)\ndef main(x):\n  snake_error(x + 1)";
+        let (resolver, ast) = snake::compile::frontend(src).unwrap();
+        let res = interp::ast::Machine::run_prog_with_input(
+            &ast,
+            Value::Int(4),
+            &mut INPUT.as_bytes(),
+        );
+        assert!(matches!(res, Err(interp::InterpErr::UserError(5))), "{:?}", res);
+        let (_, ssa) = snake::compile::middle_end(resolver, ast).unwrap();
+        let res = interp::ssa::Interp::new()
+            .with_input(INPUT.as_bytes())
+            .run(&ssa, "4".to_string());
+        assert!(matches!(res, Err(interp::InterpErr::UserError(5))), "{:?}", res);
+    }
+
+    #[test]
+    fn interpreters_parse_the_argument_alike() {
+        let (_, ast) = runner::emit_ast(Path::new("examples/add1.adder")).unwrap();
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/add1.adder")).unwrap();
+        for arg in ["3", " 3", "+3", "3.0", "x"] {
+            let from_ast = interp::ast::Machine::run_prog(&ast, arg.to_string())
+                .map(|v| v.to_string())
+                .map_err(|e| e.to_string());
+            let from_ssa = interp::ssa::Interp::new()
+                .run(&ssa, arg.to_string())
+                .map(|v| v.to_string())
+                .map_err(|e| e.to_string());
+            assert_eq!(from_ast, from_ssa, "on {:?}", arg);
+        }
+    }
+}
+
+/* ---------------------------- User Errors ----------------------------- */
+mod user_error {
+    use super::*;
+
+    const MSG: &str = "program aborted with error code 42";
+
+    #[test]
+    fn frontend() {
+        let (_, ast) = runner::emit_ast(Path::new("examples/error_code.cobra")).unwrap();
+        let err = interp::ast::Machine::run_prog(&ast, "0".to_string()).unwrap_err();
+        assert!(matches!(err, interp::InterpErr::UserError(42)));
+        assert_eq!(err.to_string(), MSG);
+    }
+
+    #[test]
+    fn middle_end() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/error_code.cobra")).unwrap();
+        let err = interp::ssa::Interp::new().run(&ssa, "0".to_string()).unwrap_err();
+        assert!(matches!(err, interp::InterpErr::UserError(42)));
+        let mut tagged = interp::ssa::Interp::new().with_repr(snake::ssa::IntRepr::Tagged);
+        let err = tagged.run_value(&ssa, interp::Value::Int(0)).unwrap_err();
+        assert!(matches!(err, interp::InterpErr::UserError(42)));
+    }
+
+    mk_frontend_test!(no_error, "error_code.cobra", "4", "5");
+    mk_middle_end_test!(no_error_ssa, "error_code.cobra", "4", "5");
+    mk_fail_test!(exe_aborts, "error_code.cobra", MSG);
+
+    /// Nothing runs after `error`, so a block calling it ends there.
+    #[test]
+    fn error_ends_block_unreachable() {
+        use snake::ssa::Terminator;
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/error_every_arm.cobra")).unwrap();
+        let ends: Vec<_> = ssa.blocks_recursive().map(|block| block.body.terminator()).collect();
+        assert_eq!(ends.iter().filter(|t| matches!(t, Terminator::Unreachable)).count(), 2);
+        assert!(!ends.iter().any(|t| matches!(t, Terminator::Return(_))));
+        for (arg, code) in [("-1", 1), ("1", 2)] {
+            let err = interp::ssa::Interp::new().run(&ssa, arg.to_string()).unwrap_err();
+            assert!(matches!(err, interp::InterpErr::UserError(n) if n == code));
+        }
+    }
+
+    #[test]
+    fn reaching_unreachable_is_an_error() {
+        use snake::asm::interp::Machine;
+        use snake::frontend::Resolver;
+        use snake::identifiers::{BlockName, FunName, IdGen, VarName};
+        use snake::middle_end::Lowerer;
+        use snake::ssa::*;
+        let (mut vars, mut blocks) = (IdGen::<VarName>::new(), IdGen::<BlockName>::new());
+        let (arg, main) = (vars.fresh("arg"), blocks.fresh("main"));
+        let ssa = Program {
+            externs: Vec::new(),
+            funs: vec![FunBlock {
+                name: FunName::unmangled("entry"),
+                params: vec![arg.clone()],
+                body: Branch { target: main.clone(), args: Vec::new() },
+            }],
+            blocks: vec![BasicBlock {
+                label: main,
+                params: Vec::new(),
+                body: BlockBody::Terminator(Terminator::Unreachable),
+            }],
+            locs: Default::default(),
+        };
+        assert_eq!(ssa.to_string().lines().last(), Some("  unreachable"));
+        let err = interp::ssa::Interp::new().run(&ssa, "0".to_string()).unwrap_err();
+        assert!(matches!(err, interp::InterpErr::ReachedUnreachable));
+        assert_eq!(err.to_string(), "reached unreachable code");
+        // compiled code stops there too, rather than running on
+        let mut emitter = backend::Emitter::from(Lowerer::from(Resolver::new()));
+        emitter.emit_prog(&ssa);
+        assert!(Machine::new().run(&emitter.to_asm(), 0, &mut Vec::new()).is_err());
+    }
+}
+
+/* ------------------------------- Timeouts ------------------------------- */
+mod timeout {
+    use super::*;
+    use snake::compile::Error;
+    use std::time::{Duration, Instant};
+
+    /// The loop is a tail call, so it runs in constant space until killed.
+    /// It is not among the examples, which other tests run to completion.
+    const LOOP_FOREVER: &str = "def main(x):\n  def spin(n):\n    spin(n + 1)\n  in\n  spin(x)";
+
+    #[test]
+    fn kills_looping_program() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let exe = tmp_dir.path().join("main.exe");
+        let rt = Path::new("runtime/stub.rs");
+        let asm = snake::compile::compile(LOOP_FOREVER).unwrap();
+        runner::link(&asm, rt, tmp_dir.path(), &exe, false).unwrap();
+        let start = Instant::now();
+        let res = runner::run_with_timeout(&exe, "0", Duration::from_millis(500), &mut Vec::new());
+        assert!(matches!(res, Err(Error::Timeout(_))), "{:?}", res);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn kills_hung_executable() {
+        let start = Instant::now();
+        let limit = Duration::from_millis(100);
+        let res = runner::run_with_timeout(Path::new("sleep"), "10", limit, &mut Vec::new());
+        assert!(matches!(res, Err(Error::Timeout(_))), "{:?}", res);
+        assert_eq!(res.unwrap_err().to_string(), "compiled code killed after running for 100ms");
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn keeps_output_of_finished_executable() {
+        let mut out = Vec::new();
+        runner::run_with_timeout(Path::new("echo"), "43", Duration::from_secs(10), &mut out)
+            .unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "43\n");
+    }
+}
+
+/* ---------------------------- Keeping Temps ----------------------------- */
+mod keep_temps {
+    use super::*;
+
+    /// Link `add1.adder` with its intermediates in a fresh directory.
+    /// Whether linking itself succeeds depends on the tools installed, but
+    /// the assembly is written out before any of them run.
+    fn link_add1(keep_temps: bool) -> (tempfile::TempDir, runner::Temps, String) {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let asm = emitted_asm("add1.adder");
+        let exe = tmp_dir.path().join("main.exe");
+        let rt = Path::new("runtime/stub.rs");
+        let _ = runner::link(&asm, rt, tmp_dir.path(), &exe, keep_temps);
+        let temps = runner::Temps::in_dir(tmp_dir.path());
+        (tmp_dir, temps, asm)
+    }
+
+    #[test]
+    fn keeps_assembly() {
+        let (_tmp_dir, temps, asm) = link_add1(true);
+        assert_eq!(std::fs::read_to_string(&temps.asm).unwrap(), asm);
+    }
+
+    #[test]
+    fn removes_temps_by_default() {
+        let (_tmp_dir, temps, _) = link_add1(false);
+        assert!(!temps.asm.exists());
+        assert!(!temps.obj.exists());
+        assert!(!temps.lib.exists());
+    }
+}
+
+/* --------------------------- Constant Pooling --------------------------- */
+mod constant_pool {
+    use super::*;
+    use snake::asm::{Arg64, Instr, MovArgs, Reg};
+
+    /// How often a constant is loaded into rax only to be stored to a slot.
+    fn stored_loads(f: &str, n: i64) -> usize {
+        let (lowerer, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", f))).unwrap();
+        let mut emitter = backend::Emitter::from(lowerer);
+        emitter.emit_prog(&ssa);
+        emitter
+            .to_asm()
+            .windows(2)
+            .filter(|w| match w {
+                [Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Signed(m))), store] => {
+                    *m == n && matches!(store, Instr::Mov(MovArgs::ToMem(..)))
+                }
+                _ => false,
+            })
+            .count()
+    }
+
+    #[test]
+    fn zero_is_loaded_once() {
+        assert_eq!(stored_loads("compare_zero.cobra", 0), 1);
+    }
+
+    mk_middle_end_test!(compare_zero_negative, "compare_zero.cobra", "-3", "-1");
+    mk_middle_end_test!(compare_zero_zero, "compare_zero.cobra", "0", "0");
+    mk_test!(compare_zero_positive, "compare_zero.cobra", "3", "1");
+}
+
+/* ---------------------------- Unused Results ---------------------------- */
+mod unused_results {
+    use super::*;
+    use snake::asm::{BinArgs, Instr, MovArgs, Reg};
+
+    /// For every call in the emitted code, whether its result is stored.
+    fn stores_after_calls(f: &str) -> Vec<bool> {
+        let (lowerer, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", f))).unwrap();
+        let mut emitter = backend::Emitter::from(lowerer);
+        emitter.emit_prog(&ssa);
+        emitter
+            .to_asm()
+            .windows(3)
+            .filter_map(|w| match w {
+                [Instr::Call(_), Instr::Add(BinArgs::ToReg(Reg::Rsp, _)), next]
+                | [Instr::Call(_), next, _] => {
+                    Some(matches!(next, Instr::Mov(MovArgs::ToMem(..))))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn print_in_statement_position() {
+        assert_eq!(stores_after_calls("print.cobra"), vec![false; 4]);
+    }
+
+    #[test]
+    fn used_result_is_stored() {
+        assert_eq!(stores_after_calls("basic_print.cobra"), vec![true]);
+    }
+}
+
+/* ----------------------------- Stable Names ----------------------------- */
+mod stable_names {
+    use super::*;
+    use snake::middle_end::Lowerer;
+    use snake::ssa::StableNames;
+
+    /// Lower an example, first generating `skip` unused names of every kind.
+    pub(super) fn lower_skipping(f: &str, skip: usize) -> snake::ssa::Program {
+        let (resolver, ast) = runner::emit_ast(Path::new(&format!("examples/{}", f))).unwrap();
+        let mut lowerer = Lowerer::from(resolver);
+        for _ in 0..skip {
+            lowerer.vars.fresh("unused");
+            lowerer.funs.fresh("unused");
+            lowerer.blocks.fresh("unused");
+        }
+        lowerer.lower_prog(ast)
+    }
+
+    #[test]
+    fn fresh_counts_do_not_change_stable_names() {
+        let fst = lower_skipping("tail_and_normal.cobra", 0);
+        let snd = lower_skipping("tail_and_normal.cobra", 7);
+        assert_ne!(fst.to_string(), snd.to_string());
+        assert_eq!(
+            fst.map_names(&mut StableNames::default()).to_string(),
+            snd.map_names(&mut StableNames::default()).to_string()
+        );
+    }
+
+    #[test]
+    fn stable_names_keep_unmangled_funs() {
+        let ssa = lower_skipping("print.cobra", 3).map_names(&mut StableNames::default());
+        assert!(ssa.externs.iter().all(|ext| ext.name.is_unmangled()));
+    }
+}
+
+/* ------------------------------- Slot Maps ------------------------------ */
+mod slot_maps {
+    use super::*;
+    use snake::asm::interp::Machine;
+    use snake::ssa::{Program, StableNames};
+
+    fn emulate(ssa: &Program, x: i64) -> i64 {
+        let mut emitter = backend::Emitter::default();
+        emitter.emit_prog(ssa);
+        Machine::new().run(&emitter.to_asm(), x, &mut Vec::new()).unwrap()
+    }
+
+    /// Stable names share indices between hints, so the backend cannot
+    /// tell variables apart by index alone.
+    #[test]
+    fn names_sharing_an_index_get_their_own_slots() {
+        for (file, x) in [("local_non_tail_call.cobra", 10), ("tail_and_normal.cobra", 5)] {
+            let ssa = stable_names::lower_skipping(file, 0);
+            let renamed = ssa.map_names(&mut StableNames::default());
+            assert_eq!(emulate(&renamed, x), emulate(&ssa, x), "{}", file);
+        }
+    }
+
+    /// A name far past the others is looked up by hashing, rather than
+    /// growing the vector of slots out to its index.
+    #[test]
+    fn name_with_a_huge_index() {
+        use snake::identifiers::{IdGen, VarName};
+        use snake::ssa::{BlockBody, Immediate, Operation, Prim2, Terminator};
+        let ssa = main_block_program(|_, x| {
+            let far: VarName = IdGen::starting_at(1 << 40).fresh("far");
+            BlockBody::Operation {
+                dest: far.clone(),
+                op: Operation::Prim2(Prim2::Add, Immediate::Var(x), Immediate::Const(1)),
+                next: Box::new(BlockBody::Terminator(Terminator::Return(Immediate::Var(far)))),
+            }
+        });
+        assert_eq!(emulate(&ssa, 41), 42);
+    }
+
+    /// Every pass recurses along the operations of a block, so a block
+    /// this long needs more stack than a test thread gets.
+    #[test]
+    fn thousands_of_variables() {
+        const N: usize = 5000;
+        let bindings: Vec<_> = (1..=N).map(|i| format!("x{} = x{} + 1", i, i - 1)).collect();
+        let src = format!("def main(x0):\n  let {} in\n  x{}", bindings.join(",\n      "), N);
+        let run = move || {
+            let (resolver, ast) = snake::compile::frontend(&src).unwrap();
+            let (_, ssa) = snake::compile::middle_end(resolver, ast).unwrap();
+            emulate(&ssa, 7)
+        };
+        let thread = std::thread::Builder::new().stack_size(1 << 28).spawn(run).unwrap();
+        assert_eq!(thread.join().unwrap(), 7 + N as i64);
+    }
+}
+
+/* ------------------------------ Renumbering ----------------------------- */
+mod renumber {
+    use super::stable_names::lower_skipping;
+
+    #[test]
+    fn renumbering_is_idempotent() {
+        let ssa = lower_skipping("non_tail_factorial.cobra", 5).renumber();
+        assert_eq!(ssa.renumber(), ssa);
+    }
+
+    #[test]
+    fn alpha_equivalent_programs_renumber_identically() {
+        let fst = lower_skipping("tail_and_normal.cobra", 0);
+        let snd = lower_skipping("tail_and_normal.cobra", 11);
+        assert_ne!(fst, snd);
+        assert_eq!(fst.renumber(), snd.renumber());
+    }
+
+    #[test]
+    fn renumbering_preserves_behavior() {
+        let ssa = lower_skipping("recursive_main_factorial.cobra", 5);
+        let run = |p: &snake::ssa::Program| {
+            let res = snake::interp::ssa::Interp::new().run(p, "5".to_string());
+            res.map(|v| v.to_string()).map_err(|e| e.to_string())
+        };
+        assert_eq!(run(&ssa.renumber()), run(&ssa));
+    }
+}
+
+/* --------------------------- Alpha-Equivalence -------------------------- */
+mod alpha_eq {
+    use super::stable_names::lower_skipping;
+    use snake::ssa::{alpha_eq, BlockBody, Immediate, Operation};
+
+    /// Add one to the first constant operation in `body`, if there is one.
+    fn bump_first_const(body: &mut BlockBody) -> bool {
+        match body {
+            BlockBody::Terminator(_) => false,
+            BlockBody::Operation { op: Operation::Immediate(Immediate::Const(n)), .. } => {
+                *n += 1;
+                true
+            }
+            BlockBody::Operation { next, .. } => bump_first_const(next),
+            BlockBody::SubBlocks { blocks, next } => {
+                blocks.iter_mut().any(|b| bump_first_const(&mut b.body))
+                    || bump_first_const(next)
+            }
+        }
+    }
+
+    #[test]
+    fn program_equals_its_renumbering() {
+        let ssa = lower_skipping("tail_and_normal.cobra", 4);
+        assert!(alpha_eq(&ssa, &ssa.renumber()));
+    }
+
+    #[test]
+    fn separate_lowerings_are_alpha_equivalent() {
+        let fst = lower_skipping("non_tail_factorial.cobra", 0);
+        let snd = lower_skipping("non_tail_factorial.cobra", 9);
+        assert_ne!(fst, snd);
+        assert!(alpha_eq(&fst, &snd));
+    }
+
+    #[test]
+    fn changing_an_operation_breaks_alpha_equivalence() {
+        let ssa = lower_skipping("non_tail_factorial.cobra", 0);
+        let mut changed = ssa.clone();
+        assert!(changed.blocks.iter_mut().any(|b| bump_first_const(&mut b.body)));
+        assert!(!alpha_eq(&ssa, &changed));
+    }
+}
+
+/* ---------------------------- Constant Folding --------------------------- */
+mod fold {
+    use snake::ast::{spans, Expr, SrcLoc};
+    use snake::fold::fold_prog;
+    use snake::frontend::Resolver;
+    use snake::parser::ProgParser;
+
+    #[test]
+    fn folded_constant_covers_original_expression() {
+        let src = "def main(x): add1(add1(5))";
+        let prog = ProgParser::new().parse(src).unwrap();
+        let prog = fold_prog(Resolver::new().resolve_prog(prog).unwrap());
+        let start = src.find("add1").unwrap();
+        match prog.body {
+            Expr::Num(7, loc) => assert_eq!(loc, SrcLoc::new(start, src.len())),
+            body => panic!("expected 7, got {}", body),
+        }
+    }
+
+    #[test]
+    fn folded_spans_stay_within_source() {
+        let src = "def main(x): let y = 2 * (3 + 4) in if y < 10: x else: !(true && false)";
+        let prog = fold_prog(ProgParser::new().parse(src).unwrap());
+        for (kind, loc) in spans(&prog) {
+            assert!(loc.start_ix <= loc.end_ix && loc.end_ix <= src.len(), "{:?}", kind);
+        }
+        assert_eq!(prog.body.to_string(), "let y = 14 in if (y < 10): x else: true");
+    }
+
+    #[test]
+    fn overflow_is_not_folded() {
+        let src = "def main(x): 9223372036854775807 + 1";
+        let prog = fold_prog(ProgParser::new().parse(src).unwrap());
+        assert!(matches!(prog.body, Expr::Prim { .. }));
+    }
+}
+
+/* ---------------------------- Block Iterators --------------------------- */
+mod blocks_recursive {
+    use super::*;
+
+    /// The labels of the blocks in the printed program, in order.
+    fn printed_labels(ssa: &snake::ssa::Program) -> Vec<String> {
+        ssa.to_string()
+            .lines()
+            .filter_map(|line| line.trim_start().strip_prefix("block "))
+            .map(|block| block.split('(').next().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn visits_nested_blocks() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/pow.cobra")).unwrap();
+        let labels: Vec<_> = ssa.blocks_recursive().map(|b| b.label.to_string()).collect();
+        assert_eq!(labels, printed_labels(&ssa));
+        assert!(labels.iter().any(|l| l.starts_with("thn")));
+        assert!(labels.iter().any(|l| l.starts_with("els")));
+    }
+
+    #[test]
+    fn mutable_blocks_split_straightline_code() {
+        let (_, mut ssa) = runner::emit_ssa(Path::new("examples/pow.cobra")).unwrap();
+        let printed = ssa.to_string();
+        let labels = printed_labels(&ssa);
+        let mut ops = 0;
+        for (block, label) in ssa.blocks_recursive_mut().zip(&labels) {
+            assert_eq!(&block.label.to_string(), label);
+            ops += block.ops.len();
+        }
+        assert_eq!(ops, printed.lines().filter(|l| l.contains(" = ")).count());
+    }
+}
+
+/* ------------------------------ Copy Shortcut ---------------------------- */
+mod copy_shortcut {
+    use super::*;
+    use snake::compile;
+    use snake::interp::ssa::Interp;
+
+    /// The result of running `src` on `arg` in the SSA interpreter, and the
+    /// number of steps it took.
+    fn run_counting(src: &str, arg: &str) -> (String, usize) {
+        let (resolver, ast) = compile::frontend(src).unwrap();
+        let (_, ssa) = compile::middle_end(resolver, ast).unwrap();
+        let mut interp = Interp::new();
+        let res = interp.run(&ssa, arg.to_string()).unwrap();
+        (res.to_string(), interp.steps())
+    }
+
+    #[test]
+    fn copies_take_no_steps() {
+        let short = run_counting("def main(x): add1(x)", "4");
+        let long = run_counting("def main(x): let a = x, b = a, c = b in add1(c)", "4");
+        assert_eq!(short.0, "5");
+        assert_eq!(long, short);
+    }
+
+    mk_middle_end_test!(factorial_unchanged, "recursive_main_factorial.cobra", "5", "120");
+    mk_middle_end_test!(tail_recursion_unchanged, "tail_recursive_main.cobra", "5", "5");
+}
+
+/* ------------------------------ x86 Emulator ----------------------------- */
+mod emulate {
+    use super::*;
+    use snake::asm::interp::Machine;
+    use snake::frontend::Resolver;
+    use snake::interp::ssa::Interp;
+    use snake::middle_end::Lowerer;
+    use snake::ssa::{IntRepr, Program};
+
+    const INPUT: &str = "4\n5\n6\n7\n";
+
+    fn emulate(ssa: &Program, repr: IntRepr, arg: i64) -> (Result<i64, String>, String) {
+        let mut emitter = backend::Emitter::from(Lowerer::from(Resolver::new())).with_repr(repr);
+        emitter.emit_prog(ssa);
+        let mut out = Vec::new();
+        let mut machine = Machine::new()
+            .with_input(std::io::Cursor::new(INPUT))
+            .with_extern("big_fun_nine", 9, big_fun)
+            .with_extern("big_fun_ten", 10, big_fun);
+        let res = machine.run(&emitter.to_asm(), arg, &mut out).map_err(|e| e.to_string());
+        (res, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn agrees_with_ssa_interp_on_examples() {
+        let mut checked = 0;
+        for entry in std::fs::read_dir("examples").unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_none_or(|ext| ext != "cobra") {
+                continue;
+            }
+            let Ok((_, ssa)) = runner::emit_ssa(&path) else { continue };
+            for repr in [IntRepr::Untagged, IntRepr::Tagged] {
+                for arg in [0, 3, 5] {
+                    let expected = Interp::new()
+                        .with_repr(repr)
+                        .with_input(std::io::Cursor::new(INPUT))
+                        .with_output(std::io::sink())
+                        .with_extern("big_fun_nine", 9, big_fun)
+                        .with_extern("big_fun_ten", 10, big_fun)
+                        .run(&ssa, arg.to_string())
+                        .map(|v| v.to_string().parse().unwrap())
+                        .map_err(|e| e.to_string());
+                    let (got, _) = emulate(&ssa, repr, arg);
+                    assert_eq!(got, expected, "{} on {} ({:?})", path.display(), arg, repr);
+                }
+            }
+            checked += 1;
+        }
+        assert!(checked >= 5, "only {} examples checked", checked);
+    }
+
+    #[test]
+    fn prints_like_the_runtime() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/extern_big_nine.cobra")).unwrap();
+        let (res, out) = emulate(&ssa, IntRepr::Tagged, 0);
+        let lines: Vec<_> = (1..=9).map(|i| format!("x{}: -{}\n", i, i)).collect();
+        assert_eq!(out, lines.concat());
+        assert_eq!(res, Ok(-46));
+    }
+
+    #[test]
+    fn catches_misaligned_calls() {
+        use snake::asm::{Arg32, BinArgs, Instr, Reg};
+        let instrs = vec![
+            Instr::Label("entry".to_string()),
+            Instr::Call("print".to_string()),
+            Instr::Ret,
+        ];
+        let res = Machine::new().run(&instrs, 1, &mut Vec::new());
+        assert_eq!(res.unwrap_err().to_string(), "call to print with a misaligned stack");
+        let mut aligned = instrs.clone();
+        aligned.insert(1, Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(8))));
+        aligned.insert(3, Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(8))));
+        assert_eq!(Machine::new().run(&aligned, 1, &mut Vec::new()), Ok(1));
+    }
+}
+
+/* ---------------------------- Non-tail Calls ---------------------------- */
+mod non_tail_calls {
+    use super::*;
+    use snake::asm::interp::Machine;
+    use snake::compile;
+    use snake::frontend::Resolver;
+    use snake::identifiers::{BlockName, FunName, IdGen, VarName};
+    use snake::interp::ssa::Interp;
+    use snake::middle_end::Lowerer;
+    use snake::ssa::{
+        BasicBlock, BlockBody, Branch, FunBlock, Immediate, Operation, Prim2, Program, Terminator,
+    };
+
+    /// Run `ssa` on `arg` with the x86 emulator.
+    fn emulate(lowerer: Lowerer, ssa: &Program, arg: i64) -> Result<i64, String> {
+        let mut emitter = backend::Emitter::from(lowerer);
+        emitter.emit_prog(ssa);
+        Machine::new().run(&emitter.to_asm(), arg, &mut Vec::new()).map_err(|e| e.to_string())
+    }
+
+    /// The call names the function lifted out of `main`, which both the
+    /// SSA interpreter and compiled code can find.
+    #[test]
+    fn calls_the_lifted_function() {
+        let src = "def main(x):\n  def f(y): y + 1 in\n  2 * f(x)";
+        let (resolver, ast) = compile::frontend(src).unwrap();
+        let (lowerer, ssa) = compile::middle_end(resolver, ast).unwrap();
+        assert_eq!(Interp::new().run(&ssa, "4".to_string()).unwrap().to_string(), "10");
+        assert_eq!(emulate(lowerer, &ssa, 4), Ok(10));
+    }
+
+    /// `entry(x)` calls a function of seven parameters, the last of them
+    /// passed on the stack, which puts its arguments together as the
+    /// digits of a number, so each must be stored where it is read.
+    #[test]
+    fn stores_every_argument() {
+        let (mut vars, mut blocks) = (IdGen::<VarName>::new(), IdGen::<BlockName>::new());
+        let digits = IdGen::<FunName>::new().fresh("digits");
+        let (x, y, result) = (vars.fresh("x"), vars.fresh("y"), vars.fresh("result"));
+        let params: Vec<_> = (0..7).map(|_| vars.fresh("p")).collect();
+        let block_params: Vec<_> = (0..7).map(|_| vars.fresh("d")).collect();
+        let (main, digits_body) = (blocks.fresh("main"), blocks.fresh("digits"));
+
+        // acc = d0, then acc = acc * 10 + d for each further parameter
+        let mut ops = Vec::new();
+        let mut acc = Immediate::Var(block_params[0].clone());
+        for d in &block_params[1..] {
+            let (shifted, sum) = (vars.fresh("shifted"), vars.fresh("acc"));
+            ops.push((shifted.clone(), Operation::Prim2(Prim2::Mul, acc, Immediate::Const(10))));
+            let (shifted, d) = (Immediate::Var(shifted), Immediate::Var(d.clone()));
+            ops.push((sum.clone(), Operation::Prim2(Prim2::Add, shifted, d)));
+            acc = Immediate::Var(sum);
+        }
+        let body = ops.into_iter().rev().fold(
+            BlockBody::Terminator(Terminator::Return(acc)),
+            |next, (dest, op)| BlockBody::Operation { dest, op, next: Box::new(next) },
+        );
+
+        let args = std::iter::once(Immediate::Var(y.clone()))
+            .chain((2..=7).map(Immediate::Const))
+            .collect();
+        let ssa = Program {
+            externs: Vec::new(),
+            funs: vec![
+                FunBlock {
+                    name: FunName::unmangled("entry"),
+                    params: vec![x.clone()],
+                    body: Branch { target: main.clone(), args: vec![Immediate::Var(x)] },
+                },
+                FunBlock {
+                    name: digits.clone(),
+                    params: params.clone(),
+                    body: Branch {
+                        target: digits_body.clone(),
+                        args: params.into_iter().map(Immediate::Var).collect(),
+                    },
+                },
+            ],
+            blocks: vec![
+                BasicBlock {
+                    label: main,
+                    params: vec![y],
+                    body: BlockBody::Operation {
+                        dest: result.clone(),
+                        op: Operation::Call { fun: digits, args },
+                        next: Box::new(BlockBody::Terminator(Terminator::Return(
+                            Immediate::Var(result),
+                        ))),
+                    },
+                },
+                BasicBlock { label: digits_body, params: block_params, body },
+            ],
+            locs: Default::default(),
+        };
+        assert_eq!(emulate(Lowerer::from(Resolver::new()), &ssa, 1), Ok(1234567));
+    }
+
+    /// A block `swap(a, b, n)` that branches back to itself with `b, a`
+    /// until `n` is 0, when it returns `10 * a + b`. Each argument is read
+    /// from the slot of a parameter that another is stored to.
+    #[test]
+    fn swaps_arguments_in_place() {
+        let (mut vars, mut blocks) = (IdGen::<VarName>::new(), IdGen::<BlockName>::new());
+        let [x, y, a, b, n, cond, tens, sum, m] =
+            ["x", "y", "a", "b", "n", "cond", "tens", "sum", "m"].map(|hint| vars.fresh(hint));
+        let [main, swap, done, again] =
+            ["main", "swap", "done", "again"].map(|hint| blocks.fresh(hint));
+        let var = |v: &VarName| Immediate::Var(v.clone());
+        let op = |dest: &VarName, op, next| BlockBody::Operation {
+            dest: dest.clone(),
+            op,
+            next: Box::new(next),
+        };
+        let ret = |v: &VarName| BlockBody::Terminator(Terminator::Return(var(v)));
+        let block =
+            |label: &BlockName, body| BasicBlock { label: label.clone(), params: Vec::new(), body };
+        let done_body = op(
+            &tens,
+            Operation::Prim2(Prim2::Mul, var(&a), Immediate::Const(10)),
+            op(&sum, Operation::Prim2(Prim2::Add, var(&tens), var(&b)), ret(&sum)),
+        );
+        let again_body = op(
+            &m,
+            Operation::Prim2(Prim2::Sub, var(&n), Immediate::Const(1)),
+            BlockBody::Terminator(Terminator::Branch(Branch {
+                target: swap.clone(),
+                args: vec![var(&b), var(&a), var(&m)],
+            })),
+        );
+        let ssa = Program {
+            externs: Vec::new(),
+            funs: vec![FunBlock {
+                name: FunName::unmangled("entry"),
+                params: vec![x.clone()],
+                body: Branch { target: main.clone(), args: vec![var(&x)] },
+            }],
+            blocks: vec![
+                BasicBlock {
+                    label: main,
+                    params: vec![y.clone()],
+                    body: BlockBody::Terminator(Terminator::Branch(Branch {
+                        target: swap.clone(),
+                        args: vec![Immediate::Const(1), Immediate::Const(2), var(&y)],
+                    })),
+                },
+                BasicBlock {
+                    label: swap,
+                    params: vec![a.clone(), b.clone(), n.clone()],
+                    body: BlockBody::SubBlocks {
+                        blocks: vec![block(&done, done_body), block(&again, again_body)],
+                        next: Box::new(op(
+                            &cond,
+                            Operation::Prim2(Prim2::Eq, var(&n), Immediate::Const(0)),
+                            BlockBody::Terminator(Terminator::ConditionalBranch {
+                                cond: var(&cond),
+                                thn: done,
+                                els: again,
+                            }),
+                        )),
+                    },
+                },
+            ],
+            locs: Default::default(),
+        };
+        let run = |arg| emulate(Lowerer::from(Resolver::new()), &ssa, arg);
+        assert_eq!(run(1), Ok(21));
+        assert_eq!(run(2), Ok(12));
+    }
+}
+
+/* ------------------------------ Jump Tables ----------------------------- */
+mod jump_tables {
+    use super::*;
+    use snake::asm::interp::Machine;
+    use snake::asm::{ConditionCode, Instr};
+    use snake::frontend::Resolver;
+    use snake::identifiers::{BlockName, FunName, IdGen, VarName};
+    use snake::middle_end::Lowerer;
+    use snake::ssa::*;
+
+    /// main(x):
+    ///   block arm_0(): ret 10
+    ///   block arm_1(): ret 20
+    ///   block arm_2(): ret 30
+    ///   block other(): ret -1
+    ///   switch x [arm_0, arm_1, arm_2] other
+    fn dispatch() -> Program {
+        let (mut vars, mut blocks) = (IdGen::<VarName>::new(), IdGen::<BlockName>::new());
+        let (arg, x) = (vars.fresh("arg"), vars.fresh("x"));
+        let main = blocks.fresh("main");
+        let ret = |label, n| BasicBlock {
+            label,
+            params: Vec::new(),
+            body: BlockBody::Terminator(Terminator::Return(Immediate::Const(n))),
+        };
+        let arms: Vec<_> = (0..3).map(|_| blocks.fresh("arm")).collect();
+        let other = blocks.fresh("other");
+        let mut sub_blocks: Vec<_> =
+            arms.iter().zip([10, 20, 30]).map(|(arm, n)| ret(arm.clone(), n)).collect();
+        sub_blocks.push(ret(other.clone(), -1));
+        let body = BlockBody::SubBlocks {
+            blocks: sub_blocks,
+            next: Box::new(BlockBody::Terminator(Terminator::Switch {
+                scrutinee: Immediate::Var(x.clone()),
+                targets: arms,
+                default: other,
+            })),
+        };
+        Program {
+            externs: Vec::new(),
+            funs: vec![FunBlock {
+                name: FunName::unmangled("entry"),
+                params: vec![arg.clone()],
+                body: Branch { target: main.clone(), args: vec![Immediate::Var(arg)] },
+            }],
+            blocks: vec![BasicBlock { label: main, params: vec![x], body }],
+            locs: Default::default(),
+        }
+    }
+
+    fn emit(prog: &Program, repr: IntRepr) -> Vec<Instr> {
+        let mut emitter = backend::Emitter::from(Lowerer::from(Resolver::new())).with_repr(repr);
+        emitter.emit_prog(prog);
+        emitter.to_asm()
+    }
+
+    #[test]
+    fn dispatch_lowers_to_jump_table() {
+        let asm = emit(&dispatch(), IntRepr::Untagged);
+        let tables: Vec<_> = asm
+            .iter()
+            .filter_map(|i| match i {
+                Instr::LabelTable(targets) => Some(targets.len()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(tables, vec![3]);
+        let jump = asm.iter().position(|i| matches!(i, Instr::JmpIndexed { .. })).unwrap();
+        assert!(matches!(asm[jump - 2], Instr::JCC(ConditionCode::AE, _)), "no bounds check");
+        assert!(!asm.iter().any(|i| matches!(i, Instr::JCC(ConditionCode::E, _))));
+    }
+
+    #[test]
+    fn dispatch_picks_the_right_arm() {
+        let prog = dispatch();
+        for repr in [IntRepr::Untagged, IntRepr::Tagged] {
+            let asm = emit(&prog, repr);
+            for (x, expected) in [(-1, -1), (0, 10), (1, 20), (2, 30), (3, -1), (i64::MIN >> 1, -1)]
+            {
+                let interp = interp::ssa::Interp::new().with_repr(repr).run(&prog, x.to_string());
+                assert_eq!(interp.unwrap().to_string(), expected.to_string());
+                let machine = Machine::new().run(&asm, x, &mut Vec::new());
+                assert_eq!(machine, Ok(expected), "{} ({:?})", x, repr);
+            }
+        }
+    }
+}
+
+/* ------------------------------- Let Kinds ------------------------------ */
+mod let_kinds {
+    use snake::compile;
+    use snake::interp::{ast::Machine, ssa::Interp};
+    use snake::parser::ProgParser;
+
+    /// The result of running `src` on `arg` in the AST interpreter before
+    /// and after resolution and in the SSA interpreter, which must agree.
+    fn run_all(src: &str, arg: i64) -> Result<String, String> {
+        let raw = ProgParser::new().parse(src).unwrap();
+        let raw_res = Machine::run_prog(&raw, arg.to_string()).map(|v| v.to_string());
+        let (resolver, ast) = compile::frontend(src).map_err(|e| e.to_string())?;
+        let resolved = Machine::run_prog(&ast, arg.to_string()).map(|v| v.to_string());
+        let (_, ssa) = compile::middle_end(resolver, ast).map_err(|e| e.to_string())?;
+        let ssa_res = Interp::new().run(&ssa, arg.to_string()).map(|v| v.to_string());
+        assert_eq!(raw_res.as_ref().ok(), resolved.as_ref().ok());
+        assert_eq!(resolved.as_ref().ok(), ssa_res.as_ref().ok());
+        resolved.map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn sequential_bindings_see_earlier_ones() {
+        assert_eq!(run_all("def main(x): let x = 1, y = x in y", 5), Ok("1".to_string()));
+    }
+
+    #[test]
+    fn parallel_bindings_see_outer_scope() {
+        assert_eq!(run_all("def main(x): let x = 1 and y = x in y", 5), Ok("5".to_string()));
+        assert_eq!(
+            run_all("def main(x): let x = 1 and y = add1(x) in x + y", 5),
+            Ok("7".to_string())
+        );
+    }
+
+    #[test]
+    fn parallel_bindings_do_not_see_each_other() {
+        let res = run_all("def main(z): let x = 1 and y = x in y", 5);
+        assert!(res.unwrap_err().contains("x"), "expected x to be unbound");
+    }
+
+    #[test]
+    fn parallel_let_prints_with_and() {
+        let prog = ProgParser::new().parse("def main(x): let a = 1 and b = x in b").unwrap();
+        assert_eq!(prog.to_string(), "def main(x): let a = 1 and b = x in b");
+    }
+}
+
+/* --------------------------------- Letrec -------------------------------- */
+mod letrec {
+    use snake::frontend::{CompileErr, Resolver};
+    use snake::interp::InterpErr;
+    use snake::lexer::tokenize;
+    use snake::parser::ProgParser;
+    use snake::txt::FileInfo;
+
+    const EVEN_ODD: &str = "def main(x):\n  letrec even = odd, odd = even in even";
+
+    #[test]
+    fn letrec_is_one_keyword() {
+        let toks: Vec<_> =
+            tokenize("letrec letrecs in").unwrap().into_iter().map(|(_, t)| t).collect();
+        assert_eq!(toks, vec!["letrec", "letrecs", "in"]);
+    }
+
+    #[test]
+    fn letrec_prints_as_written() {
+        let prog = ProgParser::new().parse(EVEN_ODD).unwrap();
+        assert_eq!(prog.to_string(), "def main(x): letrec even = odd, odd = even in even");
+    }
+
+    /// There are no function values yet, so no binding can be recursive.
+    #[test]
+    fn non_function_binding_is_rejected() {
+        let prog = ProgParser::new().parse(EVEN_ODD).unwrap();
+        let err = Resolver::new().resolve_prog(prog).unwrap_err();
+        assert!(matches!(&err, CompileErr::NonFunctionRecursiveBinding(v, _) if v == "even"));
+        assert_eq!(
+            FileInfo::new(EVEN_ODD).diagnostic(err).message,
+            r#"letrec binding "even" must be a function value"#
+        );
+    }
+
+    #[test]
+    fn interpreter_names_the_binding() {
+        let prog = ProgParser::new().parse(EVEN_ODD).unwrap();
+        let err = snake::interp::ast::Machine::run_prog(&prog, "0".to_string()).unwrap_err();
+        assert!(matches!(&err, InterpErr::RecursiveBinding(v) if v == "even"), "{}", err);
+        assert_eq!(err.to_string(), "unimplemented: recursive binding of even");
+    }
+}
+
+/* -------------------------------- Explain -------------------------------- */
+mod explain {
+    use snake::compile::{explain, Error, Pipeline};
+    use snake::ssa::IntRepr;
+
+    #[test]
+    fn counts_nested_lifted_functions() {
+        let src = "def main(x):\n  def f(y):\n    def g(z): z + y in\n    g(x)\n  \
+                   and def h(w): w\n  in\n  h(f(x))";
+        let out = explain(src).unwrap();
+        assert!(out.contains("lifted 3 local functions"), "{}", out);
+        assert!(out.contains("gave 4 variables unique names"), "{}", out);
+    }
+
+    #[test]
+    fn no_local_functions() {
+        let out = explain("def main(x): let y = x in y").unwrap();
+        assert!(out.contains("lifted 0 local functions"), "{}", out);
+    }
+
+    #[test]
+    fn follows_the_pipeline() {
+        let src = "def main(x): 4611686018427387904";
+        assert!(explain(src).is_ok());
+        let tagged = Pipeline::new().with_repr(IntRepr::Tagged);
+        assert!(matches!(tagged.explain(src), Err(Error::OutOfRange(4611686018427387904))));
+    }
+}
+
+/* ------------------------------ Stack Frames ---------------------------- */
+mod stack_frames {
+    use snake::asm::interp::Machine;
+    use snake::asm::{Arg32, Arg64, BinArgs, Instr, MemRef, MovArgs, Reg};
+    use snake::backend::Emitter;
+    use snake::compile;
+
+    fn emit(src: &str) -> Vec<Instr> {
+        let (resolver, ast) = compile::frontend(src).unwrap();
+        let (lowerer, ssa) = compile::middle_end(resolver, ast).unwrap();
+        let mut emitter = Emitter::from(lowerer);
+        emitter.emit_prog(&ssa);
+        emitter.to_asm()
+    }
+
+    #[test]
+    fn locals_survive_calls() {
+        // the emulated print overwrites the stack below rsp
+        let src = "extern print(x)\n\
+                   def main(x):\n  let y = x * 3, p = print(y), z = add1(y) in y + z + p";
+        let mut out = Vec::new();
+        let res = Machine::new().run(&emit(src), 5, &mut out);
+        assert_eq!(String::from_utf8(out).unwrap(), "15\n");
+        assert_eq!(res, Ok(46));
+    }
+
+    #[test]
+    fn nothing_is_addressed_below_rsp() {
+        let below_rsp = |mem: &MemRef| mem.reg == Reg::Rsp && mem.offset < 0;
+        let src = "extern print(x)\ndef main(x): let y = print(x) in y + x";
+        for instr in emit(src) {
+            let bad = match &instr {
+                Instr::Mov(MovArgs::ToReg(_, Arg64::Mem(mem)))
+                | Instr::Mov(MovArgs::ToMem(mem, _))
+                | Instr::Add(BinArgs::ToReg(_, Arg32::Mem(mem)))
+                | Instr::Sub(BinArgs::ToReg(_, Arg32::Mem(mem)))
+                | Instr::Cmp(BinArgs::ToReg(_, Arg32::Mem(mem))) => below_rsp(mem),
+                _ => false,
+            };
+            assert!(!bad, "{:?} addresses memory below rsp", instr);
+        }
+    }
+}
+
+/* ------------------------------ Opt Report ------------------------------ */
+mod opt_report {
+    use snake::compile;
+    use snake::dce::eliminate_dead_code_with_stats;
+    use snake::fold::fold_prog_with_stats;
+    use snake::interp::ssa::Interp;
+
+    const CONSTS: &str = "def main(x):\n  let a = 1 + 2, b = a * 4, c = 2 * 3 + 1 in\n  x + c";
+
+    #[test]
+    fn fold_and_dce_report_what_they_did() {
+        let (resolver, ast) = compile::frontend(CONSTS).unwrap();
+        let (ast, fold) = fold_prog_with_stats(ast);
+        assert_eq!(fold.folded, 3);
+        let (_, ssa) = compile::middle_end(resolver, ast).unwrap();
+        let before = Interp::new().run(&ssa, "5".to_string()).unwrap().to_string();
+        let (ssa, dce) = eliminate_dead_code_with_stats(ssa);
+        // a and b are never read, nor is anything computed for them
+        assert!(dce.removed > 0, "{:?}", dce);
+        assert_eq!(Interp::new().run(&ssa, "5".to_string()).unwrap().to_string(), before);
+        assert_eq!(before, "12");
+    }
+
+    #[test]
+    fn calls_are_kept() {
+        let src = "extern print(x)\ndef main(x): let p = print(x) in x";
+        let (resolver, ast) = compile::frontend(src).unwrap();
+        let (_, ssa) = compile::middle_end(resolver, ast).unwrap();
+        let (ssa, _) = eliminate_dead_code_with_stats(ssa);
+        assert!(ssa.to_string().contains("print("), "{}", ssa);
+        let (_, again) = eliminate_dead_code_with_stats(ssa);
+        assert_eq!(again.removed, 0);
+    }
+}
+
+/* ------------------------------ Span Merging ---------------------------- */
+mod span_merge {
+    use snake::ast::{Binding, Expr};
+    use snake::parser::ProgParser;
+    use snake::span::{Span2, SrcLoc};
+    use snake::txt::FileInfo;
+
+    const SRC: &str = "def main(x):\n  let a = 1,\n      b = 2 in a";
+
+    #[test]
+    fn adjacent_spans() {
+        assert_eq!(SrcLoc::new(2, 5).merge(SrcLoc::new(5, 9)), SrcLoc::new(2, 9));
+        assert_eq!(SrcLoc::new(5, 9).merge(SrcLoc::new(2, 5)), SrcLoc::new(2, 9));
+    }
+
+    #[test]
+    fn disjoint_spans_cover_the_gap() {
+        assert_eq!(SrcLoc::new(20, 24).merge(SrcLoc::new(3, 7)), SrcLoc::new(3, 24));
+        assert_eq!(SrcLoc::new(3, 10).merge(SrcLoc::new(4, 6)), SrcLoc::new(3, 10));
+    }
+
+    #[test]
+    fn merged_bindings_render_across_lines() {
+        let info = FileInfo::new(SRC);
+        let at = |s: &str| {
+            let ix = SRC.find(s).unwrap();
+            SrcLoc::new(ix, ix + 1)
+        };
+        let (a, b) = (at("a ="), at("b ="));
+        assert_eq!(info.span1_to_span2(a.merge(b)).to_string(), "2:6-3:7");
+        assert_eq!(
+            info.span1_to_span2(a).merge(info.span1_to_span2(b)),
+            info.span1_to_span2(a.merge(b))
+        );
+    }
+
+    #[test]
+    fn slice_is_the_binding_text() {
+        let prog = ProgParser::new().parse(SRC).unwrap();
+        let Expr::Let { bindings, .. } = &prog.body else { panic!("expected a let") };
+        let Binding { var: (_, var_loc), expr } = &bindings[1];
+        let info = FileInfo::new(SRC);
+        assert_eq!(info.slice(var_loc.merge(expr.loc())), "b = 2");
+        assert_eq!(info.slice(prog.body.loc()), "let a = 1,\n      b = 2 in a");
+    }
+
+    #[test]
+    fn slice_out_of_range_is_empty() {
+        let info = FileInfo::new(SRC);
+        assert_eq!(info.slice(SrcLoc::new(SRC.len() - 1, SRC.len() + 3)), "");
+        assert_eq!(info.slice(SrcLoc::new(5, 2)), "");
+        assert_eq!(FileInfo::new("def main(é): é").slice(SrcLoc::new(9, 10)), "");
+    }
+
+    #[test]
+    fn span2_compares_lines_before_columns() {
+        let early = Span2 { start_line: 1, start_col: 9, end_line: 1, end_col: 12 };
+        let late = Span2 { start_line: 2, start_col: 0, end_line: 2, end_col: 3 };
+        assert_eq!(early.merge(late).to_string(), "1:9-2:3");
+        assert_eq!(late.merge(early), early.merge(late));
+    }
+}
+
+/* --------------------------------- LLVM --------------------------------- */
+mod llvm {
+    use super::*;
+    use snake::backend_llvm::emit_llvm;
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    fn have(tool: &str) -> bool {
+        Command::new(tool).arg("--version").output().is_ok_and(|o| o.status.success())
+    }
+
+    /// Feed `ir` to `tool` on stdin, returning its exit code.
+    fn pipe(tool: &str, args: &[&str], ir: &str) -> Option<i32> {
+        let mut child = Command::new(tool)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap();
+        child.stdin.take().unwrap().write_all(ir.as_bytes()).unwrap();
+        child.wait().unwrap().code()
+    }
+
+    /// The IR for example `f`, with a `main` that returns `entry(arg)`.
+    fn with_driver(f: &str, arg: i64) -> String {
+        let (_, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", f))).unwrap();
+        format!(
+            "{}\ndefine i32 @main() {{\n  %r = call i64 @entry(i64 {})\n  \
+             %c = trunc i64 %r to i32\n  ret i32 %c\n}}\n",
+            emit_llvm(&ssa),
+            arg
+        )
+    }
+
+    #[test]
+    fn add1_assembles_and_runs() {
+        if !have("llvm-as") || !have("lli") {
+            eprintln!("skipping: llvm-as or lli not found");
+            return;
+        }
+        let ir = with_driver("add1.adder", 42);
+        assert_eq!(pipe("llvm-as", &["-o", "/dev/null"], &ir), Some(0), "{}", ir);
+        assert_eq!(pipe("lli", &[], &ir), Some(43));
+    }
+
+    #[test]
+    fn recursion_and_branches_run() {
+        if !have("lli") {
+            eprintln!("skipping: lli not found");
+            return;
+        }
+        assert_eq!(pipe("lli", &[], &with_driver("recursive_main_factorial.cobra", 5)), Some(120));
+        assert_eq!(pipe("lli", &[], &with_driver("tail_recursive_main.cobra", 9)), Some(5));
+    }
+
+    #[test]
+    fn reused_names_are_defined_once() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/pow.cobra")).unwrap();
+        let ir = emit_llvm(&ssa);
+        for fun in ir.split("define ").skip(1) {
+            let mut defs: Vec<_> = fun
+                .lines()
+                .filter_map(|l| l.trim().split_once(" = ").map(|(d, _)| d))
+                .collect();
+            let n = defs.len();
+            defs.sort();
+            defs.dedup();
+            assert_eq!(defs.len(), n, "{}", fun);
+        }
+    }
+}
+
+/* ------------------------------- C Backend ------------------------------ */
+mod c_backend {
+    use super::*;
+    use snake::backend_c::emit_c;
+    use std::process::Command;
+
+    /// A `main` that prints `entry` of its argument.
+    const DRIVER: &str = "#include <stdio.h>\n#include <stdlib.h>\n\
+        int main(int argc, char **argv) {\n  (void)argc;\n  \
+        printf(\"%lld\\n\", (long long)entry(atoll(argv[1])));\n  return 0;\n}\n";
+
+    fn have_cc() -> bool {
+        Command::new("cc").arg("--version").output().is_ok_and(|o| o.status.success())
+    }
+
+    #[test]
+    fn non_tail_factorial_compiles_and_runs() {
+        if !have_cc() {
+            eprintln!("skipping: cc not found");
+            return;
+        }
+        let (_, ssa) =
+            runner::emit_ssa(Path::new("examples/non_tail_factorial.cobra")).unwrap();
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let src = tmp_dir.path().join("prog.c");
+        let exe = tmp_dir.path().join("prog");
+        let c = format!("{}\n{}", emit_c(&ssa), DRIVER);
+        std::fs::write(&src, &c).unwrap();
+        let status = Command::new("cc").arg("-o").arg(&exe).arg(&src).status().unwrap();
+        assert!(status.success(), "{}", c);
+        let out = Command::new(&exe).arg("5").output().unwrap();
+        assert_eq!(String::from_utf8(out.stdout).unwrap(), "120\n");
+    }
+
+    #[test]
+    fn externs_are_declared() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/basic_print.cobra")).unwrap();
+        assert!(emit_c(&ssa).contains("int64_t print(int64_t);"));
+    }
+}
+
+/* ------------------------------ WebAssembly ----------------------------- */
+mod wasm {
+    use super::*;
+    use snake::backend_wasm::emit_wasm;
+    use std::process::Command;
+
+    fn wat(f: &str) -> String {
+        let (_, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", f))).unwrap();
+        emit_wasm(&ssa)
+    }
+
+    #[test]
+    fn add1_validates() {
+        if !Command::new("wat2wasm").arg("--version").output().is_ok_and(|o| o.status.success())
+        {
+            eprintln!("skipping: wat2wasm not found");
+            return;
+        }
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let src = tmp_dir.path().join("add1.wat");
+        let wat = wat("add1.adder");
+        std::fs::write(&src, &wat).unwrap();
+        let status = Command::new("wat2wasm")
+            .arg(&src)
+            .arg("-o")
+            .arg(tmp_dir.path().join("add1.wasm"))
+            .status()
+            .unwrap();
+        assert!(status.success(), "{}", wat);
+    }
+
+    #[test]
+    fn entry_is_exported_and_externs_imported() {
+        let wat = wat("basic_print.cobra");
+        assert!(wat.contains("(export \"entry\")"), "{}", wat);
+        assert!(wat.contains("(import \"env\" \"print\""), "{}", wat);
+    }
+}
+
+/* --------------------------- Register Allocation ------------------------ */
+mod register_sets {
+    use super::*;
+    use snake::asm::interp::Machine;
+    use snake::asm::{instrs_pretty, Arg64, Instr, MovArgs, Reg};
+    use snake::backend::{Emitter, ALLOCATABLE_REGS};
+    use snake::compile;
+    use snake::frontend::Resolver;
+    use snake::middle_end::Lowerer;
+    use snake::ssa::Program;
+
+    const TEACHING: [Reg; 4] = [Reg::Rax, Reg::Rbx, Reg::Rcx, Reg::Rdx];
+
+    fn emit(ssa: &Program, regs: &[Reg]) -> Vec<Instr> {
+        let lowerer = Lowerer::from(Resolver::new());
+        let mut emitter = Emitter::from(lowerer).with_registers(regs);
+        emitter.emit_prog(ssa);
+        emitter.to_asm()
+    }
+
+    fn run(instrs: &[Instr], arg: i64) -> (Result<i64, String>, String) {
+        let mut out = Vec::new();
+        let mut machine = Machine::new()
+            .with_input(std::io::Cursor::new("4\n5\n"))
+            .with_extern("big_fun_nine", 9, big_fun);
+        let res = machine.run(instrs, arg, &mut out).map_err(|e| e.to_string());
+        (res, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn four_registers_spill_and_stay_correct() {
+        let src = "extern print(x)\n\
+                   def main(x):\n  let a = x + 1, b = a * 2, c = b - 3, d = c + a, e = d * b,\n    \
+                   p = print(e), f = e - c in\n  a + b + c + d + e + f + p";
+        let (resolver, ast) = compile::frontend(src).unwrap();
+        let (_, ssa) = compile::middle_end(resolver, ast).unwrap();
+        let instrs = emit(&ssa, &TEACHING);
+        assert_eq!(run(&instrs, 5), (Ok(573), "180\n".to_string()));
+        let reads = |reg: Reg| {
+            instrs.iter().any(|i| {
+                matches!(i, Instr::Mov(MovArgs::ToReg(_, Arg64::Reg(r))) if *r == reg)
+            })
+        };
+        let pretty = instrs_pretty(&instrs);
+        assert!(reads(Reg::Rbx) && reads(Reg::Rcx) && reads(Reg::Rdx), "{}", pretty);
+        let spills = instrs.iter().any(|i| {
+            matches!(i, Instr::Mov(MovArgs::ToMem(mem, _)) if mem.reg == Reg::Rbp && mem.offset < 0)
+        });
+        assert!(spills, "nothing spilled to the stack:\n{}", pretty);
+    }
+
+    #[test]
+    fn every_set_agrees_on_examples() {
+        for name in ["non_tail_factorial.cobra", "pow.cobra", "extern_big_nine.cobra"] {
+            let (_, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", name))).unwrap();
+            let expected = run(&emit(&ssa, &[]), 5);
+            for regs in [&ALLOCATABLE_REGS[..], &TEACHING, &[Reg::Rdi, Reg::Rsi]] {
+                assert_eq!(run(&emit(&ssa, regs), 5), expected, "{} with {:?}", name, regs);
+            }
+        }
+    }
+}
+
+/* ------------------------------ Error Kinds ----------------------------- */
+mod error_kinds {
+    use snake::compile::{self, Error};
+    use snake::frontend::CompileErr;
+
+    #[test]
+    fn parse_and_resolve_errors_are_distinguishable() {
+        let parse = compile::compile("def main(x): x +").unwrap_err();
+        assert!(matches!(parse, Error::Parse(_)), "{:?}", parse);
+        assert!(parse.to_string().starts_with("Error parsing program: "));
+
+        let resolve = compile::compile("def main(x): y").unwrap_err();
+        match &resolve {
+            Error::Resolve(errs, _) => {
+                assert!(matches!(errs[..], [CompileErr::UnboundVariable(ref v, _)] if v == "y"))
+            }
+            _ => panic!("expected a resolve error, got {:?}", resolve),
+        }
+        assert!(resolve.to_string().starts_with("Error resolving ast: "), "{}", resolve);
+        assert!(resolve.to_string().contains("variable \"y\" unbound"), "{}", resolve);
+    }
+}
+
+/* -------------------------- Forward References -------------------------- */
+mod forward_refs {
+    use super::*;
+    use snake::frontend::{CompileErr, Resolver};
+    use snake::interp::Value;
+    use snake::middle_end::Lowerer;
+    use snake::parser::ProgParser;
+
+    const SRC: &str = "def main(x):\n  def f(y): g(y) in\n  def g(z): z + 1 in\n  f(x)";
+
+    /// `g` in the body of `h` is the one declared before it
+    const SHADOWED: &str = "def main(x):\n  def g(y): y in\n  def h(y): g(y) in\n  \
+                            def g(z): z + 1 in\n  h(x)";
+
+    /// The value of `prog` on 4 in the AST interpreter.
+    fn run_ast<V, F>(prog: &snake::ast::Prog<V, F>, forward_refs: bool) -> String
+    where
+        V: std::hash::Hash + Eq + Clone + std::fmt::Display,
+        F: std::hash::Hash + Eq + Clone + std::fmt::Display,
+    {
+        let run = if forward_refs {
+            interp::ast::Machine::run_prog_with_forward_refs
+        } else {
+            interp::ast::Machine::run_prog_with_input
+        };
+        match run(prog, Value::Int(4), &mut std::io::empty()) {
+            Ok(v) => v.to_string(),
+            Err(e) => panic!("interpreter error: {}", e),
+        }
+    }
+
+    /// The value of `src` on 4 in the interpreters of the raw AST, the
+    /// resolved AST and the SSA, resolving with `resolver`.
+    fn run_all(src: &str, mut resolver: Resolver, forward_refs: bool) -> [String; 3] {
+        let raw = ProgParser::new().parse(src).unwrap();
+        let from_raw = run_ast(&raw, forward_refs);
+        let ast = resolver.resolve_prog(raw).unwrap();
+        let from_resolved = run_ast(&ast, forward_refs);
+        let ssa = Lowerer::from(resolver).lower_prog(ast);
+        let from_ssa = interp::ssa::Interp::new().run_value(&ssa, Value::Int(4)).unwrap();
+        [from_raw, from_resolved, from_ssa.to_string()]
+    }
+
+    #[test]
+    fn earlier_fundefs_call_later_ones() {
+        assert_eq!(run_all(SRC, Resolver::new().with_forward_refs(), true), ["5", "5", "5"]);
+    }
+
+    #[test]
+    fn off_by_default() {
+        let prog = ProgParser::new().parse(SRC).unwrap();
+        let err = Resolver::new().resolve_prog(prog).unwrap_err();
+        assert!(matches!(err, CompileErr::UnboundFunction(ref g, _) if g == "g"), "{:?}", err);
+        let prog = ProgParser::new().parse(SRC).unwrap();
+        let res = interp::ast::Machine::run_prog_value(&prog, Value::Int(4));
+        assert!(matches!(res, Err(interp::InterpErr::UnboundFun(ref g)) if g == "g"), "{:?}", res);
+    }
+
+    #[test]
+    fn earlier_names_still_win() {
+        assert_eq!(run_all(SHADOWED, Resolver::new().with_forward_refs(), true), ["4", "4", "4"]);
+    }
+
+    #[test]
+    fn interpreters_agree_without_forward_refs() {
+        assert_eq!(run_all(SHADOWED, Resolver::new(), false), ["4", "4", "4"]);
+    }
+}
+
+/* ---------------------------- Primitive Table --------------------------- */
+mod prim_info {
+    use snake::ast::{Assoc, Expr, Fixity, Prim};
+    use snake::lexer::tokenize;
+    use snake::parser::ProgParser;
+
+    #[test]
+    fn arity_agrees() {
+        for prim in Prim::ALL {
+            assert_eq!(prim.info().arity, prim.arity(), "{:?}", prim);
+        }
+    }
+
+    #[test]
+    fn spellings_are_single_tokens() {
+        for prim in Prim::ALL {
+            let spelling = prim.info().spelling;
+            let toks = tokenize(spelling).unwrap();
+            assert_eq!(toks.iter().map(|(_, t)| *t).collect::<Vec<_>>(), [spelling]);
+        }
+    }
+
+    fn infix(prim: &Prim) -> Option<(u8, Assoc)> {
+        match prim.info().fixity {
+            Fixity::Infix { precedence, assoc } => Some((precedence, assoc)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn grammar_follows_precedence() {
+        for p in Prim::ALL.iter().filter(|p| infix(p).is_some()) {
+            for q in Prim::ALL.iter().filter(|q| infix(q).is_some()) {
+                let ((pp, assoc), (qp, _)) = (infix(p).unwrap(), infix(q).unwrap());
+                let src = format!("def main(x): x {} x {} x", p, q);
+                let prog = ProgParser::new().parse(&src).unwrap();
+                let Expr::Prim { prim: root, args, .. } = &prog.body else {
+                    panic!("{} did not parse to a primitive", src)
+                };
+                let p_first = pp > qp || (pp == qp && assoc == Assoc::Left);
+                if p_first {
+                    assert_eq!(root, q, "{}", src);
+                    assert!(matches!(&args[0], Expr::Prim { prim, .. } if prim == p), "{}", src);
+                } else {
+                    assert_eq!(root, p, "{}", src);
+                    assert!(matches!(&args[1], Expr::Prim { prim, .. } if prim == q), "{}", src);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn pretty_printing_reparses() {
+        let src = "def main(x): add1(x) + sub1(x) * 2 < 3 && !(x == 1) || x >= 0 && \
+                   x != x - 1 && x <= x && x > error(x)";
+        let printed = ProgParser::new().parse(src).unwrap().to_string();
+        let reprinted = ProgParser::new().parse(&printed).unwrap().to_string();
+        assert_eq!(printed, reprinted);
+    }
+}
+
+/* ------------------------------- Precedence ----------------------------- */
+mod precedence {
+    use snake::ast::{Expr, Prim, SurfExpr};
+    use snake::parser::ProgParser;
+
+    fn parse(e: &str) -> SurfExpr {
+        ProgParser::new().parse(&format!("def main(x): {}", e)).unwrap().body
+    }
+
+    #[test]
+    fn times_binds_tighter_than_plus() {
+        let Expr::Prim { prim: Prim::Add, args, .. } = parse("1 + 2 * 3") else {
+            panic!("expected an addition at the root")
+        };
+        assert!(matches!(args[0], Expr::Num(1, _)));
+        assert!(matches!(&args[1], Expr::Prim { prim: Prim::Mul, .. }));
+    }
+
+    #[test]
+    fn minus_is_left_associative() {
+        let Expr::Prim { prim: Prim::Sub, args, .. } = parse("1 - 2 - 3") else {
+            panic!("expected a subtraction at the root")
+        };
+        assert!(matches!(&args[0], Expr::Prim { prim: Prim::Sub, .. }));
+        assert!(matches!(args[1], Expr::Num(3, _)));
+    }
+
+    #[test]
+    fn mixed_operators() {
+        // the pretty-printer parenthesizes every operator
+        for (src, nested) in [
+            ("1 + 2 * 3", "(1 + (2 * 3))"),
+            ("1 * 2 + 3", "((1 * 2) + 3)"),
+            ("1 - 2 - 3", "((1 - 2) - 3)"),
+            ("1 - 2 + 3", "((1 - 2) + 3)"),
+            ("1 < 2 + 3", "(1 < (2 + 3))"),
+            ("1 + 2 == 3 * 4", "((1 + 2) == (3 * 4))"),
+            ("x < 1 && 2 < x", "((x < 1) && (2 < x))"),
+            ("true || 1 == 2", "(true || (1 == 2))"),
+            ("!true && false", "((!true) && false)"),
+        ] {
+            assert_eq!(parse(src).to_string(), nested, "{}", src);
+        }
+    }
+}
+
+/* ----------------------------- Compile With ----------------------------- */
+mod compile_with {
+    use snake::compile::{self, Error};
+    use snake::txt::FileInfo;
+
+    #[test]
+    fn same_output_on_success() {
+        let src = "def main(x):\n  def f(y): y * 2 in\n  f(add1(x))";
+        let with = compile::compile_with(src, &FileInfo::new(src)).unwrap();
+        assert_eq!(compile::compile(src).unwrap(), with);
+    }
+
+    #[test]
+    fn same_errors_on_failure() {
+        let src = "def main(x):\n  let y = 1 in\n  z";
+        let (Error::Resolve(base, _), Error::Resolve(with, info)) = (
+            compile::compile(src).unwrap_err(),
+            compile::compile_with(src, &FileInfo::new(src)).unwrap_err(),
+        ) else {
+            panic!("expected resolve errors")
+        };
+        assert_eq!(format!("{:?}", base), format!("{:?}", with));
+        assert_eq!(
+            compile::compile(src).unwrap_err().to_string(),
+            Error::Resolve(with, info).to_string()
+        );
+    }
+}
+
+/* ------------------------------ Id Reuse ------------------------------- */
+mod id_reuse {
+    use snake::ast::{BoundExpr, BoundProg, Expr};
+    use snake::frontend::Resolver;
+    use snake::identifiers::IdGen;
+    use snake::parser::ProgParser;
+    use std::ops::RangeInclusive;
+
+    const V1: &str = "def main(x):\n  let y = x + 1 in\n  def f(z): z * y in\n  f(y)";
+    const V2: &str = "def main(x):\n  let y = x + 2, w = y in\n  def f(z): z * w in\n  f(y)";
+
+    fn bound_in(e: &BoundExpr, out: &mut Vec<usize>) {
+        match e {
+            Expr::Num(..) | Expr::Bool(..) | Expr::Var(..) => {}
+            Expr::Prim { args, .. } | Expr::Call { args, .. } => {
+                args.iter().for_each(|a| bound_in(a, out))
+            }
+            Expr::Let { bindings, body, .. } => {
+                for b in bindings {
+                    out.push(b.var.0.index());
+                    bound_in(&b.expr, out);
+                }
+                bound_in(body, out);
+            }
+            Expr::If { cond, thn, els, .. } => {
+                [cond, thn, els].into_iter().for_each(|e| bound_in(e, out))
+            }
+            Expr::Match { scrutinee, arms, default, .. } => {
+                bound_in(scrutinee, out);
+                arms.iter().for_each(|arm| bound_in(&arm.body, out));
+                default.iter().for_each(|e| bound_in(e, out))
+            }
+            Expr::FunDefs { decls, body, .. } => {
+                for d in decls {
+                    out.extend(d.params.iter().map(|(p, _)| p.index()));
+                    bound_in(&d.body, out);
+                }
+                bound_in(body, out);
+            }
+        }
+    }
+
+    /// The range of indices of the variables `prog` binds.
+    fn var_range(prog: &BoundProg) -> RangeInclusive<usize> {
+        let mut idxs = vec![prog.param.0.index()];
+        bound_in(&prog.body, &mut idxs);
+        *idxs.iter().min().unwrap()..=*idxs.iter().max().unwrap()
+    }
+
+    fn resolve(resolver: &mut Resolver, src: &str) -> BoundProg {
+        resolver.resolve_prog(ProgParser::new().parse(src).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn successive_resolutions_are_disjoint() {
+        let mut resolver = Resolver::new();
+        let first = var_range(&resolve(&mut resolver, V1));
+        let second = var_range(&resolve(&mut resolver, V2));
+        assert!(first.end() < second.start(), "{:?} and {:?} overlap", first, second);
+    }
+
+    #[test]
+    fn carried_over_ids_are_disjoint() {
+        let mut old = Resolver::new();
+        let first = var_range(&resolve(&mut old, V1));
+        let (vars, funs) = (old.vars.next_index(), old.funs.next_index());
+        let mut new =
+            Resolver::new().with_ids(IdGen::starting_at(vars), IdGen::starting_at(funs));
+        let second = var_range(&resolve(&mut new, V2));
+        assert!(first.end() < second.start(), "{:?} and {:?} overlap", first, second);
+        assert_eq!(*second.start(), vars);
+    }
+}
+
+/* ----------------------------- Source Names ----------------------------- */
+mod source_names {
+    use snake::frontend::Resolver;
+    use snake::parser::ProgParser;
+    use snake::pretty::readable;
+
+    fn readable_src(src: &str) -> String {
+        let prog = Resolver::new().resolve_prog(ProgParser::new().parse(src).unwrap()).unwrap();
+        readable(&prog).to_string()
+    }
+
+    #[test]
+    fn shadowed_names_are_primed() {
+        let src = "def main(x):\n  let x = x + 1 in\n  let y = x in\n  x * y";
+        assert_eq!(readable_src(src), "def entry(x): let x' = (x + 1) in let y = x' in (x' * y)");
+    }
+
+    #[test]
+    fn unshadowed_names_are_left_alone() {
+        let src = "def main(x):\n  let y = x + 1 in\n  def f(z): z * y in\n  f(y)";
+        assert_eq!(
+            readable_src(src),
+            "def entry(x): let y = (x + 1) in def f (z): (z * y) in f(y)"
+        );
+    }
+
+    #[test]
+    fn names_out_of_scope_again_are_left_alone() {
+        let src = "def main(x):\n  (let y = x in y) + (let y = 2 in let y = y in y)";
+        assert_eq!(
+            readable_src(src),
+            "def entry(x): (let y = x in y + let y = 2 in let y' = y in y')"
+        );
+    }
+
+    #[test]
+    fn only_for_the_resolved_ast() {
+        let out = std::process::Command::new(env!("CARGO_BIN_EXE_snake"))
+            .args(["examples/add1.adder", "--source-names", "-t", "ssa"])
+            .output()
+            .unwrap();
+        assert!(!out.status.success());
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(stderr.contains("--source-names only applies to the resolved-ast target"), "{}", stderr);
+    }
+}
+
+/* --------------------------------- REPL --------------------------------- */
+mod repl {
+    use snake::runner::repl;
+
+    fn session(input: &str) -> String {
+        let mut out = Vec::new();
+        repl(input.as_bytes(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn prints_each_value() {
+        assert_eq!(session("40\nadd1(40)\n"), "40\n41\n");
+    }
+
+    #[test]
+    fn keeps_going_after_errors() {
+        let out = session("y\n1 + 1\n");
+        let lines: Vec<_> = out.lines().collect();
+        assert!(lines[0].starts_with("Error resolving ast: "), "{}", out);
+        assert_eq!(lines[1..], ["2"]);
+    }
+
+    #[test]
+    fn remembers_definitions() {
+        assert_eq!(session("def f(y): y * 2\nf(21)\ndef g(y): f(y) + 1\ng(1)\n"), "42\n3\n");
+    }
+}
+
+/* ---------------------------- Wide Constants ---------------------------- */
+mod wide_constants {
+    use super::*;
+    use snake::asm::interp::Machine;
+    use snake::compile;
+    use snake::interp::ssa::Interp;
+    use snake::ssa::IntRepr;
+
+    const SRC: &str = "def main(x): if x == 5000000000: 1 else: 0";
+
+    #[test]
+    fn compare_against_a_wide_constant() {
+        // 705032704 has the same low 32 bits as 5000000000
+        for repr in [IntRepr::Untagged, IntRepr::Tagged] {
+            for (arg, expected) in [(5000000000, 1), (705032704, 0), (0, 0)] {
+                let (resolver, ast) = compile::frontend(SRC).unwrap();
+                let (lowerer, ssa) = compile::middle_end(resolver, ast).unwrap();
+                let interp = Interp::new().with_repr(repr).run(&ssa, arg.to_string()).unwrap();
+                assert_eq!(interp.to_string(), expected.to_string(), "{} ({:?})", arg, repr);
+
+                let mut emitter = backend::Emitter::from(lowerer).with_repr(repr);
+                emitter.emit_prog(&ssa);
+                let got = Machine::new().run(&emitter.to_asm(), arg, &mut Vec::new());
+                assert_eq!(got, Ok(expected), "{} ({:?})", arg, repr);
+            }
+        }
+    }
+
+    #[test]
+    fn wide_constants_are_loaded_from_data() {
+        let src = "def main(x): if x == 5000000000: 5000000000 else: 0";
+        let (resolver, ast) = compile::frontend(src).unwrap();
+        let (lowerer, ssa) = compile::middle_end(resolver, ast).unwrap();
+        let mut emitter = backend::Emitter::from(lowerer);
+        emitter.emit_prog(&ssa);
+        let asm = emitter.to_asm();
+        let txt = asm::instrs_to_string(&asm);
+        let lines: Vec<_> = txt.lines().map(str::trim).collect();
+        let data = lines.iter().rposition(|l| *l == "section .data").unwrap();
+        // both uses share the one word
+        let words: Vec<_> = (data..lines.len()).filter(|&ix| lines[ix] == "dq 5000000000").collect();
+        assert_eq!(words.len(), 1, "{}", txt);
+        assert!(lines[words[0] - 1].ends_with(':'), "{}", txt);
+        assert!(!lines[..data].iter().any(|l| l.contains("5000000000")), "{}", txt);
+        assert_eq!(Machine::new().run(&asm, 5000000000, &mut Vec::new()), Ok(5000000000));
+    }
+
+    mk_test!(exe_compares_wide_constant, "wide_constant.cobra", "5000000000", "1");
+}
+
+/* -------------------------------- Listing ------------------------------- */
+mod listing {
+    use super::*;
+    use snake::backend::listing;
+    use snake::compile;
+
+    /// The comments of `listing`, each with the instructions after it.
+    fn sections(listing: &str) -> Vec<(&str, Vec<&str>)> {
+        let mut sections: Vec<(&str, Vec<&str>)> = Vec::new();
+        for line in listing.lines().map(str::trim) {
+            match line.strip_prefix(";;; ") {
+                Some(comment) => sections.push((comment, Vec::new())),
+                None => {
+                    if let Some((_, instrs)) = sections.last_mut() {
+                        instrs.push(line)
+                    }
+                }
+            }
+        }
+        sections
+    }
+
+    #[test]
+    fn add_is_under_the_add1_line() {
+        let path = Path::new("examples/add1.adder");
+        let src = runner::read_file(path).unwrap();
+        let (_, ssa) = runner::emit_ssa(path).unwrap();
+        let listing = listing(&src, &ssa);
+        let sections = sections(&listing);
+        let (_, instrs) = sections
+            .iter()
+            .find(|(comment, _)| *comment == "2: add1(x)")
+            .unwrap_or_else(|| panic!("no comment for the add1 line in\n{}", listing));
+        assert!(instrs.iter().any(|i| i.starts_with("add ")), "{}", listing);
+    }
+
+    #[test]
+    fn lines_follow_the_source() {
+        let src = "def main(x):\n  let y = x * 2 in\n  y + 1";
+        let (resolver, ast) = compile::frontend(src).unwrap();
+        let (_, ssa) = compile::middle_end(resolver, ast).unwrap();
+        let listing = listing(src, &ssa);
+        let comments: Vec<_> = sections(&listing).into_iter().map(|(c, _)| c).collect();
+        assert_eq!(comments, ["2: let y = x * 2 in", "3: y + 1"], "{}", listing);
+    }
+}
+
+/* ----------------------------- Saved Registers -------------------------- */
+mod saved_registers {
+    use super::*;
+    use snake::asm::interp::Machine;
+    use snake::asm::{Arg32, Instr, Reg};
+
+    #[test]
+    fn classifies_registers() {
+        for reg in [Reg::Rax, Reg::Rdi, Reg::Rsi, Reg::R10, Reg::R11] {
+            assert!(reg.is_caller_saved() && !reg.is_callee_saved(), "{:?}", reg);
+        }
+        for reg in [Reg::Rbx, Reg::Rbp, Reg::Rsp, Reg::R12, Reg::R15] {
+            assert!(reg.is_callee_saved() && !reg.is_caller_saved(), "{:?}", reg);
+        }
+    }
+
+    #[test]
+    fn extern_calls_only_save_caller_saved_registers() {
+        let path = Path::new("examples/extern_big_nine.cobra");
+        let emit = |regs: &[Reg]| {
+            let (lowerer, ssa) = runner::emit_ssa(path).unwrap();
+            let mut emitter = backend::Emitter::from(lowerer).with_registers(regs);
+            emitter.emit_prog(&ssa);
+            emitter.to_asm()
+        };
+        let asm = emit(&[Reg::Rbx, Reg::Rcx, Reg::R12, Reg::Rdx]);
+        let mut pushed = Vec::new();
+        let mut checked = 0;
+        for instr in &asm {
+            match instr {
+                Instr::Push(Arg32::Reg(reg)) if *reg != Reg::Rax => pushed.push(*reg),
+                Instr::Call(f) if f == "big_fun_nine" => {
+                    assert!(pushed.iter().all(Reg::is_caller_saved), "saved {:?}", pushed);
+                    assert!(!pushed.is_empty(), "l1 is live across the call");
+                    checked += 1;
+                    pushed.clear();
+                }
+                Instr::Label(_) | Instr::Call(_) => pushed.clear(),
+                _ => {}
+            }
+        }
+        assert_eq!(checked, 1);
+
+        let run = |asm: &[Instr]| {
+            let mut out = Vec::new();
+            let res = Machine::new().with_extern("big_fun_nine", 9, big_fun).run(asm, 0, &mut out);
+            (res, String::from_utf8(out).unwrap())
+        };
+        assert_eq!(run(&asm), run(&emit(&[])));
+    }
+}
+
+/* --------------------------- Run Interpreter --------------------------- */
+mod run_interp {
+    use std::process::Command;
+
+    #[test]
+    fn prints_result_without_linking() {
+        let out = Command::new(env!("CARGO_BIN_EXE_snake"))
+            .args(["examples/add1.adder", "-t", "exe", "--run-interp", "42"])
+            .output()
+            .unwrap();
+        assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+        assert_eq!(String::from_utf8(out.stdout).unwrap(), "43\n");
+    }
+}
+
+/* ----------------------------- Zero Tests ----------------------------- */
+mod zero_tests {
+    use super::*;
+    use snake::asm::{instrs_pretty, Arg32, BinArgs, ConditionCode, Instr, Reg};
+
+    #[test]
+    fn conditional_branches_test_instead_of_compare() {
+        let (lowerer, ssa) = runner::emit_ssa(Path::new("examples/compare_zero.cobra")).unwrap();
+        let mut emitter = backend::Emitter::from(lowerer);
+        emitter.emit_prog(&ssa);
+        let asm = emitter.to_asm();
+        let branches = asm
+            .windows(2)
+            .filter(|w| {
+                matches!(
+                    w,
+                    [
+                        Instr::Test(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rax))),
+                        Instr::JCC(ConditionCode::NE, _)
+                    ]
+                )
+            })
+            .count();
+        assert_eq!(branches, 3, "{}", instrs_pretty(&asm));
+        assert!(
+            !asm.iter().any(|i| matches!(i, Instr::Cmp(BinArgs::ToReg(_, Arg32::Signed(0))))),
+            "{}",
+            instrs_pretty(&asm)
+        );
+    }
+}
+
+/* ---------------------------- Entry Points ---------------------------- */
+mod entry_point {
+    use super::*;
+    use snake::asm::{interp::Machine, Instr};
+    use snake::frontend::Resolver;
+    use snake::middle_end::Lowerer;
+    use snake::parser::ProgParser;
+    use snake::ssa::IntRepr;
+
+    /// `add1.adder` compiled with `main` as `snake_main`.
+    fn compile_add1(repr: IntRepr) -> (snake::ssa::Program, Vec<Instr>) {
+        let src = std::fs::read_to_string("examples/add1.adder").unwrap();
+        let mut resolver = Resolver::new().with_entry("snake_main");
+        let ast = resolver.resolve_prog(ProgParser::new().parse(&src).unwrap()).unwrap();
+        let mut lowerer = Lowerer::from(resolver);
+        let ssa = lowerer.lower_prog(ast);
+        let mut emitter = backend::Emitter::from(lowerer).with_repr(repr);
+        emitter.emit_prog(&ssa);
+        (ssa, emitter.to_asm())
+    }
+
+    #[test]
+    fn custom_entry_is_global() {
+        for repr in [IntRepr::Untagged, IntRepr::Tagged] {
+            let (ssa, asm) = compile_add1(repr);
+            assert!(asm.contains(&Instr::Global("snake_main".to_string())));
+            assert!(!asm.contains(&Instr::Global("entry".to_string())));
+            assert_eq!(Machine::new().run(&asm, 42, &mut Vec::new()), Ok(43));
+            let value = interp::ssa::Interp::new().with_repr(repr).run(&ssa, "42".to_string());
+            assert_eq!(value.unwrap().to_string(), "43");
+        }
+    }
+
+    #[test]
+    fn links_with_matching_runtime() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let stub = std::fs::read_to_string("runtime/stub.rs").unwrap();
+        let stub = stub.replace(r#"link_name = "\x01entry""#, r#"link_name = "\x01snake_main""#);
+        assert!(stub.contains("snake_main"));
+        let rt = tmp_dir.path().join("stub.rs");
+        std::fs::write(&rt, stub).unwrap();
+
+        let (_, asm) = compile_add1(IntRepr::Untagged);
+        let exe = tmp_dir.path().join("main.exe");
+        runner::link(&asm::instrs_to_string(&asm), &rt, tmp_dir.path(), &exe, false).unwrap();
+        let mut out = Vec::new();
+        runner::run(&exe, "42", &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().trim(), "43");
+    }
+}
+
+/* ------------------------------- Externs ------------------------------- */
+mod externs_of {
+    use snake::compile;
+
+    fn externs(f: &str) -> Vec<(String, usize)> {
+        let src = std::fs::read_to_string(format!("examples/{}", f)).unwrap();
+        let (resolver, ast) = compile::frontend(&src).unwrap();
+        let (_, ssa) = compile::middle_end(resolver, ast).unwrap();
+        compile::externs_of(&ssa)
+    }
+
+    #[test]
+    fn reports_declared_externs() {
+        assert_eq!(externs("extern_big_nine.cobra"), vec![("big_fun_nine".to_string(), 9)]);
+    }
+
+    #[test]
+    fn reports_error_function() {
+        assert_eq!(externs("error_code.cobra"), vec![("snake_error".to_string(), 1)]);
+    }
+}
+
+/* --------------------------- Bool Coercions --------------------------- */
+mod bool_coercions {
+    use snake::bools::elide_bool_coercions_with_stats;
+    use snake::compile;
+    use snake::interp;
+    use snake::ssa::{BlockBody, Operation, Prim1, Program};
+
+    fn lower(src: &str) -> Program {
+        let (resolver, ast) = compile::frontend(src).unwrap();
+        compile::middle_end(resolver, ast).unwrap().1
+    }
+
+    fn coercions(prog: &Program) -> usize {
+        let mut count = 0;
+        for block in prog.blocks_recursive() {
+            let mut body = &block.body;
+            loop {
+                match body {
+                    BlockBody::Terminator(_) => break,
+                    BlockBody::Operation { op, next, .. } => {
+                        if matches!(op, Operation::Prim1(Prim1::IntToBool, _)) {
+                            count += 1;
+                        }
+                        body = next;
+                    }
+                    BlockBody::SubBlocks { next, .. } => body = next,
+                }
+            }
+        }
+        count
+    }
+
+    fn run(prog: &Program, x: i64) -> String {
+        interp::ssa::Interp::new().run(prog, x.to_string()).unwrap().to_string()
+    }
+
+    #[test]
+    fn comparison_is_not_coerced() {
+        let ssa = lower("def main(x):\n  let y = 5 in\n  !(x < y)");
+        assert_eq!(coercions(&ssa), 1);
+        let (elided, stats) = elide_bool_coercions_with_stats(ssa.clone());
+        assert_eq!(stats.elided, 1);
+        assert_eq!(coercions(&elided), 0);
+        for x in [3, 5, 7] {
+            assert_eq!(run(&elided, x), run(&ssa, x));
+        }
+    }
+
+    #[test]
+    fn integers_are_still_coerced() {
+        let ssa = lower("def main(x):\n  !x && (x == 3)");
+        let (elided, stats) = elide_bool_coercions_with_stats(ssa.clone());
+        assert_eq!(stats.elided, 2);
+        assert_eq!(coercions(&elided), 1);
+        for x in [0, 3, 7] {
+            assert_eq!(run(&elided, x), run(&ssa, x));
+        }
+    }
+
+    fn lower_typed(src: &str) -> Program {
+        let pipeline = compile::Pipeline::new().with_bool_types();
+        let (resolver, ast) = pipeline.frontend(src).unwrap();
+        pipeline.middle_end(resolver, ast).unwrap().1
+    }
+
+    #[test]
+    fn typed_comparisons_are_not_coerced() {
+        let src = "def main(x):\n  let a = x < 3, b = !a in\n  (x < 5 && 1 < x) || b";
+        let (ssa, typed) = (lower(src), lower_typed(src));
+        assert_eq!(coercions(&ssa), 5);
+        assert_eq!(coercions(&typed), 0);
+        for x in [0, 2, 3, 5, 7] {
+            assert_eq!(run(&typed, x), run(&ssa, x));
+        }
+    }
+
+    #[test]
+    fn typed_integers_are_still_coerced() {
+        let src = "def main(x):\n  let y = x in\n  !y && (y == 3)";
+        let (ssa, typed) = (lower(src), lower_typed(src));
+        assert_eq!(coercions(&ssa), 3);
+        assert_eq!(coercions(&typed), 1);
+        for x in [0, 3, 7] {
+            assert_eq!(run(&typed, x), run(&ssa, x));
+        }
+    }
+}
+
+/* ------------------------------ Body Sizes ------------------------------ */
+mod body_size {
+    use snake::compile;
+    use snake::identifiers::{BlockName, IdGen, VarName};
+    use snake::ssa::*;
+
+    fn op(dest: &VarName, next: BlockBody) -> BlockBody {
+        BlockBody::Operation {
+            dest: dest.clone(),
+            op: Operation::Immediate(Immediate::Const(1)),
+            next: Box::new(next),
+        }
+    }
+
+    /// block outer():
+    ///   a = 1
+    ///   block inner():
+    ///     b = 1
+    ///     c = 1
+    ///     ret 0
+    ///   d = 1
+    ///   br inner()
+    #[test]
+    fn counts_nested_operations() {
+        let mut vars: IdGen<VarName> = IdGen::new();
+        let mut blocks: IdGen<BlockName> = IdGen::new();
+        let (a, b, c, d) = (vars.fresh("a"), vars.fresh("b"), vars.fresh("c"), vars.fresh("d"));
+        let inner = BasicBlock {
+            label: blocks.fresh("inner"),
+            params: Vec::new(),
+            body: op(&b, op(&c, BlockBody::Terminator(Terminator::Return(Immediate::Const(0))))),
+        };
+        assert_eq!(inner.size(), 2);
+        let target = inner.label.clone();
+        let outer = BasicBlock {
+            label: blocks.fresh("outer"),
+            params: Vec::new(),
+            body: op(
+                &a,
+                BlockBody::SubBlocks {
+                    blocks: vec![inner],
+                    next: Box::new(op(
+                        &d,
+                        BlockBody::Terminator(Terminator::Branch(Branch {
+                            target,
+                            args: Vec::new(),
+                        })),
+                    )),
+                },
+            ),
+        };
+        assert_eq!(outer.size(), 4);
+        assert_eq!(outer.body.size(), 4);
+    }
+
+    #[test]
+    fn measures_functions() {
+        let src = "def main(x):\n  def f(y): y * 2 + 1 in\n  f(x)";
+        let (resolver, ast) = compile::frontend(src).unwrap();
+        let (_, ssa) = compile::middle_end(resolver, ast).unwrap();
+        let total: usize = ssa.blocks.iter().map(BasicBlock::size).sum();
+        let by_fun: usize = ssa.funs.iter().map(|f| ssa.fun_size(f)).sum();
+        assert!(by_fun > 0);
+        assert_eq!(by_fun, total);
+    }
+}
+
+/* ---------------------------- Parse Errors ---------------------------- */
+mod parse_errors {
+    use snake::parser::ProgParser;
+    use snake::txt::FileInfo;
+
+    fn report(src: &str) -> String {
+        let err = ProgParser::new().parse(src).unwrap_err();
+        FileInfo::new(src).report_parse_error(src, err)
+    }
+
+    #[test]
+    fn unexpected_token() {
+        let msg = report("def main(x):\n  x + + 1");
+        let expected = "unexpected \"+\", expected one of a number, an identifier";
+        assert!(msg.starts_with(expected), "{}", msg);
+        assert!(msg.ends_with(": 2:6-2:7"), "{}", msg);
+    }
+
+    #[test]
+    fn unexpected_end_of_input() {
+        let msg = report("def main(x):\n  (x + 1");
+        assert_eq!(msg, "unexpected end of input, expected \")\": 2:8-2:8");
+    }
+
+    #[test]
+    fn invalid_token() {
+        assert_eq!(report("def main(x):\n  x $ 1"), "invalid token \"$\": 2:4-2:5");
+    }
+
+    #[test]
+    fn empty_program() {
+        let msg = report("");
+        assert!(msg.starts_with("unexpected end of input"), "{}", msg);
+        assert!(msg.ends_with(": 1:0-1:0"), "{}", msg);
+    }
+}
+
+/* --------------------------- Value Ordering --------------------------- */
+mod value_ord {
+    use super::*;
+    use snake::interp::Value;
+
+    #[test]
+    fn compares_results_directly() {
+        let src = std::fs::read_to_string("examples/add1.adder").unwrap();
+        let (resolver, ast) = snake::compile::frontend(&src).unwrap();
+        let (_, ssa) = snake::compile::middle_end(resolver, ast).unwrap();
+        let result = interp::ssa::Interp::new().run(&ssa, "42".to_string()).unwrap();
+        assert_eq!(result, Value::Int(43));
+        assert_ne!(result, Value::Int(42));
+    }
+
+    #[test]
+    fn orders_ints_numerically() {
+        assert!(Value::Int(-1) < Value::Int(0));
+        assert!(Value::Int(i64::MAX) > Value::Int(i64::MIN));
+        let mut values = vec![Value::Int(3), Value::Int(-7), Value::Int(0)];
+        values.sort();
+        assert_eq!(values, vec![Value::Int(-7), Value::Int(0), Value::Int(3)]);
+        assert_eq!(Value::Int(5).cmp(&Value::Int(5)), std::cmp::Ordering::Equal);
+    }
+}
+
+/* --------------------------- Custom Runtimes --------------------------- */
+mod custom_runtime {
+    use super::*;
+
+    #[test]
+    fn runs_with_given_runtime() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let stub = std::fs::read_to_string("runtime/stub.rs").unwrap();
+        let printed = r#"println!("{}", output);"#;
+        assert!(stub.contains(printed));
+        let stub = stub.replace(printed, r#"println!("{}", output * 2);"#);
+        let rt = tmp_dir.path().join("stub.rs");
+        std::fs::write(&rt, stub).unwrap();
+
+        let mut out = Vec::new();
+        runner::compile_and_run_file_with(
+            Path::new("examples/add1.adder"),
+            tmp_dir.path(),
+            "20",
+            &rt,
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().trim(), "42");
+    }
+
+    #[test]
+    fn keeps_stderr_of_successful_run() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let stub = std::fs::read_to_string("runtime/stub.rs").unwrap();
+        let printed = r#"println!("{}", output);"#;
+        assert!(stub.contains(printed));
+        let stub = stub.replace(printed, r#"println!("{}", output); eprintln!("diagnostic");"#);
+        let rt = tmp_dir.path().join("stub.rs");
+        std::fs::write(&rt, stub).unwrap();
+
+        let exe = tmp_dir.path().join("main.exe");
+        runner::link(&emitted_asm("add1.adder"), &rt, tmp_dir.path(), &exe, false).unwrap();
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+        runner::run_with_stderr(&exe, "20", &mut out, &mut err).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().trim(), "21");
+        assert_eq!(String::from_utf8(err).unwrap(), "diagnostic\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stderr_is_captured_whatever_the_status() {
+        use std::os::unix::fs::PermissionsExt;
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let script = tmp_dir.path().join("script");
+        std::fs::write(&script, "#!/bin/sh\necho out\necho err >&2\nexit $1\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        for (status, ok) in [("0", true), ("3", false)] {
+            let (mut out, mut err) = (Vec::new(), Vec::new());
+            let res = runner::run_with_stderr(&script, status, &mut out, &mut err);
+            assert_eq!(res.is_ok(), ok);
+            assert_eq!(String::from_utf8(out).unwrap(), "out\n");
+            assert_eq!(String::from_utf8(err).unwrap(), "err\n");
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stderr_that_is_not_utf8_is_kept() {
+        use std::os::unix::fs::PermissionsExt;
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let script = tmp_dir.path().join("script");
+        std::fs::write(&script, "#!/bin/sh\nprintf 'bad \\377\\n' >&2\nexit 1\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let res = runner::run(&script, "0", &mut Vec::new());
+        assert!(
+            matches!(&res, Err(snake::compile::Error::Run { stderr, .. }) if stderr == "bad \u{FFFD}\n"),
+            "{:?}",
+            res
+        );
+    }
+}
+
+/* ---------------------------- Missing Tools ---------------------------- */
+mod missing_tools {
+    use super::*;
+    use runner::LinkOptions;
+
+    #[test]
+    fn names_the_missing_assembler() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let options = LinkOptions {
+            assembler: "no-such-assembler".to_string(),
+            ..LinkOptions::default()
+        };
+        let err = runner::link_with_options(
+            &emitted_asm("add1.adder"),
+            Path::new("runtime/stub.rs"),
+            tmp_dir.path(),
+            &tmp_dir.path().join("main.exe"),
+            false,
+            &options,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no-such-assembler not found in PATH; install it or pass --assembler"
+        );
+    }
+}
+
+/* --------------------------- Static Libraries --------------------------- */
+mod archive {
+    use super::*;
+    use std::process::Command;
+
+    /// Whether `tool` can be started at all.
+    fn installed(tool: &str) -> bool {
+        Command::new(tool).arg("--version").output().is_ok()
+    }
+
+    #[test]
+    fn archive_exports_entry() {
+        if !["nasm", "ar", "nm"].iter().all(|tool| installed(tool)) {
+            eprintln!("skipping: needs nasm, ar and nm");
+            return;
+        }
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let lib = tmp_dir.path().join("libadd1.a");
+        runner::build_archive(&emitted_asm("add1.adder"), tmp_dir.path(), &lib).unwrap();
+        let members = Command::new("ar").arg("t").arg(&lib).output().unwrap();
+        assert_eq!(String::from_utf8_lossy(&members.stdout).trim(), "compiled_code.o");
+        let symbols = Command::new("nm").arg(&lib).output().unwrap();
+        let symbols = String::from_utf8_lossy(&symbols.stdout);
+        assert!(
+            symbols.lines().any(|l| l.ends_with(" T entry") || l.ends_with(" T _entry")),
+            "{}",
+            symbols
+        );
+        assert!(!runner::Temps::in_dir(tmp_dir.path()).lib.exists());
+    }
+}
+
+/* --------------------------- Tail Recursion --------------------------- */
+mod tail_recursion {
+    use super::*;
+    use snake::ssa::is_tail_recursive;
+
+    /// Whether the function named `hint` in an example file is
+    /// tail-recursive.
+    fn tail_recursive(f: &str, hint: &str) -> bool {
+        let (_, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", f))).unwrap();
+        let fun = ssa.funs.iter().find(|fun| fun.name.hint() == hint).unwrap();
+        is_tail_recursive(&ssa, &fun.name)
+    }
+
+    #[test]
+    fn peano_sum_loops() {
+        assert!(tail_recursive("peano.boa", "peano_sum"));
+    }
+
+    #[test]
+    fn factorial_calls_itself() {
+        assert!(!tail_recursive("non_tail_factorial.cobra", "factorial"));
+    }
+
+    #[test]
+    fn main_does_not_recurse() {
+        assert!(!tail_recursive("peano.boa", "entry"));
+    }
+}
+
+/* ---------------------------- Label Spelling ---------------------------- */
+mod label_spelling {
+    use super::*;
+    use snake::asm::{interp::Machine, Instr};
+
+    fn emitted(f: &str) -> Vec<Instr> {
+        let (lowerer, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", f))).unwrap();
+        let mut emitter = backend::Emitter::from(lowerer);
+        emitter.emit_prog(&ssa);
+        emitter.to_asm()
+    }
+
+    #[test]
+    fn labels_are_assembler_safe() {
+        for f in ["peano.boa", "non_tail_factorial.cobra", "compare_zero.cobra"] {
+            let asm = emitted(f);
+            let labels: Vec<_> = asm
+                .iter()
+                .filter_map(|i| match i {
+                    Instr::Label(l) => Some(l),
+                    _ => None,
+                })
+                .collect();
+            assert!(labels.iter().all(|l| !l.contains(['#', '@', '%'])), "{:?}", labels);
+            for instr in &asm {
+                if let Instr::Jmp(target) | Instr::JCC(_, target) = instr {
+                    assert!(labels.contains(&target), "{} in {}", target, f);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn jumps_still_resolve() {
+        let run = |f: &str, arg| Machine::new().run(&emitted(f), arg, &mut Vec::new());
+        assert_eq!(run("non_tail_factorial.cobra", 5), Ok(120));
+        assert_eq!(run("peano.boa", 10), Ok(11));
+    }
+}
+
+/* ---------------------------- Function Scope ---------------------------- */
+mod fun_scope {
+    use super::*;
+
+    mk_fail_test!(sibling_branch, "sibling_branch_fun.cobra", r#"function "f" undefined: 2:36"#);
+    mk_fail_test!(after_if, "if_fun_escapes.cobra", r#"function "f" undefined: 3:2"#);
+    mk_fail_test!(outside_body, "inner_fun_escapes.cobra", r#"function "inner" undefined: 6:13"#);
+}
+
+/* -------------------------- Resolver Environment -------------------------- */
+mod dump_env {
+    use snake::frontend::{Bound, Resolver};
+    use snake::parser::ProgParser;
+    use std::process::Command;
+
+    #[test]
+    fn shadowed_variable_is_bound_twice() {
+        let src = "def main(y): let x = 1 in let x = x + y in x";
+        let prog = ProgParser::new().parse(src).unwrap();
+        let mut resolver = Resolver::new().with_bindings();
+        resolver.resolve_prog(prog).unwrap();
+        let xs: Vec<_> = resolver
+            .bindings()
+            .iter()
+            .filter(|(name, _, _)| name == "x")
+            .map(|(_, bound, _)| bound.clone())
+            .collect();
+        assert_eq!(xs.len(), 2);
+        assert!(matches!(xs[0], Bound::Var(_)));
+        assert_ne!(xs[0], xs[1]);
+    }
+
+    #[test]
+    fn off_by_default() {
+        let prog = ProgParser::new().parse("def main(x): x").unwrap();
+        let mut resolver = Resolver::new();
+        resolver.resolve_prog(prog).unwrap();
+        assert!(resolver.bindings().is_empty());
+    }
+
+    #[test]
+    fn dump_shows_original_and_renamed() {
+        let out = Command::new(env!("CARGO_BIN_EXE_snake"))
+            .args(["examples/shadowed_let.cobra", "--dump-env"])
+            .output()
+            .unwrap();
+        assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+        let out = String::from_utf8(out.stdout).unwrap();
+        let xs: Vec<_> = out
+            .lines()
+            .map(|line| line.split('\t').collect::<Vec<_>>())
+            .filter(|fields| fields[1] == "x")
+            .map(|fields| fields[2].to_string())
+            .collect();
+        assert_eq!(xs.len(), 2, "{}", out);
+        assert_ne!(xs[0], xs[1]);
+        assert!(xs.iter().all(|x| x.starts_with('x') && x != "x"), "{}", out);
+    }
+}
+
+/* ------------------------- Predecessor Annotations ------------------------- */
+mod ssa_preds {
+    use super::*;
+    use snake::pretty::ssa_with_preds;
+
+    #[test]
+    fn join_lists_both_arms() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/if_join.cobra")).unwrap();
+        let dump = ssa_with_preds(&ssa);
+        let header = |prefix: &str| {
+            dump.lines()
+                .map(str::trim)
+                .find(|line| line.starts_with(prefix))
+                .unwrap_or_else(|| panic!("no block {} in\n{}", prefix, dump))
+        };
+        let label = |prefix: &str| header(prefix)["block ".len()..].split('(').next().unwrap();
+        let (thn, els) = (label("block thn"), label("block els"));
+        let jn = header("block jn");
+        assert!(jn.ends_with(&format!("; preds: {}, {}", thn, els)), "{}", dump);
+        assert!(!header("block main_tail").contains("preds"), "{}", dump);
+    }
+
+    #[test]
+    fn otherwise_matches_display() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/add1.adder")).unwrap();
+        assert_eq!(ssa_with_preds(&ssa), ssa.to_string());
+    }
+}
+
+/* ----------------------------- Prim Lowering ----------------------------- */
+mod prim_lowering {
+    use super::*;
+    use snake::ast::{Expr, Prim, SurfProg};
+    use snake::frontend::Resolver;
+    use snake::middle_end::{Lowerer, PrimLowering};
+    use snake::parser::ProgParser;
+
+    /// `def main(x): prim(x, 3)`, with as many of `x` and 3 as `prim`
+    /// takes.
+    fn applied(prim: Prim) -> SurfProg {
+        let mut prog = ProgParser::new().parse("def main(x): x").unwrap();
+        let loc = prog.body.loc();
+        let args = [Expr::Var("x".to_string(), loc), Expr::Num(3, loc)];
+        let arity = prim.info().arity;
+        prog.body = Expr::Prim { prim, args: args[..arity].to_vec(), loc };
+        prog
+    }
+
+    #[test]
+    fn each_prim_lowers_to_what_it_means() {
+        for prim in Prim::ALL {
+            let mut resolver = Resolver::new();
+            let ast = resolver.resolve_prog(applied(prim.clone())).unwrap();
+            let ssa = Lowerer::from(resolver).lower_prog(ast.clone());
+            for arg in ["-2", "0", "3", "7"] {
+                let from_ast = interp::ast::Machine::run_prog(&ast, arg.to_string())
+                    .map(|v| v.to_string())
+                    .map_err(|e| e.to_string());
+                let from_ssa = interp::ssa::Interp::new()
+                    .run(&ssa, arg.to_string())
+                    .map(|v| v.to_string())
+                    .map_err(|e| e.to_string());
+                assert_eq!(from_ssa, from_ast, "{:?} on {}", prim, arg);
+            }
+        }
+    }
+
+    #[test]
+    fn binary_lowerings_take_two_arguments() {
+        for prim in Prim::ALL {
+            let binary = matches!(
+                prim.to_ssa(),
+                PrimLowering::Prim2(_) | PrimLowering::Logical(_) | PrimLowering::Cmp(..)
+            );
+            assert_eq!(binary, prim.info().arity == 2, "{:?}", prim);
+        }
+    }
+}
+
+/* ---------------------------- Entry Parameter ---------------------------- */
+mod entry_param {
+    use super::*;
+    use asm::interp::Machine;
+    use asm::{Arg32, Instr, Loc, MovArgs, Reg};
+    use backend::ALLOCATABLE_REGS;
+
+    fn emit(path: &str, regs: &[Reg]) -> Vec<Instr> {
+        let (lowerer, ssa) = runner::emit_ssa(Path::new(path)).unwrap();
+        let mut emitter = backend::Emitter::from(lowerer).with_registers(regs);
+        emitter.emit_prog(&ssa);
+        emitter.to_asm()
+    }
+
+    #[test]
+    fn add1_keeps_its_parameter_in_a_register() {
+        let asm = emit("examples/add1.adder", &ALLOCATABLE_REGS);
+        let start = asm
+            .iter()
+            .position(|i| matches!(i, Instr::Label(l) if l == "compiled_entry"))
+            .unwrap();
+        let shim: Vec<_> =
+            asm[start..].iter().take_while(|i| !matches!(i, Instr::Jmp(_))).collect();
+        assert!(
+            !shim.iter().any(|i| matches!(
+                i,
+                Instr::Push(Arg32::Reg(Reg::Rdi))
+                    | Instr::Pop(Loc::Reg(_))
+                    | Instr::Mov(MovArgs::ToMem(..))
+            )),
+            "{:?}",
+            shim
+        );
+        assert!(shim.iter().any(|i| matches!(i, Instr::Mov(MovArgs::ToReg(_, _)))));
+        assert_eq!(Machine::new().run(&asm, 41, &mut Vec::new()), Ok(42));
+    }
+
+    #[test]
+    fn arguments_are_not_overwritten_before_they_are_read() {
+        let orders: [&[Reg]; 3] =
+            [&ALLOCATABLE_REGS, &[Reg::Rsi, Reg::Rdx, Reg::Rdi], &[Reg::Rdx, Reg::Rbx]];
+        for regs in orders {
+            let asm = emit("examples/local_non_tail_call.cobra", regs);
+            assert_eq!(Machine::new().run(&asm, 1, &mut Vec::new()), Ok(3), "{:?}", regs);
+        }
+    }
+    /// The program whose entry passes `args`, built from its parameter,
+    /// to a block `main` with as many parameters, which returns `ret`
+    /// built from them.
+    fn entry_passing(
+        args: impl FnOnce(&snake::identifiers::VarName) -> Vec<snake::ssa::Immediate>,
+        ret: impl FnOnce(&mut SsaNames, &[snake::identifiers::VarName]) -> snake::ssa::BlockBody,
+    ) -> snake::ssa::Program {
+        use snake::identifiers::*;
+        use snake::ssa::*;
+        let mut names = SsaNames { vars: IdGen::new(), blocks: IdGen::new() };
+        let arg = names.vars.fresh("arg");
+        let args = args(&arg);
+        let params: Vec<_> = args.iter().map(|_| names.vars.fresh("p")).collect();
+        let label = names.blocks.fresh("main");
+        let body = ret(&mut names, &params);
+        Program {
+            externs: Vec::new(),
+            funs: vec![FunBlock {
+                name: FunName::unmangled("entry"),
+                params: vec![arg],
+                body: Branch { target: label.clone(), args },
+            }],
+            blocks: vec![BasicBlock { label, params, body }],
+            locs: Default::default(),
+        }
+    }
+
+    fn emulate(ssa: &snake::ssa::Program, regs: &[Reg], x: i64) -> (Vec<Instr>, i64) {
+        let mut emitter = backend::Emitter::default().with_registers(regs);
+        emitter.emit_prog(ssa);
+        let asm = emitter.to_asm();
+        let res = Machine::new().run(&asm, x, &mut Vec::new()).unwrap();
+        (asm, res)
+    }
+
+    #[test]
+    fn unused_parameter() {
+        let (resolver, ast) = snake::compile::frontend("def main(x): 5").unwrap();
+        let (_, ssa) = snake::compile::middle_end(resolver, ast).unwrap();
+        for regs in [&[][..], &ALLOCATABLE_REGS] {
+            assert_eq!(emulate(&ssa, regs, 9).1, 5, "{:?}", regs);
+        }
+    }
+
+    #[test]
+    fn block_without_parameters_stores_nothing() {
+        use snake::ssa::*;
+        let ssa = entry_passing(
+            |_| Vec::new(),
+            |_, _| BlockBody::Terminator(Terminator::Return(Immediate::Const(5))),
+        );
+        for regs in [&[][..], &ALLOCATABLE_REGS] {
+            let (asm, res) = emulate(&ssa, regs, 9);
+            assert_eq!(res, 5);
+            let start =
+                asm.iter().position(|i| matches!(i, Instr::Label(l) if l == "entry")).unwrap();
+            let shim: Vec<_> =
+                asm[start..].iter().take_while(|i| !matches!(i, Instr::Jmp(_))).collect();
+            assert!(
+                !shim.iter().any(|i| matches!(
+                    i,
+                    Instr::Mov(MovArgs::ToMem(..)) | Instr::Push(Arg32::Reg(Reg::Rdi))
+                )),
+                "{:?}",
+                shim
+            );
+        }
+    }
+
+    #[test]
+    fn arguments_go_where_the_branch_passes_them() {
+        use snake::ssa::*;
+        let ssa = entry_passing(
+            |arg| vec![Immediate::Const(7), Immediate::Var(arg.clone())],
+            |names, params| {
+                let res = names.vars.fresh("res");
+                BlockBody::Operation {
+                    dest: res.clone(),
+                    op: Operation::Prim2(
+                        Prim2::Sub,
+                        Immediate::Var(params[1].clone()),
+                        Immediate::Var(params[0].clone()),
+                    ),
+                    next: Box::new(BlockBody::Terminator(Terminator::Return(Immediate::Var(
+                        res,
+                    )))),
+                }
+            },
+        );
+        let orders: [&[Reg]; 4] = [&[], &ALLOCATABLE_REGS, &[Reg::Rdi, Reg::Rsi], &[Reg::Rsi]];
+        for regs in orders {
+            assert_eq!(emulate(&ssa, regs, 10).1, 3, "{:?}", regs);
+        }
+        assert_eq!(interp::ssa::Interp::new().run(&ssa, "10".to_string()).unwrap().to_string(), "3");
+    }
+}
+
+/* ------------------------------ Error Limits ------------------------------ */
+mod max_errors {
+    use snake::compile;
+    use snake::frontend::{CompileErr, Resolver, MAX_ERRORS};
+    use snake::parser::ProgParser;
+
+    /// A program using `n` unbound variables.
+    fn unbound(n: usize) -> String {
+        let vars: Vec<_> = (0..n).map(|i| format!("v{}", i)).collect();
+        format!("def main(x): {}", vars.join(" + "))
+    }
+
+    fn resolve_all(src: &str, resolver: Resolver) -> Vec<CompileErr> {
+        let prog = ProgParser::new().parse(src).unwrap();
+        let mut resolver = resolver;
+        resolver.resolve_prog_all(prog).unwrap_err()
+    }
+
+    #[test]
+    fn reports_at_most_the_cap() {
+        let errs = resolve_all(&unbound(3 * MAX_ERRORS), Resolver::new());
+        assert_eq!(errs.len(), MAX_ERRORS + 1);
+        for (i, err) in errs[..MAX_ERRORS].iter().enumerate() {
+            assert!(
+                matches!(err, CompileErr::UnboundVariable(v, _) if *v == format!("v{}", i)),
+                "{:?}",
+                err
+            );
+        }
+        assert!(matches!(errs[MAX_ERRORS], CompileErr::ErrorsSuppressed(_)));
+
+        let msg = compile::compile(&unbound(3 * MAX_ERRORS)).unwrap_err().to_string();
+        assert_eq!(msg.lines().count(), MAX_ERRORS + 1, "{}", msg);
+        assert!(msg.lines().last().unwrap().contains("further errors suppressed"), "{}", msg);
+    }
+
+    #[test]
+    fn cap_is_configurable() {
+        let errs = resolve_all(&unbound(10), Resolver::new().with_max_errors(3));
+        assert_eq!(errs.len(), 4, "{:?}", errs);
+        assert!(matches!(errs[3], CompileErr::ErrorsSuppressed(_)));
+    }
+
+    #[test]
+    fn no_note_under_the_cap() {
+        let errs = resolve_all("def main(x): let y = f(1) in z + y", Resolver::new());
+        assert!(
+            matches!(
+                errs[..],
+                [CompileErr::UnboundFunction(ref f, _), CompileErr::UnboundVariable(ref z, _)]
+                    if f == "f" && z == "z"
+            ),
+            "{:?}",
+            errs
+        );
+    }
+
+    #[test]
+    fn resolve_prog_stops_at_the_first() {
+        let prog = ProgParser::new().parse(&unbound(5)).unwrap();
+        let err = Resolver::new().resolve_prog(prog).unwrap_err();
+        assert!(matches!(err, CompileErr::UnboundVariable(v, _) if v == "v0"));
+    }
+}
+
+/* -------------------------- Top-Level Functions -------------------------- */
+mod top_level_funs {
+    use super::*;
+    use snake::parser::ProgParser;
+
+    mk_frontend_test!(helpers_frontend, "top_level_funs.cobra", "5", "11");
+    mk_middle_end_test!(helpers_middle_end, "top_level_funs.cobra", "4", "8");
+    mk_fail_test!(main_param_out_of_scope, "top_level_uses_main_param.cobra", "\"x\" unbound");
+
+    #[test]
+    fn helpers_emulated() {
+        let (lowerer, ssa) = runner::emit_ssa(Path::new("examples/top_level_funs.cobra")).unwrap();
+        let mut emitter = backend::Emitter::from(lowerer);
+        emitter.emit_prog(&ssa);
+        let asm = emitter.to_asm();
+        let run = |arg| asm::interp::Machine::new().run(&asm, arg, &mut Vec::new());
+        assert_eq!(run(5), Ok(11));
+        assert_eq!(run(6), Ok(12));
+    }
+
+    #[test]
+    fn main_must_come_last() {
+        let err = ProgParser::new().parse("def main(x): x\ndef f(y): y").unwrap_err();
+        assert!(format!("{:?}", err).contains("main"), "{:?}", err);
+    }
+
+    #[test]
+    fn printed_program_parses_again() {
+        let src = std::fs::read_to_string("examples/top_level_funs.cobra").unwrap();
+        let prog = ProgParser::new().parse(&src).unwrap();
+        let again = ProgParser::new().parse(&prog.to_string()).unwrap();
+        assert_eq!(again.funs.len(), 3);
+        assert_eq!(again.to_string(), prog.to_string());
+    }
+}
+
+/* --------------------------- Lowering Order --------------------------- */
+mod lowering_order {
+    use super::*;
+
+    #[test]
+    fn main_first_then_source_order() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/nested_fun_order.cobra")).unwrap();
+        let funs: Vec<_> = ssa.funs.iter().map(|f| f.name.hint().to_string()).collect();
+        let blocks: Vec<_> = ssa.blocks.iter().map(|b| b.label.hint().to_string()).collect();
+        assert_eq!(funs, ["entry", "f", "g", "k", "h"]);
+        assert_eq!(blocks, ["main_tail", "f_tail", "g_tail", "k_tail", "h_tail"]);
+    }
+
+    #[test]
+    fn top_level_functions_follow_main() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/top_level_funs.cobra")).unwrap();
+        let funs: Vec<_> = ssa.funs.iter().map(|f| f.name.hint().to_string()).collect();
+        assert_eq!(funs, ["entry", "double", "is_even", "is_odd"]);
+    }
+
+    #[test]
+    fn same_program_same_ssa() {
+        let lower = || runner::emit_ssa(Path::new("examples/nested_fun_order.cobra")).unwrap().1;
+        assert_eq!(lower().to_string(), lower().to_string());
+    }
+
+    /// `g` captures `x` through `f`, which captured it from `main`, but
+    /// takes it only once.
+    #[test]
+    fn captured_variables_are_passed_once() {
+        let src = "def main(x):\n  def f(y):\n    def g(z): x + y + z in\n    g(1)\n  in\n  f(2)";
+        let (resolver, ast) = snake::compile::frontend(src).unwrap();
+        let (_, ssa) = snake::compile::middle_end(resolver, ast).unwrap();
+        let params = |hint: &str| -> Vec<_> {
+            let block = ssa.blocks_recursive().find(|b| b.label.hint() == hint).unwrap();
+            block.params.iter().map(|p| p.hint().to_string()).collect()
+        };
+        assert_eq!(params("f_tail"), ["y", "x"]);
+        assert_eq!(params("g_tail"), ["z", "x", "y"]);
+        let value = interp::ssa::Interp::new().run(&ssa, "10".to_string()).unwrap();
+        assert_eq!(value.to_string(), "13");
+    }
+}
+
+/* ---------------------------- Sign Extension ---------------------------- */
+mod cqo {
+    use snake::asm::interp::Machine;
+    use snake::asm::{instr_to_string, Arg64, Instr, MovArgs, Reg};
+
+    #[test]
+    fn formats_as_cqo() {
+        assert_eq!(instr_to_string(&Instr::Cqo).trim(), "cqo");
+    }
+
+    /// rdx after sign-extending `n` from rax.
+    fn high_word(n: i64) -> i64 {
+        let instrs = vec![
+            Instr::Global("entry".to_string()),
+            Instr::Label("entry".to_string()),
+            Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Signed(n))),
+            Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Signed(7))),
+            Instr::Cqo,
+            Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::Rdx))),
+            Instr::Ret,
+        ];
+        Machine::new().run(&instrs, 0, &mut Vec::new()).unwrap()
+    }
+
+    #[test]
+    fn fills_rdx_with_the_sign_of_rax() {
+        assert_eq!(high_word(5), 0);
+        assert_eq!(high_word(0), 0);
+        assert_eq!(high_word(-5), -1);
+        assert_eq!(high_word(i64::MIN), -1);
+    }
+}
+
+/* --------------------------------- Traps -------------------------------- */
+mod ud2 {
+    use super::*;
+    use snake::asm::interp::{Machine, MachineErr};
+    use snake::asm::{instr_to_string, Instr};
+
+    #[test]
+    fn formats_as_ud2() {
+        assert_eq!(instr_to_string(&Instr::Ud2).trim(), "ud2");
+    }
+
+    #[test]
+    fn executing_it_traps() {
+        let instrs = vec![
+            Instr::Global("entry".to_string()),
+            Instr::Label("entry".to_string()),
+            Instr::Ud2,
+            Instr::Ret,
+        ];
+        let res = Machine::new().run(&instrs, 0, &mut Vec::new());
+        assert!(matches!(res, Err(MachineErr::Trap)));
+    }
+
+    /// Each block ending after a call to `error` is followed by a trap
+    /// rather than whatever code comes next.
+    #[test]
+    fn unreachable_emits_ud2() {
+        let asm = emitted_asm("error_every_arm.cobra");
+        let lines: Vec<_> = asm.lines().map(str::trim).collect();
+        let traps: Vec<_> = (0..lines.len()).filter(|&ix| lines[ix] == "ud2").collect();
+        assert_eq!(traps.len(), 2, "{}", asm);
+        for ix in traps {
+            assert_eq!(lines[ix - 1], "call snake_error", "{}", asm);
+        }
+    }
+}
+
+/* ------------------------------ CFI Directives ---------------------------- */
+mod cfi {
+    use super::*;
+    use snake::asm::{instr_to_string, Arg32, Arg64, BinArgs, Instr, MovArgs, Reg};
+    use snake::asm::interp::Machine;
+    use snake::ssa::IntRepr;
+
+    fn emit(file: &str, repr: IntRepr, cfi: bool) -> Vec<snake::asm::Instr> {
+        let (lowerer, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", file))).unwrap();
+        let mut emitter = backend::Emitter::from(lowerer).with_repr(repr);
+        if cfi {
+            emitter = emitter.with_cfi();
+        }
+        emitter.emit_prog(&ssa);
+        emitter.to_asm()
+    }
+
+    fn lines(file: &str, repr: IntRepr) -> Vec<String> {
+        emit(file, repr, true).iter().map(|i| instr_to_string(i).trim().to_string()).collect()
+    }
+
+    #[test]
+    fn every_function_is_one_procedure() {
+        for repr in [IntRepr::Untagged, IntRepr::Tagged] {
+            let lines = lines("local_non_tail_call.cobra", repr);
+            let mut open = false;
+            for line in &lines {
+                match line.as_str() {
+                    ".cfi_startproc" => assert!(!open, "nested procedure in {:#?}", lines),
+                    ".cfi_endproc" => assert!(open, "unmatched end in {:#?}", lines),
+                    _ => continue,
+                }
+                open = !open;
+            }
+            assert!(!open, "unterminated procedure in {:#?}", lines);
+            for fun in ["entry:", "foo.f0:"] {
+                let ix = lines.iter().position(|l| l == fun).unwrap();
+                assert_eq!(lines[ix + 1], ".cfi_startproc", "{:?}: {:#?}", repr, lines);
+            }
+        }
+    }
+
+    /// Where the stack pointer, frame pointer and CFA are in a procedure,
+    /// as offsets from the stack pointer on entry.
+    #[derive(Clone, Debug)]
+    struct Frame {
+        rsp: i64,
+        rbp: Option<i64>,
+        cfa: (Reg, i64),
+        pushed_rbp: Option<i64>,
+        saved_rbp: Option<i64>,
+    }
+
+    impl Frame {
+        fn entry() -> Self {
+            Frame { rsp: 0, rbp: None, cfa: (Reg::Rsp, 8), pushed_rbp: None, saved_rbp: None }
+        }
+
+        fn base(&self, reg: Reg) -> i64 {
+            match reg {
+                Reg::Rsp => self.rsp,
+                _ => self.rbp.expect("the CFA is based on rbp before it is set"),
+            }
+        }
+    }
+
+    /// Follow the CFI directives of `asm` alongside the instructions that
+    /// move the stack and frame pointers, checking that the CFA is the
+    /// stack pointer before the call at every instruction, once the
+    /// directives for the one before it are in, and that rbp is said to be
+    /// saved where it was pushed.
+    fn check_cfa(asm: &[Instr], what: &str) {
+        let (mut frame, mut remembered, mut after_ret) = (Frame::entry(), Vec::new(), false);
+        for (ix, instr) in asm.iter().enumerate() {
+            let at = || format!("{} at {}: {:#?}", what, ix, &asm[ix.saturating_sub(8)..=ix]);
+            if !after_ret && !matches!(instr, Instr::Directive(_)) {
+                let (base, offset) = frame.cfa;
+                assert_eq!(frame.base(base) + offset, 8, "{}", at());
+            }
+            match instr {
+                Instr::Directive(d) => {
+                    let (name, args) = d.split_once(' ').unwrap_or((d, ""));
+                    let args: Vec<&str> = args.split(", ").collect();
+                    let reg = |s: &str| if s == "rbp" { Reg::Rbp } else { Reg::Rsp };
+                    match name {
+                        ".cfi_startproc" => frame = Frame::entry(),
+                        ".cfi_def_cfa" => {
+                            let (base, offset) = (reg(args[0]), args[1].parse().unwrap());
+                            if base == Reg::Rbp && frame.rbp.is_none() {
+                                // a procedure in a frame some other one set
+                                // up, which saved rbp where rbp points
+                                frame.rbp = Some(8 - offset);
+                                frame.pushed_rbp = frame.rbp;
+                            }
+                            frame.cfa = (base, offset);
+                        }
+                        ".cfi_def_cfa_offset" => frame.cfa.1 = args[0].parse().unwrap(),
+                        ".cfi_adjust_cfa_offset" => {
+                            frame.cfa.1 += args[0].parse::<i64>().unwrap()
+                        }
+                        ".cfi_def_cfa_register" => {
+                            let base = reg(args[0]);
+                            frame.cfa.1 += frame.base(frame.cfa.0) - frame.base(base);
+                            frame.cfa.0 = base;
+                        }
+                        ".cfi_offset" => {
+                            frame.saved_rbp = Some(8 + args[1].parse::<i64>().unwrap());
+                            assert_eq!(frame.saved_rbp, frame.pushed_rbp, "{}", at());
+                        }
+                        ".cfi_restore" => frame.saved_rbp = None,
+                        ".cfi_remember_state" => remembered.push(frame.clone()),
+                        ".cfi_restore_state" => {
+                            frame = remembered.pop().unwrap();
+                            after_ret = false;
+                        }
+                        _ => {}
+                    }
+                }
+                Instr::Push(arg) => {
+                    frame.rsp -= 8;
+                    if *arg == Arg32::Reg(Reg::Rbp) {
+                        frame.pushed_rbp = Some(frame.rsp);
+                    }
+                }
+                Instr::Pop(_) => frame.rsp += 8,
+                Instr::Sub(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(n))) => frame.rsp -= *n as i64,
+                Instr::Add(BinArgs::ToReg(Reg::Rsp, Arg32::Signed(n))) => frame.rsp += *n as i64,
+                Instr::Mov(MovArgs::ToReg(Reg::Rbp, Arg64::Reg(Reg::Rsp))) => {
+                    frame.rbp = Some(frame.rsp)
+                }
+                Instr::Mov(MovArgs::ToReg(Reg::Rsp, Arg64::Reg(Reg::Rbp))) => {
+                    frame.rsp = frame.base(Reg::Rbp)
+                }
+                Instr::Ret => after_ret = true,
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn cfa_follows_the_prologue_and_epilogue() {
+        for file in ["local_non_tail_call.cobra", "fib_non_tail.cobra", "extern_big_ten.cobra"] {
+            for repr in [IntRepr::Untagged, IntRepr::Tagged] {
+                check_cfa(&emit(file, repr, true), &format!("{} ({:?})", file, repr));
+            }
+        }
+    }
+
+    #[test]
+    fn pushing_rbp_moves_the_cfa_offset_then_rbp_becomes_the_base() {
+        let asm = emit("local_non_tail_call.cobra", IntRepr::Untagged, true);
+        let push = asm.iter().position(|i| *i == Instr::Push(Arg32::Reg(Reg::Rbp))).unwrap();
+        let directives = |from: usize| -> Vec<String> {
+            asm[from + 1..]
+                .iter()
+                .map_while(|i| match i {
+                    Instr::Directive(d) => Some(d.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(directives(push), [".cfi_def_cfa_offset 16", ".cfi_offset rbp, -16"]);
+        assert_eq!(asm[push + 3], Instr::Mov(MovArgs::ToReg(Reg::Rbp, Arg64::Reg(Reg::Rsp))));
+        assert_eq!(directives(push + 3), [".cfi_def_cfa_register rbp"]);
+    }
+
+    #[test]
+    fn off_by_default() {
+        let asm = emit("local_non_tail_call.cobra", IntRepr::Untagged, false);
+        assert!(!asm.iter().any(|i| instr_to_string(i).contains(".cfi")));
+    }
+
+    #[test]
+    fn rejected_when_the_code_would_be_assembled() {
+        for args in [&["-t", "exe"][..], &["-t", "lib"], &["-x", "5"]] {
+            let out = std::process::Command::new(env!("CARGO_BIN_EXE_snake"))
+                .args(["examples/add1.adder", "--cfi"])
+                .args(args)
+                .output()
+                .unwrap();
+            assert!(!out.status.success(), "{:?}", args);
+            let stderr = String::from_utf8_lossy(&out.stderr);
+            assert!(stderr.contains("--cfi is only for assembly listings"), "{:?}: {}", args, stderr);
+        }
+    }
+
+    #[test]
+    fn emulated_code_is_unchanged() {
+        for repr in [IntRepr::Untagged, IntRepr::Tagged] {
+            let run = |cfi| {
+                let asm = emit("local_non_tail_call.cobra", repr, cfi);
+                Machine::new().run(&asm, 10, &mut Vec::new()).unwrap()
+            };
+            assert_eq!(run(true), run(false), "{:?}", repr);
+        }
+    }
+}
+
+/* ------------------------------ Strict SSA ------------------------------ */
+mod strict_ssa {
+    use super::*;
+    use interp::ssa::Interp;
+    use snake::ssa::*;
+
+    fn run(prog: &Program, x: i64, strict: bool) -> Result<String, String> {
+        let interp = Interp::new();
+        let mut interp = if strict { interp.with_strict() } else { interp };
+        interp.run(prog, x.to_string()).map(|v| v.to_string()).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn branch_with_too_few_arguments() {
+        let prog = main_block_program(|names, _x| {
+            let (jn, y) = (names.blocks.fresh("jn"), names.vars.fresh("y"));
+            BlockBody::SubBlocks {
+                blocks: vec![BasicBlock {
+                    label: jn.clone(),
+                    params: vec![y.clone()],
+                    body: BlockBody::Terminator(Terminator::Return(Immediate::Var(y))),
+                }],
+                next: Box::new(BlockBody::Terminator(Terminator::Branch(Branch {
+                    target: jn,
+                    args: Vec::new(),
+                }))),
+            }
+        });
+        assert_eq!(run(&prog, 1, false), Err("unbound variable: y%2".to_string()));
+        assert_eq!(
+            run(&prog, 1, true),
+            Err("branching to block jn#1 with wrong arity: expected 1, got 0".to_string())
+        );
+    }
+
+    #[test]
+    fn redefining_a_live_variable() {
+        let prog = main_block_program(|_names, x| BlockBody::Operation {
+            dest: x.clone(),
+            op: Operation::Immediate(Immediate::Const(7)),
+            next: Box::new(BlockBody::Terminator(Terminator::Return(Immediate::Var(x)))),
+        });
+        assert_eq!(run(&prog, 1, false), Ok("7".to_string()));
+        assert_eq!(
+            run(&prog, 1, true),
+            Err("variable x%1 is defined again while in scope (defined in block main#0)"
+                .to_string())
+        );
+    }
+
+    #[test]
+    fn lowered_programs_are_well_formed() {
+        for file in ["nested_fun_order.cobra", "top_level_funs.cobra", "fib_non_tail.cobra"] {
+            let (_, ssa) = runner::emit_ssa(&Path::new("examples").join(file)).unwrap();
+            assert_eq!(run(&ssa, 5, true), run(&ssa, 5, false), "{}", file);
+            assert!(run(&ssa, 5, true).is_ok(), "{}", file);
+        }
+    }
+}
+
+/* ------------------------------- Pipeline ------------------------------- */
+mod pipeline {
+    use super::*;
+    use snake::compile::{self, Pipeline};
+    use snake::parser::ProgParser;
+
+    #[test]
+    fn default_matches_free_functions() {
+        let pipeline = Pipeline::new();
+        for file in ["fib_non_tail.cobra", "top_level_funs.cobra", "nested_fun_order.cobra"] {
+            let src = std::fs::read_to_string(Path::new("examples").join(file)).unwrap();
+            let expected = compile::compile(&src).unwrap();
+            assert_eq!(pipeline.compile(&src).unwrap(), expected, "{}", file);
+        }
+    }
+
+    #[test]
+    fn default_matches_free_functions_on_errors() {
+        let src = "def main(x):\n  let y = 1 in\n  z";
+        assert_eq!(
+            Pipeline::new().compile(src).unwrap_err().to_string(),
+            compile::compile(src).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn middle_end_runs_the_enabled_passes_in_order() {
+        use snake::bools::elide_bool_coercions_with_stats;
+        use snake::dce::eliminate_dead_code_with_stats;
+        use snake::licm::hoist_invariants_with_stats;
+
+        let src = std::fs::read_to_string("examples/loop_invariant.cobra").unwrap();
+        let pipeline = Pipeline::new()
+            .with_elide_bool_coercions()
+            .with_hoist_invariants()
+            .with_eliminate_dead_code();
+        let (resolver, ast) = pipeline.frontend(&src).unwrap();
+        let (_, lowered) = pipeline.lower(resolver, ast);
+        let (ssa, bools) = elide_bool_coercions_with_stats(lowered.clone());
+        let (ssa, licm) = hoist_invariants_with_stats(ssa);
+        let (ssa, dce) = eliminate_dead_code_with_stats(ssa);
+
+        let (optimized, report) = pipeline.optimize(lowered);
+        assert_eq!(optimized, ssa);
+        let expected =
+            compile::OptReport { fold: None, bools: Some(bools), licm: Some(licm), dce: Some(dce) };
+        assert_eq!(report, expected);
+        assert!(licm.hoisted > 0, "nothing to hoist in\n{}", optimized);
+
+        let (resolver, ast) = pipeline.frontend(&src).unwrap();
+        assert_eq!(pipeline.middle_end(resolver, ast).unwrap().1, ssa);
+    }
+
+    #[test]
+    fn opt_report_lists_the_passes_that_ran() {
+        let src = "def main(x): add1(2) * x";
+        let pipeline = Pipeline::new().with_fold_constants().with_eliminate_dead_code();
+        let mut resolver = pipeline.resolver();
+        let ast = resolver.resolve_prog(ProgParser::new().parse(src).unwrap()).unwrap();
+        let (folded, report) = pipeline.fold(ast);
+        assert_eq!(report.to_string(), "fold: folded 1 primitives\n");
+        let (_, ssa) = pipeline.lower(resolver, folded);
+        let (_, report) = pipeline.optimize(ssa);
+        assert_eq!(report.to_string(), "dce: removed 0 operations\n");
+    }
+}
+
+/* ---------------------- Loop-Invariant Code Motion ---------------------- */
+mod licm {
+    use super::*;
+    use snake::compile;
+    use snake::licm::hoist_invariants_with_stats;
+    use snake::ssa::{BasicBlock, BlockBody, Operation, Prim2, Program};
+
+    fn lower(src: &str) -> Program {
+        let (resolver, ast) = compile::frontend(src).unwrap();
+        compile::middle_end(resolver, ast).unwrap().1
+    }
+
+    fn run(prog: &Program, x: i64) -> String {
+        interp::ssa::Interp::new().with_strict().run(prog, x.to_string()).unwrap().to_string()
+    }
+
+    /// The blocks some block they dominate branches back to.
+    fn headers(prog: &Program) -> Vec<&BasicBlock> {
+        let loops = prog.loops();
+        prog.blocks_recursive().filter(|b| loops.iter().any(|l| l.header == b.label)).collect()
+    }
+
+    /// Whether `block`, leaving out the blocks nested in it, computes `pred`.
+    fn computes(block: &BasicBlock, pred: impl Fn(&Operation) -> bool) -> bool {
+        let mut body = &block.body;
+        loop {
+            match body {
+                BlockBody::Terminator(_) => return false,
+                BlockBody::Operation { op, .. } if pred(op) => return true,
+                BlockBody::Operation { next, .. } | BlockBody::SubBlocks { next, .. } => {
+                    body = next
+                }
+            }
+        }
+    }
+
+    fn is_mul(op: &Operation) -> bool {
+        matches!(op, Operation::Prim2(Prim2::Mul, _, _))
+    }
+
+    #[test]
+    fn constant_subexpression_runs_once() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/loop_invariant.cobra")).unwrap();
+        let [header] = headers(&ssa)[..] else { panic!("expected one loop:\n{}", ssa) };
+        assert!(header.blocks_recursive().any(|b| computes(b, is_mul)), "{}", ssa);
+
+        let (hoisted, stats) = hoist_invariants_with_stats(ssa.clone());
+        assert!(stats.hoisted > 0, "{:?}", stats);
+        let [header] = headers(&hoisted)[..] else { panic!("expected one loop:\n{}", hoisted) };
+        assert!(!header.blocks_recursive().any(|b| computes(b, is_mul)), "{}", hoisted);
+        // the preheader, which nothing branches back to, computes it instead
+        let preheader = hoisted.blocks_recursive().find(|b| computes(b, is_mul)).unwrap();
+        assert!(!headers(&hoisted).contains(&preheader), "{}", hoisted);
+        for x in 0..5 {
+            assert_eq!(run(&hoisted, x), run(&ssa, x), "{}", x);
+        }
+        assert_eq!(run(&hoisted, 3), "90");
+    }
+
+    #[test]
+    fn varying_values_and_calls_stay_in_the_loop() {
+        let src = "extern print(x)\n\
+                   def main(n):\n\
+                   \x20 def loop(i):\n\
+                   \x20   if i == 0: 0 else: let p = print(7) in loop(i - 1)\n\
+                   \x20 in\n\
+                   \x20 loop(n)";
+        let (hoisted, _) = hoist_invariants_with_stats(lower(src));
+        let [header] = headers(&hoisted)[..] else { panic!("expected one loop:\n{}", hoisted) };
+        let is_call = |op: &Operation| matches!(op, Operation::Call { .. });
+        let is_sub = |op: &Operation| matches!(op, Operation::Prim2(Prim2::Sub, _, _));
+        assert!(header.blocks_recursive().any(|b| computes(b, is_call)), "{}", hoisted);
+        assert!(header.blocks_recursive().any(|b| computes(b, is_sub)), "{}", hoisted);
+    }
+
+    #[test]
+    fn nothing_to_hoist_without_loops() {
+        let ssa = lower("def main(x): let y = x * 2 in y + 1");
+        let (hoisted, stats) = hoist_invariants_with_stats(ssa.clone());
+        assert_eq!(stats.hoisted, 0);
+        assert_eq!(hoisted, ssa);
+    }
+}
+
+/* ------------------------------ Natural Loops ----------------------------- */
+mod loops {
+    use super::*;
+    use snake::ssa::Loop;
+
+    /// The hints of the header, latch and sorted body of `loop_`, which
+    /// do not depend on how the compiler numbers its names.
+    fn hints(loop_: &Loop) -> (&str, &str, Vec<&str>) {
+        let mut body: Vec<&str> = loop_.body.iter().map(|b| b.hint()).collect();
+        body.sort();
+        (loop_.header.hint(), loop_.latch.hint(), body)
+    }
+
+    fn loops_of(file: &str) -> Vec<Loop> {
+        let (_, ssa) = runner::emit_ssa(&Path::new("examples").join(file)).unwrap();
+        ssa.loops()
+    }
+
+    #[test]
+    fn peano_tail_recursion() {
+        let loops = loops_of("peano.boa");
+        let loops: Vec<_> = loops.iter().map(hints).collect();
+        assert_eq!(loops, [("peano_sum_tail", "els", vec!["els", "peano_sum_tail"])]);
+    }
+
+    #[test]
+    fn one_loop_per_back_edge() {
+        let loops = loops_of("tail_recursive_main.cobra");
+        let latches: Vec<&str> = loops.iter().map(|l| l.latch.hint()).collect();
+        assert_eq!(latches, ["thn", "els"]);
+        assert_ne!(loops[0].latch, loops[1].latch);
+        for loop_ in &loops {
+            let (header, latch, body) = hints(loop_);
+            assert_eq!(header, "main_tail");
+            let mut expected = vec!["els", latch, header];
+            expected.sort();
+            assert_eq!(body, expected);
+            assert!(loop_.body.contains(&loop_.latch) && loop_.body.contains(&loop_.header));
+        }
+    }
+
+    #[test]
+    fn no_loops_without_tail_calls() {
+        assert!(loops_of("if_join.cobra").is_empty());
+    }
+}
+
+/* ------------------------------- Id Offset ------------------------------ */
+mod id_offset {
+    use super::*;
+    use snake::compile::Pipeline;
+
+    const OFFSET: usize = 7919;
+
+    /// What the SSA interpreter and the emulated assembly give for `src`
+    /// with argument `x`, or `None` if it does not compile.
+    fn results(pipeline: &Pipeline, src: &str, x: i64) -> Option<(String, String)> {
+        let (resolver, ast) = pipeline.frontend(src).ok()?;
+        let (lowerer, ssa) = pipeline.middle_end(resolver, ast).ok()?;
+        // what the program prints is compared in the emulated run
+        let interp = interp::ssa::Interp::new()
+            .with_input(std::io::Cursor::new("4\n5\n"))
+            .with_output(std::io::sink())
+            .with_extern("big_fun_nine", 9, big_fun)
+            .with_extern("big_fun_ten", 10, big_fun)
+            .run(&ssa, x.to_string())
+            .map(|v| v.to_string())
+            .map_err(|e| e.to_string());
+        let mut emitter = pipeline.emitter(lowerer, &ssa).ok()?;
+        emitter.emit_prog(&ssa);
+        let mut out = Vec::new();
+        let emulated = asm::interp::Machine::new()
+            .with_input(std::io::Cursor::new("4\n5\n"))
+            .with_extern("big_fun_nine", 9, big_fun)
+            .with_extern("big_fun_ten", 10, big_fun)
+            .run(&emitter.to_asm(), x, &mut out)
+            .map(|v| format!("{}{}", String::from_utf8(out).unwrap(), v));
+        // errors name variables, whose indices differ
+        Some((
+            interp.unwrap_or_else(|_| "error".to_string()),
+            emulated.unwrap_or_else(|_| "error".to_string()),
+        ))
+    }
+
+    #[test]
+    fn add1_with_advanced_ids() {
+        let src = runner::read_file(Path::new("examples/add1.adder")).unwrap();
+        let pipeline = Pipeline::new().with_id_offset(OFFSET);
+        let asm = pipeline.compile(&src).unwrap();
+        assert!(asm.contains(&format!("{}", OFFSET)), "{}", asm);
+        assert_eq!(results(&pipeline, &src, 41), Some(("42".to_string(), "42".to_string())));
+    }
+
+    #[test]
+    fn examples_agree_with_advanced_ids() {
+        let mut files: Vec<_> =
+            std::fs::read_dir("examples").unwrap().map(|e| e.unwrap().path()).collect();
+        files.sort();
+        for file in files.iter().filter(|f| f.is_file()) {
+            let src = runner::read_file(file).unwrap();
+            assert_eq!(
+                results(&Pipeline::new().with_id_offset(OFFSET), &src, 7),
+                results(&Pipeline::new(), &src, 7),
+                "{}",
+                file.display()
+            );
+        }
+    }
+}
+
+/* ---------------------------- Identifier Parts --------------------------- */
+mod identifier_parts {
+    use snake::identifiers::{BlockName, FunName, IdGen, ParseIdErr, VarName};
+
+    #[test]
+    fn var_from_parts_round_trips() {
+        let x = VarName::from_parts(3, "x");
+        assert_eq!(x.to_string(), "x%3");
+        assert_eq!(x.to_string().parse(), Ok(x));
+    }
+
+    #[test]
+    fn parts_match_generated_names() {
+        let (mut vars, mut funs, mut blocks) = (IdGen::new(), IdGen::new(), IdGen::new());
+        let var: VarName = vars.fresh("y");
+        let fun: FunName = funs.fresh("f");
+        let block: BlockName = blocks.fresh("b");
+        assert_eq!(VarName::from_parts(0, "y"), var);
+        assert_eq!(FunName::mangled(0, "f"), fun);
+        assert_eq!(BlockName::from_parts(0, "b"), block);
+        assert_eq!(fun.to_string().parse(), Ok(fun));
+        assert_eq!(block.to_string().parse(), Ok(block));
+    }
+
+    #[test]
+    fn index_is_the_one_generated() {
+        let var: VarName = IdGen::starting_at(42).fresh("x");
+        let fun: FunName = IdGen::starting_at(43).fresh("f");
+        let block: BlockName = IdGen::starting_at(44).fresh("b");
+        assert_eq!(var.index(), 42);
+        assert_eq!(fun.index(), Some(43));
+        assert_eq!(block.index(), 44);
+        assert_eq!(FunName::unmangled("print").index(), None);
+        assert!(FunName::unmangled("print").is_unmangled());
+    }
+
+    #[test]
+    fn names_without_an_index_are_unmangled_or_rejected() {
+        assert_eq!("print".parse(), Ok(FunName::unmangled("print")));
+        assert_eq!("x".parse::<VarName>(), Err(ParseIdErr("x".to_string())));
+        assert!("b#one".parse::<BlockName>().is_err());
+        assert!("f@".parse::<FunName>().is_err());
+    }
+}
+
+/* --------------------------- Trivial Programs --------------------------- */
+mod trivial_programs {
+    use super::*;
+    use snake::asm::interp::Machine;
+    use snake::backend::{Emitter, ALLOCATABLE_REGS};
+    use snake::ssa::IntRepr;
+
+    // the constant never reads its parameter, which is stored all the same
+    mk_test!(constant, "constant.adder", "-3", "5");
+    mk_frontend_test!(constant_frontend, "constant.adder", "-3", "5");
+    mk_middle_end_test!(constant_middle_end, "constant.adder", "-3", "5");
+    mk_test!(identity, "identity.adder", "-7", "-7");
+    mk_frontend_test!(identity_frontend, "identity.adder", "-7", "-7");
+    mk_middle_end_test!(identity_middle_end, "identity.adder", "-7", "-7");
+
+    #[test]
+    fn emulated_with_every_repr_and_allocation() {
+        for (file, expected) in [("constant.adder", [5, 5, 5]), ("identity.adder", [-7, 0, 42])] {
+            let (_, ssa) = runner::emit_ssa(&Path::new("examples").join(file)).unwrap();
+            for repr in [IntRepr::Untagged, IntRepr::Tagged] {
+                for regs in [&[][..], &ALLOCATABLE_REGS[..]] {
+                    let mut emitter = Emitter::default().with_repr(repr).with_registers(regs);
+                    emitter.emit_prog(&ssa);
+                    let instrs = emitter.to_asm();
+                    for (arg, expected) in [-7, 0, 42].into_iter().zip(expected) {
+                        let got = Machine::new().run(&instrs, arg, &mut Vec::new()).unwrap();
+                        assert_eq!(got, expected, "{} {:?} {} {}", file, repr, regs.len(), arg);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/* -------------------------- Pretty Instructions -------------------------- */
+mod instrs_pretty {
+    use snake::asm::{instrs_pretty, instrs_to_string, Arg64, Instr, MovArgs, Reg};
+
+    #[test]
+    fn numbers_each_instruction() {
+        let instrs = vec![
+            Instr::Label("entry".to_string()),
+            Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Signed(5))),
+            Instr::Ret,
+        ];
+        assert_eq!(instrs_pretty(&instrs), "0 entry:\n1         mov rax, 5\n2         ret\n");
+        let pretty = instrs_pretty(&instrs);
+        let unnumbered: Vec<&str> = pretty.lines().map(|l| &l[2..]).collect();
+        assert_eq!(unnumbered, instrs_to_string(&instrs).lines().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn indices_are_right_aligned() {
+        let pretty = instrs_pretty(&vec![Instr::Cqo; 11]);
+        let lines: Vec<&str> = pretty.lines().collect();
+        assert_eq!(lines[0], " 0         cqo");
+        assert_eq!(lines[10], "10         cqo");
+        assert_eq!(instrs_pretty(&[]), "");
+    }
+
+    #[test]
+    fn directives_are_written_verbatim() {
+        let instrs = vec![
+            Instr::Label("entry".to_string()),
+            Instr::Directive(".cfi_def_cfa rsp, 8".to_string()),
+            Instr::Directive("align 16, db 0".to_string()),
+        ];
+        assert_eq!(
+            instrs_to_string(&instrs),
+            "entry:\n        .cfi_def_cfa rsp, 8\n        align 16, db 0\n"
+        );
+    }
+}
+
+/* ------------------------------- Save SSA ------------------------------- */
+mod save_ssa {
+    use std::process::Command;
+
+    #[test]
+    fn written_alongside_the_asm() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let path = tmp_dir.path().join("add1.ssa");
+        let out = Command::new(env!("CARGO_BIN_EXE_snake"))
+            .args(["examples/add1.adder", "-t", "asm", "--save-ssa"])
+            .arg(&path)
+            .output()
+            .unwrap();
+        assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+        assert!(String::from_utf8(out.stdout).unwrap().contains("global entry"));
+        let ssa = std::fs::read_to_string(&path).unwrap();
+        assert!(ssa.contains("fun entry"), "{}", ssa);
+    }
+
+    #[test]
+    fn unwritable_path_is_an_error() {
+        let out = Command::new(env!("CARGO_BIN_EXE_snake"))
+            .args(["examples/add1.adder", "-t", "asm", "--save-ssa", "no/such/dir/add1.ssa"])
+            .output()
+            .unwrap();
+        assert!(!out.status.success());
+        assert!(String::from_utf8_lossy(&out.stderr).contains("Error writing"));
+    }
+}
+
+/* --------------------------------- Check -------------------------------- */
+mod check {
+    use std::process::Command;
+
+    fn check(file: &str, args: &[&str]) -> std::process::Output {
+        Command::new(env!("CARGO_BIN_EXE_snake"))
+            .args(["--check", &format!("examples/{}", file)])
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    #[test]
+    fn valid_program_is_silent() {
+        let out = check("local_non_tail_call.cobra", &[]);
+        assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+        assert!(out.stdout.is_empty() && out.stderr.is_empty());
+    }
+
+    #[test]
+    fn unbound_variable_is_reported() {
+        let out = check("free.adder", &[]);
+        assert!(!out.status.success());
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(stderr.contains("variable \"z\" unbound"), "{}", stderr);
+    }
+
+    #[test]
+    fn resolves_whatever_the_target() {
+        for target in ["ast", "ssa", "exe"] {
+            let out = check("free.adder", &["-t", target, "-x", "0"]);
+            assert!(!out.status.success(), "{}", target);
+            assert!(out.stdout.is_empty(), "{}", target);
+            let stderr = String::from_utf8_lossy(&out.stderr);
+            assert!(stderr.contains("variable \"z\" unbound"), "{}: {}", target, stderr);
+        }
+    }
+}
+
+/* -------------------------- Wrapping Arithmetic ------------------------- */
+mod wrapping_arithmetic {
+    use super::*;
+    use snake::ssa::IntRepr;
+
+    // 3037000500 squared is just over i64::MAX
+    const BIG: &str = "3037000500";
+    const WRAPPED: &str = "-9223372036709301616";
+    const MAX: &str = "9223372036854775807";
+    const MIN: &str = "-9223372036854775808";
+
+    mk_test!(exe, "wrapping_mul.cobra", BIG, WRAPPED);
+    mk_frontend_test!(frontend, "wrapping_mul.cobra", BIG, WRAPPED);
+    mk_middle_end_test!(middle_end, "wrapping_mul.cobra", BIG, WRAPPED);
+    mk_frontend_test!(add1_frontend, "add1.adder", MAX, MIN);
+    mk_middle_end_test!(add1_middle_end, "add1.adder", MAX, MIN);
+
+    #[test]
+    fn interpreter_agrees_with_emulated_code() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/wrapping_mul.cobra")).unwrap();
+        for repr in [IntRepr::Untagged, IntRepr::Tagged] {
+            let x = if repr == IntRepr::Tagged { 2147483648 } else { 3037000500 };
+            let interp = interp::ssa::Interp::new().with_repr(repr).run(&ssa, x.to_string());
+            let mut emitter = backend::Emitter::default().with_repr(repr);
+            emitter.emit_prog(&ssa);
+            let emulated = asm::interp::Machine::new().run(&emitter.to_asm(), x, &mut Vec::new());
+            assert_eq!(interp.unwrap().to_string(), emulated.unwrap().to_string(), "{:?}", repr);
+        }
+    }
+}
+
+/* ---------------------------- Match Expressions --------------------------- */
+mod match_expr {
+    use super::*;
+    use snake::ssa::{IntRepr, Terminator};
+
+    mk_test!(exe, "match.cobra", "4", "104");
+    mk_test!(exe_let, "match_let.cobra", "7", "2070");
+    mk_frontend_test!(first_arm, "match.cobra", "0", "100");
+    mk_frontend_test!(duplicate_pattern, "match.cobra", "1", "101");
+    mk_frontend_test!(default, "match.cobra", "3", "6");
+    mk_frontend_test!(let_sparse, "match_let.cobra", "-1000", "1000");
+    mk_frontend_test!(let_both, "match_let.cobra", "7", "2070");
+    mk_middle_end_test!(first_arm_middle_end, "match.cobra", "0", "100");
+    mk_middle_end_test!(duplicate_pattern_middle_end, "match.cobra", "1", "101");
+    mk_middle_end_test!(last_arm_middle_end, "match.cobra", "4", "104");
+    mk_middle_end_test!(below_table_middle_end, "match.cobra", "-1", "-2");
+    mk_middle_end_test!(above_table_middle_end, "match.cobra", "5", "10");
+    mk_middle_end_test!(let_sparse_middle_end, "match_let.cobra", "123456789", "3000");
+    mk_middle_end_test!(let_dense_middle_end, "match_let.cobra", "6", "60");
+    mk_middle_end_test!(let_neither_middle_end, "match_let.cobra", "8", "0");
+    mk_fail_test!(missing_default, "match_no_default.cobra", "match has no catch-all \"_\" arm");
+
+    fn switches(file: &str) -> usize {
+        let (_, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", file))).unwrap();
+        ssa.blocks_recursive()
+            .filter(|block| matches!(block.body.terminator(), Terminator::Switch { .. }))
+            .count()
+    }
+
+    #[test]
+    fn dense_patterns_use_a_jump_table() {
+        assert_eq!(switches("match.cobra"), 1);
+        assert_eq!(switches("match_let.cobra"), 1);
+    }
+
+    #[test]
+    fn emulated_code_agrees_with_interpreter() {
+        for (file, inputs) in [
+            ("match.cobra", &[-1, 0, 1, 2, 3, 4, 5][..]),
+            ("match_let.cobra", &[-1000, 0, 5, 6, 7, 8, 123456789][..]),
+        ] {
+            let (_, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", file))).unwrap();
+            for repr in [IntRepr::Untagged, IntRepr::Tagged] {
+                let mut emitter = backend::Emitter::default().with_repr(repr);
+                emitter.emit_prog(&ssa);
+                let instrs = emitter.to_asm();
+                for &x in inputs {
+                    let expected =
+                        interp::ssa::Interp::new().with_repr(repr).run(&ssa, x.to_string());
+                    let emulated = asm::interp::Machine::new().run(&instrs, x, &mut Vec::new());
+                    assert_eq!(
+                        expected.unwrap().to_string(),
+                        emulated.unwrap().to_string(),
+                        "{} on {} ({:?})",
+                        file,
+                        x,
+                        repr
+                    );
+                }
+            }
+        }
+    }
+}
+
+/* -------------------------- Three-Way Comparison ------------------------- */
+mod three_way_cmp {
+    use super::*;
+    use snake::ssa::IntRepr;
+
+    mk_test!(less_exe, "cmp.cobra", "1", "-1");
+    mk_test!(equal_exe, "cmp.cobra", "2", "0");
+    mk_test!(greater_exe, "cmp.cobra", "3", "1");
+    mk_frontend_test!(less, "cmp.cobra", "1", "-1");
+    mk_frontend_test!(equal, "cmp.cobra", "2", "0");
+    mk_frontend_test!(greater, "cmp.cobra", "3", "1");
+    mk_middle_end_test!(less_middle_end, "cmp.cobra", "1", "-1");
+    mk_middle_end_test!(equal_middle_end, "cmp.cobra", "2", "0");
+    mk_middle_end_test!(greater_middle_end, "cmp.cobra", "3", "1");
+
+    #[test]
+    fn emulated_code_agrees_with_interpreter() {
+        let (_, ssa) = runner::emit_ssa(Path::new("examples/cmp.cobra")).unwrap();
+        for repr in [IntRepr::Untagged, IntRepr::Tagged] {
+            let mut emitter = backend::Emitter::default().with_repr(repr);
+            emitter.emit_prog(&ssa);
+            let instrs = emitter.to_asm();
+            for (x, expected) in [(1, "-1"), (2, "0"), (3, "1")] {
+                let interp = interp::ssa::Interp::new().with_repr(repr).run(&ssa, x.to_string());
+                let emulated = asm::interp::Machine::new().run(&instrs, x, &mut Vec::new());
+                assert_eq!(interp.unwrap().to_string(), expected, "{} ({:?})", x, repr);
+                assert_eq!(emulated.unwrap().to_string(), expected, "{} ({:?})", x, repr);
+            }
+        }
+    }
+
+    #[test]
+    fn constant_comparisons_fold() {
+        for (src, expected) in [("cmp(1, 2)", "-1"), ("cmp(2, 2)", "0"), ("cmp(3, 2)", "1")] {
+            let prog = snake::parser::ProgParser::new()
+                .parse(&format!("def main(x): {}", src))
+                .unwrap();
+            assert_eq!(snake::fold::fold_prog(prog).body.to_string(), expected, "{}", src);
+        }
+    }
+
+    #[test]
+    fn pretty_prints_both_arguments() {
+        let prog = snake::parser::ProgParser::new().parse("def main(x): cmp(x + 1, 2)").unwrap();
+        assert_eq!(prog.body.to_string(), "cmp((x + 1), 2)");
+    }
+}
+
+/* --------------------------- Example Discovery -------------------------- */
+mod all_examples {
+    use super::*;
+    use snake::compile::{self, Error};
+    use std::path::PathBuf;
+
+    /// Every file under `dir`, in its subdirectories too.
+    fn files_in(dir: &Path) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                files.extend(files_in(&path));
+            } else {
+                files.push(path);
+            }
+        }
+        files.sort();
+        files
+    }
+
+    /// Whether the tools linking needs are installed.
+    fn can_link() -> bool {
+        ["nasm", "ar", "rustc"].iter().all(|tool| {
+            std::process::Command::new(tool).arg("--version").output().is_ok()
+        })
+    }
+
+    /// The examples that are meant not to parse or resolve.
+    const REJECTED: [&str; 20] = [
+        "control-flow-examples/arity_mismatch.boa",
+        "control-flow-examples/conditionals.boa",
+        "control-flow-examples/free.boa",
+        "control-flow-examples/fun_dupe.boa",
+        "control-flow-examples/let_dupe.boa",
+        "control-flow-examples/param_dupe.boa",
+        "control-flow-examples/var_dupe.boa",
+        "duplicate_corecursive_fun.cobra",
+        "extern_arity_mismatch.cobra",
+        "extern_param_dupe.cobra",
+        "free.adder",
+        "if_fun_escapes.cobra",
+        "inner_fun_escapes.cobra",
+        "let_dupe.adder",
+        "local_arity_mismatch.cobra",
+        "match_no_default.cobra",
+        "sibling_branch_fun.cobra",
+        "top_level_uses_main_param.cobra",
+        "unbound_local_fun.cobra",
+        "unbound_print.cobra",
+    ];
+
+    /// The examples in `REJECTED` must fail to parse or resolve, and every
+    /// other one must compile. Anything else going wrong, like a panic, is
+    /// an internal error.
+    #[test]
+    fn every_example_compiles_or_is_rejected() {
+        let link = can_link();
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let mut compiled = 0;
+        for path in files_in(Path::new("examples")) {
+            let src = std::fs::read_to_string(&path).unwrap();
+            let res = std::panic::catch_unwind(|| {
+                let (resolver, ast) = compile::frontend(&src)?;
+                let (lowerer, ssa) = compile::middle_end(resolver, ast)?;
+                Ok::<_, Error>(compile::backend(lowerer, ssa))
+            });
+            let rejected = REJECTED.iter().any(|file| path == Path::new("examples").join(file));
+            let asm = match res {
+                Ok(Ok(_)) if rejected => panic!("{} should be rejected", path.display()),
+                Ok(Ok(asm)) => asm,
+                Ok(Err(Error::Parse(_) | Error::Resolve(..))) if rejected => continue,
+                Ok(Err(e)) => panic!("{} failed to compile: {}", path.display(), e),
+                Err(_) => panic!("compiling {} panicked", path.display()),
+            };
+            if link {
+                let exe = tmp_dir.path().join("main.exe");
+                let rt = Path::new("runtime/stub.rs");
+                runner::link(&asm, rt, tmp_dir.path(), &exe, false)
+                    .unwrap_or_else(|e| panic!("{} failed to link: {}", path.display(), e));
+            }
+            compiled += 1;
+        }
+        assert!(compiled >= 20, "only {} examples compiled", compiled);
+    }
+}
+
+/*
+ * YOUR TESTS END HERE
+ */
+
+/* ----------------------- Test Implementation Details ---------------------- */
+
+use snake::{asm, backend, interp, runner};
+use std::path::Path;
+
+#[path = "../runtime/heap.rs"]
+mod heap;
+
+/// Field `i` of the heap object the tagged pointer `ptr` points to.
+fn field(ptr: i64, i: usize) -> i64 {
+    unsafe { *((ptr & !1) as *const i64).add(1 + i) }
+}
+
+fn set_field(ptr: i64, i: usize, val: i64) {
+    unsafe { *((ptr & !1) as *mut i64).add(1 + i) = val }
+}
+
+/// The assembly emitted for an example file.
+fn emitted_asm(f: &str) -> String {
+    let (lowerer, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", f)))
+        .unwrap_or_else(|e| panic!("failed to compile {}: {}", f, e));
+    let mut emitter = backend::Emitter::from(lowerer);
+    emitter.emit_prog(&ssa);
+    asm::instrs_to_string(&emitter.to_asm())
+}
+
+/// The labels of the assembly emitted for an example file, in order.
+fn emitted_labels(f: &str) -> Vec<String> {
+    let (lowerer, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", f)))
+        .unwrap_or_else(|e| panic!("failed to compile {}: {}", f, e));
+    let mut emitter = backend::Emitter::from(lowerer);
+    emitter.emit_prog(&ssa);
+    emitter
+        .to_asm()
+        .into_iter()
+        .filter_map(|i| match i {
+            asm::Instr::Label(l) => Some(l),
+            _ => None,
+        })
+        .collect()
+}
+
+fn test_example_file(f: &str, arg: &str, expected: &str) -> std::io::Result<()> {
+    let tmp_dir = tempfile::TempDir::new()?;
+    let mut buf = Vec::new();
+    match runner::compile_and_run_file(
+        &Path::new(&format!("examples/{}", f)),
+        tmp_dir.path(),
+        arg,
+        &mut buf,
+    ) {
+        Ok(()) => {
+            assert_eq!(String::from_utf8_lossy(&buf).trim(), expected.trim())
+        }
+        Err(e) => {
+            assert!(false, "Expected {}, got an error: {}", expected, e)
+        }
+    }
+    Ok(())
+}
+
+/// Like `test_example_file`, but feeds `input` to the executable on stdin.
+fn test_example_with_input(f: &str, arg: &str, input: &str, expected: &str) -> std::io::Result<()> {
+    let tmp_dir = tempfile::TempDir::new()?;
+    let mut buf = Vec::new();
+    let exe = tmp_dir.path().join("main.exe");
+    let rt = Path::new("runtime/stub.rs");
+    let res = runner::link(&emitted_asm(f), rt, tmp_dir.path(), &exe, false)
+        .and_then(|()| runner::run_with_input(&exe, arg, input, &mut buf));
+    match res {
+        Ok(()) => assert_eq!(String::from_utf8_lossy(&buf).trim(), expected),
+        Err(e) => panic!("Expected {}, got an error: {}", expected, e),
+    }
+    Ok(())
+}
+
+#[allow(unused)]
+fn test_example_frontend(f: &str, arg: &str, expected: &str) -> std::io::Result<()> {
+    let res = runner::emit_ast(&Path::new(&format!("examples/{}", f)))
+        .map_err(|e| e.to_string())
+        .and_then(|(_, ast)| {
+            interp::ast::Machine::run_prog(&ast, arg.to_string()).map_err(|e| format!("{}", e))
+        });
+    match res {
+        Ok(v) => assert_eq!(v.to_string(), expected),
+        Err(e) => assert!(false, "Expected {}, got an error: {}", expected, e),
+    }
+    Ok(())
+}
+
+#[allow(unused)]
+fn test_example_middle_end(f: &str, arg: &str, expected: &str) -> std::io::Result<()> {
+    let res = runner::emit_ssa(&Path::new(&format!("examples/{}", f)))
+        .map_err(|e| e.to_string())
+        .and_then(|(_, ssa)| {
+            let mut interp = interp::ssa::Interp::new();
+            interp.run(&ssa, arg.to_string()).map_err(|e| format!("{}", e))
+        });
+    match res {
+        Ok(v) => assert_eq!(v.to_string(), expected),
+        Err(e) => assert!(false, "Expected {}, got an error: {}", expected, e),
+    }
+    Ok(())
+}
+
+/// Names for building SSA programs by hand in tests.
+struct SsaNames {
+    vars: snake::identifiers::IdGen<snake::identifiers::VarName>,
+    blocks: snake::identifiers::IdGen<snake::identifiers::BlockName>,
+}
+
+/// The SSA program whose entry branches to a single block `main(x)`, with
+/// the body of `main` built by `build` from its parameter `x`.
+fn main_block_program(
+    build: impl FnOnce(&mut SsaNames, snake::identifiers::VarName) -> snake::ssa::BlockBody,
+) -> snake::ssa::Program {
+    use snake::identifiers::*;
+    use snake::ssa::*;
+    let mut names = SsaNames { vars: IdGen::new(), blocks: IdGen::new() };
+    let (arg, param) = (names.vars.fresh("arg"), names.vars.fresh("x"));
+    let label = names.blocks.fresh("main");
+    let body = build(&mut names, param.clone());
+    Program {
+        externs: Vec::new(),
+        funs: vec![FunBlock {
+            name: FunName::unmangled("entry"),
+            params: vec![arg.clone()],
+            body: Branch { target: label.clone(), args: vec![Immediate::Var(arg)] },
+        }],
+        blocks: vec![BasicBlock { label, params: vec![param], body }],
+        locs: Default::default(),
+    }
+}
+
+/// Run the program `main_block_program(build)` on `x`.
+fn run_main_block(
+    x: i64,
+    build: impl FnOnce(&mut SsaNames, snake::identifiers::VarName) -> snake::ssa::BlockBody,
+) -> Result<String, String> {
+    interp::ssa::Interp::new()
+        .run(&main_block_program(build), x.to_string())
+        .map(|v| v.to_string())
+        .map_err(|e| format!("{}", e))
+}
+
+/// Run the SSA program `entry(x) = x <prim> c` in the interpreter.
+fn run_prim2(prim: snake::ssa::Prim2, x: i64, c: i64) -> String {
+    use snake::ssa::*;
+    run_main_block(x, |names, x| {
+        let res = names.vars.fresh("res");
+        BlockBody::Operation {
+            dest: res.clone(),
+            op: Operation::Prim2(prim, Immediate::Var(x), Immediate::Const(c)),
+            next: Box::new(BlockBody::Terminator(Terminator::Return(Immediate::Var(res)))),
+        }
+    })
+    .unwrap_or_else(|e| panic!("interpreter error: {}", e))
+}
+
+#[allow(unused)]
+fn test_example_tagged(
+    f: &str, inputs: impl IntoIterator<Item = i64>,
+) -> std::io::Result<()> {
+    use snake::ssa::IntRepr;
+    let (_, ast) = runner::emit_ast(Path::new(&format!("examples/{}", f)))
+        .unwrap_or_else(|e| panic!("failed to compile {}: {}", f, e));
+    let (lowerer, ssa) = runner::emit_ssa(Path::new(&format!("examples/{}", f)))
+        .unwrap_or_else(|e| panic!("failed to compile {}: {}", f, e));
+    let mut emitter = backend::Emitter::from(lowerer).with_repr(IntRepr::Tagged);
+    emitter.emit_prog(&ssa);
+    let asm = emitter.to_asm();
+    for arg in inputs {
+        let run = |repr| {
+            interp::ssa::Interp::new()
+                .with_repr(repr)
+                .run(&ssa, arg.to_string())
+                .map(|v| v.to_string())
+                .map_err(|e| format!("{}", e))
+        };
+        let expected = run(IntRepr::Untagged);
+        assert_eq!(run(IntRepr::Tagged), expected, "on input {}", arg);
+        let from_ast = interp::ast::Interp::new()
+            .with_repr(IntRepr::Tagged)
+            .run(&ast, arg.to_string())
+            .map(|v| v.to_string())
+            .map_err(|e| format!("{}", e));
+        assert_eq!(from_ast.is_ok(), expected.is_ok(), "on input {}", arg);
+        if from_ast.is_ok() {
+            assert_eq!(from_ast, expected, "ast on input {}", arg);
+        }
+        let emulated = asm::interp::Machine::new()
+            .run(&asm, arg, &mut Vec::new())
+            .map(|v| v.to_string())
+            .map_err(|e| format!("{}", e));
+        assert_eq!(emulated.is_ok(), expected.is_ok(), "on input {}", arg);
+        if emulated.is_ok() {
+            assert_eq!(emulated, expected, "emulated on input {}", arg);
+        }
+    }
+    Ok(())
+}
+
+/// Like `test_example_file`, but expects whatever the SSA interpreter
+/// gives, so that the compiled code is checked against it. Only the
+/// value it returns is expected, so the example may not print.
+#[allow(unused)]
+fn test_example_consistency(f: &str, arg: &str) -> std::io::Result<()> {
+    let expected = runner::emit_ssa(Path::new(&format!("examples/{}", f)))
+        .map_err(|e| e.to_string())
+        .and_then(|(_, ssa)| {
+            let mut interp = interp::ssa::Interp::new();
+            interp.run(&ssa, arg.to_string()).map_err(|e| format!("{}", e))
+        });
+    match expected {
+        Ok(v) => test_example_file(f, arg, &v.to_string()),
+        Err(e) => panic!("The SSA interpreter failed on {}: {}", f, e),
+    }
+}
+
+fn test_example_fail(f: &str, includes: &str) -> std::io::Result<()> {
+    let tmp_dir = tempfile::TempDir::new()?;
+    let mut buf = Vec::new();
+    match runner::compile_and_run_file(
+        &Path::new(&format!("examples/{}", f)),
+        tmp_dir.path(),
+        "0",
+        &mut buf,
+    ) {
+        Ok(()) => {
+            assert!(false, "Expected a failure but got: {}", String::from_utf8_lossy(&buf).trim())
+        }
+        Err(e) => {
+            let msg = format!("{}", e);
+            assert!(
+                msg.contains(includes),
+                "Expected error message to include the string \"{}\" but got the error: {}",
+                includes,
+                msg
+            )
+        }
+    }
+    Ok(())
+}
+
+/// `big_fun_nine` and `big_fun_ten` as `runtime/stub.rs` defines them: print
+/// each argument on a line of its own, then return their sum.
+fn big_fun(args: &[i64], out: &mut dyn std::io::Write) -> std::io::Result<i64> {
+    for (i, x) in args.iter().enumerate() {
+        writeln!(out, "x{}: {}", i + 1, x)?;
+    }
+    Ok(args.iter().sum())
 }